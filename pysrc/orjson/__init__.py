@@ -6,23 +6,87 @@ from .orjson import __version__
 
 __all__ = (
     "__version__",
+    "Decoder",
     "dumps",
+    "dumps_chunked",
+    "dumps_into_buffer",
+    "Encoder",
+    "EpochNanoseconds",
     "Fragment",
     "JSONDecodeError",
     "JSONEncodeError",
+    "load",
     "loads",
+    "OPT_ANNOTATED_MISSING_AS_NULL",
     "OPT_APPEND_NEWLINE",
+    "OPT_BYTES_AS_UUID",
+    "OPT_CACHE_KEYS",
+    "OPT_CANONICAL",
+    "OPT_DEDUPLICATE_ARRAYS",
+    "OPT_DEFAULT_FIRST",
+    "OPT_ENSURE_ASCII",
+    "OPT_ENUM_FULL",
+    "OPT_ENUM_REPR",
+    "OPT_ESCAPE_HTML",
+    "OPT_FLAG_AS_INT",
+    "OPT_FLOAT_TRAILING_ZERO",
+    "OPT_FRACTION_AS_FLOAT",
     "OPT_INDENT_2",
+    "OPT_INT_BASE62",
+    "OPT_LENGTH_TAGGED",
+    "OPT_NAIVE_AS_NULL",
     "OPT_NAIVE_UTC",
+    "OPT_NAMEDTUPLE_AS_OBJECT",
+    "OPT_NONFINITE_AS_STRING",
     "OPT_NON_STR_KEYS",
+    "OPT_NORMALIZE_UTC",
+    "OPT_NUMPY_BASE64",
+    "OPT_NUMPY_SHORTEST_FLOAT",
+    "OPT_OFFSET_NO_COLON",
     "OPT_OMIT_MICROSECONDS",
+    "OPT_OMIT_NONE",
+    "OPT_OMIT_TOP_LEVEL_NONE",
+    "OPT_PAD_NANOSECONDS",
+    "OPT_PARSE_NUMBER_AS_STRING",
+    "OPT_PARSE_OBJECT_PAIRS",
     "OPT_PASSTHROUGH_DATACLASS",
     "OPT_PASSTHROUGH_DATETIME",
+    "OPT_PASSTHROUGH_ENUM",
+    "OPT_PASSTHROUGH_NON_FINITE",
     "OPT_PASSTHROUGH_SUBCLASS",
+    "OPT_PYDANTIC_EXCLUDE_UNSET",
+    "OPT_RANGE_AS_ARRAY",
+    "OPT_SERIALIZE_ANNOTATED",
+    "OPT_SERIALIZE_BYTES",
+    "OPT_SERIALIZE_BYTES_GZIP",
     "OPT_SERIALIZE_DATACLASS",
+    "OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET",
+    "OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR",
+    "OPT_SERIALIZE_DATETIME_AS_UNIX",
+    "OPT_SERIALIZE_FRACTIONS",
+    "OPT_SERIALIZE_ITERATORS",
+    "OPT_SERIALIZE_JSON_METHOD",
+    "OPT_SERIALIZE_MAPPING",
     "OPT_SERIALIZE_NUMPY",
+    "OPT_SERIALIZE_ORJSON_DEFAULT",
+    "OPT_SERIALIZE_PYDANTIC",
+    "OPT_SERIALIZE_RANGE",
+    "OPT_SERIALIZE_TIMEDELTA",
     "OPT_SERIALIZE_UUID",
+    "OPT_SERIALIZE_WEAKREF",
+    "OPT_SORT_DATACLASS_FIELDS",
     "OPT_SORT_KEYS",
+    "OPT_SORT_KEYS_BY_HASH",
+    "OPT_SORT_KEYS_BY_VALUE",
+    "OPT_STRICT_INPUT",
     "OPT_STRICT_INTEGER",
+    "OPT_TIMEDELTA_ISO8601",
+    "OPT_UNIQUE_KEYS",
+    "OPT_UNIX_MILLISECONDS",
     "OPT_UTC_Z",
+    "OPT_UUID_BRACED",
+    "OPT_UUID_HEX",
+    "OPT_UUID_URN",
+    "OPT_ZONE_NAME",
+    "qualname",
 )
@@ -3,34 +3,213 @@
 
 import json
 from collections.abc import Callable
-from typing import Any
+from typing import Any, Literal, overload
 
 __version__: str
 
+@overload
 def dumps(
     __obj: Any,
     default: Callable[[Any], Any] | None = ...,
     option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[False] = ...,
+    max_sequence_len: int | None = ...,
 ) -> bytes: ...
-def loads(__obj: bytes | bytearray | memoryview | str) -> Any: ...
+@overload
+def dumps(
+    __obj: Any,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[True],
+    max_sequence_len: int | None = ...,
+) -> None: ...
+@overload
+def dumps_chunked(
+    __obj: Any,
+    __chunk_size: int,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[False] = ...,
+    max_sequence_len: int | None = ...,
+) -> list[bytes]: ...
+@overload
+def dumps_chunked(
+    __obj: Any,
+    __chunk_size: int,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[True],
+    max_sequence_len: int | None = ...,
+) -> None: ...
+@overload
+def dumps_into_buffer(
+    __obj: Any,
+    __buffer: Any,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[False] = ...,
+    max_sequence_len: int | None = ...,
+) -> int: ...
+@overload
+def dumps_into_buffer(
+    __obj: Any,
+    __buffer: Any,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+    *,
+    indent: int | None = ...,
+    key_delimiter: str | None = ...,
+    float_sigfigs: int | None = ...,
+    max_output_size: int | None = ...,
+    key_transform: Callable[[str], str] | None = ...,
+    validate_only: Literal[True],
+    max_sequence_len: int | None = ...,
+) -> None: ...
+def load(
+    __fp: Any,
+    option: int | None = ...,
+) -> Any: ...
+def loads(
+    __obj: bytes | bytearray | memoryview | str,
+    option: int | None = ...,
+) -> Any: ...
+def qualname(__obj: Any) -> str: ...
+
+class JSONDecodeError(json.JSONDecodeError):
+    code: int
+    byte_pos: int
+
+class JSONEncodeError(TypeError):
+    path: list[str | int]
+
+class Encoder:
+    def __init__(
+        self,
+        default: Callable[[Any], Any] | None = ...,
+        option: int | None = ...,
+        *,
+        indent: int | None = ...,
+        key_delimiter: str | None = ...,
+        float_sigfigs: int | None = ...,
+        max_output_size: int | None = ...,
+        datetime_formats: dict[type, str] | None = ...,
+        key_transform: Callable[[str], str] | None = ...,
+        validate_only: bool = ...,
+        max_sequence_len: int | None = ...,
+        type_handlers: dict[type, Callable[[Any], Any]] | None = ...,
+        field_schemas: dict[type, dict[str, set[str] | frozenset[str]]] | None = ...,
+    ) -> None: ...
+    def encode(self, __obj: Any) -> bytes | None: ...
+    def encode_compact(self, __obj: Any) -> bytes | None: ...
+
+class Decoder:
+    def __init__(self, option: int | None = ...) -> None: ...
+    def decode(self, __obj: bytes | bytearray | memoryview | str) -> Any: ...
 
-class JSONDecodeError(json.JSONDecodeError): ...
-class JSONEncodeError(TypeError): ...
+class EpochNanoseconds:
+    def __init__(self, __value: int) -> None: ...
 
 class Fragment(tuple):
     contents: bytes | str
 
+OPT_ANNOTATED_MISSING_AS_NULL: int
 OPT_APPEND_NEWLINE: int
+OPT_BYTES_AS_UUID: int
+OPT_CACHE_KEYS: int
+OPT_CANONICAL: int
+OPT_DEDUPLICATE_ARRAYS: int
+OPT_DEFAULT_FIRST: int
+OPT_ENSURE_ASCII: int
+OPT_ENUM_FULL: int
+OPT_ENUM_REPR: int
+OPT_ESCAPE_HTML: int
+OPT_FLAG_AS_INT: int
+OPT_FLOAT_TRAILING_ZERO: int
+OPT_FRACTION_AS_FLOAT: int
 OPT_INDENT_2: int
+OPT_INT_BASE62: int
+OPT_LENGTH_TAGGED: int
+OPT_NAIVE_AS_NULL: int
 OPT_NAIVE_UTC: int
+OPT_NAMEDTUPLE_AS_OBJECT: int
+OPT_NONFINITE_AS_STRING: int
 OPT_NON_STR_KEYS: int
+OPT_NORMALIZE_UTC: int
+OPT_NUMPY_BASE64: int
+OPT_NUMPY_SHORTEST_FLOAT: int
+OPT_OFFSET_NO_COLON: int
 OPT_OMIT_MICROSECONDS: int
+OPT_OMIT_NONE: int
+OPT_OMIT_TOP_LEVEL_NONE: int
+OPT_PAD_NANOSECONDS: int
+OPT_PARSE_NUMBER_AS_STRING: int
+OPT_PARSE_OBJECT_PAIRS: int
 OPT_PASSTHROUGH_DATACLASS: int
 OPT_PASSTHROUGH_DATETIME: int
+OPT_PASSTHROUGH_ENUM: int
+OPT_PASSTHROUGH_NON_FINITE: int
 OPT_PASSTHROUGH_SUBCLASS: int
+OPT_PYDANTIC_EXCLUDE_UNSET: int
+OPT_RANGE_AS_ARRAY: int
+OPT_SERIALIZE_ANNOTATED: int
+OPT_SERIALIZE_BYTES: int
+OPT_SERIALIZE_BYTES_GZIP: int
 OPT_SERIALIZE_DATACLASS: int
+OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET: int
+OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR: int
+OPT_SERIALIZE_DATETIME_AS_UNIX: int
+OPT_SERIALIZE_FRACTIONS: int
+OPT_SERIALIZE_ITERATORS: int
+OPT_SERIALIZE_JSON_METHOD: int
+OPT_SERIALIZE_MAPPING: int
 OPT_SERIALIZE_NUMPY: int
+OPT_SERIALIZE_ORJSON_DEFAULT: int
+OPT_SERIALIZE_PYDANTIC: int
+OPT_SERIALIZE_RANGE: int
+OPT_SERIALIZE_TIMEDELTA: int
 OPT_SERIALIZE_UUID: int
+OPT_SERIALIZE_WEAKREF: int
+OPT_SORT_DATACLASS_FIELDS: int
 OPT_SORT_KEYS: int
+OPT_SORT_KEYS_BY_HASH: int
+OPT_SORT_KEYS_BY_VALUE: int
+OPT_STRICT_INPUT: int
 OPT_STRICT_INTEGER: int
+OPT_TIMEDELTA_ISO8601: int
+OPT_UNIQUE_KEYS: int
+OPT_UNIX_MILLISECONDS: int
 OPT_UTC_Z: int
+OPT_UUID_BRACED: int
+OPT_UUID_HEX: int
+OPT_UUID_URN: int
+OPT_ZONE_NAME: int
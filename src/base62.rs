@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+// Base-62 integers for OPT_INT_BASE62, using the digits 0-9A-Za-z after
+// the prefix `~` so that an encoding is distinct from other strings.
+
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+const PREFIX: u8 = b'~';
+
+// https://tools.ietf.org/html/rfc7159#section-6
+// integers of greater magnitude than "(2**53)-1" are encoded
+const BASE62_THRESHOLD: u64 = 9007199254740991;
+
+/// The length of the longest encoding, `~-` and 11 digits for `i64::MIN`.
+pub(crate) const BASE62_MAX_LEN: usize = 13;
+
+/// The number of digits of the shortest encoding above `BASE62_THRESHOLD`.
+const MIN_DIGITS: usize = 9;
+
+pub(crate) enum Base62Int {
+    Negative(i64),
+    Positive(u64),
+}
+
+/// Write `magnitude`, negated if `negative`, to the end of `buf` and return
+/// the index of the first byte written.
+pub(crate) fn encode(negative: bool, mut magnitude: u64, buf: &mut [u8; BASE62_MAX_LEN]) -> usize {
+    let mut idx = BASE62_MAX_LEN;
+    loop {
+        idx -= 1;
+        buf[idx] = DIGITS[(magnitude % 62) as usize];
+        magnitude /= 62;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if negative {
+        idx -= 1;
+        buf[idx] = b'-';
+    }
+    idx -= 1;
+    buf[idx] = PREFIX;
+    idx
+}
+
+/// Decode what `encode()` writes for an integer of greater magnitude than
+/// `BASE62_THRESHOLD`. Anything else, including strings without the prefix
+/// and encodings with leading zeros or of smaller integers, is not decoded.
+pub(crate) fn decode(value: &str) -> Option<Base62Int> {
+    let (negative, digits) = match value.as_bytes() {
+        [PREFIX, b'-', rest @ ..] => (true, rest),
+        [PREFIX, rest @ ..] => (false, rest),
+        _ => return None,
+    };
+    if !(MIN_DIGITS..BASE62_MAX_LEN - 1).contains(&digits.len()) || digits[0] == b'0' {
+        return None;
+    }
+    let mut magnitude: u64 = 0;
+    for &each in digits {
+        let digit = match each {
+            b'0'..=b'9' => each - b'0',
+            b'A'..=b'Z' => each - b'A' + 10,
+            b'a'..=b'z' => each - b'a' + 36,
+            _ => return None,
+        };
+        magnitude = magnitude.checked_mul(62)?.checked_add(u64::from(digit))?;
+    }
+    if magnitude <= BASE62_THRESHOLD {
+        None
+    } else if !negative {
+        Some(Base62Int::Positive(magnitude))
+    } else if magnitude <= i64::MIN.unsigned_abs() {
+        Some(Base62Int::Negative(magnitude.cast_signed().wrapping_neg()))
+    } else {
+        None
+    }
+}
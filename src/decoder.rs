@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use core::ptr::{NonNull, null_mut};
+
+use crate::deserialize::deserialize;
+use crate::exception::{raise_loads_exception, raise_loads_exception_fixed};
+use crate::ffi::{
+    METH_O, Py_TPFLAGS_DEFAULT, PyDict_GetItemWithError, PyDict_Size, PyMethodDef,
+    PyMethodDefPointer, PyObject, PyTupleRef, PyType_Ready, PyType_Type, PyTypeObject, PyVarObject,
+};
+use crate::loads_opts_from_arg;
+use crate::opt::{HighOpt, Opt};
+use crate::typeref::OPTION;
+
+#[cfg(Py_GIL_DISABLED)]
+use crate::ffi::AtomicCULong;
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+/// `orjson.Decoder`, which holds the arguments of `loads()` other than the
+/// input so that they are converted once rather than on every call. It is
+/// immutable after it is created.
+#[repr(C)]
+pub(crate) struct Decoder {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    pub opts: Opt,
+    pub high_opts: HighOpt,
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decoder_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let argsob = PyTupleRef::from_ptr_unchecked(args);
+        let num_args = argsob.len();
+        if num_args > 1 {
+            return raise_loads_exception_fixed(
+                "orjson.Decoder() takes at most 1 positional argument",
+            );
+        }
+
+        let mut optsptr = null_mut();
+        if !kwds.is_null() {
+            optsptr = PyDict_GetItemWithError(kwds, OPTION);
+            let found = usize::from(!optsptr.is_null());
+            if crate::util::isize_to_usize(PyDict_Size(kwds)) != found {
+                return raise_loads_exception_fixed(
+                    "orjson.Decoder() got an unexpected keyword argument",
+                );
+            }
+        }
+        if num_args == 1 {
+            if !optsptr.is_null() {
+                return raise_loads_exception_fixed(
+                    "orjson.Decoder() got multiple values for argument: 'option'",
+                );
+            }
+            optsptr = argsob.get(0);
+        }
+
+        let mut opts = 0 as Opt;
+        let mut high_opts = 0 as HighOpt;
+        if let Some(tmp) = NonNull::new(optsptr) {
+            match loads_opts_from_arg(tmp) {
+                Ok(val) => (opts, high_opts) = val,
+                Err(()) => return raise_loads_exception_fixed("Invalid opts"),
+            }
+        }
+
+        let obj = Box::new(Decoder {
+            #[cfg(Py_GIL_DISABLED)]
+            ob_tid: 0,
+            #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+            ob_flags: 0,
+            #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+            _padding: 0,
+            #[cfg(Py_GIL_DISABLED)]
+            ob_mutex: core::mem::zeroed(),
+            #[cfg(Py_GIL_DISABLED)]
+            ob_gc_bits: 0,
+            #[cfg(Py_GIL_DISABLED)]
+            ob_ref_local: AtomicU32::new(0),
+            #[cfg(Py_GIL_DISABLED)]
+            ob_ref_shared: AtomicIsize::new(0),
+            #[cfg(not(Py_GIL_DISABLED))]
+            ob_refcnt: 1,
+            #[cfg(PyPy)]
+            ob_pypy_link: 0,
+            ob_type: crate::typeref::DECODER_TYPE,
+            opts: opts,
+            high_opts: high_opts,
+        });
+        Box::into_raw(obj).cast::<PyObject>()
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decoder_dealloc(object: *mut PyObject) {
+    unsafe {
+        crate::ffi::PyMem_Free(object.cast::<core::ffi::c_void>());
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_decoder_decode(
+    slf: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let decoder = &*slf.cast::<Decoder>();
+        deserialize(obj, decoder.opts, decoder.high_opts)
+            .map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decodertype_new() -> *mut PyTypeObject {
+    unsafe {
+        let methods = Box::new([
+            PyMethodDef {
+                ml_name: c"decode".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_decoder_decode,
+                },
+                ml_flags: METH_O,
+                ml_doc: c"decode($self, obj, /)\n--\n\nDeserialize JSON to Python objects."
+                    .as_ptr(),
+            },
+            PyMethodDef::zeroed(),
+        ]);
+
+        #[cfg(Py_GIL_DISABLED)]
+        let tp_flags: AtomicCULong =
+            AtomicCULong::new(Py_TPFLAGS_DEFAULT | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE);
+        #[cfg(not(Py_GIL_DISABLED))]
+        let tp_flags: core::ffi::c_ulong = Py_TPFLAGS_DEFAULT | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE;
+        let ob = Box::new(PyTypeObject {
+            ob_base: PyVarObject {
+                ob_base: PyObject {
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_tid: 0,
+                    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+                    ob_flags: 0,
+                    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+                    _padding: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_mutex: core::mem::zeroed(),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_gc_bits: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_local: AtomicU32::new(crate::ffi::compat::_Py_IMMORTAL_REFCNT_LOCAL),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_shared: AtomicIsize::new(0),
+                    #[cfg(all(Py_3_12, not(Py_GIL_DISABLED)))]
+                    ob_refcnt: pyo3_ffi::PyObjectObRefcnt { ob_refcnt: 0 },
+                    #[cfg(not(Py_3_12))]
+                    ob_refcnt: 0,
+                    #[cfg(PyPy)]
+                    ob_pypy_link: 0,
+                    ob_type: &raw mut PyType_Type,
+                },
+                #[cfg(not(GraalPy))]
+                ob_size: 0,
+                #[cfg(GraalPy)]
+                _ob_size_graalpy: 0,
+            },
+            tp_name: c"orjson.Decoder".as_ptr(),
+            tp_basicsize: core::mem::size_of::<Decoder>().cast_signed(),
+            tp_itemsize: 0,
+            tp_dealloc: Some(orjson_decoder_dealloc),
+            tp_init: None,
+            tp_new: Some(orjson_decoder_tp_new),
+            tp_flags: tp_flags,
+            // ...
+            tp_bases: null_mut(),
+            tp_cache: null_mut(),
+            tp_del: None,
+            tp_finalize: None,
+            tp_free: None,
+            tp_is_gc: None,
+            tp_mro: null_mut(),
+            tp_subclasses: null_mut(),
+            tp_vectorcall: None,
+            tp_version_tag: 0,
+            tp_weaklist: null_mut(),
+            tp_vectorcall_offset: 0,
+            tp_getattr: None,
+            tp_setattr: None,
+            tp_as_async: null_mut(),
+            tp_repr: None,
+            tp_as_number: null_mut(),
+            tp_as_sequence: null_mut(),
+            tp_as_mapping: null_mut(),
+            tp_hash: None,
+            tp_call: None,
+            tp_str: None,
+            tp_getattro: None,
+            tp_setattro: None,
+            tp_as_buffer: null_mut(),
+            tp_doc: c"Decoder(option=None)\n--\n\nDeserialize JSON to Python objects with fixed arguments.".as_ptr(),
+            tp_traverse: None,
+            tp_clear: None,
+            tp_richcompare: None,
+            tp_weaklistoffset: 0,
+            tp_iter: None,
+            tp_iternext: None,
+            tp_methods: Box::into_raw(methods).cast::<PyMethodDef>(),
+            tp_members: null_mut(),
+            tp_getset: null_mut(),
+            tp_base: null_mut(),
+            tp_dict: null_mut(),
+            tp_descr_get: None,
+            tp_descr_set: None,
+            tp_dictoffset: 0,
+            tp_alloc: None,
+            #[cfg(Py_3_12)]
+            tp_watched: 0,
+        });
+        let ob_ptr = Box::into_raw(ob);
+        PyType_Ready(ob_ptr);
+        ob_ptr
+    }
+}
@@ -5,9 +5,15 @@ use super::ffi::{
     YYJSON_READ_SUCCESS, yyjson_alc, yyjson_alc_pool_init, yyjson_doc, yyjson_read_err,
     yyjson_read_opts, yyjson_val,
 };
-use crate::deserialize::DeserializeError;
-use crate::deserialize::pyobject::get_unicode_key;
-use crate::ffi::{PyBoolRef, PyDictRef, PyFloatRef, PyIntRef, PyListRef, PyNoneRef, PyStrRef};
+use crate::base62::{Base62Int, decode};
+use crate::deserialize::pyobject::{KeyCache, get_unicode_key};
+use crate::deserialize::{DeserializeError, MEMORY_ALLOCATION};
+use crate::ffi::{
+    PyBoolRef, PyDictRef, PyFloatRef, PyIntRef, PyListRef, PyNoneRef, PyStrRef, PyTupleRef,
+};
+use crate::opt::{
+    CACHE_KEYS, HighOpt, INT_BASE62, Opt, PARSE_NUMBER_AS_STRING, PARSE_OBJECT_PAIRS,
+};
 use core::ffi::c_char;
 use core::ptr::{NonNull, null, null_mut};
 use std::borrow::Cow;
@@ -67,12 +73,15 @@ fn unsafe_yyjson_get_next_non_container(val: *mut yyjson_val) -> *mut yyjson_val
 
 pub(crate) fn deserialize(
     data: &'static str,
+    opts: Opt,
+    high_opts: HighOpt,
 ) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
     assume!(!data.is_empty());
     let buffer_capacity = buffer_capacity_to_allocate(data.len());
     let buffer_ptr = ffi!(PyMem_Malloc(buffer_capacity));
     if buffer_ptr.is_null() {
         return Err(DeserializeError::from_yyjson(
+            MEMORY_ALLOCATION,
             Cow::Borrowed("Not enough memory to allocate buffer for parsing"),
             0,
             data,
@@ -107,14 +116,34 @@ pub(crate) fn deserialize(
         let msg: Cow<str> = unsafe { core::ffi::CStr::from_ptr(err.msg).to_string_lossy() };
         #[allow(clippy::cast_possible_wrap)]
         let pos = err.pos as i64;
-        return Err(DeserializeError::from_yyjson(msg, pos, data));
+        return Err(DeserializeError::from_yyjson(err.code, msg, pos, data));
     }
+    let mut keys = if opt_enabled!(high_opts, CACHE_KEYS) {
+        cold_path!();
+        Some(KeyCache::new())
+    } else {
+        None
+    };
+    let mut numbers = if opt_enabled!(high_opts, PARSE_NUMBER_AS_STRING) {
+        cold_path!();
+        Some(NumberTokens::new(data))
+    } else {
+        None
+    };
+    let base62 = opt_enabled!(opts, INT_BASE62);
+    let pairs = opt_enabled!(high_opts, PARSE_OBJECT_PAIRS);
     let val = yyjson_doc_get_root(doc);
     let pyval = {
         if !unsafe_yyjson_is_ctn(val) {
             cold_path!();
             match ElementType::from_tag(val) {
+                ElementType::String if base62 => parse_yy_string_base62(val),
                 ElementType::String => parse_yy_string(val),
+                ElementType::Uint64 | ElementType::Int64 | ElementType::Double
+                    if numbers.is_some() =>
+                {
+                    parse_number_token(&mut numbers)
+                }
                 ElementType::Uint64 => parse_yy_u64(val),
                 ElementType::Int64 => parse_yy_i64(val),
                 ElementType::Double => parse_yy_f64(val),
@@ -126,17 +155,14 @@ pub(crate) fn deserialize(
         } else if is_yyjson_tag!(val, TAG_ARRAY) {
             let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
             if unsafe_yyjson_get_len(val) > 0 {
-                populate_yy_array(pyval.clone(), val);
+                populate_yy_array(pyval.clone(), val, &mut keys, &mut numbers, base62, pairs);
             }
             pyval.as_non_null_ptr()
         } else {
-            let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
-            if unsafe_yyjson_get_len(val) > 0 {
-                populate_yy_object(pyval.clone(), val);
-            }
-            pyval.as_non_null_ptr()
+            parse_yy_object(val, &mut keys, &mut numbers, base62, pairs)
         }
     };
+    drop(keys);
     ffi!(PyMem_Free(buffer_ptr));
     Ok(pyval)
 }
@@ -194,8 +220,84 @@ fn parse_yy_f64(elem: *mut yyjson_val) -> NonNull<crate::ffi::PyObject> {
     PyFloatRef::from_f64(unsafe { (*elem).uni.f64_ }).as_non_null_ptr()
 }
 
+/// Deserialize a `str` that is an integer serialized by `OPT_INT_BASE62` as
+/// an `int`, and any other `str` unchanged.
+#[cold]
 #[inline(never)]
-fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
+fn parse_yy_string_base62(elem: *mut yyjson_val) -> NonNull<crate::ffi::PyObject> {
+    let value = str_from_slice!((*elem).uni.str_.cast::<u8>(), unsafe_yyjson_get_len(elem));
+    match decode(value) {
+        Some(Base62Int::Negative(val)) => PyIntRef::from_i64(val).as_non_null_ptr(),
+        Some(Base62Int::Positive(val)) => PyIntRef::from_u64(val).as_non_null_ptr(),
+        None => PyStrRef::from_str(value).as_non_null_ptr(),
+    }
+}
+
+/// The number tokens of a document in order, for `OPT_PARSE_NUMBER_AS_STRING`.
+/// yyjson does not keep the position of a value, but values are visited in
+/// document order and the document is valid, so outside of strings a number
+/// is the only token that starts with `-` or a digit.
+pub(crate) struct NumberTokens {
+    data: &'static [u8],
+    idx: usize,
+}
+
+impl NumberTokens {
+    fn new(data: &'static str) -> Self {
+        NumberTokens {
+            data: data.as_bytes(),
+            idx: 0,
+        }
+    }
+
+    fn next_token(&mut self) -> &'static str {
+        let data = self.data;
+        let mut idx = self.idx;
+        loop {
+            match data[idx] {
+                b'-' | b'0'..=b'9' => break,
+                b'"' => {
+                    idx += 1;
+                    while data[idx] != b'"' {
+                        if data[idx] == b'\\' {
+                            idx += 1;
+                        }
+                        idx += 1;
+                    }
+                    idx += 1;
+                }
+                _ => idx += 1,
+            }
+        }
+        let start = idx;
+        while idx < data.len()
+            && matches!(data[idx], b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+        {
+            idx += 1;
+        }
+        self.idx = idx;
+        str_from_slice!(data.as_ptr().add(start), idx - start)
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn parse_number_token(numbers: &mut Option<NumberTokens>) -> NonNull<crate::ffi::PyObject> {
+    match numbers {
+        Some(tokens) => PyStrRef::from_str(tokens.next_token()).as_non_null_ptr(),
+        None => unreachable_unchecked!(),
+    }
+}
+
+#[inline(never)]
+fn populate_yy_array(
+    mut list: PyListRef,
+    elem: *mut yyjson_val,
+    keys: &mut Option<KeyCache>,
+    numbers: &mut Option<NumberTokens>,
+    base62: bool,
+    pairs: bool,
+) {
     unsafe {
         let len = unsafe_yyjson_get_len(elem);
         assume!(len >= 1);
@@ -210,19 +312,22 @@ fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
                     let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
                     list.set(idx, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_array(pyval.clone(), val);
+                        populate_yy_array(pyval.clone(), val, keys, numbers, base62, pairs);
                     }
                 } else {
-                    let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
+                    let pyval = parse_yy_object(val, keys, numbers, base62, pairs);
                     list.set(idx, pyval.as_ptr());
-                    if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_object(pyval.clone(), val);
-                    }
                 }
             } else {
                 next = unsafe_yyjson_get_next_non_container(val);
                 let pyval = match ElementType::from_tag(val) {
+                    ElementType::String if base62 => parse_yy_string_base62(val),
                     ElementType::String => parse_yy_string(val),
+                    ElementType::Uint64 | ElementType::Int64 | ElementType::Double
+                        if numbers.is_some() =>
+                    {
+                        parse_number_token(numbers)
+                    }
                     ElementType::Uint64 => parse_yy_u64(val),
                     ElementType::Int64 => parse_yy_i64(val),
                     ElementType::Double => parse_yy_f64(val),
@@ -238,7 +343,13 @@ fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
 }
 
 #[inline(never)]
-fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
+fn populate_yy_object(
+    mut dict: PyDictRef,
+    elem: *mut yyjson_val,
+    keys: &mut Option<KeyCache>,
+    numbers: &mut Option<NumberTokens>,
+    base62: bool,
+) {
     unsafe {
         let len = unsafe_yyjson_get_len(elem);
         assume!(len >= 1);
@@ -251,7 +362,10 @@ fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
                     (*next_key).uni.str_.cast::<u8>(),
                     unsafe_yyjson_get_len(next_key)
                 );
-                get_unicode_key(key_str)
+                match keys {
+                    Some(cache) => cache.get(key_str),
+                    None => get_unicode_key(key_str),
+                }
             };
             if unsafe_yyjson_is_ctn(val) {
                 cold_path!();
@@ -261,20 +375,27 @@ fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
                     let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
                     dict.set(pykey, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_array(pyval, val);
+                        // a dict is not populated with OPT_PARSE_OBJECT_PAIRS
+                        populate_yy_array(pyval, val, keys, numbers, base62, false);
                     }
                 } else {
                     let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
                     dict.set(pykey, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_object(pyval.clone(), val);
+                        populate_yy_object(pyval.clone(), val, keys, numbers, base62);
                     }
                 }
             } else {
                 next_key = unsafe_yyjson_get_next_non_container(val);
                 next_val = next_key.add(1);
                 let pyval = match ElementType::from_tag(val) {
+                    ElementType::String if base62 => parse_yy_string_base62(val),
                     ElementType::String => parse_yy_string(val),
+                    ElementType::Uint64 | ElementType::Int64 | ElementType::Double
+                        if numbers.is_some() =>
+                    {
+                        parse_number_token(numbers)
+                    }
                     ElementType::Uint64 => parse_yy_u64(val),
                     ElementType::Int64 => parse_yy_i64(val),
                     ElementType::Double => parse_yy_f64(val),
@@ -288,3 +409,94 @@ fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
         }
     }
 }
+
+/// Deserialize an object as a `dict` or, with `OPT_PARSE_OBJECT_PAIRS`, as a
+/// `list` of `(key, value)` tuples.
+#[inline(always)]
+fn parse_yy_object(
+    elem: *mut yyjson_val,
+    keys: &mut Option<KeyCache>,
+    numbers: &mut Option<NumberTokens>,
+    base62: bool,
+    pairs: bool,
+) -> NonNull<crate::ffi::PyObject> {
+    let len = unsafe_yyjson_get_len(elem);
+    if pairs {
+        cold_path!();
+        let pyval = PyListRef::with_capacity(len);
+        if len > 0 {
+            populate_yy_object_pairs(pyval.clone(), elem, keys, numbers, base62);
+        }
+        pyval.as_non_null_ptr()
+    } else {
+        let pyval = PyDictRef::with_capacity(len);
+        if len > 0 {
+            populate_yy_object(pyval.clone(), elem, keys, numbers, base62);
+        }
+        pyval.as_non_null_ptr()
+    }
+}
+
+/// Populate a `list` of `(key, value)` tuples in document order, including
+/// duplicate keys, for `OPT_PARSE_OBJECT_PAIRS`.
+#[cold]
+#[inline(never)]
+fn populate_yy_object_pairs(
+    mut list: PyListRef,
+    elem: *mut yyjson_val,
+    keys: &mut Option<KeyCache>,
+    numbers: &mut Option<NumberTokens>,
+    base62: bool,
+) {
+    unsafe {
+        let len = unsafe_yyjson_get_len(elem);
+        assume!(len >= 1);
+        let mut next_key = unsafe_yyjson_get_first(elem);
+        for idx in 0..len {
+            let key = next_key;
+            let val = next_key.add(1);
+            let pykey = {
+                let key_str =
+                    str_from_slice!((*key).uni.str_.cast::<u8>(), unsafe_yyjson_get_len(key));
+                match keys {
+                    Some(cache) => cache.get(key_str),
+                    None => get_unicode_key(key_str),
+                }
+            };
+            let pyval = if unsafe_yyjson_is_ctn(val) {
+                next_key = unsafe_yyjson_get_next_container(val);
+                if is_yyjson_tag!(val, TAG_ARRAY) {
+                    let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
+                    if unsafe_yyjson_get_len(val) > 0 {
+                        populate_yy_array(pyval.clone(), val, keys, numbers, base62, true);
+                    }
+                    pyval.as_non_null_ptr()
+                } else {
+                    parse_yy_object(val, keys, numbers, base62, true)
+                }
+            } else {
+                next_key = unsafe_yyjson_get_next_non_container(val);
+                match ElementType::from_tag(val) {
+                    ElementType::String if base62 => parse_yy_string_base62(val),
+                    ElementType::String => parse_yy_string(val),
+                    ElementType::Uint64 | ElementType::Int64 | ElementType::Double
+                        if numbers.is_some() =>
+                    {
+                        parse_number_token(numbers)
+                    }
+                    ElementType::Uint64 => parse_yy_u64(val),
+                    ElementType::Int64 => parse_yy_i64(val),
+                    ElementType::Double => parse_yy_f64(val),
+                    ElementType::Null => PyNoneRef::none().as_non_null_ptr(),
+                    ElementType::True => PyBoolRef::pytrue().as_non_null_ptr(),
+                    ElementType::False => PyBoolRef::pyfalse().as_non_null_ptr(),
+                    ElementType::Array | ElementType::Object => unreachable_unchecked!(),
+                }
+            };
+            let mut pair = PyTupleRef::with_capacity(2);
+            pair.set(0, pykey.as_ptr());
+            pair.set(1, pyval.as_ptr());
+            list.set(idx, pair.as_ptr());
+        }
+    }
+}
@@ -1,34 +1,72 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2024-2026)
 
-use super::DeserializeError;
 use super::input::Utf8Buffer;
+use super::{DeserializeError, UNEXPECTED_CHARACTER};
 use crate::ffi::PyStrRef;
+use crate::opt::{HighOpt, Opt, PARSE_OBJECT_PAIRS, STRICT_INPUT};
 use core::ptr::NonNull;
+use std::borrow::Cow;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// With `OPT_STRICT_INPUT`, the input must not begin with a UTF-8 byte order
+/// mark or whitespace, which are otherwise an error and skipped respectively.
+#[cold]
+#[inline(never)]
+fn check_strict_input(data: &'static str) -> Result<(), DeserializeError<'static>> {
+    let first = data.as_bytes()[0];
+    let message = if data.as_bytes().starts_with(UTF8_BOM) {
+        format!("Input begins with a UTF-8 byte order mark: {first:#04x}")
+    } else if matches!(first, b' ' | b'\t' | b'\n' | b'\r') {
+        format!("Input begins with whitespace: {first:#04x}")
+    } else {
+        return Ok(());
+    };
+    Err(DeserializeError::from_yyjson(
+        UNEXPECTED_CHARACTER,
+        Cow::Owned(message),
+        0,
+        data,
+    ))
+}
 
-#[repr(transparent)]
 pub struct Deserializer {
     buffer: Utf8Buffer,
+    opts: Opt,
+    high_opts: HighOpt,
 }
 
 impl Deserializer {
     #[inline]
     pub fn from_pyobject(
         ptr: *mut crate::ffi::PyObject,
+        opts: Opt,
+        high_opts: HighOpt,
     ) -> Result<Self, DeserializeError<'static>> {
         let buffer = Utf8Buffer::from_pyobject(ptr)?;
         debug_assert!(!buffer.as_str().is_empty());
-        Ok(Self { buffer: buffer })
+        Ok(Self {
+            buffer: buffer,
+            opts: opts,
+            high_opts: high_opts,
+        })
     }
 
     #[inline]
     pub fn deserialize(&self) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+        if opt_enabled!(self.high_opts, STRICT_INPUT) {
+            check_strict_input(self.buffer.as_str())?;
+        }
         if self.buffer.len() == 2 {
             cold_path!();
             match self.buffer.as_bytes() {
                 b"[]" => {
                     return Ok(nonnull!(ffi!(PyList_New(0))));
                 }
+                b"{}" if opt_enabled!(self.high_opts, PARSE_OBJECT_PAIRS) => {
+                    return Ok(nonnull!(ffi!(PyList_New(0))));
+                }
                 b"{}" => {
                     return Ok(nonnull!(unsafe { crate::ffi::PyDict_New(0) }));
                 }
@@ -38,13 +76,15 @@ impl Deserializer {
                 _ => {}
             }
         }
-        crate::deserialize::backend::deserialize(self.buffer.as_str())
+        crate::deserialize::backend::deserialize(self.buffer.as_str(), self.opts, self.high_opts)
     }
 }
 
 pub(crate) fn deserialize(
     ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
+    high_opts: HighOpt,
 ) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
-    let deserializer = Deserializer::from_pyobject(ptr)?;
+    let deserializer = Deserializer::from_pyobject(ptr, opts, high_opts)?;
     deserializer.deserialize()
 }
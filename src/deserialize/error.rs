@@ -3,28 +3,52 @@
 
 use std::borrow::Cow;
 
+/// The `code` of a `JSONDecodeError`. These are the values of yyjson's
+/// `yyjson_read_code` and are documented as stable, so errors orjson raises
+/// before parsing use the closest of them.
+pub(crate) type DecodeErrorCode = u32;
+
+/// The input is not a supported type or an option is invalid.
+pub(crate) const INVALID_PARAMETER: DecodeErrorCode = 1;
+/// Allocating the buffer to parse the document failed.
+pub(crate) const MEMORY_ALLOCATION: DecodeErrorCode = 2;
+/// The input is empty.
+pub(crate) const EMPTY_CONTENT: DecodeErrorCode = 3;
+/// The input has an unexpected character.
+pub(crate) const UNEXPECTED_CHARACTER: DecodeErrorCode = 6;
+/// The input is not valid UTF-8.
+pub(crate) const INVALID_STRING: DecodeErrorCode = 10;
+
 pub(crate) struct DeserializeError<'a> {
     pub message: Cow<'a, str>,
     pub data: Option<&'a str>,
     pub pos: i64,
+    pub code: DecodeErrorCode,
 }
 
 impl<'a> DeserializeError<'a> {
     #[cold]
-    pub fn invalid(message: Cow<'a, str>) -> Self {
+    pub fn invalid(code: DecodeErrorCode, message: Cow<'a, str>) -> Self {
         DeserializeError {
             message: message,
             data: None,
             pos: 0,
+            code: code,
         }
     }
 
     #[cold]
-    pub fn from_yyjson(message: Cow<'a, str>, pos: i64, data: &'a str) -> Self {
+    pub fn from_yyjson(
+        code: DecodeErrorCode,
+        message: Cow<'a, str>,
+        pos: i64,
+        data: &'a str,
+    ) -> Self {
         DeserializeError {
             message: message,
             data: Some(data),
             pos: pos,
+            code: code,
         }
     }
 
@@ -43,4 +67,13 @@ impl<'a> DeserializeError<'a> {
             None => 0,
         }
     }
+
+    /// Return position of the error as a byte offset in the UTF-8 input
+    #[cold]
+    pub fn byte_pos(&self) -> i64 {
+        match self.data {
+            Some(_) => self.pos,
+            None => 0,
+        }
+    }
 }
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2026)
 
-use crate::deserialize::DeserializeError;
+use crate::deserialize::{DeserializeError, EMPTY_CONTENT, INVALID_PARAMETER, INVALID_STRING};
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 use crate::ffi::{PyByteArrayRef, PyMemoryViewRef};
 use crate::ffi::{PyBytesRef, PyStrRef};
@@ -36,7 +36,10 @@ impl Utf8Buffer {
         } else if let Ok(ob) = PyMemoryViewRef::from_ptr(ptr) {
             Ok(ob.as_str())
         } else {
-            Err(DeserializeError::invalid(Cow::Borrowed(INPUT_TYPE_MESSAGE)))
+            Err(DeserializeError::invalid(
+                INVALID_PARAMETER,
+                Cow::Borrowed(INPUT_TYPE_MESSAGE),
+            ))
         }
     }
 
@@ -49,7 +52,10 @@ impl Utf8Buffer {
         } else if let Ok(ob) = PyStrRef::from_ptr(ptr) {
             Ok(ob.as_str())
         } else {
-            Err(DeserializeError::invalid(Cow::Borrowed(INPUT_TYPE_MESSAGE)))
+            Err(DeserializeError::invalid(
+                INVALID_PARAMETER,
+                Cow::Borrowed(INPUT_TYPE_MESSAGE),
+            ))
         }
     }
 
@@ -61,18 +67,25 @@ impl Utf8Buffer {
             Ok(Some(as_str)) => {
                 if as_str.is_empty() {
                     cold_path!();
-                    Err(DeserializeError::invalid(Cow::Borrowed(
-                        "Input is a zero-length, empty document",
-                    )))
+                    Err(DeserializeError::invalid(
+                        EMPTY_CONTENT,
+                        Cow::Borrowed("Input is a zero-length, empty document"),
+                    ))
                 } else {
                     Ok(Self { buffer: as_str })
                 }
             }
             Ok(None) => {
                 cold_path!();
-                Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR)))
+                Err(DeserializeError::invalid(
+                    INVALID_STRING,
+                    Cow::Borrowed(INVALID_STR),
+                ))
             }
-            Err(_) => Err(DeserializeError::invalid(Cow::Borrowed(INPUT_TYPE_MESSAGE))),
+            Err(_) => Err(DeserializeError::invalid(
+                INVALID_PARAMETER,
+                Cow::Borrowed(INPUT_TYPE_MESSAGE),
+            )),
         }
     }
 
@@ -8,8 +8,13 @@ mod deserializer;
 mod error;
 mod input;
 mod pyobject;
+mod reader;
 
 #[cfg(not(Py_GIL_DISABLED))]
 pub(crate) use cache::{KEY_MAP, KeyMap};
 pub(crate) use deserializer::deserialize;
-pub(crate) use error::DeserializeError;
+pub(crate) use error::{
+    DecodeErrorCode, DeserializeError, EMPTY_CONTENT, INVALID_PARAMETER, INVALID_STRING,
+    MEMORY_ALLOCATION, UNEXPECTED_CHARACTER,
+};
+pub(crate) use reader::read_input;
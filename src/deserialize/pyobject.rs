@@ -2,6 +2,8 @@
 // Copyright ijl (2022-2026)
 
 use crate::ffi::PyStrRef;
+use core::hash::{BuildHasherDefault, Hasher};
+use std::collections::HashMap;
 
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 #[inline(always)]
@@ -41,3 +43,77 @@ pub(crate) fn get_unicode_key(key_str: &str) -> PyStrRef {
 pub(crate) fn get_unicode_key(key_str: &str) -> PyStrRef {
     PyStrRef::from_str(key_str)
 }
+
+const KEY_CACHE_CAPACITY: usize = 8192;
+
+/// Keys created during a single `loads()` call with `OPT_CACHE_KEYS`.
+///
+/// Each distinct key is created once and the same object is used for every
+/// occurrence in the document. Unlike `KEY_MAP`, there is no limit on key
+/// length, entries are never evicted, and it is available on all builds.
+/// Once `KEY_CACHE_CAPACITY` distinct keys are stored, new keys are created
+/// without being cached so that documents with unique keys do not build a
+/// large map. The map borrows from the document buffer and must be dropped
+/// before it is freed.
+pub(crate) struct KeyCache {
+    map: HashMap<&'static str, PyStrRef, BuildHasherDefault<KeyHasher>>,
+}
+
+/// Hash a key with a single call to `xxh3_64()` rather than the streaming
+/// hasher. `str` writes a trailing `0xff` byte that is ignored.
+#[derive(Default)]
+pub(crate) struct KeyHasher {
+    hash: u64,
+}
+
+impl Hasher for KeyHasher {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash = xxhash_rust::xxh3::xxh3_64(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, _: u8) {}
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl KeyCache {
+    pub fn new() -> Self {
+        KeyCache {
+            map: HashMap::default(),
+        }
+    }
+
+    #[inline]
+    pub fn get(&mut self, key_str: &'static str) -> PyStrRef {
+        if self.map.len() < KEY_CACHE_CAPACITY {
+            let key = self
+                .map
+                .entry(key_str)
+                .or_insert_with(|| get_unicode_key(key_str));
+            ffi!(Py_INCREF(key.as_ptr()));
+            key.clone()
+        } else {
+            cold_path!();
+            match self.map.get(key_str) {
+                Some(key) => {
+                    ffi!(Py_INCREF(key.as_ptr()));
+                    key.clone()
+                }
+                None => get_unicode_key(key_str),
+            }
+        }
+    }
+}
+
+impl Drop for KeyCache {
+    fn drop(&mut self) {
+        for key in self.map.values() {
+            ffi!(Py_DECREF(key.as_ptr()));
+        }
+    }
+}
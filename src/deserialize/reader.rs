@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::deserialize::{DeserializeError, INVALID_PARAMETER, INVALID_STRING};
+use crate::exception::{raise_loads_exception, raise_loads_exception_fixed};
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+use crate::ffi::{PyByteArrayRef, PyMemoryViewRef};
+use crate::ffi::{PyBytesRef, PyStrRef};
+use crate::typeref::READ_STR;
+use crate::util::{INVALID_STR, usize_to_isize};
+use std::borrow::Cow;
+
+const READ_CHUNK_SIZE: i64 = 64 * 1024;
+
+const FP_TYPE_MESSAGE: &str =
+    "Input must be a file-like object with read() or support the buffer protocol";
+
+const READ_TYPE_MESSAGE: &str = "read() must return bytes or str";
+
+/// Return an object `loads()` accepts for the argument to `load()`, which is
+/// either already such an object, an object supporting the buffer protocol,
+/// or a file-like object that is read to the end in chunks. Returns a new
+/// reference or null with an exception set.
+pub(crate) fn read_input(fp: *mut crate::ffi::PyObject) -> *mut crate::ffi::PyObject {
+    debug_assert!(!fp.is_null());
+    if is_input_type(fp) {
+        ffi!(Py_INCREF(fp));
+        return fp;
+    }
+    #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+    if ffi!(PyObject_CheckBuffer(fp)) == 1 {
+        return ffi!(PyMemoryView_FromObject(fp));
+    }
+    if ffi!(PyObject_HasAttr(fp, READ_STR)) == 0 {
+        cold_path!();
+        return raise_loads_exception_fixed(FP_TYPE_MESSAGE);
+    }
+    read_to_end(fp)
+}
+
+#[inline]
+fn is_input_type(ptr: *mut crate::ffi::PyObject) -> bool {
+    #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+    {
+        PyBytesRef::from_ptr(ptr).is_ok()
+            || PyStrRef::from_ptr(ptr).is_ok()
+            || PyByteArrayRef::from_ptr(ptr).is_ok()
+            || PyMemoryViewRef::from_ptr(ptr).is_ok()
+    }
+    #[cfg(any(not(CPython), Py_GIL_DISABLED))]
+    {
+        PyBytesRef::from_ptr(ptr).is_ok() || PyStrRef::from_ptr(ptr).is_ok()
+    }
+}
+
+/// Call `read(READ_CHUNK_SIZE)` until it returns an empty `bytes` or `str`
+/// and return the contents as `bytes`, so that the document is parsed, and
+/// errors are positioned, as a whole.
+fn read_to_end(fp: *mut crate::ffi::PyObject) -> *mut crate::ffi::PyObject {
+    let size = ffi!(PyLong_FromLongLong(READ_CHUNK_SIZE));
+    let mut buf: Vec<u8> = Vec::new();
+    loop {
+        let chunk = call_method!(fp, READ_STR, size);
+        if chunk.is_null() {
+            cold_path!();
+            ffi!(Py_DECREF(size));
+            return core::ptr::null_mut();
+        }
+        let done = match chunk_as_bytes(chunk) {
+            Ok([]) => true,
+            Ok(contents) => {
+                buf.extend_from_slice(contents);
+                false
+            }
+            Err(err) => {
+                cold_path!();
+                ffi!(Py_DECREF(chunk));
+                ffi!(Py_DECREF(size));
+                return raise_loads_exception(err);
+            }
+        };
+        ffi!(Py_DECREF(chunk));
+        if done {
+            break;
+        }
+    }
+    ffi!(Py_DECREF(size));
+    ffi!(PyBytes_FromStringAndSize(
+        buf.as_ptr().cast::<core::ffi::c_char>(),
+        usize_to_isize(buf.len())
+    ))
+}
+
+/// The contents of a chunk returned by `read()`. The slice borrows from the
+/// chunk and must be copied before the chunk is released.
+fn chunk_as_bytes(
+    chunk: *mut crate::ffi::PyObject,
+) -> Result<&'static [u8], DeserializeError<'static>> {
+    if let Ok(ob) = PyBytesRef::from_ptr(chunk) {
+        return Ok(ob.as_bytes());
+    } else if let Ok(ob) = PyStrRef::from_ptr(chunk) {
+        return match ob.as_str() {
+            Some(uni) => Ok(uni.as_bytes()),
+            None => Err(DeserializeError::invalid(
+                INVALID_STRING,
+                Cow::Borrowed(INVALID_STR),
+            )),
+        };
+    }
+    #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+    if let Ok(ob) = PyByteArrayRef::from_ptr(chunk) {
+        return Ok(ob.as_bytes());
+    }
+    Err(DeserializeError::invalid(
+        INVALID_PARAMETER,
+        Cow::Borrowed(READ_TYPE_MESSAGE),
+    ))
+}
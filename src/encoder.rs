@@ -0,0 +1,610 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use core::ptr::{NonNull, null_mut};
+
+use crate::exception::{raise_dumps_exception_dynamic, raise_dumps_exception_fixed};
+use crate::ffi::{
+    METH_O, Py_DECREF, Py_INCREF, Py_TPFLAGS_DEFAULT, Py_TPFLAGS_HAVE_GC, PyDict_GetItemWithError,
+    PyDict_Size, PyMethodDef, PyMethodDefPointer, PyNoneRef, PyObject, PyObject_GC_Del,
+    PyObject_GC_New, PyObject_GC_Track, PyObject_GC_UnTrack, PyTupleRef, PyType_Ready, PyType_Type,
+    PyTypeObject, PyVarObject, visitproc,
+};
+use crate::serialize::{serialize, validate};
+use crate::typeref::{
+    DATE_TYPE, DATETIME_FORMATS, DATETIME_TYPE, DEFAULT, DICT_TYPE, EXCLUDE_STR, FIELD_SCHEMAS,
+    FLOAT_SIGFIGS, INCLUDE_STR, INDENT, KEY_DELIMITER, KEY_TRANSFORM, MAX_OUTPUT_SIZE,
+    MAX_SEQUENCE_LEN, NONE, OPTION, STR_TYPE, TIME_TYPE, TYPE_HANDLERS, VALIDATE_ONLY,
+};
+use crate::util::isize_to_usize;
+use crate::{DumpsArgs, DumpsOptions};
+
+#[cfg(Py_GIL_DISABLED)]
+use crate::ffi::AtomicCULong;
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+/// `orjson.Encoder`, which holds the arguments of `dumps()` other than the
+/// object so that they are converted once rather than on every call. It is
+/// immutable after it is created, except that the garbage collector may
+/// clear the objects it holds to break a reference cycle through it.
+#[repr(C)]
+pub(crate) struct Encoder {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    pub default: Option<NonNull<PyObject>>,
+    pub options: DumpsOptions,
+    pub datetime_formats: Option<NonNull<PyObject>>,
+    pub type_handlers: Option<NonNull<PyObject>>,
+    pub field_schemas: Option<NonNull<PyObject>>,
+}
+
+const DATETIME_FORMATS_MESSAGE: &str =
+    "datetime_formats must be a dict of datetime.datetime, datetime.date, or datetime.time to str";
+
+/// Convert a `datetime_formats` argument, which is a `dict` of
+/// `datetime.datetime`, `datetime.date`, or `datetime.time` to a `str`
+/// format for `strftime()` or `None`. The returned `dict` is a new reference
+/// to a copy so that it cannot be changed after it is validated.
+#[cold]
+unsafe fn datetime_formats_from_arg(
+    ptr: NonNull<PyObject>,
+) -> Result<Option<NonNull<PyObject>>, ()> {
+    unsafe {
+        if core::ptr::eq(ptr.as_ptr(), NONE) {
+            return Ok(None);
+        }
+        if !is_class_by_type!(ob_type!(ptr.as_ptr()), DICT_TYPE) {
+            return Err(());
+        }
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while pydict_next!(ptr.as_ptr(), &raw mut pos, &raw mut key, &raw mut value) == 1 {
+            let key = key.cast::<PyTypeObject>();
+            if !(core::ptr::eq(key, DATETIME_TYPE)
+                || core::ptr::eq(key, DATE_TYPE)
+                || core::ptr::eq(key, TIME_TYPE))
+                || !is_class_by_type!(ob_type!(value), STR_TYPE)
+            {
+                return Err(());
+            }
+        }
+        if PyDict_Size(ptr.as_ptr()) == 0 {
+            return Ok(None);
+        }
+        Ok(NonNull::new(crate::ffi::PyDict_Copy(ptr.as_ptr())))
+    }
+}
+
+const TYPE_HANDLERS_MESSAGE: &str = "type_handlers must be a dict of type to callable";
+
+/// Convert a `type_handlers` argument, which is a `dict` of a type to a
+/// callable or `None`. The returned `dict` is a new reference to a copy so
+/// that it cannot be changed after it is validated.
+#[cold]
+unsafe fn type_handlers_from_arg(ptr: NonNull<PyObject>) -> Result<Option<NonNull<PyObject>>, ()> {
+    unsafe {
+        if core::ptr::eq(ptr.as_ptr(), NONE) {
+            return Ok(None);
+        }
+        if !is_class_by_type!(ob_type!(ptr.as_ptr()), DICT_TYPE) {
+            return Err(());
+        }
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while pydict_next!(ptr.as_ptr(), &raw mut pos, &raw mut key, &raw mut value) == 1 {
+            if !is_subclass_by_flag!(tp_flags!(ob_type!(key)), Py_TPFLAGS_TYPE_SUBCLASS)
+                || crate::ffi::PyCallable_Check(value) == 0
+            {
+                return Err(());
+            }
+        }
+        if PyDict_Size(ptr.as_ptr()) == 0 {
+            return Ok(None);
+        }
+        Ok(NonNull::new(crate::ffi::PyDict_Copy(ptr.as_ptr())))
+    }
+}
+
+const FIELD_SCHEMAS_MESSAGE: &str =
+    "field_schemas must be a dict of type to a dict of \"include\" or \"exclude\" to a set of str";
+
+/// Copy a set of field names in `field_schemas` to a new `frozenset`, or
+/// return null if it is not a `set` or `frozenset` of `str`.
+#[cold]
+unsafe fn field_names_from_arg(ptr: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        if ptr.is_null() {
+            return NONE;
+        }
+        if crate::ffi::PyAnySet_Check(ptr) == 0 {
+            return null_mut();
+        }
+        let names = crate::ffi::PyFrozenSet_New(ptr);
+        if names.is_null() {
+            crate::ffi::PyErr_Clear();
+            return null_mut();
+        }
+        let iter = crate::ffi::PyObject_GetIter(names);
+        loop {
+            let name = crate::ffi::PyIter_Next(iter);
+            if name.is_null() {
+                break;
+            }
+            let valid = is_class_by_type!(ob_type!(name), STR_TYPE);
+            Py_DECREF(name);
+            if !valid {
+                Py_DECREF(iter);
+                Py_DECREF(names);
+                return null_mut();
+            }
+        }
+        Py_DECREF(iter);
+        names
+    }
+}
+
+/// Convert a `field_schemas` argument, which is a `dict` of a type to a
+/// `dict` of `"include"`, `"exclude"`, or both to a `set` of field names, or
+/// `None`. The returned `dict` is a new reference of each type to a `tuple`
+/// of the `frozenset` to include and to exclude, either of which may be
+/// `None`, so that it cannot be changed after it is validated.
+#[cold]
+unsafe fn field_schemas_from_arg(ptr: NonNull<PyObject>) -> Result<Option<NonNull<PyObject>>, ()> {
+    unsafe {
+        if core::ptr::eq(ptr.as_ptr(), NONE) {
+            return Ok(None);
+        }
+        if !is_class_by_type!(ob_type!(ptr.as_ptr()), DICT_TYPE) {
+            return Err(());
+        }
+        let schemas = crate::ffi::PyDict_New(0);
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while pydict_next!(ptr.as_ptr(), &raw mut pos, &raw mut key, &raw mut value) == 1 {
+            if !is_subclass_by_flag!(tp_flags!(ob_type!(key)), Py_TPFLAGS_TYPE_SUBCLASS)
+                || !is_class_by_type!(ob_type!(value), DICT_TYPE)
+            {
+                Py_DECREF(schemas);
+                return Err(());
+            }
+            let mut found: usize = 0;
+            let include = field_names_from_arg(get_kwarg(value, INCLUDE_STR, &mut found));
+            let exclude = field_names_from_arg(get_kwarg(value, EXCLUDE_STR, &mut found));
+            if include.is_null()
+                || exclude.is_null()
+                || found == 0
+                || isize_to_usize(PyDict_Size(value)) != found
+            {
+                for names in [include, exclude] {
+                    if !names.is_null() && !core::ptr::eq(names, NONE) {
+                        Py_DECREF(names);
+                    }
+                }
+                Py_DECREF(schemas);
+                return Err(());
+            }
+            let schema = crate::ffi::PyTuple_New(2);
+            for (idx, names) in [include, exclude].into_iter().enumerate() {
+                if core::ptr::eq(names, NONE) {
+                    Py_INCREF(names);
+                }
+                crate::ffi::PyTuple_SET_ITEM(schema, crate::util::usize_to_isize(idx), names);
+            }
+            crate::ffi::PyDict_SetItem(schemas, key, schema);
+            Py_DECREF(schema);
+        }
+        if PyDict_Size(schemas) == 0 {
+            Py_DECREF(schemas);
+            return Ok(None);
+        }
+        Ok(NonNull::new(schemas))
+    }
+}
+
+/// Get a keyword argument from `kwds` and count it in `found`.
+#[cold]
+unsafe fn get_kwarg(kwds: *mut PyObject, name: *mut PyObject, found: &mut usize) -> *mut PyObject {
+    unsafe {
+        if kwds.is_null() {
+            return null_mut();
+        }
+        let val = PyDict_GetItemWithError(kwds, name);
+        if !val.is_null() {
+            *found += 1;
+        }
+        val
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_encoder_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let argsob = PyTupleRef::from_ptr_unchecked(args);
+        let num_args = argsob.len();
+        if num_args > 2 {
+            return raise_dumps_exception_fixed(
+                "orjson.Encoder() takes at most 2 positional arguments",
+            );
+        }
+
+        let mut found: usize = 0;
+        let mut dumps_args = DumpsArgs {
+            default: NonNull::new(get_kwarg(kwds, DEFAULT, &mut found)),
+            option: NonNull::new(get_kwarg(kwds, OPTION, &mut found)),
+            indent: NonNull::new(get_kwarg(kwds, INDENT, &mut found)),
+            key_delimiter: NonNull::new(get_kwarg(kwds, KEY_DELIMITER, &mut found)),
+            float_sigfigs: NonNull::new(get_kwarg(kwds, FLOAT_SIGFIGS, &mut found)),
+            max_output_size: NonNull::new(get_kwarg(kwds, MAX_OUTPUT_SIZE, &mut found)),
+            key_transform: NonNull::new(get_kwarg(kwds, KEY_TRANSFORM, &mut found)),
+            validate_only: NonNull::new(get_kwarg(kwds, VALIDATE_ONLY, &mut found)),
+            max_sequence_len: NonNull::new(get_kwarg(kwds, MAX_SEQUENCE_LEN, &mut found)),
+        };
+        let datetime_formatsptr = get_kwarg(kwds, DATETIME_FORMATS, &mut found);
+        let type_handlersptr = get_kwarg(kwds, TYPE_HANDLERS, &mut found);
+        let field_schemasptr = get_kwarg(kwds, FIELD_SCHEMAS, &mut found);
+        if !kwds.is_null() && isize_to_usize(PyDict_Size(kwds)) != found {
+            return raise_dumps_exception_fixed(
+                "orjson.Encoder() got an unexpected keyword argument",
+            );
+        }
+        if num_args >= 1 {
+            if dumps_args.default.is_some() {
+                return raise_dumps_exception_fixed(
+                    "orjson.Encoder() got multiple values for argument: 'default'",
+                );
+            }
+            dumps_args.default = NonNull::new(argsob.get(0));
+        }
+        if num_args == 2 {
+            if dumps_args.option.is_some() {
+                return raise_dumps_exception_fixed(
+                    "orjson.Encoder() got multiple values for argument: 'option'",
+                );
+            }
+            dumps_args.option = NonNull::new(argsob.get(1));
+        }
+
+        let default = dumps_args
+            .default
+            .filter(|tmp| !core::ptr::eq(tmp.as_ptr(), crate::typeref::NONE));
+
+        let mut options = match dumps_args.convert() {
+            Ok(val) => val,
+            Err(msg) => return raise_dumps_exception_fixed(msg),
+        };
+
+        let mut datetime_formats: Option<NonNull<PyObject>> = None;
+        if let Some(tmp) = NonNull::new(datetime_formatsptr) {
+            match datetime_formats_from_arg(tmp) {
+                Ok(val) => datetime_formats = val,
+                Err(()) => return raise_dumps_exception_fixed(DATETIME_FORMATS_MESSAGE),
+            }
+        }
+        if datetime_formats.is_some() {
+            options.opts |= crate::opt::DATETIME_FORMATS;
+        }
+
+        let mut type_handlers: Option<NonNull<PyObject>> = None;
+        if let Some(tmp) = NonNull::new(type_handlersptr) {
+            match type_handlers_from_arg(tmp) {
+                Ok(val) => type_handlers = val,
+                Err(()) => {
+                    if let Some(tmp) = datetime_formats {
+                        Py_DECREF(tmp.as_ptr());
+                    }
+                    return raise_dumps_exception_fixed(TYPE_HANDLERS_MESSAGE);
+                }
+            }
+        }
+
+        let mut field_schemas: Option<NonNull<PyObject>> = None;
+        if let Some(tmp) = NonNull::new(field_schemasptr) {
+            match field_schemas_from_arg(tmp) {
+                Ok(val) => field_schemas = val,
+                Err(()) => {
+                    for tmp in [datetime_formats, type_handlers].into_iter().flatten() {
+                        Py_DECREF(tmp.as_ptr());
+                    }
+                    return raise_dumps_exception_fixed(FIELD_SCHEMAS_MESSAGE);
+                }
+            }
+        }
+
+        if let Some(tmp) = default {
+            Py_INCREF(tmp.as_ptr());
+        }
+        if let Some(tmp) = options.key_delimiter {
+            Py_INCREF(tmp.as_ptr());
+        }
+        if let Some(tmp) = options.key_transform {
+            Py_INCREF(tmp.as_ptr());
+        }
+        let obj = PyObject_GC_New::<Encoder>(crate::typeref::ENCODER_TYPE);
+        if obj.is_null() {
+            for tmp in [
+                default,
+                options.key_delimiter,
+                options.key_transform,
+                datetime_formats,
+                type_handlers,
+                field_schemas,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                Py_DECREF(tmp.as_ptr());
+            }
+            return null_mut();
+        }
+        (&raw mut (*obj).default).write(default);
+        (&raw mut (*obj).options).write(options);
+        (&raw mut (*obj).datetime_formats).write(datetime_formats);
+        (&raw mut (*obj).type_handlers).write(type_handlers);
+        (&raw mut (*obj).field_schemas).write(field_schemas);
+        PyObject_GC_Track(obj.cast::<core::ffi::c_void>());
+        obj.cast::<PyObject>()
+    }
+}
+
+impl Encoder {
+    /// Each object held, which may be part of a reference cycle through the
+    /// `Encoder`, e.g., a bound method of an object holding the `Encoder`.
+    #[inline(always)]
+    fn held_objects(&mut self) -> [&mut Option<NonNull<PyObject>>; 6] {
+        [
+            &mut self.default,
+            &mut self.options.key_delimiter,
+            &mut self.options.key_transform,
+            &mut self.datetime_formats,
+            &mut self.type_handlers,
+            &mut self.field_schemas,
+        ]
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_encoder_traverse(
+    object: *mut PyObject,
+    visit: visitproc,
+    arg: *mut core::ffi::c_void,
+) -> core::ffi::c_int {
+    unsafe {
+        for tmp in (*object.cast::<Encoder>())
+            .held_objects()
+            .into_iter()
+            .flatten()
+        {
+            let ret = visit(tmp.as_ptr(), arg);
+            if ret != 0 {
+                return ret;
+            }
+        }
+        0
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_encoder_clear(object: *mut PyObject) -> core::ffi::c_int {
+    unsafe {
+        for each in (*object.cast::<Encoder>()).held_objects() {
+            if let Some(tmp) = each.take() {
+                Py_DECREF(tmp.as_ptr());
+            }
+        }
+        0
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_encoder_dealloc(object: *mut PyObject) {
+    unsafe {
+        PyObject_GC_UnTrack(object.cast::<core::ffi::c_void>());
+        orjson_encoder_clear(object);
+        PyObject_GC_Del(object.cast::<core::ffi::c_void>());
+    }
+}
+
+/// Serialize `obj` with the arguments of `encoder`. If `compact` is true,
+/// its `indent` and `OPT_INDENT_2` are ignored so that the output has no
+/// whitespace between tokens.
+#[inline(always)]
+unsafe fn encode(encoder: &Encoder, obj: *mut PyObject, compact: bool) -> *mut PyObject {
+    let options = encoder.options;
+    let (opts, indent) = if compact {
+        (options.opts & !crate::opt::INDENT_2, None)
+    } else {
+        (options.opts, options.indent)
+    };
+    let config = options
+        .config(encoder.datetime_formats, encoder.type_handlers)
+        .with_field_schemas(encoder.field_schemas);
+    if options.validate_only {
+        cold_path!();
+        return validate(obj, encoder.default, opts, config).map_or_else(
+            |err| raise_dumps_exception_dynamic(err.as_str()),
+            |()| PyNoneRef::none().as_ptr(),
+        );
+    }
+    serialize(
+        obj,
+        encoder.default,
+        opts,
+        options.high_opts,
+        config,
+        indent,
+        options.max_output_size,
+    )
+    .map_or_else(
+        |err| raise_dumps_exception_dynamic(err.as_str()),
+        NonNull::as_ptr,
+    )
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_encoder_encode(
+    slf: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    unsafe { encode(&*slf.cast::<Encoder>(), obj, false) }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_encoder_encode_compact(
+    slf: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    unsafe { encode(&*slf.cast::<Encoder>(), obj, true) }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_encodertype_new() -> *mut PyTypeObject {
+    unsafe {
+        let methods = Box::new([
+            PyMethodDef {
+                ml_name: c"encode".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_encoder_encode,
+                },
+                ml_flags: METH_O,
+                ml_doc: c"encode($self, obj, /)\n--\n\nSerialize Python objects to JSON.".as_ptr(),
+            },
+            PyMethodDef {
+                ml_name: c"encode_compact".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_encoder_encode_compact,
+                },
+                ml_flags: METH_O,
+                ml_doc: c"encode_compact($self, obj, /)\n--\n\nSerialize Python objects to JSON without indentation.".as_ptr(),
+            },
+            PyMethodDef::zeroed(),
+        ]);
+
+        #[cfg(Py_GIL_DISABLED)]
+        let tp_flags: AtomicCULong = AtomicCULong::new(
+            Py_TPFLAGS_DEFAULT | Py_TPFLAGS_HAVE_GC | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE,
+        );
+        #[cfg(not(Py_GIL_DISABLED))]
+        let tp_flags: core::ffi::c_ulong =
+            Py_TPFLAGS_DEFAULT | Py_TPFLAGS_HAVE_GC | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE;
+        let ob = Box::new(PyTypeObject {
+            ob_base: PyVarObject {
+                ob_base: PyObject {
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_tid: 0,
+                    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+                    ob_flags: 0,
+                    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+                    _padding: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_mutex: core::mem::zeroed(),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_gc_bits: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_local: AtomicU32::new(crate::ffi::compat::_Py_IMMORTAL_REFCNT_LOCAL),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_shared: AtomicIsize::new(0),
+                    #[cfg(all(Py_3_12, not(Py_GIL_DISABLED)))]
+                    ob_refcnt: pyo3_ffi::PyObjectObRefcnt { ob_refcnt: 0 },
+                    #[cfg(not(Py_3_12))]
+                    ob_refcnt: 0,
+                    #[cfg(PyPy)]
+                    ob_pypy_link: 0,
+                    ob_type: &raw mut PyType_Type,
+                },
+                #[cfg(not(GraalPy))]
+                ob_size: 0,
+                #[cfg(GraalPy)]
+                _ob_size_graalpy: 0,
+            },
+            tp_name: c"orjson.Encoder".as_ptr(),
+            tp_basicsize: core::mem::size_of::<Encoder>().cast_signed(),
+            tp_itemsize: 0,
+            tp_dealloc: Some(orjson_encoder_dealloc),
+            tp_init: None,
+            tp_new: Some(orjson_encoder_tp_new),
+            tp_flags: tp_flags,
+            // ...
+            tp_bases: null_mut(),
+            tp_cache: null_mut(),
+            tp_del: None,
+            tp_finalize: None,
+            tp_free: Some(PyObject_GC_Del),
+            tp_is_gc: None,
+            tp_mro: null_mut(),
+            tp_subclasses: null_mut(),
+            tp_vectorcall: None,
+            tp_version_tag: 0,
+            tp_weaklist: null_mut(),
+            tp_vectorcall_offset: 0,
+            tp_getattr: None,
+            tp_setattr: None,
+            tp_as_async: null_mut(),
+            tp_repr: None,
+            tp_as_number: null_mut(),
+            tp_as_sequence: null_mut(),
+            tp_as_mapping: null_mut(),
+            tp_hash: None,
+            tp_call: None,
+            tp_str: None,
+            tp_getattro: None,
+            tp_setattro: None,
+            tp_as_buffer: null_mut(),
+            tp_doc: c"Encoder(default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, datetime_formats=None, key_transform=None, validate_only=False, max_sequence_len=None, type_handlers=None, field_schemas=None)\n--\n\nSerialize Python objects to JSON with fixed arguments.".as_ptr(),
+            tp_traverse: Some(orjson_encoder_traverse),
+            tp_clear: Some(orjson_encoder_clear),
+            tp_richcompare: None,
+            tp_weaklistoffset: 0,
+            tp_iter: None,
+            tp_iternext: None,
+            tp_methods: Box::into_raw(methods).cast::<PyMethodDef>(),
+            tp_members: null_mut(),
+            tp_getset: null_mut(),
+            tp_base: null_mut(),
+            tp_dict: null_mut(),
+            tp_descr_get: None,
+            tp_descr_set: None,
+            tp_dictoffset: 0,
+            tp_alloc: None,
+            #[cfg(Py_3_12)]
+            tp_watched: 0,
+        });
+        let ob_ptr = Box::into_raw(ob);
+        PyType_Ready(ob_ptr);
+        ob_ptr
+    }
+}
@@ -2,10 +2,11 @@
 // Copyright ijl (2020-2026), Jack Amadeo (2023)
 
 use core::ptr::null_mut;
+use std::borrow::Cow;
 
-use crate::deserialize::DeserializeError;
+use crate::deserialize::{DecodeErrorCode, DeserializeError, INVALID_PARAMETER};
 use crate::ffi::{Py_DECREF, PyErr_SetObject, PyIntRef, PyObject, PyStrRef, PyTupleRef};
-use crate::typeref::{JsonDecodeError, JsonEncodeError};
+use crate::typeref::{BYTE_POS_STR, CODE_STR, JsonDecodeError, JsonEncodeError, PATH_STR};
 
 #[cold]
 #[inline(never)]
@@ -25,6 +26,69 @@ pub(crate) fn raise_loads_exception(err: DeserializeError) -> *mut PyObject {
         PyErr_SetObject(JsonDecodeError, args.as_ptr());
         Py_DECREF(args.as_ptr());
     }
+    set_error_code(err.code, err.byte_pos());
+    null_mut()
+}
+
+/// Set `code` and `byte_pos` on the `JSONDecodeError` that was just raised.
+#[cold]
+#[inline(never)]
+#[cfg(Py_3_12)]
+fn set_error_code(code: DecodeErrorCode, byte_pos: i64) {
+    unsafe {
+        let exc: *mut PyObject = crate::ffi::PyErr_GetRaisedException();
+        let code = PyIntRef::from_u64(u64::from(code));
+        crate::ffi::PyObject_SetAttr(exc, CODE_STR, code.as_ptr());
+        Py_DECREF(code.as_ptr());
+        let byte_pos = PyIntRef::from_i64(byte_pos);
+        crate::ffi::PyObject_SetAttr(exc, BYTE_POS_STR, byte_pos.as_ptr());
+        Py_DECREF(byte_pos.as_ptr());
+        crate::ffi::PyErr_SetRaisedException(exc);
+    }
+}
+
+/// Set `code` and `byte_pos` on the `JSONDecodeError` that was just raised.
+#[cold]
+#[inline(never)]
+#[cfg(not(Py_3_12))]
+fn set_error_code(code: DecodeErrorCode, byte_pos: i64) {
+    unsafe {
+        let mut tp: *mut PyObject = null_mut();
+        let mut val: *mut PyObject = null_mut();
+        let mut traceback: *mut PyObject = null_mut();
+        crate::ffi::PyErr_Fetch(&mut tp, &mut val, &mut traceback);
+        crate::ffi::PyErr_NormalizeException(&mut tp, &mut val, &mut traceback);
+        let code = PyIntRef::from_u64(u64::from(code));
+        crate::ffi::PyObject_SetAttr(val, CODE_STR, code.as_ptr());
+        Py_DECREF(code.as_ptr());
+        let byte_pos = PyIntRef::from_i64(byte_pos);
+        crate::ffi::PyObject_SetAttr(val, BYTE_POS_STR, byte_pos.as_ptr());
+        Py_DECREF(byte_pos.as_ptr());
+        crate::ffi::PyErr_Restore(tp, val, traceback);
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_loads_exception_fixed(msg: &'static str) -> *mut PyObject {
+    raise_loads_exception(DeserializeError::invalid(
+        INVALID_PARAMETER,
+        Cow::Borrowed(msg),
+    ))
+}
+
+/// Raise `TypeError` for invalid arguments to `loads()` or `load()`, as
+/// CPython does for a builtin function.
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_loads_args_exception(msg: &'static str) -> *mut PyObject {
+    unsafe {
+        let err_msg = PyStrRef::from_str(msg);
+        PyErr_SetObject(crate::ffi::PyExc_TypeError, err_msg.as_ptr());
+        Py_DECREF(err_msg.as_ptr());
+    }
     null_mut()
 }
 
@@ -37,14 +101,71 @@ pub(crate) fn raise_dumps_exception_fixed(msg: &str) -> *mut PyObject {
         PyErr_SetObject(JsonEncodeError, err_msg.as_ptr());
         Py_DECREF(err_msg.as_ptr());
     }
+    set_raised_error_path();
     null_mut()
 }
 
+/// Set `path` on the `JSONEncodeError` that was just raised.
+#[cold]
+#[inline(never)]
+#[cfg(Py_3_12)]
+fn set_raised_error_path() {
+    unsafe {
+        let exc: *mut PyObject = crate::ffi::PyErr_GetRaisedException();
+        set_error_path(exc);
+        crate::ffi::PyErr_SetRaisedException(exc);
+    }
+}
+
+/// Set `path` on the `JSONEncodeError` that was just raised.
+#[cold]
+#[inline(never)]
+#[cfg(not(Py_3_12))]
+fn set_raised_error_path() {
+    unsafe {
+        let mut tp: *mut PyObject = null_mut();
+        let mut val: *mut PyObject = null_mut();
+        let mut traceback: *mut PyObject = null_mut();
+        crate::ffi::PyErr_Fetch(&mut tp, &mut val, &mut traceback);
+        crate::ffi::PyErr_NormalizeException(&mut tp, &mut val, &mut traceback);
+        set_error_path(val);
+        crate::ffi::PyErr_Restore(tp, val, traceback);
+    }
+}
+
+/// Set `path` on a `JSONEncodeError` raised by serialization to the keys and
+/// indices leading to the object that failed.
+#[cold]
+#[inline(never)]
+fn set_error_path(exc: *mut PyObject) {
+    unsafe {
+        let path = crate::serialize::take_error_path();
+        crate::ffi::PyObject_SetAttr(exc, PATH_STR, path);
+        Py_DECREF(path);
+    }
+}
+
+/// Leave the exception raised by `key_transform` as is instead of chaining
+/// it to a `JSONEncodeError`.
+#[cold]
+#[inline(never)]
+fn propagate_key_transform_exception(err: &str) -> bool {
+    if err == crate::serialize::KEY_TRANSFORM_RAISED_MESSAGE && !ffi!(PyErr_Occurred()).is_null() {
+        crate::serialize::clear_error_path();
+        true
+    } else {
+        false
+    }
+}
+
 #[cold]
 #[inline(never)]
 #[cfg_attr(feature = "optimize", optimize(size))]
 #[cfg(Py_3_12)]
 pub(crate) fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
+    if propagate_key_transform_exception(err) {
+        return null_mut();
+    }
     unsafe {
         let cause_exc: *mut PyObject = crate::ffi::PyErr_GetRaisedException();
 
@@ -52,11 +173,12 @@ pub(crate) fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
         PyErr_SetObject(JsonEncodeError, err_msg.as_ptr());
         Py_DECREF(err_msg.as_ptr());
 
+        let exc: *mut PyObject = crate::ffi::PyErr_GetRaisedException();
         if !cause_exc.is_null() {
-            let exc: *mut PyObject = crate::ffi::PyErr_GetRaisedException();
             crate::ffi::PyException_SetCause(exc, cause_exc);
-            crate::ffi::PyErr_SetRaisedException(exc);
         }
+        set_error_path(exc);
+        crate::ffi::PyErr_SetRaisedException(exc);
     }
     null_mut()
 }
@@ -66,6 +188,9 @@ pub(crate) fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
 #[cfg_attr(feature = "optimize", optimize(size))]
 #[cfg(not(Py_3_12))]
 pub(crate) fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
+    if propagate_key_transform_exception(err) {
+        return null_mut();
+    }
     unsafe {
         let mut cause_tp: *mut PyObject = null_mut();
         let mut cause_val: *mut PyObject = null_mut();
@@ -94,6 +219,7 @@ pub(crate) fn raise_dumps_exception_dynamic(err: &str) -> *mut PyObject {
         if !cause_traceback.is_null() {
             Py_DECREF(cause_traceback);
         }
+        set_error_path(val);
 
         crate::ffi::PyErr_Restore(tp, val, traceback);
     }
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use core::ffi::c_char;
+
+use crate::ffi::{
+    Py_DECREF, Py_TPFLAGS_DEFAULT, PyErr_Occurred, PyErr_SetObject, PyExc_TypeError,
+    PyLong_AsLongLong, PyObject, PyTupleRef, PyType_Ready, PyType_Type, PyTypeObject,
+    PyUnicode_FromStringAndSize, PyVarObject,
+};
+use core::ptr::null_mut;
+
+#[cfg(Py_GIL_DISABLED)]
+use super::atomiculong::AtomicCULong;
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+#[cfg(Py_GIL_DISABLED)]
+macro_rules! pymutex_new {
+    () => {
+        unsafe { core::mem::zeroed() }
+    };
+}
+
+/// `orjson.EpochNanoseconds`, an `int` of nanoseconds since the Unix epoch
+/// that is serialized as an RFC 3339 timestamp in UTC.
+#[repr(C)]
+pub(crate) struct EpochNanoseconds {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    pub value: i64,
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+fn raise_args_exception() {
+    unsafe {
+        let msg = "orjson.EpochNanoseconds() takes exactly 1 positional argument";
+        let err_msg =
+            PyUnicode_FromStringAndSize(msg.as_ptr().cast::<c_char>(), msg.len().cast_signed());
+        PyErr_SetObject(PyExc_TypeError, err_msg);
+        Py_DECREF(err_msg);
+    };
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_epochnanoseconds_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let argsob = PyTupleRef::from_ptr_unchecked(args);
+        if argsob.len() != 1 || !kwds.is_null() {
+            raise_args_exception();
+            null_mut()
+        } else {
+            let value = PyLong_AsLongLong(argsob.get(0));
+            if value == -1 && !PyErr_Occurred().is_null() {
+                return null_mut();
+            }
+            let obj = Box::new(EpochNanoseconds {
+                #[cfg(Py_GIL_DISABLED)]
+                ob_tid: 0,
+                #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+                ob_flags: 0,
+                #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+                _padding: 0,
+                #[cfg(Py_GIL_DISABLED)]
+                ob_mutex: pymutex_new!(),
+                #[cfg(Py_GIL_DISABLED)]
+                ob_gc_bits: 0,
+                #[cfg(Py_GIL_DISABLED)]
+                ob_ref_local: AtomicU32::new(0),
+                #[cfg(Py_GIL_DISABLED)]
+                ob_ref_shared: AtomicIsize::new(0),
+                #[cfg(not(Py_GIL_DISABLED))]
+                ob_refcnt: 1,
+                #[cfg(PyPy)]
+                ob_pypy_link: 0,
+                ob_type: crate::typeref::EPOCH_NANOSECONDS_TYPE,
+                value: value,
+            });
+            Box::into_raw(obj).cast::<PyObject>()
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_epochnanoseconds_dealloc(object: *mut PyObject) {
+    unsafe {
+        crate::ffi::PyMem_Free(object.cast::<core::ffi::c_void>());
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_epochnanosecondstype_new() -> *mut PyTypeObject {
+    unsafe {
+        #[cfg(Py_GIL_DISABLED)]
+        let tp_flags: AtomicCULong =
+            AtomicCULong::new(Py_TPFLAGS_DEFAULT | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE);
+        #[cfg(not(Py_GIL_DISABLED))]
+        let tp_flags: core::ffi::c_ulong = Py_TPFLAGS_DEFAULT | pyo3_ffi::Py_TPFLAGS_IMMUTABLETYPE;
+        let ob = Box::new(PyTypeObject {
+            ob_base: PyVarObject {
+                ob_base: PyObject {
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_tid: 0,
+                    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+                    ob_flags: 0,
+                    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+                    _padding: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_mutex: pymutex_new!(),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_gc_bits: 0,
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_local: AtomicU32::new(crate::ffi::compat::_Py_IMMORTAL_REFCNT_LOCAL),
+                    #[cfg(Py_GIL_DISABLED)]
+                    ob_ref_shared: AtomicIsize::new(0),
+                    #[cfg(all(Py_3_12, not(Py_GIL_DISABLED)))]
+                    ob_refcnt: pyo3_ffi::PyObjectObRefcnt { ob_refcnt: 0 },
+                    #[cfg(not(Py_3_12))]
+                    ob_refcnt: 0,
+                    #[cfg(PyPy)]
+                    ob_pypy_link: 0,
+                    ob_type: &raw mut PyType_Type,
+                },
+                #[cfg(not(GraalPy))]
+                ob_size: 0,
+                #[cfg(GraalPy)]
+                _ob_size_graalpy: 0,
+            },
+            tp_name: c"orjson.EpochNanoseconds".as_ptr(),
+            tp_basicsize: core::mem::size_of::<EpochNanoseconds>().cast_signed(),
+            tp_itemsize: 0,
+            tp_dealloc: Some(orjson_epochnanoseconds_dealloc),
+            tp_init: None,
+            tp_new: Some(orjson_epochnanoseconds_tp_new),
+            tp_flags: tp_flags,
+            // ...
+            tp_bases: null_mut(),
+            tp_cache: null_mut(),
+            tp_del: None,
+            tp_finalize: None,
+            tp_free: None,
+            tp_is_gc: None,
+            tp_mro: null_mut(),
+            tp_subclasses: null_mut(),
+            tp_vectorcall: None,
+            tp_version_tag: 0,
+            tp_weaklist: null_mut(),
+            tp_vectorcall_offset: 0,
+            tp_getattr: None,
+            tp_setattr: None,
+            tp_as_async: null_mut(),
+            tp_repr: None,
+            tp_as_number: null_mut(),
+            tp_as_sequence: null_mut(),
+            tp_as_mapping: null_mut(),
+            tp_hash: None,
+            tp_call: None,
+            tp_str: None,
+            tp_getattro: None,
+            tp_setattro: None,
+            tp_as_buffer: null_mut(),
+            tp_doc: core::ptr::null_mut(),
+            tp_traverse: None,
+            tp_clear: None,
+            tp_richcompare: None,
+            tp_weaklistoffset: 0,
+            tp_iter: None,
+            tp_iternext: None,
+            tp_methods: null_mut(),
+            tp_members: null_mut(),
+            tp_getset: null_mut(),
+            tp_base: null_mut(),
+            tp_dict: null_mut(),
+            tp_descr_get: None,
+            tp_descr_set: None,
+            tp_dictoffset: 0,
+            tp_alloc: None,
+            #[cfg(Py_3_12)]
+            tp_watched: 0,
+        });
+        let ob_ptr = Box::into_raw(ob);
+        PyType_Ready(ob_ptr);
+        ob_ptr
+    }
+}
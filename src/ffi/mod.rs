@@ -7,6 +7,7 @@ mod atomiculong;
 mod buffer;
 mod bytes;
 pub(crate) mod compat;
+mod epochnanoseconds;
 mod fragment;
 mod pyboolref;
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
@@ -27,9 +28,13 @@ mod utf8;
 
 pub(crate) use compat::*;
 
+#[cfg(Py_GIL_DISABLED)]
+pub(crate) use atomiculong::AtomicCULong;
+
 #[allow(unused_imports)]
 pub(crate) use {
     bytes::{PyBytes_AS_STRING, PyBytes_GET_SIZE, PyBytesObject},
+    epochnanoseconds::{EpochNanoseconds, orjson_epochnanosecondstype_new},
     fragment::{Fragment, orjson_fragmenttype_new},
     pyboolref::PyBoolRef,
     pybytesref::{PyBytesRef, PyBytesRefError},
@@ -54,38 +59,60 @@ pub(crate) use {
 #[allow(unused_imports)]
 pub(crate) use pyo3_ffi::{
     METH_FASTCALL, METH_KEYWORDS, METH_O, Py_DECREF, Py_False, Py_INCREF, Py_None, Py_REFCNT,
-    Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DICT_SUBCLASS, Py_TPFLAGS_LIST_SUBCLASS,
+    Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DICT_SUBCLASS, Py_TPFLAGS_HEAPTYPE, Py_TPFLAGS_LIST_SUBCLASS,
     Py_TPFLAGS_LONG_SUBCLASS, Py_TPFLAGS_TUPLE_SUBCLASS, Py_TPFLAGS_UNICODE_SUBCLASS, Py_TYPE,
     Py_True, Py_XDECREF, Py_buffer, Py_hash_t, Py_intptr_t, Py_mod_exec, Py_ssize_t, PyASCIIObject,
     PyBool_Type, PyBuffer_IsContiguous, PyByteArray_AsString, PyByteArray_Size, PyByteArray_Type,
     PyBytes_FromStringAndSize, PyBytes_Type, PyCFunction_NewEx, PyCapsule_Import,
-    PyCriticalSection, PyCriticalSection_Begin, PyCriticalSection_End,
-    PyCompactUnicodeObject, PyDateTime_CAPI, PyDateTime_DATE_GET_HOUR,
-    PyDateTime_DATE_GET_MICROSECOND, PyDateTime_DATE_GET_MINUTE, PyDateTime_DATE_GET_SECOND,
-    PyDateTime_DATE_GET_TZINFO, PyDateTime_DELTA_GET_DAYS, PyDateTime_DELTA_GET_SECONDS,
+    PyCompactUnicodeObject, PyCriticalSection, PyCriticalSection_Begin, PyCriticalSection_End,
+    PyDateTime_CAPI, PyDateTime_DATE_GET_HOUR, PyDateTime_DATE_GET_MICROSECOND,
+    PyDateTime_DATE_GET_MINUTE, PyDateTime_DATE_GET_SECOND, PyDateTime_DATE_GET_TZINFO,
+    PyDateTime_DELTA_GET_DAYS, PyDateTime_DELTA_GET_MICROSECONDS, PyDateTime_DELTA_GET_SECONDS,
     PyDateTime_DateTime, PyDateTime_GET_DAY, PyDateTime_GET_MONTH, PyDateTime_GET_YEAR,
     PyDateTime_IMPORT, PyDateTime_TIME_GET_HOUR, PyDateTime_TIME_GET_MICROSECOND,
     PyDateTime_TIME_GET_MINUTE, PyDateTime_TIME_GET_SECOND, PyDateTime_Time, PyDict_Contains,
-    PyDict_Next, PyDict_SetItem, PyDict_Type, PyDictObject, PyErr_Clear, PyErr_NewException,
-    PyErr_Occurred, PyErr_SetObject, PyExc_TypeError, PyException_SetCause, PyFloat_AS_DOUBLE,
-    PyFloat_FromDouble, PyFloat_Type, PyImport_ImportModule, PyList_GET_ITEM, PyList_New,
-    PyList_SET_ITEM, PyList_Type, PyListObject, PyLong_AsLong, PyLong_AsLongLong,
-    PyLong_AsUnsignedLongLong, PyLong_FromLongLong, PyLong_FromUnsignedLongLong, PyLong_Type,
-    PyLongObject, PyMapping_GetItemString, PyMem_Free, PyMem_Malloc, PyMem_Realloc,
-    PyMemoryView_Type, PyMethodDef, PyMethodDefPointer, PyModule_AddIntConstant,
-    PyModule_AddObject, PyModuleDef, PyModuleDef_HEAD_INIT, PyModuleDef_Init, PyModuleDef_Slot,
-    PyObject, PyObject_CallFunctionObjArgs, PyObject_CallMethodObjArgs, PyObject_GenericGetDict,
-    PyObject_GetAttr, PyObject_HasAttr, PyObject_Hash, PyObject_Vectorcall, PyTuple_New,
-    PyTuple_Type, PyTupleObject, PyType_Ready, PyType_Type, PyTypeObject, PyUnicode_AsUTF8AndSize,
-    PyUnicode_FromStringAndSize, PyUnicode_InternFromString, PyUnicode_New, PyUnicode_Type,
-    PyVarObject, PyVectorcall_NARGS,
+    PyDict_GetItemWithError, PyDict_Merge, PyDict_Next, PyDict_SetItem, PyDict_Size, PyDict_Type,
+    PyDictObject, PyErr_Clear, PyErr_ExceptionMatches, PyErr_NewException, PyErr_Occurred,
+    PyErr_SetObject, PyExc_AttributeError, PyExc_TypeError, PyException_SetCause,
+    PyFloat_AS_DOUBLE, PyFloat_FromDouble, PyFloat_Type, PyImport_ImportModule, PyIter_Next,
+    PyList_GET_ITEM, PyList_New, PyList_SET_ITEM, PyList_Type, PyListObject, PyLong_AsLong,
+    PyLong_AsLongLong, PyLong_AsUnsignedLongLong, PyLong_FromLongLong, PyLong_FromUnsignedLongLong,
+    PyLong_Type, PyLongObject, PyMapping_GetItemString, PyMem_Free, PyMem_Malloc, PyMem_Realloc,
+    PyMemoryView_FromObject, PyMemoryView_Type, PyMethodDef, PyMethodDefPointer,
+    PyModule_AddIntConstant, PyModule_AddObject, PyModuleDef, PyModuleDef_HEAD_INIT,
+    PyModuleDef_Init, PyModuleDef_Slot, PyNumber_Float, PyObject, PyObject_CallFunctionObjArgs,
+    PyObject_CallMethodObjArgs, PyObject_GenericGetDict, PyObject_GetAttr, PyObject_GetItem,
+    PyObject_GetIter, PyObject_HasAttr, PyObject_Hash, PyObject_IsTrue, PyObject_Repr,
+    PyObject_SetAttr, PyObject_Vectorcall, PyTuple_New, PyTuple_Type, PyTupleObject, PyType_Ready,
+    PyType_Type, PyTypeObject, PyUnicode_AsUTF8AndSize, PyUnicode_FromStringAndSize,
+    PyUnicode_InternFromString, PyUnicode_New, PyUnicode_Type, PyVarObject, PyVectorcall_NARGS,
+};
+
+pub(crate) use pyo3_ffi::{
+    Py_TPFLAGS_HAVE_GC, PyObject_GC_Del, PyObject_GC_New, PyObject_GC_Track, PyObject_GC_UnTrack,
+    visitproc,
+};
+
+pub(crate) use pyo3_ffi::{
+    PyBUF_FULL_RO, PyBUF_WRITABLE, PyBuffer_Release, PyBuffer_ToContiguous, PyObject_Bytes,
+    PyObject_GetBuffer,
+};
+
+pub(crate) use pyo3_ffi::{
+    Py_LT, Py_TPFLAGS_BYTES_SUBCLASS, Py_TPFLAGS_TYPE_SUBCLASS, PyAnySet_Check, PyCallable_Check,
+    PyDict_Copy, PyDictProxy_Type, PyFrozenSet_New, PyLong_AsUnsignedLongLongMask, PyMapping_Check,
+    PyMapping_Keys, PyNumber_Lshift, PyNumber_Rshift, PyObject_RichCompareBool, PyRange_Type,
+    PySet_Contains, PyType_IsSubtype, PyWeakref_CheckProxy, compat::PyWeakref_GetRef,
 };
 
 #[allow(unused_imports, deprecated)]
 pub(crate) use pyo3_ffi::PyErr_Restore;
 
 #[cfg(CPython)]
-pub(crate) use pyo3_ffi::{PyObject_CallMethodNoArgs, PyObject_CallMethodOneArg};
+#[allow(unused_imports)]
+pub(crate) use pyo3_ffi::{
+    PyObject_CallMethodNoArgs, PyObject_CallMethodOneArg, PyObject_CheckBuffer,
+};
 
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 pub(crate) use buffer::PyMemoryView_GET_BUFFER;
@@ -35,6 +35,18 @@ impl PyBytesRef {
         }
     }
 
+    #[allow(unused)]
+    #[inline]
+    pub unsafe fn from_ptr_unchecked(ptr: *mut pyo3_ffi::PyObject) -> Self {
+        unsafe {
+            debug_assert!(!ptr.is_null());
+            debug_assert!(ob_type!(ptr) == crate::typeref::BYTES_TYPE);
+            Self {
+                ptr: core::ptr::NonNull::new_unchecked(ptr),
+            }
+        }
+    }
+
     #[inline]
     pub fn as_ptr(&self) -> *mut pyo3_ffi::PyObject {
         self.ptr.as_ptr()
@@ -4,7 +4,7 @@
 #[allow(unused)]
 use super::Py_TPFLAGS_LONG_SUBCLASS;
 use super::{PyLong_FromLongLong, PyLong_FromUnsignedLongLong, PyObject};
-use crate::opt::{MAX_OPT, Opt};
+use crate::opt::{HighOpt, MAX_HIGH_OPT, MAX_OPT, Opt};
 
 // longintrepr.h, _longobject, _PyLongValue
 
@@ -13,6 +13,10 @@ use crate::opt::{MAX_OPT, Opt};
 #[allow(non_upper_case_globals)]
 const SIGN_MASK: usize = 3;
 
+#[cfg(all(Py_3_12, feature = "inline_int"))]
+#[allow(non_upper_case_globals)]
+const SIGN_ZERO: usize = 1;
+
 #[cfg(all(Py_3_12, feature = "inline_int"))]
 #[allow(non_upper_case_globals)]
 const NON_SIZE_BITS: usize = 3;
@@ -135,6 +139,20 @@ impl PyIntRef {
         unsafe { (*self.as_ptr().cast::<super::PyVarObject>()).ob_size < 0 }
     }
 
+    #[cfg(all(Py_3_12, feature = "inline_int"))]
+    #[inline]
+    fn is_zero(&self) -> bool {
+        unsafe {
+            (*self.as_ptr().cast::<PyLongObject>()).long_value.lv_tag & SIGN_MASK == SIGN_ZERO
+        }
+    }
+
+    #[cfg(all(not(Py_3_12), feature = "inline_int"))]
+    #[inline]
+    fn is_zero(&self) -> bool {
+        unsafe { super::Py_SIZE(self.as_ptr()) == 0 }
+    }
+
     #[cfg(all(Py_3_12, feature = "inline_int"))]
     #[inline]
     pub fn fits_in_i32(&self) -> bool {
@@ -244,35 +262,82 @@ impl PyIntRef {
     }
 
     #[cfg(feature = "inline_int")]
-    pub fn as_opt(&self) -> Result<Opt, PyIntOptConversionError> {
-        let val = self.get_inline_value();
-        if val == 0 {
-            Ok(val as Opt)
+    pub fn as_opt(&self) -> Result<(Opt, HighOpt), PyIntOptConversionError> {
+        // the first digit of a larger int may also be 0
+        if self.is_zero() {
+            Ok((0, 0))
         } else {
             match self.kind() {
                 PyIntKind::U32 => {
-                    if !(0..=MAX_OPT as u32).contains(&val) {
+                    let val = self.get_inline_value();
+                    if Opt::from(val) > MAX_OPT {
                         Err(PyIntOptConversionError::InvalidRange)
                     } else {
-                        Ok(val as Opt)
+                        Ok((Opt::from(val), 0))
                     }
                 }
+                PyIntKind::U64 => match unsafe { self.as_u64() } {
+                    Ok(val) if val <= MAX_OPT => Ok((val, 0)),
+                    Ok(_) => Err(PyIntOptConversionError::InvalidRange),
+                    Err(_) => self.as_opt_high(),
+                },
                 _ => Err(PyIntOptConversionError::InvalidRange),
             }
         }
     }
 
     #[cfg(not(feature = "inline_int"))]
-    pub fn as_opt(&self) -> Result<Opt, PyIntOptConversionError> {
+    pub fn as_opt(&self) -> Result<(Opt, HighOpt), PyIntOptConversionError> {
         match unsafe { self.as_u64() } {
             Ok(val) => {
-                if !(0..=MAX_OPT as u64).contains(&val) {
+                if val > MAX_OPT {
                     Err(PyIntOptConversionError::InvalidRange)
                 } else {
-                    Ok(val as Opt)
+                    Ok((val, 0))
                 }
             }
-            Err(_) => Err(PyIntOptConversionError::InvalidRange),
+            Err(_) => self.as_opt_high(),
+        }
+    }
+
+    /// An `int` that does not fit in a `u64` may have options of `HighOpt`
+    /// in its high 64 bits.
+    #[cold]
+    #[inline(never)]
+    fn as_opt_high(&self) -> Result<(Opt, HighOpt), PyIntOptConversionError> {
+        ffi!(PyErr_Clear());
+        let shift = ffi!(PyLong_FromLongLong(64));
+        let high = ffi!(PyNumber_Rshift(self.as_ptr(), shift));
+        ffi!(Py_DECREF(shift));
+        if high.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyIntOptConversionError::InvalidRange);
+        }
+        // a negative int or one of more than 128 bits raises OverflowError
+        let high_val = ffi!(PyLong_AsUnsignedLongLong(high));
+        ffi!(Py_DECREF(high));
+        if high_val == u64::MAX && !ffi!(PyErr_Occurred()).is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyIntOptConversionError::InvalidRange);
+        }
+        let low_val = ffi!(PyLong_AsUnsignedLongLongMask(self.as_ptr()));
+        if low_val > MAX_OPT || high_val > MAX_HIGH_OPT {
+            Err(PyIntOptConversionError::InvalidRange)
+        } else {
+            Ok((low_val, high_val))
+        }
+    }
+
+    #[cold]
+    pub fn as_bounded_usize(&self, max: usize) -> Option<usize> {
+        let val = ffi!(PyLong_AsLongLong(self.as_ptr()));
+        if val == -1 && !ffi!(PyErr_Occurred()).is_null() {
+            ffi!(PyErr_Clear());
+            None
+        } else if val < 0 || val.cast_unsigned() > max as u64 {
+            None
+        } else {
+            Some(val.cast_unsigned() as usize)
         }
     }
 
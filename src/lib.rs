@@ -45,26 +45,33 @@ extern crate unwinding;
 mod util;
 
 mod alloc;
+mod base62;
+mod decoder;
 mod deserialize;
+mod encoder;
 mod exception;
 mod ffi;
 mod opt;
+mod qualname;
 mod serialize;
 mod typeref;
 
 use core::ffi::{c_char, c_int, c_void};
 use core::ptr::{NonNull, null, null_mut};
 
-use crate::deserialize::deserialize;
+use crate::deserialize::{deserialize, read_input};
 use crate::exception::{
-    raise_dumps_exception_dynamic, raise_dumps_exception_fixed, raise_loads_exception,
+    raise_dumps_exception_dynamic, raise_dumps_exception_fixed, raise_loads_args_exception,
+    raise_loads_exception, raise_loads_exception_fixed,
 };
 use crate::ffi::{
-    METH_KEYWORDS, METH_O, Py_SIZE, Py_ssize_t, PyCFunction_NewEx, PyIntRef, PyMethodDef,
+    METH_KEYWORDS, Py_SIZE, Py_buffer, Py_ssize_t, PyBUF_WRITABLE, PyBoolRef, PyBuffer_Release,
+    PyBytes_AS_STRING, PyBytes_GET_SIZE, PyCFunction_NewEx, PyIntRef, PyMethodDef,
     PyMethodDefPointer, PyModuleDef, PyModuleDef_HEAD_INIT, PyModuleDef_Slot, PyNoneRef, PyObject,
-    PyTupleRef, PyUnicode_FromStringAndSize, PyUnicode_InternFromString, PyVectorcall_NARGS,
+    PyObject_GetBuffer, PyStrRef, PyTupleRef, PyUnicode_FromStringAndSize,
+    PyUnicode_InternFromString, PyVectorcall_NARGS,
 };
-use crate::serialize::serialize;
+use crate::serialize::{DumpsConfig, OUTPUT_TOO_LARGE_MESSAGE, serialize, validate};
 use crate::util::{isize_to_usize, usize_to_isize};
 
 #[cfg(Py_3_13)]
@@ -81,17 +88,33 @@ macro_rules! add {
     };
 }
 
+// options do not fit in a C long, which is 32 bits on Windows
 macro_rules! opt {
     ($mptr:expr, $name:expr, $opt:expr) => {
-        #[cfg(all(not(target_os = "windows"), target_pointer_width = "64"))]
-        crate::ffi::PyModule_AddIntConstant($mptr, $name.as_ptr(), i64::from($opt));
-        #[cfg(all(not(target_os = "windows"), target_pointer_width = "32"))]
-        crate::ffi::PyModule_AddIntConstant($mptr, $name.as_ptr(), $opt as i32);
-        #[cfg(target_os = "windows")]
-        crate::ffi::PyModule_AddIntConstant($mptr, $name.as_ptr(), $opt as i32);
+        add!($mptr, $name, crate::ffi::PyLong_FromUnsignedLongLong($opt));
     };
 }
 
+macro_rules! high_opt {
+    ($mptr:expr, $name:expr, $opt:expr) => {
+        add!($mptr, $name, pylong_from_high_opt($opt));
+    };
+}
+
+/// Create the `int` of an option of `HighOpt`, which is its bit shifted into
+/// the high 64 bits.
+#[cold]
+unsafe fn pylong_from_high_opt(opt: opt::HighOpt) -> *mut PyObject {
+    unsafe {
+        let val = crate::ffi::PyLong_FromUnsignedLongLong(opt);
+        let shift = crate::ffi::PyLong_FromLongLong(64);
+        let ret = crate::ffi::PyNumber_Lshift(val, shift);
+        crate::ffi::Py_DECREF(val);
+        crate::ffi::Py_DECREF(shift);
+        ret
+    }
+}
+
 #[allow(non_snake_case)]
 #[unsafe(no_mangle)]
 #[cold]
@@ -110,7 +133,7 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         }
 
         {
-            let dumps_doc = c"dumps(obj, /, default=None, option=None)\n--\n\nSerialize Python objects to JSON.";
+            let dumps_doc = c"dumps(obj, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)\n--\n\nSerialize Python objects to JSON.";
 
             let wrapped_dumps = Box::new(PyMethodDef {
                 ml_name: c"dumps".as_ptr(),
@@ -130,12 +153,53 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         }
 
         {
-            let loads_doc = c"loads(obj, /)\n--\n\nDeserialize JSON to Python objects.";
+            let dumps_into_buffer_doc = c"dumps_into_buffer(obj, buffer, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)\n--\n\nSerialize Python objects to JSON written to a writable buffer.";
+
+            let wrapped_dumps_into_buffer = Box::new(PyMethodDef {
+                ml_name: c"dumps_into_buffer".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: dumps_into_buffer,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: dumps_into_buffer_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_dumps_into_buffer),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"dumps_into_buffer", func);
+        }
+
+        {
+            let dumps_chunked_doc = c"dumps_chunked(obj, chunk_size, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)\n--\n\nSerialize Python objects to JSON split into a list of bytes of at most chunk_size.";
+
+            let wrapped_dumps_chunked = Box::new(PyMethodDef {
+                ml_name: c"dumps_chunked".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: dumps_chunked,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: dumps_chunked_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_dumps_chunked),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"dumps_chunked", func);
+        }
+
+        {
+            let loads_doc =
+                c"loads(obj, /, option=None)\n--\n\nDeserialize JSON to Python objects.";
 
             let wrapped_loads = Box::new(PyMethodDef {
                 ml_name: c"loads".as_ptr(),
-                ml_meth: PyMethodDefPointer { PyCFunction: loads },
-                ml_flags: METH_O,
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: loads,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
                 ml_doc: loads_doc.as_ptr(),
             });
             let func = PyCFunction_NewEx(
@@ -146,26 +210,168 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
             add!(mptr, c"loads", func);
         }
 
+        {
+            let load_doc = c"load(fp, /, option=None)\n--\n\nDeserialize JSON read from a file-like object to Python objects.";
+
+            let wrapped_load = Box::new(PyMethodDef {
+                ml_name: c"load".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: load,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: load_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_load),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"load", func);
+        }
+
+        {
+            let qualname_doc = c"qualname(obj, /)\n--\n\nReturn the module and qualified name of a function or class.";
+
+            let wrapped_qualname = Box::new(PyMethodDef {
+                ml_name: c"qualname".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: qualname::qualname,
+                },
+                ml_flags: crate::ffi::METH_O,
+                ml_doc: qualname_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_qualname),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"qualname", func);
+        }
+
+        add!(mptr, c"Decoder", typeref::DECODER_TYPE.cast::<PyObject>());
+        add!(mptr, c"Encoder", typeref::ENCODER_TYPE.cast::<PyObject>());
+        add!(
+            mptr,
+            c"EpochNanoseconds",
+            typeref::EPOCH_NANOSECONDS_TYPE.cast::<PyObject>()
+        );
         add!(mptr, c"Fragment", typeref::FRAGMENT_TYPE.cast::<PyObject>());
 
+        opt!(
+            mptr,
+            c"OPT_ANNOTATED_MISSING_AS_NULL",
+            opt::ANNOTATED_MISSING_AS_NULL
+        );
         opt!(mptr, c"OPT_APPEND_NEWLINE", opt::APPEND_NEWLINE);
+        opt!(mptr, c"OPT_BYTES_AS_UUID", opt::BYTES_AS_UUID);
+        high_opt!(mptr, c"OPT_CACHE_KEYS", opt::CACHE_KEYS);
+        opt!(mptr, c"OPT_CANONICAL", opt::CANONICAL);
+        opt!(mptr, c"OPT_DEDUPLICATE_ARRAYS", opt::DEDUPLICATE_ARRAYS);
+        opt!(mptr, c"OPT_DEFAULT_FIRST", opt::DEFAULT_FIRST);
+        high_opt!(mptr, c"OPT_ENSURE_ASCII", opt::ENSURE_ASCII);
+        opt!(mptr, c"OPT_ENUM_FULL", opt::ENUM_FULL);
+        opt!(mptr, c"OPT_ENUM_REPR", opt::ENUM_REPR);
+        high_opt!(mptr, c"OPT_ESCAPE_HTML", opt::ESCAPE_HTML);
+        opt!(mptr, c"OPT_FLAG_AS_INT", opt::FLAG_AS_INT);
+        opt!(mptr, c"OPT_FLOAT_TRAILING_ZERO", opt::FLOAT_TRAILING_ZERO);
+        opt!(mptr, c"OPT_FRACTION_AS_FLOAT", opt::FRACTION_AS_FLOAT);
         opt!(mptr, c"OPT_INDENT_2", opt::INDENT_2);
+        opt!(mptr, c"OPT_INT_BASE62", opt::INT_BASE62);
+        opt!(mptr, c"OPT_LENGTH_TAGGED", opt::LENGTH_TAGGED);
+        opt!(mptr, c"OPT_NAIVE_AS_NULL", opt::NAIVE_AS_NULL);
         opt!(mptr, c"OPT_NAIVE_UTC", opt::NAIVE_UTC);
+        opt!(mptr, c"OPT_NAMEDTUPLE_AS_OBJECT", opt::NAMEDTUPLE_AS_OBJECT);
+        opt!(mptr, c"OPT_NONFINITE_AS_STRING", opt::NONFINITE_AS_STRING);
         opt!(mptr, c"OPT_NON_STR_KEYS", opt::NON_STR_KEYS);
+        opt!(mptr, c"OPT_NORMALIZE_UTC", opt::NORMALIZE_UTC);
+        opt!(mptr, c"OPT_NUMPY_BASE64", opt::NUMPY_BASE64);
+        opt!(mptr, c"OPT_NUMPY_SHORTEST_FLOAT", opt::NUMPY_SHORTEST_FLOAT);
+        opt!(mptr, c"OPT_OFFSET_NO_COLON", opt::OFFSET_NO_COLON);
         opt!(mptr, c"OPT_OMIT_MICROSECONDS", opt::OMIT_MICROSECONDS);
+        opt!(mptr, c"OPT_OMIT_NONE", opt::OMIT_NONE);
+        high_opt!(mptr, c"OPT_OMIT_TOP_LEVEL_NONE", opt::OMIT_TOP_LEVEL_NONE);
+        opt!(mptr, c"OPT_PAD_NANOSECONDS", opt::PAD_NANOSECONDS);
+        high_opt!(
+            mptr,
+            c"OPT_PARSE_NUMBER_AS_STRING",
+            opt::PARSE_NUMBER_AS_STRING
+        );
+        high_opt!(mptr, c"OPT_PARSE_OBJECT_PAIRS", opt::PARSE_OBJECT_PAIRS);
         opt!(
             mptr,
             c"OPT_PASSTHROUGH_DATACLASS",
             opt::PASSTHROUGH_DATACLASS
         );
         opt!(mptr, c"OPT_PASSTHROUGH_DATETIME", opt::PASSTHROUGH_DATETIME);
+        opt!(mptr, c"OPT_PASSTHROUGH_ENUM", opt::PASSTHROUGH_ENUM);
+        opt!(
+            mptr,
+            c"OPT_PASSTHROUGH_NON_FINITE",
+            opt::PASSTHROUGH_NON_FINITE
+        );
         opt!(mptr, c"OPT_PASSTHROUGH_SUBCLASS", opt::PASSTHROUGH_SUBCLASS);
+        opt!(
+            mptr,
+            c"OPT_PYDANTIC_EXCLUDE_UNSET",
+            opt::PYDANTIC_EXCLUDE_UNSET
+        );
+        opt!(mptr, c"OPT_RANGE_AS_ARRAY", opt::RANGE_AS_ARRAY);
+        opt!(mptr, c"OPT_SERIALIZE_ANNOTATED", opt::SERIALIZE_ANNOTATED);
+        opt!(mptr, c"OPT_SERIALIZE_BYTES", opt::SERIALIZE_BYTES);
+        opt!(mptr, c"OPT_SERIALIZE_BYTES_GZIP", opt::SERIALIZE_BYTES_GZIP);
         opt!(mptr, c"OPT_SERIALIZE_DATACLASS", opt::SERIALIZE_DATACLASS);
+        opt!(
+            mptr,
+            c"OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET",
+            opt::SERIALIZE_DATETIME_AS_EPOCH_OFFSET
+        );
+        opt!(
+            mptr,
+            c"OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR",
+            opt::SERIALIZE_DATETIME_AS_ISOCALENDAR
+        );
+        opt!(
+            mptr,
+            c"OPT_SERIALIZE_DATETIME_AS_UNIX",
+            opt::SERIALIZE_DATETIME_AS_UNIX
+        );
+        opt!(mptr, c"OPT_SERIALIZE_FRACTIONS", opt::SERIALIZE_FRACTIONS);
+        opt!(mptr, c"OPT_SERIALIZE_ITERATORS", opt::SERIALIZE_ITERATORS);
+        opt!(
+            mptr,
+            c"OPT_SERIALIZE_JSON_METHOD",
+            opt::SERIALIZE_JSON_METHOD
+        );
+        opt!(mptr, c"OPT_SERIALIZE_MAPPING", opt::SERIALIZE_MAPPING);
         opt!(mptr, c"OPT_SERIALIZE_NUMPY", opt::SERIALIZE_NUMPY);
+        opt!(
+            mptr,
+            c"OPT_SERIALIZE_ORJSON_DEFAULT",
+            opt::SERIALIZE_ORJSON_DEFAULT
+        );
+        opt!(mptr, c"OPT_SERIALIZE_PYDANTIC", opt::SERIALIZE_PYDANTIC);
+        opt!(mptr, c"OPT_SERIALIZE_RANGE", opt::SERIALIZE_RANGE);
+        opt!(mptr, c"OPT_SERIALIZE_TIMEDELTA", opt::SERIALIZE_TIMEDELTA);
         opt!(mptr, c"OPT_SERIALIZE_UUID", opt::SERIALIZE_UUID);
+        opt!(mptr, c"OPT_SERIALIZE_WEAKREF", opt::SERIALIZE_WEAKREF);
+        opt!(
+            mptr,
+            c"OPT_SORT_DATACLASS_FIELDS",
+            opt::SORT_DATACLASS_FIELDS
+        );
         opt!(mptr, c"OPT_SORT_KEYS", opt::SORT_KEYS);
+        opt!(mptr, c"OPT_SORT_KEYS_BY_HASH", opt::SORT_KEYS_BY_HASH);
+        opt!(mptr, c"OPT_SORT_KEYS_BY_VALUE", opt::SORT_KEYS_BY_VALUE);
+        high_opt!(mptr, c"OPT_STRICT_INPUT", opt::STRICT_INPUT);
         opt!(mptr, c"OPT_STRICT_INTEGER", opt::STRICT_INTEGER);
+        opt!(mptr, c"OPT_TIMEDELTA_ISO8601", opt::TIMEDELTA_ISO8601);
+        opt!(mptr, c"OPT_UNIQUE_KEYS", opt::UNIQUE_KEYS);
+        opt!(mptr, c"OPT_UNIX_MILLISECONDS", opt::UNIX_MILLISECONDS);
         opt!(mptr, c"OPT_UTC_Z", opt::UTC_Z);
+        opt!(mptr, c"OPT_UUID_BRACED", opt::UUID_BRACED);
+        opt!(mptr, c"OPT_UUID_HEX", opt::UUID_HEX);
+        opt!(mptr, c"OPT_UUID_URN", opt::UUID_URN);
+        opt!(mptr, c"OPT_ZONE_NAME", opt::ZONE_NAME);
 
         add!(mptr, c"JSONDecodeError", typeref::JsonDecodeError);
         add!(mptr, c"JSONEncodeError", typeref::JsonEncodeError);
@@ -231,11 +437,6 @@ pub(crate) unsafe extern "C" fn PyInit_orjson() -> *mut PyModuleDef {
     }
 }
 
-#[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn loads(_self: *mut PyObject, obj: *mut PyObject) -> *mut PyObject {
-    deserialize(obj).map_or_else(raise_loads_exception, NonNull::as_ptr)
-}
-
 #[cfg(CPython)]
 macro_rules! matches_kwarg {
     ($val:expr, $ref:expr) => {
@@ -250,29 +451,381 @@ macro_rules! matches_kwarg {
     };
 }
 
+const INDENT_MESSAGE: &str = "indent must be an int from 0 to 8";
+
+const KEY_DELIMITER_MESSAGE: &str = "key_delimiter must be a str";
+
+const FLOAT_SIGFIGS_MESSAGE: &str = "float_sigfigs must be an int from 1 to 17";
+
+const KEY_TRANSFORM_MESSAGE: &str = "key_transform must be callable";
+
+const MAX_OUTPUT_SIZE_MESSAGE: &str = "max_output_size must be a positive int";
+
+const VALIDATE_ONLY_MESSAGE: &str = "validate_only must be a bool";
+
+const MAX_SEQUENCE_LEN_MESSAGE: &str = "max_sequence_len must be a positive int";
+
+const CHUNK_SIZE_MESSAGE: &str = "chunk_size must be a positive int";
+
+const BUFFER_MESSAGE: &str =
+    "buffer must be a writable, C-contiguous object supporting the buffer protocol";
+
+/// Convert an `option` argument, which is an `int` of options or `None`, to
+/// its low and high words.
+#[cold]
+fn option_words_from_arg(ptr: NonNull<PyObject>) -> Result<(opt::Opt, opt::HighOpt), ()> {
+    match PyIntRef::from_ptr(ptr.as_ptr()) {
+        Ok(val) => val.as_opt().map_err(|_| ()),
+        Err(_) => {
+            if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+                Ok((0, 0))
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// Convert an `option` argument of `dumps()` or `orjson.Encoder`, which is an
+/// `int` of options of `dumps()` or `None`, to its low and high words.
+#[cold]
+pub(crate) fn opts_from_arg(ptr: NonNull<PyObject>) -> Result<(opt::Opt, opt::HighOpt), ()> {
+    match option_words_from_arg(ptr) {
+        // the options only of loads() do not apply
+        Ok((_, high_opts)) if high_opts & opt::LOADS_HIGH_OPT != 0 => Err(()),
+        // the UUID formats are mutually exclusive
+        Ok((opts, _)) if (opts & opt::UUID_FORMATS).count_ones() > 1 => Err(()),
+        // as are the enum formats
+        Ok((opts, _)) if opts & opt::ENUM_FORMATS == opt::ENUM_FORMATS => Err(()),
+        // as are the key orders other than lexical
+        Ok((opts, _)) if opts & opt::SORT_KEYS_ORDERS == opt::SORT_KEYS_ORDERS => Err(()),
+        // the length of an array with OPT_LENGTH_TAGGED would count the
+        // elements that OPT_DEDUPLICATE_ARRAYS omits
+        Ok((opts, _))
+            if opt_enabled!(opts, opt::LENGTH_TAGGED)
+                && opt_enabled!(opts, opt::DEDUPLICATE_ARRAYS) =>
+        {
+            Err(())
+        }
+        // canonical output has no insignificant whitespace, escapes only
+        // what it must, does not serialize numpy floats, and has its own
+        // float format and key order
+        Ok((opts, high_opts))
+            if opt_enabled!(opts, opt::CANONICAL)
+                && (opts & opt::CANONICAL_INCOMPATIBLE != 0
+                    || high_opts & opt::CANONICAL_INCOMPATIBLE_HIGH != 0) =>
+        {
+            Err(())
+        }
+        Ok((opts, high_opts)) if opt_enabled!(opts, opt::CANONICAL) => {
+            Ok((opts | opt::SORT_KEYS | opt::STRICT_INTEGER, high_opts))
+        }
+        Ok((opts, high_opts)) if opts & opt::SORT_KEYS_ORDERS != 0 => {
+            Ok((opts | opt::SORT_KEYS, high_opts))
+        }
+        res => res,
+    }
+}
+
+/// Convert an `option` argument of `loads()`, `load()`, or `orjson.Decoder`,
+/// which is an `int` of options of `loads()` or `None`, to its low and high
+/// words.
+#[cold]
+pub(crate) fn loads_opts_from_arg(ptr: NonNull<PyObject>) -> Result<(opt::Opt, opt::HighOpt), ()> {
+    match option_words_from_arg(ptr) {
+        Ok((opts, high_opts))
+            if opts & !opt::LOADS_OPT != 0 || high_opts & !opt::LOADS_HIGH_OPT != 0 =>
+        {
+            Err(())
+        }
+        res => res,
+    }
+}
+
+/// Convert an `indent` argument, which is an `int` of at most `MAX_INDENT`
+/// or `None`.
+#[cold]
+fn indent_from_arg(ptr: NonNull<PyObject>) -> Result<Option<usize>, ()> {
+    match PyIntRef::from_ptr(ptr.as_ptr()) {
+        Ok(val) => match val.as_bounded_usize(opt::MAX_INDENT) {
+            Some(width) => Ok(Some(width)),
+            None => Err(()),
+        },
+        Err(_) => {
+            if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+                Ok(None)
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// Convert a `key_delimiter` argument, which is a `str` or `None`.
+#[cold]
+fn key_delimiter_from_arg(ptr: NonNull<PyObject>) -> Result<Option<NonNull<PyObject>>, ()> {
+    if PyStrRef::from_ptr(ptr.as_ptr()).is_ok() {
+        Ok(Some(ptr))
+    } else if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+        Ok(None)
+    } else {
+        Err(())
+    }
+}
+
+/// Convert a `key_transform` argument, which is a callable or `None`.
+#[cold]
+fn key_transform_from_arg(ptr: NonNull<PyObject>) -> Result<Option<NonNull<PyObject>>, ()> {
+    if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+        Ok(None)
+    } else if ffi!(PyCallable_Check(ptr.as_ptr())) == 1 {
+        Ok(Some(ptr))
+    } else {
+        Err(())
+    }
+}
+
+/// Convert a `float_sigfigs` argument, which is an `int` from 1 to
+/// `MAX_FLOAT_SIGFIGS` or `None`.
+#[cold]
+fn float_sigfigs_from_arg(ptr: NonNull<PyObject>) -> Result<Option<u8>, ()> {
+    match PyIntRef::from_ptr(ptr.as_ptr()) {
+        Ok(val) => match val.as_bounded_usize(opt::MAX_FLOAT_SIGFIGS) {
+            Some(0) | None => Err(()),
+            Some(sigfigs) => Ok(Some(u8::try_from(sigfigs).unwrap())),
+        },
+        Err(_) => {
+            if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+                Ok(None)
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// Convert a `max_output_size` or `max_sequence_len` argument, which is a
+/// positive `int` or `None`.
+#[cold]
+fn max_size_from_arg(ptr: NonNull<PyObject>) -> Result<Option<usize>, ()> {
+    match PyIntRef::from_ptr(ptr.as_ptr()) {
+        Ok(val) => match val.as_bounded_usize(usize::MAX) {
+            Some(0) | None => Err(()),
+            Some(size) => Ok(Some(size)),
+        },
+        Err(_) => {
+            if core::ptr::eq(ptr.as_ptr(), PyNoneRef::none().as_ptr()) {
+                Ok(None)
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// Convert a `validate_only` argument, which is a `bool`.
+#[cold]
+fn validate_only_from_arg(ptr: NonNull<PyObject>) -> Result<bool, ()> {
+    if core::ptr::eq(ptr.as_ptr(), PyBoolRef::pytrue().as_ptr()) {
+        Ok(true)
+    } else if core::ptr::eq(ptr.as_ptr(), PyBoolRef::pyfalse().as_ptr()) {
+        Ok(false)
+    } else {
+        Err(())
+    }
+}
+
+/// The arguments of `dumps()` other than the object, as given. They are
+/// shared by `dumps()`, `dumps_into_buffer()`, `dumps_chunked()`, and
+/// `orjson.Encoder` and converted by `DumpsArgs::convert()`.
+#[derive(Default)]
+pub(crate) struct DumpsArgs {
+    pub default: Option<NonNull<PyObject>>,
+    pub option: Option<NonNull<PyObject>>,
+    pub indent: Option<NonNull<PyObject>>,
+    pub key_delimiter: Option<NonNull<PyObject>>,
+    pub float_sigfigs: Option<NonNull<PyObject>>,
+    pub max_output_size: Option<NonNull<PyObject>>,
+    pub key_transform: Option<NonNull<PyObject>>,
+    pub validate_only: Option<NonNull<PyObject>>,
+    pub max_sequence_len: Option<NonNull<PyObject>>,
+}
+
+/// The converted arguments of `dumps()` other than the object and `default`.
+#[derive(Copy, Clone)]
+pub(crate) struct DumpsOptions {
+    pub opts: opt::Opt,
+    pub high_opts: opt::HighOpt,
+    pub indent: Option<usize>,
+    pub key_delimiter: Option<NonNull<PyObject>>,
+    pub float_sigfigs: Option<u8>,
+    pub max_output_size: Option<usize>,
+    pub key_transform: Option<NonNull<PyObject>>,
+    pub validate_only: bool,
+    pub max_sequence_len: Option<usize>,
+}
+
+impl DumpsArgs {
+    /// Set the keyword argument `name` if it is one of `dumps()` other than
+    /// `default` and `option`, returning whether it is.
+    #[cold]
+    pub fn set_kwarg(&mut self, name: *mut PyObject, value: NonNull<PyObject>) -> bool {
+        let field = if matches_kwarg!(name, typeref::INDENT) {
+            &mut self.indent
+        } else if matches_kwarg!(name, typeref::KEY_DELIMITER) {
+            &mut self.key_delimiter
+        } else if matches_kwarg!(name, typeref::FLOAT_SIGFIGS) {
+            &mut self.float_sigfigs
+        } else if matches_kwarg!(name, typeref::MAX_OUTPUT_SIZE) {
+            &mut self.max_output_size
+        } else if matches_kwarg!(name, typeref::KEY_TRANSFORM) {
+            &mut self.key_transform
+        } else if matches_kwarg!(name, typeref::VALIDATE_ONLY) {
+            &mut self.validate_only
+        } else if matches_kwarg!(name, typeref::MAX_SEQUENCE_LEN) {
+            &mut self.max_sequence_len
+        } else {
+            return false;
+        };
+        *field = Some(value);
+        true
+    }
+
+    /// Set the keyword arguments of a vectorcall of `name`, which has
+    /// `default` and `option` already set if they were given positionally.
+    #[cold]
+    pub unsafe fn set_vectorcall_kwargs(
+        &mut self,
+        name: &str,
+        kwargs: *const *mut PyObject,
+        kwnames: *mut PyObject,
+    ) -> Result<(), String> {
+        unsafe {
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                let value = NonNull::new_unchecked(*kwargs.offset(i));
+                if matches_kwarg!(arg, typeref::OPTION) {
+                    if self.option.is_some() {
+                        return Err(format!(
+                            "{name}() got multiple values for argument: 'option'"
+                        ));
+                    }
+                    self.option = Some(value);
+                } else if matches_kwarg!(arg, typeref::DEFAULT) {
+                    if self.default.is_some() {
+                        return Err(format!(
+                            "{name}() got multiple values for argument: 'default'"
+                        ));
+                    }
+                    self.default = Some(value);
+                } else if !self.set_kwarg(arg, value) {
+                    return Err(format!("{name}() got an unexpected keyword argument"));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Convert the arguments, returning the message of the first that is
+    /// invalid.
+    #[inline(always)]
+    pub fn convert(&self) -> Result<DumpsOptions, &'static str> {
+        let mut ret = DumpsOptions {
+            opts: 0,
+            high_opts: 0,
+            indent: None,
+            key_delimiter: None,
+            float_sigfigs: None,
+            max_output_size: None,
+            key_transform: None,
+            validate_only: false,
+            max_sequence_len: None,
+        };
+        if let Some(tmp) = self.option {
+            cold_path!();
+            (ret.opts, ret.high_opts) = opts_from_arg(tmp).map_err(|()| "Invalid opts")?;
+        }
+        if let Some(tmp) = self.indent {
+            cold_path!();
+            ret.indent = indent_from_arg(tmp).map_err(|()| INDENT_MESSAGE)?;
+        }
+        if let Some(tmp) = self.key_delimiter {
+            cold_path!();
+            ret.key_delimiter = key_delimiter_from_arg(tmp).map_err(|()| KEY_DELIMITER_MESSAGE)?;
+        }
+        if let Some(tmp) = self.float_sigfigs {
+            cold_path!();
+            ret.float_sigfigs = float_sigfigs_from_arg(tmp).map_err(|()| FLOAT_SIGFIGS_MESSAGE)?;
+        }
+        if let Some(tmp) = self.max_output_size {
+            cold_path!();
+            ret.max_output_size = max_size_from_arg(tmp).map_err(|()| MAX_OUTPUT_SIZE_MESSAGE)?;
+        }
+        if let Some(tmp) = self.key_transform {
+            cold_path!();
+            ret.key_transform = key_transform_from_arg(tmp).map_err(|()| KEY_TRANSFORM_MESSAGE)?;
+        }
+        if let Some(tmp) = self.validate_only {
+            cold_path!();
+            ret.validate_only = validate_only_from_arg(tmp).map_err(|()| VALIDATE_ONLY_MESSAGE)?;
+        }
+        if let Some(tmp) = self.max_sequence_len {
+            cold_path!();
+            ret.max_sequence_len = max_size_from_arg(tmp).map_err(|()| MAX_SEQUENCE_LEN_MESSAGE)?;
+        }
+        Ok(ret)
+    }
+}
+
+impl DumpsOptions {
+    /// The configuration of serializing with these options and the
+    /// `datetime_formats` and `type_handlers` of an `orjson.Encoder`.
+    #[inline(always)]
+    pub fn config(
+        &self,
+        datetime_formats: Option<NonNull<PyObject>>,
+        type_handlers: Option<NonNull<PyObject>>,
+    ) -> DumpsConfig {
+        let config = DumpsConfig::new(
+            self.key_delimiter,
+            self.float_sigfigs,
+            datetime_formats,
+            self.key_transform,
+            type_handlers,
+        );
+        match self.max_sequence_len {
+            Some(val) => config.with_max_sequence_len(val),
+            None => config,
+        }
+    }
+}
+
 #[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn dumps(
+pub(crate) unsafe extern "C" fn loads(
     _self: *mut PyObject,
     args: *const *mut PyObject,
     nargs: Py_ssize_t,
     kwnames: *mut PyObject,
 ) -> *mut PyObject {
     unsafe {
-        let mut default: Option<NonNull<PyObject>> = None;
         let mut optsptr: Option<NonNull<PyObject>> = None;
 
         let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
         if num_args == 0 {
             cold_path!();
-            return raise_dumps_exception_fixed(
-                "dumps() missing 1 required positional argument: 'obj'",
+            return raise_loads_args_exception(
+                "loads() missing 1 required positional argument: 'obj'",
             );
         }
-        if num_args & 2 == 2 {
-            default = Some(NonNull::new_unchecked(*args.offset(1)));
+        if num_args > 2 {
+            cold_path!();
+            return raise_loads_args_exception(
+                "loads() takes at most 2 positional arguments",
+            );
         }
-        if num_args & 3 == 3 {
-            optsptr = Some(NonNull::new_unchecked(*args.offset(2)));
+        if num_args == 2 {
+            optsptr = Some(NonNull::new_unchecked(*args.offset(1)));
         }
         if !kwnames.is_null() {
             cold_path!();
@@ -280,53 +833,370 @@ pub(crate) unsafe extern "C" fn dumps(
             for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
                 let arg = kwob.get(i.cast_unsigned());
                 if matches_kwarg!(arg, typeref::OPTION) {
-                    if num_args & 3 == 3 {
+                    if num_args == 2 {
                         cold_path!();
-                        return raise_dumps_exception_fixed(
-                            "dumps() got multiple values for argument: 'option'",
+                        return raise_loads_args_exception(
+                            "loads() got multiple values for argument: 'option'",
                         );
                     }
                     optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
-                } else if matches_kwarg!(arg, typeref::DEFAULT) {
-                    if num_args & 2 == 2 {
-                        cold_path!();
-                        return raise_dumps_exception_fixed(
-                            "dumps() got multiple values for argument: 'default'",
-                        );
-                    }
-                    default = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
                 } else {
-                    return raise_dumps_exception_fixed(
-                        "dumps() got an unexpected keyword argument",
+                    return raise_loads_args_exception(
+                        "loads() got an unexpected keyword argument",
                     );
                 }
             }
         }
 
         let mut opts = 0 as opt::Opt;
+        let mut high_opts = 0 as opt::HighOpt;
         if let Some(tmp) = optsptr {
             cold_path!();
-            match PyIntRef::from_ptr(tmp.as_ptr()) {
-                Ok(val) => match val.as_opt() {
-                    Ok(opt) => {
-                        opts = opt;
-                    }
-                    Err(_) => {
-                        return raise_dumps_exception_fixed("Invalid opts");
-                    }
-                },
-                Err(_) => {
-                    if !core::ptr::eq(tmp.as_ptr(), PyNoneRef::none().as_ptr()) {
+            match loads_opts_from_arg(tmp) {
+                Ok(val) => (opts, high_opts) = val,
+                Err(()) => return raise_loads_exception_fixed("Invalid opts"),
+            }
+        }
+
+        deserialize(*args, opts, high_opts).map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn load(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut optsptr: Option<NonNull<PyObject>> = None;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return raise_loads_args_exception(
+                "load() missing 1 required positional argument: 'fp'",
+            );
+        }
+        if num_args > 2 {
+            cold_path!();
+            return raise_loads_args_exception(
+                "load() takes at most 2 positional arguments",
+            );
+        }
+        if num_args == 2 {
+            optsptr = Some(NonNull::new_unchecked(*args.offset(1)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::OPTION) {
+                    if num_args == 2 {
                         cold_path!();
-                        return raise_dumps_exception_fixed("Invalid opts");
+                        return raise_loads_args_exception(
+                            "load() got multiple values for argument: 'option'",
+                        );
                     }
+                    optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+                } else {
+                    return raise_loads_args_exception(
+                        "load() got an unexpected keyword argument",
+                    );
                 }
             }
         }
 
-        serialize(*args, default, opts).map_or_else(
+        let mut opts = 0 as opt::Opt;
+        let mut high_opts = 0 as opt::HighOpt;
+        if let Some(tmp) = optsptr {
+            cold_path!();
+            match loads_opts_from_arg(tmp) {
+                Ok(val) => (opts, high_opts) = val,
+                Err(()) => return raise_loads_exception_fixed("Invalid opts"),
+            }
+        }
+
+        let input = read_input(*args);
+        if input.is_null() {
+            cold_path!();
+            return null_mut();
+        }
+        let ret =
+            deserialize(input, opts, high_opts).map_or_else(raise_loads_exception, NonNull::as_ptr);
+        crate::ffi::Py_DECREF(input);
+        ret
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut dumps_args = DumpsArgs::default();
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return raise_dumps_exception_fixed(
+                "dumps() missing 1 required positional argument: 'obj'",
+            );
+        }
+        if num_args & 2 == 2 {
+            dumps_args.default = Some(NonNull::new_unchecked(*args.offset(1)));
+        }
+        if num_args & 3 == 3 {
+            dumps_args.option = Some(NonNull::new_unchecked(*args.offset(2)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            if let Err(err) =
+                dumps_args.set_vectorcall_kwargs("dumps", args.offset(num_args), kwnames)
+            {
+                return raise_dumps_exception_fixed(&err);
+            }
+        }
+
+        let options = match dumps_args.convert() {
+            Ok(val) => val,
+            Err(msg) => return raise_dumps_exception_fixed(msg),
+        };
+        let default = dumps_args.default;
+
+        if options.validate_only {
+            cold_path!();
+            return validate(*args, default, options.opts, options.config(None, None)).map_or_else(
+                |err| raise_dumps_exception_dynamic(err.as_str()),
+                |()| PyNoneRef::none().as_ptr(),
+            );
+        }
+
+        serialize(
+            *args,
+            default,
+            options.opts,
+            options.high_opts,
+            options.config(None, None),
+            options.indent,
+            options.max_output_size,
+        )
+        .map_or_else(
             |err| raise_dumps_exception_dynamic(err.as_str()),
             NonNull::as_ptr,
         )
     }
 }
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps_into_buffer(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut dumps_args = DumpsArgs::default();
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args < 2 {
+            cold_path!();
+            return raise_dumps_exception_fixed(
+                "dumps_into_buffer() missing required positional arguments: 'obj' and 'buffer'",
+            );
+        }
+        if num_args > 4 {
+            cold_path!();
+            return raise_dumps_exception_fixed(
+                "dumps_into_buffer() takes at most 4 positional arguments",
+            );
+        }
+        if num_args >= 3 {
+            dumps_args.default = Some(NonNull::new_unchecked(*args.offset(2)));
+        }
+        if num_args == 4 {
+            dumps_args.option = Some(NonNull::new_unchecked(*args.offset(3)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            if let Err(err) = dumps_args.set_vectorcall_kwargs(
+                "dumps_into_buffer",
+                args.offset(num_args),
+                kwnames,
+            ) {
+                return raise_dumps_exception_fixed(&err);
+            }
+        }
+
+        let options = match dumps_args.convert() {
+            Ok(val) => val,
+            Err(msg) => return raise_dumps_exception_fixed(msg),
+        };
+        let default = dumps_args.default;
+
+        // the buffer is exported for the duration of serialization so that
+        // `default` cannot resize or release it
+        let mut view = core::mem::MaybeUninit::<Py_buffer>::uninit();
+        if PyObject_GetBuffer(*args.offset(1), view.as_mut_ptr(), PyBUF_WRITABLE) != 0 {
+            cold_path!();
+            crate::ffi::PyErr_Clear();
+            return raise_dumps_exception_fixed(BUFFER_MESSAGE);
+        }
+        let mut view = view.assume_init();
+        let capacity = isize_to_usize(view.len);
+
+        let ret = if options.validate_only {
+            cold_path!();
+            validate(*args, default, options.opts, options.config(None, None)).map_or_else(
+                |err| raise_dumps_exception_dynamic(err.as_str()),
+                |()| PyNoneRef::none().as_ptr(),
+            )
+        } else {
+            // the output is bounded by the buffer so that serializing stops
+            // once it cannot fit, and it is copied only once it is complete
+            // so that the buffer is not modified on error
+            let max_output_size = options
+                .max_output_size
+                .map_or(capacity, |val| val.min(capacity));
+            match serialize(
+                *args,
+                default,
+                options.opts,
+                options.high_opts,
+                options.config(None, None),
+                options.indent,
+                Some(max_output_size),
+            ) {
+                Ok(bytes) => {
+                    let len = isize_to_usize(PyBytes_GET_SIZE(bytes.as_ptr()));
+                    debug_assert!(len <= capacity);
+                    core::ptr::copy_nonoverlapping(
+                        PyBytes_AS_STRING(bytes.as_ptr()).cast::<u8>(),
+                        view.buf.cast::<u8>(),
+                        len,
+                    );
+                    crate::ffi::Py_DECREF(bytes.as_ptr());
+                    PyIntRef::from_u64(len as u64).as_ptr()
+                }
+                Err(err) if err == OUTPUT_TOO_LARGE_MESSAGE && max_output_size == capacity => {
+                    raise_dumps_exception_dynamic(&format!(
+                        "Serialized output does not fit in buffer of {capacity} bytes"
+                    ))
+                }
+                Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+            }
+        };
+        PyBuffer_Release(&raw mut view);
+        ret
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps_chunked(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut dumps_args = DumpsArgs::default();
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args < 2 {
+            cold_path!();
+            return raise_dumps_exception_fixed(
+                "dumps_chunked() missing required positional arguments: 'obj' and 'chunk_size'",
+            );
+        }
+        if num_args > 4 {
+            cold_path!();
+            return raise_dumps_exception_fixed(
+                "dumps_chunked() takes at most 4 positional arguments",
+            );
+        }
+        if num_args >= 3 {
+            dumps_args.default = Some(NonNull::new_unchecked(*args.offset(2)));
+        }
+        if num_args == 4 {
+            dumps_args.option = Some(NonNull::new_unchecked(*args.offset(3)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            if let Err(err) =
+                dumps_args.set_vectorcall_kwargs("dumps_chunked", args.offset(num_args), kwnames)
+            {
+                return raise_dumps_exception_fixed(&err);
+            }
+        }
+
+        let chunk_size = match max_size_from_arg(NonNull::new_unchecked(*args.offset(1))) {
+            Ok(Some(val)) => val,
+            Ok(None) | Err(()) => return raise_dumps_exception_fixed(CHUNK_SIZE_MESSAGE),
+        };
+
+        let options = match dumps_args.convert() {
+            Ok(val) => val,
+            Err(msg) => return raise_dumps_exception_fixed(msg),
+        };
+        let default = dumps_args.default;
+
+        if options.validate_only {
+            cold_path!();
+            return validate(*args, default, options.opts, options.config(None, None)).map_or_else(
+                |err| raise_dumps_exception_dynamic(err.as_str()),
+                |()| PyNoneRef::none().as_ptr(),
+            );
+        }
+
+        match serialize(
+            *args,
+            default,
+            options.opts,
+            options.high_opts,
+            options.config(None, None),
+            options.indent,
+            options.max_output_size,
+        ) {
+            Ok(bytes) => {
+                let len = isize_to_usize(PyBytes_GET_SIZE(bytes.as_ptr()));
+                let list = crate::ffi::PyList_New(usize_to_isize(len.div_ceil(chunk_size)));
+                if list.is_null() {
+                    cold_path!();
+                    crate::ffi::Py_DECREF(bytes.as_ptr());
+                    return null_mut();
+                } else if len == 0 {
+                    // e.g., an empty orjson.Fragment, which is no chunks
+                    crate::ffi::Py_DECREF(bytes.as_ptr());
+                    return list;
+                } else if len <= chunk_size {
+                    // the output is the only chunk, so it is not copied
+                    crate::ffi::PyList_SET_ITEM(list, 0, bytes.as_ptr());
+                    return list;
+                }
+                let buf = PyBytes_AS_STRING(bytes.as_ptr());
+                for (idx, start) in (0..len).step_by(chunk_size).enumerate() {
+                    let chunk = crate::ffi::PyBytes_FromStringAndSize(
+                        buf.add(start),
+                        usize_to_isize(chunk_size.min(len - start)),
+                    );
+                    if chunk.is_null() {
+                        cold_path!();
+                        // the items not yet set are null, which the list skips
+                        crate::ffi::Py_DECREF(list);
+                        crate::ffi::Py_DECREF(bytes.as_ptr());
+                        return null_mut();
+                    }
+                    crate::ffi::PyList_SET_ITEM(list, usize_to_isize(idx), chunk);
+                }
+                crate::ffi::Py_DECREF(bytes.as_ptr());
+                list
+            }
+            Err(err) => raise_dumps_exception_dynamic(err.as_str()),
+        }
+    }
+}
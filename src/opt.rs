@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2020-2025)
+// Copyright ijl (2020-2026)
 
-pub(crate) type Opt = u32;
+/// The options of `dumps()` that are checked as each object is serialized.
+/// They are the low 64 bits of an `option` argument.
+pub(crate) type Opt = u64;
 
 pub(crate) const INDENT_2: Opt = 1;
 pub(crate) const NAIVE_UTC: Opt = 1 << 1;
@@ -15,6 +17,74 @@ pub(crate) const PASSTHROUGH_SUBCLASS: Opt = 1 << 8;
 pub(crate) const PASSTHROUGH_DATETIME: Opt = 1 << 9;
 pub(crate) const APPEND_NEWLINE: Opt = 1 << 10;
 pub(crate) const PASSTHROUGH_DATACLASS: Opt = 1 << 11;
+pub(crate) const UNIQUE_KEYS: Opt = 1 << 12;
+pub(crate) const SERIALIZE_BYTES_GZIP: Opt = 1 << 13;
+pub(crate) const SERIALIZE_JSON_METHOD: Opt = 1 << 14;
+pub(crate) const NORMALIZE_UTC: Opt = 1 << 15;
+pub(crate) const NAMEDTUPLE_AS_OBJECT: Opt = 1 << 16;
+pub(crate) const NUMPY_SHORTEST_FLOAT: Opt = 1 << 17;
+pub(crate) const PASSTHROUGH_ENUM: Opt = 1 << 18;
+pub(crate) const DEDUPLICATE_ARRAYS: Opt = 1 << 19;
+pub(crate) const SERIALIZE_ITERATORS: Opt = 1 << 20;
+pub(crate) const SERIALIZE_ANNOTATED: Opt = 1 << 21;
+pub(crate) const ANNOTATED_MISSING_AS_NULL: Opt = 1 << 22;
+pub(crate) const UUID_HEX: Opt = 1 << 23;
+pub(crate) const UUID_URN: Opt = 1 << 24;
+pub(crate) const ENUM_REPR: Opt = 1 << 25;
+pub(crate) const INT_BASE62: Opt = 1 << 26;
+pub(crate) const NAIVE_AS_NULL: Opt = 1 << 27;
+pub(crate) const DEFAULT_FIRST: Opt = 1 << 28;
+pub(crate) const UUID_BRACED: Opt = 1 << 29;
+pub(crate) const CANONICAL: Opt = 1 << 30;
+pub(crate) const BYTES_AS_UUID: Opt = 1 << 31;
+pub(crate) const SORT_KEYS_BY_HASH: Opt = 1 << 32;
+pub(crate) const SERIALIZE_PYDANTIC: Opt = 1 << 33;
+pub(crate) const PYDANTIC_EXCLUDE_UNSET: Opt = 1 << 34;
+pub(crate) const SERIALIZE_DATETIME_AS_UNIX: Opt = 1 << 35;
+pub(crate) const UNIX_MILLISECONDS: Opt = 1 << 36;
+pub(crate) const SERIALIZE_MAPPING: Opt = 1 << 37;
+pub(crate) const FLAG_AS_INT: Opt = 1 << 38;
+pub(crate) const SERIALIZE_RANGE: Opt = 1 << 39;
+pub(crate) const RANGE_AS_ARRAY: Opt = 1 << 40;
+pub(crate) const FLOAT_TRAILING_ZERO: Opt = 1 << 41;
+pub(crate) const LENGTH_TAGGED: Opt = 1 << 42;
+pub(crate) const SERIALIZE_WEAKREF: Opt = 1 << 43;
+pub(crate) const PASSTHROUGH_NON_FINITE: Opt = 1 << 44;
+pub(crate) const SORT_DATACLASS_FIELDS: Opt = 1 << 45;
+pub(crate) const OMIT_NONE: Opt = 1 << 46;
+pub(crate) const SERIALIZE_BYTES: Opt = 1 << 47;
+pub(crate) const SERIALIZE_DATETIME_AS_ISOCALENDAR: Opt = 1 << 48;
+pub(crate) const OFFSET_NO_COLON: Opt = 1 << 49;
+pub(crate) const SERIALIZE_ORJSON_DEFAULT: Opt = 1 << 50;
+pub(crate) const ENUM_FULL: Opt = 1 << 51;
+pub(crate) const PAD_NANOSECONDS: Opt = 1 << 52;
+pub(crate) const SERIALIZE_TIMEDELTA: Opt = 1 << 53;
+pub(crate) const TIMEDELTA_ISO8601: Opt = 1 << 54;
+pub(crate) const SERIALIZE_DATETIME_AS_EPOCH_OFFSET: Opt = 1 << 55;
+pub(crate) const SERIALIZE_FRACTIONS: Opt = 1 << 56;
+pub(crate) const FRACTION_AS_FLOAT: Opt = 1 << 57;
+pub(crate) const SORT_KEYS_BY_VALUE: Opt = 1 << 58;
+pub(crate) const ZONE_NAME: Opt = 1 << 59;
+pub(crate) const NONFINITE_AS_STRING: Opt = 1 << 60;
+pub(crate) const NUMPY_BASE64: Opt = 1 << 61;
+
+/// The options in the high 64 bits of an `option` argument: those of
+/// `loads()` and those of `dumps()` that apply once to the whole output. They
+/// are kept apart from `Opt` so that it is not wider than 64 bits.
+pub(crate) type HighOpt = u64;
+
+pub(crate) const CACHE_KEYS: HighOpt = 1;
+pub(crate) const PARSE_NUMBER_AS_STRING: HighOpt = 1 << 1;
+pub(crate) const STRICT_INPUT: HighOpt = 1 << 2;
+pub(crate) const PARSE_OBJECT_PAIRS: HighOpt = 1 << 3;
+
+pub(crate) const ENSURE_ASCII: HighOpt = 1 << 4;
+pub(crate) const ESCAPE_HTML: HighOpt = 1 << 5;
+pub(crate) const OMIT_TOP_LEVEL_NONE: HighOpt = 1 << 6;
+
+// set by orjson.Encoder if it has datetime_formats; greater than MAX_OPT, so
+// it cannot be specified as an option
+pub(crate) const DATETIME_FORMATS: Opt = 1 << 63;
 
 // deprecated
 pub(crate) const SERIALIZE_DATACLASS: Opt = 0;
@@ -22,21 +92,107 @@ pub(crate) const SERIALIZE_UUID: Opt = 0;
 
 pub(crate) const SORT_OR_NON_STR_KEYS: Opt = SORT_KEYS | NON_STR_KEYS;
 
-pub(crate) const NOT_PASSTHROUGH: Opt =
-    !(PASSTHROUGH_DATETIME | PASSTHROUGH_DATACLASS | PASSTHROUGH_SUBCLASS);
+// the options of loads(); INT_BASE62 is also an option of dumps()
+pub(crate) const LOADS_OPT: Opt = INT_BASE62;
+pub(crate) const LOADS_HIGH_OPT: HighOpt =
+    CACHE_KEYS | PARSE_NUMBER_AS_STRING | PARSE_OBJECT_PAIRS | STRICT_INPUT;
+
+pub(crate) const UUID_FORMATS: Opt = UUID_BRACED | UUID_HEX | UUID_URN;
+
+pub(crate) const ENUM_FORMATS: Opt = ENUM_FULL | ENUM_REPR;
+
+pub(crate) const SORT_KEYS_ORDERS: Opt = SORT_KEYS_BY_HASH | SORT_KEYS_BY_VALUE;
+
+pub(crate) const CANONICAL_INCOMPATIBLE: Opt = APPEND_NEWLINE
+    | FLOAT_TRAILING_ZERO
+    | INDENT_2
+    | NONFINITE_AS_STRING
+    | SERIALIZE_NUMPY
+    | SORT_KEYS_BY_HASH
+    | SORT_KEYS_BY_VALUE;
+
+pub(crate) const CANONICAL_INCOMPATIBLE_HIGH: HighOpt = ENSURE_ASCII | ESCAPE_HTML;
+
+pub(crate) const NOT_PASSTHROUGH: Opt = !(PASSTHROUGH_DATETIME
+    | PASSTHROUGH_DATACLASS
+    | PASSTHROUGH_ENUM
+    | PASSTHROUGH_NON_FINITE
+    | PASSTHROUGH_SUBCLASS);
 
-#[allow(clippy::cast_possible_wrap)]
-pub(crate) const MAX_OPT: i32 = (APPEND_NEWLINE
+pub(crate) const MAX_OPT: Opt = ANNOTATED_MISSING_AS_NULL
+    | APPEND_NEWLINE
+    | BYTES_AS_UUID
+    | CANONICAL
+    | DEDUPLICATE_ARRAYS
+    | DEFAULT_FIRST
+    | ENUM_FULL
+    | ENUM_REPR
+    | FLAG_AS_INT
+    | FLOAT_TRAILING_ZERO
+    | FRACTION_AS_FLOAT
     | INDENT_2
+    | INT_BASE62
+    | LENGTH_TAGGED
+    | NAIVE_AS_NULL
     | NAIVE_UTC
+    | NAMEDTUPLE_AS_OBJECT
+    | NONFINITE_AS_STRING
     | NON_STR_KEYS
+    | NORMALIZE_UTC
+    | NUMPY_BASE64
+    | NUMPY_SHORTEST_FLOAT
+    | OFFSET_NO_COLON
     | OMIT_MICROSECONDS
+    | OMIT_NONE
+    | PAD_NANOSECONDS
     | PASSTHROUGH_DATETIME
     | PASSTHROUGH_DATACLASS
+    | PASSTHROUGH_ENUM
+    | PASSTHROUGH_NON_FINITE
     | PASSTHROUGH_SUBCLASS
+    | PYDANTIC_EXCLUDE_UNSET
+    | RANGE_AS_ARRAY
+    | SERIALIZE_ANNOTATED
+    | SERIALIZE_BYTES
+    | SERIALIZE_BYTES_GZIP
     | SERIALIZE_DATACLASS
+    | SERIALIZE_DATETIME_AS_EPOCH_OFFSET
+    | SERIALIZE_DATETIME_AS_ISOCALENDAR
+    | SERIALIZE_DATETIME_AS_UNIX
+    | SERIALIZE_FRACTIONS
+    | SERIALIZE_ITERATORS
+    | SERIALIZE_JSON_METHOD
+    | SERIALIZE_MAPPING
     | SERIALIZE_NUMPY
+    | SERIALIZE_ORJSON_DEFAULT
+    | SERIALIZE_PYDANTIC
+    | SERIALIZE_RANGE
+    | SERIALIZE_TIMEDELTA
     | SERIALIZE_UUID
+    | SERIALIZE_WEAKREF
+    | SORT_DATACLASS_FIELDS
     | SORT_KEYS
+    | SORT_KEYS_BY_HASH
+    | SORT_KEYS_BY_VALUE
     | STRICT_INTEGER
-    | UTC_Z) as i32;
+    | TIMEDELTA_ISO8601
+    | UNIQUE_KEYS
+    | UNIX_MILLISECONDS
+    | UTC_Z
+    | UUID_BRACED
+    | UUID_HEX
+    | UUID_URN
+    | ZONE_NAME;
+
+pub(crate) const MAX_HIGH_OPT: HighOpt = CACHE_KEYS
+    | ENSURE_ASCII
+    | ESCAPE_HTML
+    | OMIT_TOP_LEVEL_NONE
+    | PARSE_NUMBER_AS_STRING
+    | PARSE_OBJECT_PAIRS
+    | STRICT_INPUT;
+
+pub(crate) const MAX_INDENT: usize = 8;
+
+// the digits of the shortest representation of any f64
+pub(crate) const MAX_FLOAT_SIGFIGS: usize = 17;
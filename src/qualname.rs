@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use core::ptr::null_mut;
+
+use crate::ffi::{Py_DECREF, PyObject, PyObject_GetAttr, PyStrRef};
+use crate::typeref::{FUNC_STR, MODULE_STR, PARTIAL_TYPE, QUALNAME_STR};
+
+/// `orjson.qualname()`, which returns the `__module__` and `__qualname__` of
+/// a function, class, or other object having them, joined by `.`, for use
+/// by a handler in `type_handlers` of `orjson.Encoder`. A `functools.partial`
+/// is unwrapped to the function it calls. If `__module__` is not a `str`,
+/// e.g., for a method of a builtin type, only `__qualname__` is returned.
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn qualname(
+    _self: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut obj = obj;
+        crate::ffi::Py_INCREF(obj);
+        while is_class_by_type!(ob_type!(obj), PARTIAL_TYPE) {
+            let func = PyObject_GetAttr(obj, FUNC_STR);
+            Py_DECREF(obj);
+            if func.is_null() {
+                return null_mut();
+            }
+            obj = func;
+        }
+        let name = PyObject_GetAttr(obj, QUALNAME_STR);
+        if name.is_null() {
+            Py_DECREF(obj);
+            return null_mut();
+        }
+        let module = PyObject_GetAttr(obj, MODULE_STR);
+        Py_DECREF(obj);
+        if module.is_null() {
+            crate::ffi::PyErr_Clear();
+            return name;
+        }
+        let ret = match (
+            PyStrRef::from_ptr(module).ok().and_then(|val| val.as_str()),
+            PyStrRef::from_ptr(name).ok().and_then(|val| val.as_str()),
+        ) {
+            (Some(module_str), Some(name_str)) => {
+                PyStrRef::from_str(&format!("{module_str}.{name_str}")).as_ptr()
+            }
+            _ => {
+                crate::ffi::Py_INCREF(name);
+                name
+            }
+        };
+        Py_DECREF(module);
+        Py_DECREF(name);
+        ret
+    }
+}
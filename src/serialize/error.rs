@@ -1,26 +1,59 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2021-2025)
+// Copyright ijl (2021-2026)
 
 use core::ffi::CStr;
 use core::ptr::NonNull;
 
+use crate::serialize::per_type::DateTimeError;
+
+pub(crate) const RECURSION_LIMIT_MESSAGE: &str = "Recursion limit reached";
+
+pub(crate) const OUTPUT_TOO_LARGE_MESSAGE: &str = "Output exceeds max_output_size";
+
+pub(crate) const KEY_TRANSFORM_RAISED_MESSAGE: &str = "key_transform raised an exception";
+
 pub(crate) enum SerializeError {
+    AnnotatedAttribute(NonNull<crate::ffi::PyObject>),
+    Bytes(NonNull<crate::ffi::PyObject>),
+    BytesCompression,
+    DatetimeFormat(NonNull<crate::ffi::PyObject>),
     DatetimeLibraryUnsupported,
+    DatetimeNaiveUnix,
+    DatetimeUtcOutOfRange,
     DefaultRecursionLimit,
+    EnumRepr(NonNull<crate::ffi::PyObject>),
+    Fraction(NonNull<crate::ffi::PyObject>),
+    FloatNotFinite,
     Integer53Bits,
     Integer64Bits,
     InvalidStr,
     InvalidFragment,
+    Iterator(NonNull<crate::ffi::PyObject>),
+    JsonMethod(NonNull<crate::ffi::PyObject>),
     KeyMustBeStr,
+    KeyTransform,
+    KeyTransformNotStr,
+    Mapping(NonNull<crate::ffi::PyObject>),
+    NamedTupleFields,
+    OrjsonDefaultMethod(NonNull<crate::ffi::PyObject>),
     RecursionLimit,
-    TimeHasTzinfo,
+    TimeOffsetUnsupported,
+    TypeHandler(NonNull<crate::ffi::PyObject>),
     DictIntegerKey64Bit,
+    DictKeyDataclass(String),
     DictKeyInvalidType,
+    DictKeyTupleElement,
+    DictKeyDuplicate,
+    DictValueCompare(NonNull<crate::ffi::PyObject>),
     NumpyMalformed,
     NumpyNotCContiguous,
     NumpyNotNativeEndian,
     NumpyUnsupportedDatatype,
+    OutputTooLarge,
+    SequenceTooLong(usize),
+    PydanticAttribute(NonNull<crate::ffi::PyObject>),
     UnsupportedType(NonNull<crate::ffi::PyObject>),
+    WeakrefDead,
 }
 
 impl core::fmt::Display for SerializeError {
@@ -28,28 +61,120 @@ impl core::fmt::Display for SerializeError {
     #[cfg_attr(feature = "optimize", optimize(size))]
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
+            SerializeError::AnnotatedAttribute(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(
+                    f,
+                    "getting an annotated attribute raised an exception: {name}"
+                )
+            }
+            SerializeError::Bytes(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(
+                    f,
+                    "getting the bytes of an object raised an exception: {name}"
+                )
+            }
+            SerializeError::BytesCompression => write!(f, "failed to gzip-compress bytes"),
+            SerializeError::DatetimeFormat(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "strftime() raised an exception: {name}")
+            }
             SerializeError::DatetimeLibraryUnsupported => write!(
                 f,
                 "datetime's timezone library is not supported: use datetime.timezone.utc, pendulum, pytz, or dateutil"
             ),
+            SerializeError::DatetimeNaiveUnix => write!(
+                f,
+                "datetime without tzinfo cannot be serialized as a Unix timestamp: use OPT_NAIVE_UTC"
+            ),
+            SerializeError::DatetimeUtcOutOfRange => {
+                write!(f, "datetime is out of range when converted to UTC")
+            }
             SerializeError::DefaultRecursionLimit => {
                 write!(f, "default serializer exceeds recursion limit")
             }
+            SerializeError::EnumRepr(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "repr() of enum raised an exception: {name}")
+            }
+            SerializeError::FloatNotFinite => {
+                write!(f, "Float must be finite with OPT_CANONICAL")
+            }
+            SerializeError::Fraction(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "converting a fraction raised an exception: {name}")
+            }
             SerializeError::Integer53Bits => write!(f, "Integer exceeds 53-bit range"),
             SerializeError::Integer64Bits => write!(f, "Integer exceeds 64-bit range"),
             SerializeError::InvalidStr => write!(f, "{}", crate::util::INVALID_STR),
             SerializeError::InvalidFragment => {
                 write!(f, "orjson.Fragment's content is not of type bytes or str")
             }
+            SerializeError::Iterator(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "iteration raised an exception: {name}")
+            }
+            SerializeError::JsonMethod(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "__json__() raised an exception: {name}")
+            }
             SerializeError::KeyMustBeStr => write!(f, "Dict key must be str"),
-            SerializeError::RecursionLimit => write!(f, "Recursion limit reached"),
-            SerializeError::TimeHasTzinfo => write!(f, "datetime.time must not have tzinfo set"),
+            SerializeError::KeyTransform => f.write_str(KEY_TRANSFORM_RAISED_MESSAGE),
+            SerializeError::KeyTransformNotStr => write!(f, "key_transform must return str"),
+            SerializeError::Mapping(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "getting a mapping item raised an exception: {name}")
+            }
+            SerializeError::NamedTupleFields => {
+                write!(f, "namedtuple _fields must be a tuple of the same length")
+            }
+            SerializeError::OrjsonDefaultMethod(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "__orjson_default__() raised an exception: {name}")
+            }
+            SerializeError::RecursionLimit => f.write_str(RECURSION_LIMIT_MESSAGE),
+            SerializeError::TimeOffsetUnsupported => write!(
+                f,
+                "datetime.time tzinfo must have an offset that does not depend on a date"
+            ),
+            SerializeError::TypeHandler(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "type_handlers callable raised an exception: {name}")
+            }
             SerializeError::DictIntegerKey64Bit => {
                 write!(f, "Dict integer key must be within 64-bit range")
             }
+            SerializeError::DictKeyDataclass(ref msg) => {
+                write!(f, "Dict key dataclass is not serializable: {msg}")
+            }
             SerializeError::DictKeyInvalidType => {
                 write!(f, "Dict key must a type serializable with OPT_NON_STR_KEYS")
             }
+            SerializeError::DictKeyTupleElement => {
+                write!(
+                    f,
+                    "Dict key tuple must contain only types serializable with OPT_NON_STR_KEYS other than tuple"
+                )
+            }
+            SerializeError::DictKeyDuplicate => {
+                write!(f, "Dict key is duplicated after conversion to str")
+            }
+            SerializeError::DictValueCompare(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(f, "comparing dict values raised an exception: {name}")
+            }
             SerializeError::NumpyMalformed => write!(f, "numpy array is malformed"),
             SerializeError::NumpyNotCContiguous => write!(
                 f,
@@ -61,11 +186,37 @@ impl core::fmt::Display for SerializeError {
             SerializeError::NumpyUnsupportedDatatype => {
                 write!(f, "unsupported datatype in numpy array")
             }
+            SerializeError::OutputTooLarge => f.write_str(OUTPUT_TOO_LARGE_MESSAGE),
+            SerializeError::SequenceTooLong(limit) => {
+                write!(f, "Array exceeds max_sequence_len of {limit}")
+            }
+            SerializeError::PydanticAttribute(ptr) => {
+                let name =
+                    unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
+                write!(
+                    f,
+                    "getting a pydantic model attribute raised an exception: {name}"
+                )
+            }
             SerializeError::UnsupportedType(ptr) => {
                 let name =
                     unsafe { CStr::from_ptr((*ob_type!(ptr.as_ptr())).tp_name).to_string_lossy() };
                 write!(f, "Type is not JSON serializable: {name}")
             }
+            SerializeError::WeakrefDead => {
+                write!(f, "weakref.proxy referent no longer exists")
+            }
+        }
+    }
+}
+
+impl From<DateTimeError> for SerializeError {
+    #[cold]
+    fn from(err: DateTimeError) -> Self {
+        match err {
+            DateTimeError::LibraryUnsupported => SerializeError::DatetimeLibraryUnsupported,
+            DateTimeError::NaiveUnix => SerializeError::DatetimeNaiveUnix,
+            DateTimeError::UtcOutOfRange => SerializeError::DatetimeUtcOutOfRange,
         }
     }
 }
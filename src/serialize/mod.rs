@@ -4,9 +4,16 @@
 mod buffer;
 mod error;
 mod obtype;
+mod path;
 mod per_type;
 mod serializer;
 mod state;
+mod validate;
 pub(crate) mod writer;
 
-pub(crate) use serializer::serialize;
+pub(crate) use error::{KEY_TRANSFORM_RAISED_MESSAGE, OUTPUT_TOO_LARGE_MESSAGE};
+pub(crate) use path::{clear_error_path, take_error_path};
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) use per_type::{DATACLASS_FIELDS_MAP, DataclassFieldsMap};
+pub(crate) use serializer::{serialize, validate};
+pub(crate) use state::DumpsConfig;
@@ -1,13 +1,22 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
-// Copyright ijl (2020-2025), Aviram Hassan (2020)
+// Copyright ijl (2020-2026), Aviram Hassan (2020)
 
 use crate::opt::{
-    Opt, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME, PASSTHROUGH_SUBCLASS, SERIALIZE_NUMPY,
+    BYTES_AS_UUID, DATETIME_FORMATS, DEFAULT_FIRST, ENUM_FORMATS, LENGTH_TAGGED,
+    NAMEDTUPLE_AS_OBJECT, Opt, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME, PASSTHROUGH_ENUM,
+    PASSTHROUGH_NON_FINITE, PASSTHROUGH_SUBCLASS, SERIALIZE_ANNOTATED, SERIALIZE_BYTES,
+    SERIALIZE_BYTES_GZIP, SERIALIZE_FRACTIONS, SERIALIZE_ITERATORS, SERIALIZE_MAPPING,
+    SERIALIZE_NUMPY, SERIALIZE_PYDANTIC, SERIALIZE_RANGE, SERIALIZE_TIMEDELTA, SERIALIZE_WEAKREF,
+};
+use crate::serialize::per_type::{
+    is_bytes_like, is_enum_type, is_flag_as_int, is_fraction, is_iterator, is_mapping,
+    is_numpy_array, is_numpy_scalar, is_pydantic_model, is_range, is_weakref_proxy,
 };
-use crate::serialize::per_type::{is_numpy_array, is_numpy_scalar};
 use crate::typeref::{
-    BOOL_TYPE, DATACLASS_FIELDS_STR, DATE_TYPE, DATETIME_TYPE, DICT_TYPE, ENUM_TYPE, FLOAT_TYPE,
-    FRAGMENT_TYPE, INT_TYPE, LIST_TYPE, NONE_TYPE, STR_TYPE, TIME_TYPE, TUPLE_TYPE, UUID_TYPE,
+    BOOL_TYPE, BYTEARRAY_TYPE, BYTES_TYPE, DATACLASS_FIELDS_STR, DATE_TYPE, DATETIME_TYPE,
+    DICT_TYPE, EPOCH_NANOSECONDS_TYPE, FLOAT_TYPE, FRAGMENT_TYPE, INT_TYPE, LIST_TYPE,
+    MEMORYVIEW_TYPE, NAMEDTUPLE_FIELDS_STR, NONE_TYPE, STR_TYPE, TIME_TYPE, TIMEDELTA_TYPE,
+    TUPLE_TYPE, UUID_TYPE,
 };
 
 #[repr(u32)]
@@ -22,6 +31,7 @@ pub(crate) enum ObType {
     Datetime,
     Date,
     Time,
+    DatetimeFormat,
     Tuple,
     Uuid,
     Dataclass,
@@ -30,6 +40,19 @@ pub(crate) enum ObType {
     Enum,
     StrSubclass,
     Fragment,
+    EpochNanoseconds,
+    Bytes,
+    NamedTuple,
+    Iterator,
+    Mapping,
+    Range,
+    Timedelta,
+    Fraction,
+    LengthTagged,
+    Weakref,
+    Pydantic,
+    Annotated,
+    DefaultFirst,
     Unknown,
 }
 
@@ -43,35 +66,68 @@ pub(crate) fn pyobject_to_obtype(obj: *mut crate::ffi::PyObject, opts: Opt) -> O
         ObType::Bool
     } else if is_class_by_type!(ob_type, NONE_TYPE) {
         ObType::None
-    } else if is_class_by_type!(ob_type, FLOAT_TYPE) {
+    } else if is_class_by_type!(ob_type, FLOAT_TYPE)
+        && (opt_disabled!(opts, PASSTHROUGH_NON_FINITE) || ffi!(PyFloat_AS_DOUBLE(obj)).is_finite())
+    {
+        // a float that is not finite with OPT_PASSTHROUGH_NON_FINITE is
+        // ObType::Unknown and so passed to default
         ObType::Float
-    } else if is_class_by_type!(ob_type, LIST_TYPE) {
+    } else if is_class_by_type!(ob_type, LIST_TYPE) && opt_disabled!(opts, LENGTH_TAGGED) {
         ObType::List
-    } else if is_class_by_type!(ob_type, DICT_TYPE) {
+    } else if is_class_by_type!(ob_type, DICT_TYPE) && opt_disabled!(opts, LENGTH_TAGGED) {
         ObType::Dict
-    } else if is_class_by_type!(ob_type, DATETIME_TYPE) && opt_disabled!(opts, PASSTHROUGH_DATETIME)
+    } else if is_class_by_type!(ob_type, DATETIME_TYPE)
+        && opt_disabled!(
+            opts,
+            PASSTHROUGH_DATETIME | DEFAULT_FIRST | DATETIME_FORMATS
+        )
     {
         ObType::Datetime
     } else {
-        pyobject_to_obtype_unlikely(ob_type, opts)
+        pyobject_to_obtype_unlikely(obj, ob_type, opts)
     }
 }
 
 #[cfg_attr(feature = "optimize", optimize(size))]
 #[inline(never)]
 pub(crate) fn pyobject_to_obtype_unlikely(
+    obj: *mut crate::ffi::PyObject,
     ob_type: *mut crate::ffi::PyTypeObject,
     opts: Opt,
 ) -> ObType {
+    if opt_enabled!(opts, LENGTH_TAGGED)
+        && (is_class_by_type!(ob_type, LIST_TYPE)
+            || is_class_by_type!(ob_type, DICT_TYPE)
+            || is_class_by_type!(ob_type, TUPLE_TYPE))
+    {
+        return ObType::LengthTagged;
+    }
+
+    if opt_enabled!(opts, DEFAULT_FIRST)
+        && !is_class_by_type!(ob_type, TUPLE_TYPE)
+        && !is_class_by_type!(ob_type, FRAGMENT_TYPE)
+    {
+        return ObType::DefaultFirst;
+    }
+
     if is_class_by_type!(ob_type, UUID_TYPE) {
         return ObType::Uuid;
     } else if is_class_by_type!(ob_type, TUPLE_TYPE) {
         return ObType::Tuple;
     } else if is_class_by_type!(ob_type, FRAGMENT_TYPE) {
         return ObType::Fragment;
+    } else if is_class_by_type!(ob_type, EPOCH_NANOSECONDS_TYPE) {
+        return ObType::EpochNanoseconds;
     }
 
     if opt_disabled!(opts, PASSTHROUGH_DATETIME) {
+        if opt_enabled!(opts, DATETIME_FORMATS)
+            && (is_class_by_type!(ob_type, DATETIME_TYPE)
+                || is_class_by_type!(ob_type, DATE_TYPE)
+                || is_class_by_type!(ob_type, TIME_TYPE))
+        {
+            return ObType::DatetimeFormat;
+        }
         if is_class_by_type!(ob_type, DATE_TYPE) {
             return ObType::Date;
         } else if is_class_by_type!(ob_type, TIME_TYPE) {
@@ -79,6 +135,13 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         }
     }
 
+    if opt_enabled!(opts, PASSTHROUGH_ENUM | ENUM_FORMATS) && is_enum_type(ob_type) {
+        if opt_enabled!(opts, PASSTHROUGH_ENUM) && !is_flag_as_int(ob_type, opts) {
+            return ObType::Unknown;
+        }
+        return ObType::Enum;
+    }
+
     let tp_flags = tp_flags!(ob_type);
 
     if opt_disabled!(opts, PASSTHROUGH_SUBCLASS) {
@@ -87,21 +150,52 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         } else if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_LONG_SUBCLASS) {
             return ObType::Int;
         } else if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_LIST_SUBCLASS) {
+            if opt_enabled!(opts, LENGTH_TAGGED) {
+                return ObType::LengthTagged;
+            }
             return ObType::List;
         } else if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_DICT_SUBCLASS) {
+            if opt_enabled!(opts, LENGTH_TAGGED) {
+                return ObType::LengthTagged;
+            }
             return ObType::Dict;
         }
     }
 
-    if is_subclass_by_type!(ob_type, ENUM_TYPE) {
+    if is_enum_type(ob_type) {
         return ObType::Enum;
     }
 
+    if opt_enabled!(opts, NAMEDTUPLE_AS_OBJECT)
+        && is_subclass_by_flag!(tp_flags, Py_TPFLAGS_TUPLE_SUBCLASS)
+        && ffi!(PyObject_HasAttr(
+            ob_type.cast::<crate::ffi::PyObject>(),
+            NAMEDTUPLE_FIELDS_STR
+        )) == 1
+    {
+        return ObType::NamedTuple;
+    }
+
     if opt_disabled!(opts, PASSTHROUGH_DATACLASS) && pydict_contains!(ob_type, DATACLASS_FIELDS_STR)
     {
         return ObType::Dataclass;
     }
 
+    if opt_enabled!(opts, BYTES_AS_UUID)
+        && is_class_by_type!(ob_type, BYTES_TYPE)
+        && ffi!(Py_SIZE(obj)) == 16
+    {
+        return ObType::Bytes;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_BYTES_GZIP)
+        && (is_class_by_type!(ob_type, BYTES_TYPE)
+            || is_class_by_type!(ob_type, BYTEARRAY_TYPE)
+            || is_class_by_type!(ob_type, MEMORYVIEW_TYPE))
+    {
+        return ObType::Bytes;
+    }
+
     if opt_enabled!(opts, SERIALIZE_NUMPY) {
         cold_path!();
         if is_numpy_scalar(ob_type) {
@@ -111,5 +205,46 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         }
     }
 
+    if opt_enabled!(opts, SERIALIZE_BYTES) && is_bytes_like(obj, ob_type) {
+        return ObType::Bytes;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_PYDANTIC)
+        && is_subclass_by_flag!(tp_flags, Py_TPFLAGS_HEAPTYPE)
+        && is_pydantic_model(ob_type)
+    {
+        return ObType::Pydantic;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_WEAKREF) && is_weakref_proxy(obj) {
+        return ObType::Weakref;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_RANGE) && is_range(ob_type) {
+        return ObType::Range;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_TIMEDELTA) && is_class_by_type!(ob_type, TIMEDELTA_TYPE) {
+        return ObType::Timedelta;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_FRACTIONS) && is_fraction(ob_type) {
+        return ObType::Fraction;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_MAPPING) && is_mapping(obj, ob_type) {
+        return ObType::Mapping;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_ITERATORS) && is_iterator(ob_type) {
+        return ObType::Iterator;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_ANNOTATED)
+        && is_subclass_by_flag!(tp_flags, Py_TPFLAGS_HEAPTYPE)
+    {
+        return ObType::Annotated;
+    }
+
     ObType::Unknown
 }
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{PyIntRef, PyObject, PyStrRef};
+use core::cell::RefCell;
+
+/// A `dict` key, object field, or array index leading to the object that
+/// failed to serialize.
+enum PathSegment {
+    Index(usize),
+    Key(String),
+}
+
+// Segments are pushed innermost first as an error propagates out of each
+// container, so serialization that succeeds never touches this.
+std::thread_local! {
+    static ERROR_PATH: RefCell<Vec<PathSegment>> = const { RefCell::new(Vec::new()) };
+}
+
+#[cold]
+#[inline(never)]
+fn push_segment(segment: PathSegment) {
+    ERROR_PATH.with_borrow_mut(|path| path.push(segment));
+}
+
+/// The path of the most recent error, leaving it to be taken, as a normalized
+/// path of RFC 9535, e.g., `$['a'][0]`.
+#[cold]
+#[inline(never)]
+pub(crate) fn format_error_path() -> String {
+    ERROR_PATH.with_borrow(|segments| {
+        let mut ret = String::from("$");
+        for segment in segments.iter().rev() {
+            match segment {
+                PathSegment::Index(val) => {
+                    ret.push('[');
+                    ret.push_str(itoa::Buffer::new().format(*val));
+                    ret.push(']');
+                }
+                PathSegment::Key(val) => {
+                    ret.push_str("['");
+                    for each in val.chars() {
+                        if each == '\'' || each == '\\' {
+                            ret.push('\\');
+                        }
+                        ret.push(each);
+                    }
+                    ret.push_str("']");
+                }
+            }
+        }
+        ret
+    })
+}
+
+/// Discard the path of an error that is reported as an error of an object
+/// containing it.
+#[cold]
+#[inline(never)]
+pub(crate) fn clear_error_path() {
+    ERROR_PATH.with_borrow_mut(Vec::clear);
+}
+
+/// Record the location of an element in its container if serializing it
+/// failed.
+pub(crate) trait ErrorPath {
+    fn at_index(self, idx: usize) -> Self;
+    fn at_key(self, key: &str) -> Self;
+}
+
+impl<E> ErrorPath for Result<(), E> {
+    #[inline(always)]
+    fn at_index(self, idx: usize) -> Self {
+        if self.is_err() {
+            push_segment(PathSegment::Index(idx));
+        }
+        self
+    }
+
+    #[inline(always)]
+    fn at_key(self, key: &str) -> Self {
+        if self.is_err() {
+            push_segment(PathSegment::Key(String::from(key)));
+        }
+        self
+    }
+}
+
+/// Take the path of the most recent error as a `list` of `str` keys and
+/// `int` indices from the outermost object, leaving it empty.
+#[cold]
+#[inline(never)]
+pub(crate) fn take_error_path() -> *mut PyObject {
+    let segments = ERROR_PATH.with_borrow_mut(core::mem::take);
+    unsafe {
+        let list = ffi!(PyList_New(crate::util::usize_to_isize(segments.len())));
+        for (idx, segment) in segments.iter().rev().enumerate() {
+            let item = match segment {
+                PathSegment::Index(val) => PyIntRef::from_u64(*val as u64).as_ptr(),
+                PathSegment::Key(val) => PyStrRef::from_str(val).as_ptr(),
+            };
+            crate::ffi::PyList_SET_ITEM(list, crate::util::usize_to_isize(idx), item);
+        }
+        list
+    }
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyStrRef;
+use crate::opt::ANNOTATED_MISSING_AS_NULL;
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::per_type::{DefaultSerializer, NoneSerializer};
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::{ANNOTATIONS_STR, DICT_TYPE, STR_TYPE, TUPLE_TYPE};
+use crate::util::isize_to_usize;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Serialize an instance of a plain class as an object of the attributes
+/// named in `__annotations__` of its class and bases, in declaration order.
+/// An instance of a class without annotations, or with a handler in
+/// `type_handlers`, `__orjson_default__`, or `__json__` that applies, is
+/// serialized as it would be without `OPT_SERIALIZE_ANNOTATED`.
+#[repr(transparent)]
+pub(crate) struct AnnotatedSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> AnnotatedSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl AnnotatedSerializer<'_> {
+    /// Merge the annotations of the MRO, base classes first, into a new
+    /// `dict` so that a name redeclared by a subclass keeps the position of
+    /// its first declaration. Returns null if nothing is annotated.
+    fn fields(&self) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        let mro = unsafe { (*ob_type!(self.previous.ptr)).tp_mro };
+        debug_assert!(is_class_by_type!(ob_type!(mro), TUPLE_TYPE));
+        let mro_ptr = unsafe { (*mro.cast::<crate::ffi::PyTupleObject>()).ob_item.as_ptr() };
+        let fields = ffi!(PyDict_New(0));
+        for idx in (0..isize_to_usize(ffi!(Py_SIZE(mro)))).rev() {
+            let base = unsafe { *mro_ptr.add(idx) };
+            let annotations = ffi!(PyObject_GetAttr(base, ANNOTATIONS_STR));
+            if annotations.is_null() {
+                if ffi!(PyErr_ExceptionMatches(crate::ffi::PyExc_AttributeError)) == 0 {
+                    cold_path!();
+                    ffi!(Py_DECREF(fields));
+                    return Err(SerializeError::AnnotatedAttribute(nonnull!(
+                        self.previous.ptr
+                    )));
+                }
+                ffi!(PyErr_Clear());
+                continue;
+            }
+            if is_class_by_type!(ob_type!(annotations), DICT_TYPE) {
+                ffi!(PyDict_Merge(fields, annotations, 1));
+            }
+            ffi!(Py_DECREF(annotations));
+        }
+        if ffi!(PyDict_Size(fields)) == 0 {
+            ffi!(Py_DECREF(fields));
+            return Ok(core::ptr::null_mut());
+        }
+        Ok(fields)
+    }
+
+    fn serialize_fields<S>(
+        &self,
+        fields: *mut crate::ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = self.previous.state.copy_for_recursive_call();
+        let missing_as_null = opt_enabled!(state.opts(), ANNOTATED_MISSING_AS_NULL);
+
+        let mut map = serializer.serialize_map(None).unwrap();
+
+        let mut pos = 0;
+        let mut attr: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut annotation: *mut crate::ffi::PyObject = core::ptr::null_mut();
+
+        // fields is not shared, so iterating it directly is safe
+        while pydict_next!(fields, &raw mut pos, &raw mut attr, &raw mut annotation) == 1 {
+            if !is_class_by_type!(ob_type!(attr), STR_TYPE) {
+                cold_path!();
+                err!(SerializeError::KeyMustBeStr)
+            }
+            let key_as_str = match unsafe { PyStrRef::from_ptr_unchecked(attr).as_str() } {
+                Some(uni) => uni,
+                None => err!(SerializeError::InvalidStr),
+            };
+            if key_as_str.starts_with('_') {
+                cold_path!();
+                continue;
+            }
+
+            let value = ffi!(PyObject_GetAttr(self.previous.ptr, attr));
+            if value.is_null() {
+                if ffi!(PyErr_ExceptionMatches(crate::ffi::PyExc_AttributeError)) == 0 {
+                    cold_path!();
+                    err!(SerializeError::AnnotatedAttribute(nonnull!(
+                        self.previous.ptr
+                    )))
+                }
+                ffi!(PyErr_Clear());
+                if missing_as_null {
+                    map.serialize_key(key_as_str).unwrap();
+                    map.serialize_value(&NoneSerializer::new()).unwrap();
+                }
+                continue;
+            }
+            let pyvalue = PyObjectSerializer::new(value, state, self.previous.default);
+
+            map.serialize_key(key_as_str).unwrap();
+            let res = map.serialize_value(&pyvalue).at_key(key_as_str);
+            ffi!(Py_DECREF(value));
+            res?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for AnnotatedSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if DefaultSerializer::new(self.previous).has_hook() {
+            return DefaultSerializer::new(self.previous).serialize(serializer);
+        }
+        if self.previous.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let fields = match self.fields() {
+            Ok(fields) => fields,
+            Err(err) => err!(err),
+        };
+        if fields.is_null() {
+            return DefaultSerializer::new(self.previous).serialize(serializer);
+        }
+        let ret = self.serialize_fields(fields, serializer);
+        ffi!(Py_DECREF(fields));
+        ret
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{PyBytesRef, PyObject};
+use crate::opt::{BYTES_AS_UUID, Opt, SERIALIZE_BYTES, SERIALIZE_BYTES_GZIP};
+use crate::serialize::buffer::SmallFixedBuffer;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::uuid::write_uuid;
+use crate::typeref::{
+    BYTEARRAY_TYPE, BYTES_DUNDER_STR, BYTES_TYPE, COMPRESS_STR, FLUSH_STR, MEMORYVIEW_TYPE,
+    ZLIB_COMPRESSOBJ, load_zlib_compressobj,
+};
+use crate::util::isize_to_usize;
+use serde::ser::{Serialize, Serializer};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const GZIP_PREFIX: &[u8] = b"data:application/gzip;base64,";
+
+// zlib.DEFLATED
+const ZLIB_DEFLATED: i64 = 8;
+// 16 + zlib.MAX_WBITS selects a gzip header and trailer
+const ZLIB_GZIP_WBITS: i64 = 31;
+// zlib.Z_DEFAULT_COMPRESSION
+const ZLIB_DEFAULT_COMPRESSION: i64 = -1;
+
+// the order of a C-contiguous buffer
+#[allow(clippy::cast_possible_wrap)]
+const C_CONTIGUOUS: core::ffi::c_char = b'C' as core::ffi::c_char;
+
+#[inline]
+pub(crate) fn base64_encoded_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+pub(crate) fn write_base64(data: &[u8], buf: &mut Vec<u8>) {
+    buf.reserve(base64_encoded_len(data.len()));
+    let mut chunks = data.chunks_exact(3);
+    for chunk in chunks.by_ref() {
+        let val = (u32::from(chunk[0]) << 16) | (u32::from(chunk[1]) << 8) | u32::from(chunk[2]);
+        buf.extend_from_slice(&[
+            BASE64_ALPHABET[(val >> 18) as usize & 63],
+            BASE64_ALPHABET[(val >> 12) as usize & 63],
+            BASE64_ALPHABET[(val >> 6) as usize & 63],
+            BASE64_ALPHABET[val as usize & 63],
+        ]);
+    }
+    match *chunks.remainder() {
+        [a] => {
+            let val = u32::from(a) << 16;
+            buf.extend_from_slice(&[
+                BASE64_ALPHABET[(val >> 18) as usize & 63],
+                BASE64_ALPHABET[(val >> 12) as usize & 63],
+                b'=',
+                b'=',
+            ]);
+        }
+        [a, b] => {
+            let val = (u32::from(a) << 16) | (u32::from(b) << 8);
+            buf.extend_from_slice(&[
+                BASE64_ALPHABET[(val >> 18) as usize & 63],
+                BASE64_ALPHABET[(val >> 12) as usize & 63],
+                BASE64_ALPHABET[(val >> 6) as usize & 63],
+                b'=',
+            ]);
+        }
+        _ => {}
+    }
+}
+
+/// Compress `bytes` or a `memoryview` to the gzip format using the `zlib`
+/// module with a zeroed header timestamp so that output is deterministic.
+/// `zlib` reads either through the buffer protocol, so the contents are not
+/// copied. This returns `None` with a Python exception set on failure.
+#[cold]
+#[inline(never)]
+fn gzip_compress(ptr: *mut PyObject) -> Option<Vec<u8>> {
+    let compressobj = unsafe { (*ZLIB_COMPRESSOBJ.get_or_init(load_zlib_compressobj))? };
+    unsafe {
+        let args: [*mut PyObject; 3] = [
+            crate::ffi::PyLong_FromLongLong(ZLIB_DEFAULT_COMPRESSION),
+            crate::ffi::PyLong_FromLongLong(ZLIB_DEFLATED),
+            crate::ffi::PyLong_FromLongLong(ZLIB_GZIP_WBITS),
+        ];
+        let compressor = crate::ffi::PyObject_Vectorcall(
+            compressobj.as_ptr(),
+            args.as_ptr(),
+            args.len(),
+            core::ptr::null_mut(),
+        );
+        for each in args {
+            ffi!(Py_DECREF(each));
+        }
+        if compressor.is_null() {
+            return None;
+        }
+        let body = call_method!(compressor, COMPRESS_STR, ptr);
+        if body.is_null() {
+            ffi!(Py_DECREF(compressor));
+            return None;
+        }
+        let tail = call_method!(compressor, FLUSH_STR);
+        ffi!(Py_DECREF(compressor));
+        if tail.is_null() {
+            ffi!(Py_DECREF(body));
+            return None;
+        }
+        let mut out: Vec<u8> = Vec::new();
+        for each in [body, tail] {
+            match PyBytesRef::from_ptr(each) {
+                Ok(val) => out.extend_from_slice(val.as_bytes()),
+                Err(_) => {
+                    ffi!(Py_DECREF(body));
+                    ffi!(Py_DECREF(tail));
+                    return None;
+                }
+            }
+        }
+        ffi!(Py_DECREF(body));
+        ffi!(Py_DECREF(tail));
+        Some(out)
+    }
+}
+
+/// Copy the contents of a `bytearray` to a new `bytes`. `zlib` may release
+/// the GIL while compressing, and on free-threaded builds another thread may
+/// write to the `bytearray` at any time, so it is compressed from this
+/// snapshot rather than through the buffer protocol. On free-threaded builds
+/// the copy is made in a critical section on the `bytearray`, as its
+/// methods do, so that it is not torn by a concurrent write or resize. This
+/// returns null with a Python exception set on failure.
+#[cold]
+#[inline(never)]
+fn bytearray_snapshot(ptr: *mut PyObject) -> *mut PyObject {
+    debug_assert!(is_class_by_type!(ob_type!(ptr), BYTEARRAY_TYPE));
+    unsafe {
+        #[cfg(Py_GIL_DISABLED)]
+        let mut cs = core::mem::MaybeUninit::<crate::ffi::PyCriticalSection>::uninit();
+        #[cfg(Py_GIL_DISABLED)]
+        crate::ffi::PyCriticalSection_Begin(cs.as_mut_ptr(), ptr);
+
+        let snapshot = crate::ffi::PyBytes_FromStringAndSize(
+            crate::ffi::PyByteArray_AsString(ptr),
+            crate::ffi::PyByteArray_Size(ptr),
+        );
+
+        #[cfg(Py_GIL_DISABLED)]
+        crate::ffi::PyCriticalSection_End(cs.as_mut_ptr());
+        snapshot
+    }
+}
+
+/// Write the `str` that `bytes`, a `bytearray`, or a `memoryview` is
+/// serialized as with `OPT_SERIALIZE_BYTES_GZIP`, without quotes.
+fn write_gzip_base64(ptr: *mut PyObject, buf: &mut Vec<u8>) -> Result<(), SerializeError> {
+    let compressed = if is_class_by_type!(ob_type!(ptr), BYTEARRAY_TYPE) {
+        let snapshot = bytearray_snapshot(ptr);
+        if snapshot.is_null() {
+            None
+        } else {
+            let ret = gzip_compress(snapshot);
+            ffi!(Py_DECREF(snapshot));
+            ret
+        }
+    } else {
+        gzip_compress(ptr)
+    };
+    match compressed {
+        Some(compressed) => {
+            buf.reserve(GZIP_PREFIX.len() + base64_encoded_len(compressed.len()));
+            buf.extend_from_slice(GZIP_PREFIX);
+            write_base64(&compressed, buf);
+            Ok(())
+        }
+        None => Err(SerializeError::BytesCompression),
+    }
+}
+
+/// Whether `obj` is serialized with `OPT_SERIALIZE_BYTES`, i.e., it supports
+/// the buffer protocol or its type has `__bytes__()`.
+#[cold]
+#[inline(never)]
+pub(crate) fn is_bytes_like(obj: *mut PyObject, ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    #[cfg(CPython)]
+    if ffi!(PyObject_CheckBuffer(obj)) == 1 {
+        return true;
+    }
+    #[cfg(not(CPython))]
+    let _ = obj;
+    ffi!(PyObject_HasAttr(
+        ob_type.cast::<PyObject>(),
+        BYTES_DUNDER_STR
+    )) == 1
+}
+
+/// Whether `ptr` is `bytes`, a `bytearray`, or a `memoryview`, which are
+/// compressed with `OPT_SERIALIZE_BYTES_GZIP`.
+#[inline(always)]
+fn is_gzip_type(ptr: *mut PyObject) -> bool {
+    let ob_type = ob_type!(ptr);
+    is_class_by_type!(ob_type, BYTES_TYPE)
+        || is_class_by_type!(ob_type, BYTEARRAY_TYPE)
+        || is_class_by_type!(ob_type, MEMORYVIEW_TYPE)
+}
+
+/// Write the base64 of the contents of an object supporting the buffer
+/// protocol, or else of the `bytes` returned by its `__bytes__()`, as it is
+/// serialized with `OPT_SERIALIZE_BYTES`, without quotes. A buffer that is
+/// not C-contiguous, e.g., a `memoryview` with a step, is copied to be so.
+/// The buffer is released on every path.
+#[cold]
+#[inline(never)]
+fn write_bytes_base64(ptr: *mut PyObject, buf: &mut Vec<u8>) -> Result<(), SerializeError> {
+    // another thread may write to a bytearray while it is read
+    #[cfg(Py_GIL_DISABLED)]
+    if is_class_by_type!(ob_type!(ptr), BYTEARRAY_TYPE) {
+        let snapshot = bytearray_snapshot(ptr);
+        if snapshot.is_null() {
+            return Err(SerializeError::Bytes(nonnull!(ptr)));
+        }
+        let ret = write_bytes_base64(snapshot, buf);
+        ffi!(Py_DECREF(snapshot));
+        return ret;
+    }
+    #[cfg(CPython)]
+    if ffi!(PyObject_CheckBuffer(ptr)) == 1 {
+        let mut view = core::mem::MaybeUninit::<crate::ffi::Py_buffer>::uninit();
+        if ffi!(PyObject_GetBuffer(
+            ptr,
+            view.as_mut_ptr(),
+            crate::ffi::PyBUF_FULL_RO
+        )) != 0
+        {
+            return Err(SerializeError::Bytes(nonnull!(ptr)));
+        }
+        let mut view = unsafe { view.assume_init() };
+        let len = isize_to_usize(view.len);
+        let ret = if len == 0 {
+            Ok(())
+        } else if ffi!(PyBuffer_IsContiguous(&raw const view, C_CONTIGUOUS)) == 1 {
+            write_base64(
+                unsafe { core::slice::from_raw_parts(view.buf.cast::<u8>().cast_const(), len) },
+                buf,
+            );
+            Ok(())
+        } else {
+            let mut contiguous: Vec<u8> = vec![0; len];
+            if ffi!(PyBuffer_ToContiguous(
+                contiguous.as_mut_ptr().cast::<core::ffi::c_void>(),
+                &raw const view,
+                view.len,
+                C_CONTIGUOUS
+            )) == 0
+            {
+                write_base64(&contiguous, buf);
+                Ok(())
+            } else {
+                Err(SerializeError::Bytes(nonnull!(ptr)))
+            }
+        };
+        ffi!(PyBuffer_Release(&raw mut view));
+        return ret;
+    }
+    let bytes = ffi!(PyObject_Bytes(ptr));
+    if bytes.is_null() {
+        return Err(SerializeError::Bytes(nonnull!(ptr)));
+    }
+    let len = isize_to_usize(ffi!(Py_SIZE(bytes)));
+    if len > 0 {
+        write_base64(
+            unsafe {
+                core::slice::from_raw_parts(crate::ffi::PyBytes_AS_STRING(bytes).cast::<u8>(), len)
+            },
+            buf,
+        );
+    }
+    ffi!(Py_DECREF(bytes));
+    Ok(())
+}
+
+/// Convert a `dict` key that is `bytes` or an instance of a subclass of
+/// `bytes` to the `str` it would be serialized as if it were exactly `bytes`
+/// with `OPT_BYTES_AS_UUID` or `OPT_SERIALIZE_BYTES_GZIP`. With
+/// `OPT_SERIALIZE_BYTES`, the key may be any object it serializes, e.g., a
+/// read-only `memoryview`.
+#[cold]
+#[inline(never)]
+pub(crate) fn bytes_key_to_string(ptr: *mut PyObject, opts: Opt) -> Result<String, SerializeError> {
+    let is_bytes = is_subclass_by_flag!(tp_flags!(ob_type!(ptr)), Py_TPFLAGS_BYTES_SUBCLASS);
+    debug_assert!(is_bytes || opt_enabled!(opts, SERIALIZE_BYTES | SERIALIZE_BYTES_GZIP));
+    if opt_enabled!(opts, BYTES_AS_UUID) && is_bytes && ffi!(Py_SIZE(ptr)) == 16 {
+        let uuid = unsafe { *crate::ffi::PyBytes_AS_STRING(ptr).cast::<[u8; 16]>() };
+        let mut buf = SmallFixedBuffer::new();
+        write_uuid(&mut buf, u128::from_be_bytes(uuid), opts);
+        return Ok(String::from(str_from_slice!(buf.as_ptr(), buf.len())));
+    }
+    if opt_enabled!(opts, SERIALIZE_BYTES_GZIP) && (is_bytes || is_gzip_type(ptr)) {
+        let mut buf: Vec<u8> = Vec::new();
+        write_gzip_base64(ptr, &mut buf)?;
+        return Ok(String::from(str_from_slice!(buf.as_ptr(), buf.len())));
+    }
+    if opt_enabled!(opts, SERIALIZE_BYTES) {
+        let mut buf: Vec<u8> = Vec::new();
+        write_bytes_base64(ptr, &mut buf)?;
+        return Ok(String::from(str_from_slice!(buf.as_ptr(), buf.len())));
+    }
+    Err(SerializeError::DictKeyInvalidType)
+}
+
+pub(crate) struct BytesSerializer {
+    ptr: *mut PyObject,
+    opts: Opt,
+}
+
+impl BytesSerializer {
+    pub fn new(ptr: *mut PyObject, opts: Opt) -> Self {
+        debug_assert!(is_gzip_type(ptr) || opt_enabled!(opts, SERIALIZE_BYTES));
+        BytesSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+impl Serialize for BytesSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `bytes` of other lengths, `bytearray`, and `memoryview` are only
+        // serialized here with `OPT_SERIALIZE_BYTES_GZIP` or
+        // `OPT_SERIALIZE_BYTES`, and other objects only with the latter
+        if opt_enabled!(self.opts, BYTES_AS_UUID)
+            && let Ok(val) = PyBytesRef::from_ptr(self.ptr)
+            && let Ok(uuid) = <[u8; 16]>::try_from(val.as_bytes())
+        {
+            // the same as `uuid.UUID(bytes=...)`
+            let mut buf = SmallFixedBuffer::new();
+            write_uuid(&mut buf, u128::from_be_bytes(uuid), self.opts);
+            return serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()));
+        }
+        let mut buf: Vec<u8> = vec![b'"'];
+        let res = if opt_enabled!(self.opts, SERIALIZE_BYTES_GZIP) && is_gzip_type(self.ptr) {
+            write_gzip_base64(self.ptr, &mut buf)
+        } else {
+            write_bytes_base64(self.ptr, &mut buf)
+        };
+        match res {
+            Ok(()) => {
+                buf.push(b'"');
+                serializer.serialize_bytes(&buf)
+            }
+            Err(err) => err!(err),
+        }
+    }
+}
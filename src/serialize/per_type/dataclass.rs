@@ -2,19 +2,28 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyStrRef;
+use crate::opt::SORT_DATACLASS_FIELDS;
 use crate::serialize::error::SerializeError;
-use crate::serialize::per_type::dict::ZeroDictSerializer;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::per_type::dict::{ZeroDictSerializer, is_omitted_none};
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
 use crate::typeref::{
-    DATACLASS_FIELDS_STR, DICT_STR, FIELD_TYPE, FIELD_TYPE_STR, SLOTS_STR, STR_TYPE,
+    DATACLASS_FIELDS_STR, DICT_STR, FIELD_TYPE, FIELD_TYPE_STR, NONE, SLOTS_STR, STR_TYPE,
 };
 #[cfg(not(Py_GIL_DISABLED))]
 use crate::util::isize_to_usize;
 
+#[cfg(not(Py_GIL_DISABLED))]
+use associative_cache::{AssociativeCache, Capacity64, HashDirectMapped, RoundRobinReplacement};
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use smallvec::SmallVec;
 
+#[cfg(not(Py_GIL_DISABLED))]
+use core::cell::OnceCell;
 use core::ptr::NonNull;
+#[cfg(not(Py_GIL_DISABLED))]
+use std::rc::Rc;
 
 #[cfg(Py_GIL_DISABLED)]
 struct DictItemsSnapshot {
@@ -45,6 +54,201 @@ impl Drop for DictItemsSnapshot {
     }
 }
 
+/// The names of the fields of a dataclass type, in the order of
+/// `__dataclass_fields__`, excluding `ClassVar` and `InitVar` pseudo-fields,
+/// read once per type for those serialized without `__dict__`. The type and
+/// names are strong references, so the type is not freed, and its address
+/// reused, while it is cached.
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) struct DataclassFields {
+    ob_type: *mut crate::ffi::PyTypeObject,
+    names: Vec<*mut crate::ffi::PyObject>,
+}
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) type DataclassFieldsMap = AssociativeCache<
+    usize,
+    Rc<DataclassFields>,
+    Capacity64,
+    HashDirectMapped,
+    RoundRobinReplacement,
+>;
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) static mut DATACLASS_FIELDS_MAP: OnceCell<DataclassFieldsMap> = OnceCell::new();
+
+#[cfg(not(Py_GIL_DISABLED))]
+impl DataclassFields {
+    /// The fields of `ob_type`, from the cache if it has been serialized
+    /// before. An entry may be evicted while serializing a field, so the
+    /// caller holds its own reference.
+    #[inline]
+    fn of(ob_type: *mut crate::ffi::PyTypeObject) -> Rc<Self> {
+        let key = ob_type as usize;
+        let cache = unsafe {
+            DATACLASS_FIELDS_MAP
+                .get_mut()
+                .unwrap_or_else(|| unreachable_unchecked!())
+        };
+        if let Some(fields) = cache.get(&key) {
+            return fields.clone();
+        }
+        cold_path!();
+        let fields = Rc::new(Self::new(ob_type));
+        cache.insert(key, fields.clone());
+        fields
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn new(ob_type: *mut crate::ffi::PyTypeObject) -> Self {
+        let fields = ffi!(PyObject_GetAttr(
+            ob_type.cast::<crate::ffi::PyObject>(),
+            DATACLASS_FIELDS_STR
+        ));
+        debug_assert!(ffi!(Py_REFCNT(fields)) >= 2);
+        let len = isize_to_usize(ffi!(Py_SIZE(fields)));
+        let mut names = Vec::with_capacity(len);
+
+        let mut pos = 0;
+        let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut next_value: *mut crate::ffi::PyObject = core::ptr::null_mut();
+
+        pydict_next!(fields, &raw mut pos, &raw mut next_key, &raw mut next_value);
+
+        for _ in 0..len {
+            let attr = next_key;
+            let field = next_value;
+
+            pydict_next!(fields, &raw mut pos, &raw mut next_key, &raw mut next_value);
+
+            let field_type = ffi!(PyObject_GetAttr(field, FIELD_TYPE_STR));
+            debug_assert!(ffi!(Py_REFCNT(field_type)) >= 2);
+            ffi!(Py_DECREF(field_type));
+            if unsafe { core::ptr::eq(field_type.cast::<crate::ffi::PyTypeObject>(), FIELD_TYPE) } {
+                ffi!(Py_INCREF(attr));
+                names.push(attr);
+            }
+        }
+        ffi!(Py_DECREF(fields));
+        ffi!(Py_INCREF(ob_type.cast::<crate::ffi::PyObject>()));
+        Self {
+            ob_type: ob_type,
+            names: names,
+        }
+    }
+}
+
+#[cfg(not(Py_GIL_DISABLED))]
+impl Drop for DataclassFields {
+    fn drop(&mut self) {
+        for &name in self.names.iter() {
+            ffi!(Py_DECREF(name));
+        }
+        ffi!(Py_DECREF(self.ob_type.cast::<crate::ffi::PyObject>()));
+    }
+}
+
+/// Fields of a dataclass collected to be serialized sorted by name with
+/// `OPT_SORT_DATACLASS_FIELDS`. If `owned`, each value is a strong reference
+/// released on drop.
+struct SortedFields<'a> {
+    items: SmallVec<[(&'a str, *mut crate::ffi::PyObject); 8]>,
+    owned: bool,
+}
+
+impl<'a> SortedFields<'a> {
+    #[inline]
+    fn new(state: SerializerState, owned: bool) -> Option<Self> {
+        if opt_enabled!(state.opts(), SORT_DATACLASS_FIELDS) {
+            cold_path!();
+            Some(Self {
+                items: SmallVec::new(),
+                owned: owned,
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, key: &'a str, value: *mut crate::ffi::PyObject) {
+        self.items.push((key, value));
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn serialize_into<M>(
+        &mut self,
+        map: &mut M,
+        state: SerializerState,
+        default: Option<NonNull<crate::ffi::PyObject>>,
+    ) -> Result<(), M::Error>
+    where
+        M: SerializeMap,
+    {
+        self.items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        for &(key, value) in self.items.iter() {
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&PyObjectSerializer::new(value, state, default))
+                .at_key(key)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SortedFields<'_> {
+    fn drop(&mut self) {
+        if self.owned {
+            for &(_, value) in self.items.iter() {
+                ffi!(Py_DECREF(value));
+            }
+        }
+    }
+}
+
+/// The fields to include and to exclude for a dataclass type from
+/// `field_schemas` of `orjson.Encoder`. Either is null if not given.
+#[derive(Copy, Clone)]
+pub(crate) struct FieldSchema {
+    include: *mut crate::ffi::PyObject,
+    exclude: *mut crate::ffi::PyObject,
+}
+
+impl FieldSchema {
+    #[inline]
+    fn of(state: SerializerState, ob_type: *mut crate::ffi::PyTypeObject) -> Option<Self> {
+        let schemas = state.field_schemas()?;
+        cold_path!();
+        let schema = ffi!(PyDict_GetItemWithError(
+            schemas.as_ptr(),
+            ob_type.cast::<crate::ffi::PyObject>()
+        ));
+        if schema.is_null() {
+            return None;
+        }
+        let names = |idx| {
+            let ptr = unsafe { crate::ffi::PyTuple_GET_ITEM(schema, idx) };
+            if unsafe { core::ptr::eq(ptr, NONE) } {
+                core::ptr::null_mut()
+            } else {
+                ptr
+            }
+        };
+        Some(Self {
+            include: names(0),
+            exclude: names(1),
+        })
+    }
+
+    /// Whether the field named `key` is not serialized.
+    #[inline]
+    fn skips(self, key: *mut crate::ffi::PyObject) -> bool {
+        (!self.include.is_null() && ffi!(PySet_Contains(self.include, key)) != 1)
+            || (!self.exclude.is_null() && ffi!(PySet_Contains(self.exclude, key)) == 1)
+    }
+}
+
 #[repr(transparent)]
 pub(crate) struct DataclassGenericSerializer<'a> {
     previous: &'a PyObjectSerializer,
@@ -65,8 +269,20 @@ impl Serialize for DataclassGenericSerializer<'_> {
         if self.previous.state.recursion_limit() {
             err!(SerializeError::RecursionLimit)
         }
-        let dict = ffi!(PyObject_GetAttr(self.previous.ptr, DICT_STR));
         let ob_type = ob_type!(self.previous.ptr);
+        let schema = FieldSchema::of(self.previous.state, ob_type);
+        // checked before getting `__dict__` so that an instance of a
+        // dataclass with `__slots__` does not raise and clear AttributeError
+        if pydict_contains!(ob_type, SLOTS_STR) {
+            return DataclassFallbackSerializer::new(
+                self.previous.ptr,
+                self.previous.state,
+                self.previous.default,
+                schema,
+            )
+            .serialize(serializer);
+        }
+        let dict = ffi!(PyObject_GetAttr(self.previous.ptr, DICT_STR));
         if dict.is_null() {
             cold_path!();
             ffi!(PyErr_Clear());
@@ -74,23 +290,19 @@ impl Serialize for DataclassGenericSerializer<'_> {
                 self.previous.ptr,
                 self.previous.state,
                 self.previous.default,
+                schema,
             )
             .serialize(serializer)
-        } else if pydict_contains!(ob_type, SLOTS_STR) {
-            let ret = DataclassFallbackSerializer::new(
-                self.previous.ptr,
+        } else {
+            let ret = DataclassFastSerializer::new(
+                dict,
                 self.previous.state,
                 self.previous.default,
+                schema,
             )
             .serialize(serializer);
             ffi!(Py_DECREF(dict));
             ret
-        } else {
-            let ret =
-                DataclassFastSerializer::new(dict, self.previous.state, self.previous.default)
-                    .serialize(serializer);
-            ffi!(Py_DECREF(dict));
-            ret
         }
     }
 }
@@ -99,6 +311,7 @@ pub(crate) struct DataclassFastSerializer {
     ptr: *mut crate::ffi::PyObject,
     state: SerializerState,
     default: Option<NonNull<crate::ffi::PyObject>>,
+    schema: Option<FieldSchema>,
 }
 
 impl DataclassFastSerializer {
@@ -106,11 +319,13 @@ impl DataclassFastSerializer {
         ptr: *mut crate::ffi::PyObject,
         state: SerializerState,
         default: Option<NonNull<crate::ffi::PyObject>>,
+        schema: Option<FieldSchema>,
     ) -> Self {
         DataclassFastSerializer {
             ptr: ptr,
             state: state.copy_for_recursive_call(),
             default: default,
+            schema: schema,
         }
     }
 }
@@ -130,6 +345,7 @@ impl Serialize for DataclassFastSerializer {
             }
 
             let mut map = serializer.serialize_map(None).unwrap();
+            let mut sorted = SortedFields::new(self.state, false);
             for (key, value) in snapshot.items.iter() {
                 let key_as_str = {
                     let key_ob_type = ob_type!(key.as_ptr());
@@ -146,10 +362,23 @@ impl Serialize for DataclassFastSerializer {
                     cold_path!();
                     continue;
                 }
+                if self.schema.is_some_and(|schema| schema.skips(key.as_ptr())) {
+                    continue;
+                }
+                if is_omitted_none(value.as_ptr(), self.state.opts()) {
+                    continue;
+                }
+                if let Some(fields) = sorted.as_mut() {
+                    fields.push(key_as_str, value.as_ptr());
+                    continue;
+                }
 
                 let pyvalue = PyObjectSerializer::new(value.as_ptr(), self.state, self.default);
                 map.serialize_key(key_as_str).unwrap();
-                map.serialize_value(&pyvalue)?;
+                map.serialize_value(&pyvalue).at_key(key_as_str)?;
+            }
+            if let Some(mut fields) = sorted {
+                fields.serialize_into(&mut map, self.state, self.default)?;
             }
             map.end()
         }
@@ -162,6 +391,7 @@ impl Serialize for DataclassFastSerializer {
                 return ZeroDictSerializer::new().serialize(serializer);
             }
             let mut map = serializer.serialize_map(None).unwrap();
+            let mut sorted = SortedFields::new(self.state, false);
 
             let mut pos = 0;
             let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
@@ -200,9 +430,22 @@ impl Serialize for DataclassFastSerializer {
                     cold_path!();
                     continue;
                 }
+                if self.schema.is_some_and(|schema| schema.skips(key)) {
+                    continue;
+                }
+                if is_omitted_none(value, self.state.opts()) {
+                    continue;
+                }
+                if let Some(fields) = sorted.as_mut() {
+                    fields.push(key_as_str, value);
+                    continue;
+                }
                 let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
                 map.serialize_key(key_as_str).unwrap();
-                map.serialize_value(&pyvalue)?;
+                map.serialize_value(&pyvalue).at_key(key_as_str)?;
+            }
+            if let Some(mut fields) = sorted {
+                fields.serialize_into(&mut map, self.state, self.default)?;
             }
             map.end()
         }
@@ -213,6 +456,7 @@ pub(crate) struct DataclassFallbackSerializer {
     ptr: *mut crate::ffi::PyObject,
     state: SerializerState,
     default: Option<NonNull<crate::ffi::PyObject>>,
+    schema: Option<FieldSchema>,
 }
 
 impl DataclassFallbackSerializer {
@@ -220,11 +464,13 @@ impl DataclassFallbackSerializer {
         ptr: *mut crate::ffi::PyObject,
         state: SerializerState,
         default: Option<NonNull<crate::ffi::PyObject>>,
+        schema: Option<FieldSchema>,
     ) -> Self {
         DataclassFallbackSerializer {
             ptr: ptr,
             state: state.copy_for_recursive_call(),
             default: default,
+            schema: schema,
         }
     }
 }
@@ -249,6 +495,7 @@ impl Serialize for DataclassFallbackSerializer {
                 return ZeroDictSerializer::new().serialize(serializer);
             }
             let mut map = serializer.serialize_map(None).unwrap();
+            let mut sorted = SortedFields::new(self.state, true);
 
             for (attr, field) in snapshot.items.iter() {
                 let field_type = ffi!(PyObject_GetAttr(field.as_ptr(), FIELD_TYPE_STR));
@@ -268,52 +515,47 @@ impl Serialize for DataclassFallbackSerializer {
                     cold_path!();
                     continue;
                 }
+                if self
+                    .schema
+                    .is_some_and(|schema| schema.skips(attr.as_ptr()))
+                {
+                    continue;
+                }
 
                 let value = ffi!(PyObject_GetAttr(self.ptr, attr.as_ptr()));
                 debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
+                if is_omitted_none(value, self.state.opts()) {
+                    ffi!(Py_DECREF(value));
+                    continue;
+                }
+                if let Some(fields) = sorted.as_mut() {
+                    fields.push(key_as_str, value);
+                    continue;
+                }
                 let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
 
                 map.serialize_key(key_as_str).unwrap();
-                let res = map.serialize_value(&pyvalue);
+                let res = map.serialize_value(&pyvalue).at_key(key_as_str);
                 ffi!(Py_DECREF(value));
                 res?;
             }
+            if let Some(mut fields) = sorted {
+                fields.serialize_into(&mut map, self.state, self.default)?;
+            }
             map.end()
         }
 
         #[cfg(not(Py_GIL_DISABLED))]
         {
-            let fields = ffi!(PyObject_GetAttr(self.ptr, DATACLASS_FIELDS_STR));
-            debug_assert!(ffi!(Py_REFCNT(fields)) >= 2);
-            ffi!(Py_DECREF(fields));
-            let len = isize_to_usize(ffi!(Py_SIZE(fields)));
-            if len == 0 {
+            let fields = DataclassFields::of(ob_type!(self.ptr));
+            if fields.names.is_empty() {
                 cold_path!();
                 return ZeroDictSerializer::new().serialize(serializer);
             }
             let mut map = serializer.serialize_map(None).unwrap();
+            let mut sorted = SortedFields::new(self.state, false);
 
-            let mut pos = 0;
-            let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
-            let mut next_value: *mut crate::ffi::PyObject = core::ptr::null_mut();
-
-            pydict_next!(fields, &raw mut pos, &raw mut next_key, &raw mut next_value);
-
-            for _ in 0..len {
-                let attr = next_key;
-                let field = next_value;
-
-                pydict_next!(fields, &raw mut pos, &raw mut next_key, &raw mut next_value);
-
-                let field_type = ffi!(PyObject_GetAttr(field, FIELD_TYPE_STR));
-                debug_assert!(ffi!(Py_REFCNT(field_type)) >= 2);
-                ffi!(Py_DECREF(field_type));
-                if unsafe {
-                    !core::ptr::eq(field_type.cast::<crate::ffi::PyTypeObject>(), FIELD_TYPE)
-                } {
-                    continue;
-                }
-
+            for &attr in fields.names.iter() {
                 let key_as_str = match unsafe { PyStrRef::from_ptr_unchecked(attr).as_str() } {
                     Some(uni) => uni,
                     None => err!(SerializeError::InvalidStr),
@@ -322,14 +564,27 @@ impl Serialize for DataclassFallbackSerializer {
                     cold_path!();
                     continue;
                 }
+                if self.schema.is_some_and(|schema| schema.skips(attr)) {
+                    continue;
+                }
 
                 let value = ffi!(PyObject_GetAttr(self.ptr, attr));
                 debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
                 ffi!(Py_DECREF(value));
+                if is_omitted_none(value, self.state.opts()) {
+                    continue;
+                }
+                if let Some(fields) = sorted.as_mut() {
+                    fields.push(key_as_str, value);
+                    continue;
+                }
                 let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
 
                 map.serialize_key(key_as_str).unwrap();
-                map.serialize_value(&pyvalue)?;
+                map.serialize_value(&pyvalue).at_key(key_as_str)?;
+            }
+            if let Some(mut fields) = sorted {
+                fields.serialize_into(&mut map, self.state, self.default)?;
             }
             map.end()
         }
@@ -1,14 +1,24 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
 // Copyright ijl (2018-2026), Ben Sully (2021)
 
-use crate::opt::{OMIT_MICROSECONDS, Opt};
+use crate::ffi::PyStrRef;
+use crate::opt::{
+    CANONICAL, NAIVE_AS_NULL, NAIVE_UTC, NORMALIZE_UTC, OMIT_MICROSECONDS, Opt, PAD_NANOSECONDS,
+    SERIALIZE_DATETIME_AS_EPOCH_OFFSET, SERIALIZE_DATETIME_AS_ISOCALENDAR,
+    SERIALIZE_DATETIME_AS_UNIX, UNIX_MILLISECONDS, ZONE_NAME,
+};
 use crate::serialize::buffer::SmallFixedBuffer;
 use crate::serialize::error::SerializeError;
-use crate::serialize::per_type::datetimelike::{DateTimeError, DateTimeLike, Offset};
+use crate::serialize::per_type::datetimelike::{
+    DateTimeError, DateTimeLike, Offset, days_from_civil, iso_calendar, write_offset,
+};
+use crate::serialize::per_type::float::write_canonical;
+use crate::serialize::serializer::PyObjectSerializer;
 use crate::typeref::{
-    CONVERT_METHOD_STR, DST_STR, NORMALIZE_METHOD_STR, UTCOFFSET_METHOD_STR, ZONEINFO_TYPE,
+    CONVERT_METHOD_STR, DATE_TYPE, DATETIME_TYPE, DST_STR, KEY_STR, NORMALIZE_METHOD_STR, STR_TYPE,
+    STRFTIME_STR, TIME_TYPE, UTCOFFSET_METHOD_STR, ZONEINFO_TYPE,
 };
-use serde::ser::{Serialize, Serializer};
+use serde::ser::{Serialize, SerializeMap, Serializer};
 
 macro_rules! write_double_digit {
     ($buf:ident, $value:ident) => {
@@ -20,24 +30,188 @@ macro_rules! write_double_digit {
 }
 
 macro_rules! write_microsecond {
-    ($buf:ident, $microsecond:ident) => {
-        if $microsecond != 0 {
+    ($buf:ident, $microsecond:ident, $pad:expr) => {
+        if $microsecond != 0 || $pad {
             let mut buf = itoa::Buffer::new();
             let formatted = buf.format($microsecond);
             $buf.put_slice(&[b'.', b'0', b'0', b'0', b'0', b'0', b'0'][..(7 - formatted.len())]);
             $buf.put_slice(formatted.as_bytes());
+            if $pad {
+                $buf.put_slice(b"000");
+            }
         }
     };
 }
 
-#[repr(transparent)]
+/// A Unix timestamp written, with `SERIALIZE_DATETIME_AS_UNIX`, as a number
+/// of seconds or, with `UNIX_MILLISECONDS`, as an integer number of
+/// milliseconds rounded down.
+pub(crate) struct UnixTimestamp {
+    microseconds: i64,
+    opts: Opt,
+}
+
+impl UnixTimestamp {
+    pub fn new(microseconds: i64, opts: Opt) -> Self {
+        UnixTimestamp {
+            microseconds: microseconds,
+            opts: opts,
+        }
+    }
+
+    fn milliseconds(&self) -> i64 {
+        self.microseconds.div_euclid(1_000)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn seconds(&self) -> f64 {
+        self.microseconds as f64 / 1_000_000.0
+    }
+
+    /// Write the timestamp as it is serialized, for a `dict` key.
+    pub fn write_buf<B>(&self, buf: &mut B)
+    where
+        B: bytes::BufMut,
+    {
+        if opt_enabled!(self.opts, UNIX_MILLISECONDS) {
+            buf.put_slice(itoa::Buffer::new().format(self.milliseconds()).as_bytes());
+        } else if opt_enabled!(self.opts, CANONICAL) {
+            write_canonical(buf, self.seconds());
+        } else {
+            buf.put_slice(zmij::Buffer::new().format_finite(self.seconds()).as_bytes());
+        }
+    }
+}
+
+impl Serialize for UnixTimestamp {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(self.opts, UNIX_MILLISECONDS) {
+            serializer.serialize_i64(self.milliseconds())
+        } else if opt_enabled!(self.opts, CANONICAL) {
+            let mut buf = SmallFixedBuffer::new();
+            self.write_buf(&mut buf);
+            serializer
+                .serialize_bytes(unsafe { core::slice::from_raw_parts(buf.as_ptr(), buf.len()) })
+        } else {
+            serializer.serialize_f64(self.seconds())
+        }
+    }
+}
+
+/// A datetime written, with `SERIALIZE_DATETIME_AS_EPOCH_OFFSET`, as an
+/// object of its Unix timestamp and the offset of its timezone in minutes, so
+/// that both the instant and the local time can be recovered.
+pub(crate) struct EpochOffset {
+    epoch: UnixTimestamp,
+    offset: i32,
+}
+
+impl EpochOffset {
+    pub fn new(microseconds: i64, offset: &Offset, opts: Opt) -> Self {
+        EpochOffset {
+            epoch: UnixTimestamp::new(microseconds, opts),
+            // an offset of seconds is rounded down to a whole minute
+            offset: (offset.day * 86_400 + offset.second).div_euclid(60),
+        }
+    }
+}
+
+impl Serialize for EpochOffset {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None).unwrap();
+        map.serialize_key("epoch").unwrap();
+        map.serialize_value(&self.epoch)?;
+        map.serialize_key("offset").unwrap();
+        map.serialize_value(&self.offset)?;
+        map.end()
+    }
+}
+
+/// An ISO 8601 calendar date written, with
+/// `SERIALIZE_DATETIME_AS_ISOCALENDAR`, as an object of the year, week, and
+/// weekday of `isocalendar()`.
+pub(crate) struct IsoCalendar {
+    year: i32,
+    week: i32,
+    weekday: i32,
+}
+
+impl IsoCalendar {
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        let (year, week, weekday) = iso_calendar(year, month, day);
+        IsoCalendar {
+            year: year,
+            week: week,
+            weekday: weekday,
+        }
+    }
+}
+
+impl Serialize for IsoCalendar {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, value) in [
+            ("iso_year", self.year),
+            ("iso_week", self.week),
+            ("iso_weekday", self.weekday),
+        ] {
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&value)?;
+        }
+        map.end()
+    }
+}
+
 pub(crate) struct Date {
     ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
 }
 
 impl Date {
-    pub fn new(ptr: *mut crate::ffi::PyObject) -> Self {
-        Date { ptr: ptr }
+    pub fn new(ptr: *mut crate::ffi::PyObject, opts: Opt) -> Self {
+        Date {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn iso_calendar(&self) -> IsoCalendar {
+        let year = ffi!(PyDateTime_GET_YEAR(self.ptr));
+        let month = ffi!(PyDateTime_GET_MONTH(self.ptr));
+        let day = ffi!(PyDateTime_GET_DAY(self.ptr));
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let ret = IsoCalendar::new(year, month as u8, day as u8);
+        ret
+    }
+
+    /// The number of microseconds from 1970-01-01T00:00:00 UTC to midnight
+    /// UTC of the date.
+    #[cold]
+    #[inline(never)]
+    pub fn unix_microseconds(&self) -> i64 {
+        let year = ffi!(PyDateTime_GET_YEAR(self.ptr));
+        let month = ffi!(PyDateTime_GET_MONTH(self.ptr));
+        let day = ffi!(PyDateTime_GET_DAY(self.ptr));
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let days = days_from_civil(year, month as u8, day as u8);
+        days * 86_400_000_000
     }
 
     #[inline(never)]
@@ -45,6 +219,11 @@ impl Date {
     where
         B: bytes::BufMut,
     {
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_UNIX) {
+            cold_path!();
+            UnixTimestamp::new(self.unix_microseconds(), self.opts).write_buf(buf);
+            return;
+        }
         {
             let year = ffi!(PyDateTime_GET_YEAR(self.ptr));
             let mut yearbuf = itoa::Buffer::new();
@@ -77,6 +256,14 @@ impl Serialize for Date {
     where
         S: Serializer,
     {
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_ISOCALENDAR) {
+            cold_path!();
+            return self.iso_calendar().serialize(serializer);
+        }
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_UNIX) {
+            cold_path!();
+            return UnixTimestamp::new(self.unix_microseconds(), self.opts).serialize(serializer);
+        }
         let mut buf = SmallFixedBuffer::new();
         self.write_buf(&mut buf);
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
@@ -84,7 +271,7 @@ impl Serialize for Date {
 }
 
 pub(crate) enum TimeError {
-    HasTimezone,
+    OffsetUnsupported,
 }
 
 pub(crate) struct Time {
@@ -105,9 +292,12 @@ impl Time {
     where
         B: bytes::BufMut,
     {
-        if unsafe { (*self.ptr.cast::<crate::ffi::PyDateTime_Time>()).hastzinfo == 1 } {
-            return Err(TimeError::HasTimezone);
-        }
+        let offset = if unsafe { (*self.ptr.cast::<crate::ffi::PyDateTime_Time>()).hastzinfo == 1 }
+        {
+            Some(self.offset()?)
+        } else {
+            None
+        };
         let hour = ffi!(PyDateTime_TIME_GET_HOUR(self.ptr)).cast_unsigned();
         write_double_digit!(buf, hour);
         buf.put_u8(b':');
@@ -118,10 +308,34 @@ impl Time {
         write_double_digit!(buf, second);
         if opt_disabled!(self.opts, OMIT_MICROSECONDS) {
             let microsecond = ffi!(PyDateTime_TIME_GET_MICROSECOND(self.ptr)).cast_unsigned();
-            write_microsecond!(buf, microsecond);
+            write_microsecond!(buf, microsecond, opt_enabled!(self.opts, PAD_NANOSECONDS));
+        }
+        if let Some(offset) = offset {
+            write_offset(buf, &offset, self.opts);
         }
         Ok(())
     }
+
+    /// The offset of the timezone, which must not depend on a date, as with
+    /// `datetime.timezone`.
+    #[cold]
+    #[inline(never)]
+    fn offset(&self) -> Result<Offset, TimeError> {
+        let py_offset = call_method!(self.ptr, UTCOFFSET_METHOD_STR);
+        if py_offset.is_null() {
+            return Err(TimeError::OffsetUnsupported);
+        }
+        if unsafe { core::ptr::eq(py_offset, crate::typeref::NONE) } {
+            ffi!(Py_DECREF(py_offset));
+            return Err(TimeError::OffsetUnsupported);
+        }
+        let offset = Offset {
+            second: ffi!(PyDateTime_DELTA_GET_SECONDS(py_offset)),
+            day: ffi!(PyDateTime_DELTA_GET_DAYS(py_offset)),
+        };
+        ffi!(Py_DECREF(py_offset));
+        Ok(offset)
+    }
 }
 
 impl Serialize for Time {
@@ -131,7 +345,7 @@ impl Serialize for Time {
     {
         let mut buf = SmallFixedBuffer::new();
         if self.write_buf(&mut buf).is_err() {
-            err!(SerializeError::DatetimeLibraryUnsupported)
+            err!(SerializeError::TimeOffsetUnsupported)
         }
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
     }
@@ -149,6 +363,36 @@ impl DateTime {
             opts: opts,
         }
     }
+
+    /// The IANA time zone name of the `tzinfo` with `ZONE_NAME`, which is
+    /// its `key` if that is a `str`, e.g., of a `zoneinfo.ZoneInfo`. It is
+    /// `None` for a naive datetime, a `tzinfo` without a name, such as
+    /// `datetime.timezone`, or with `NORMALIZE_UTC`, which writes UTC.
+    #[cold]
+    #[inline(never)]
+    pub fn zone_name(&self) -> Option<String> {
+        if opt_disabled!(self.opts, ZONE_NAME)
+            || opt_enabled!(self.opts, NORMALIZE_UTC)
+            || !self.has_tz()
+        {
+            return None;
+        }
+        let tzinfo = ffi!(PyDateTime_DATE_GET_TZINFO(self.ptr));
+        let key = ffi!(PyObject_GetAttr(tzinfo, KEY_STR));
+        if key.is_null() {
+            ffi!(PyErr_Clear());
+            return None;
+        }
+        let ret = if is_class_by_type!(ob_type!(key), STR_TYPE) {
+            unsafe { PyStrRef::from_ptr_unchecked(key) }
+                .as_str()
+                .map(String::from)
+        } else {
+            None
+        };
+        ffi!(Py_DECREF(key));
+        ret
+    }
 }
 
 macro_rules! pydatetime_get {
@@ -239,15 +483,111 @@ impl DateTimeLike for DateTime {
     }
 }
 
+impl DateTime {
+    /// The Unix timestamp and offset of the datetime. A naive datetime has an
+    /// offset of 0 if `opts` has `NAIVE_UTC` and is an error otherwise.
+    #[cold]
+    #[inline(never)]
+    fn epoch_offset(&self) -> Result<EpochOffset, DateTimeError> {
+        let microseconds = self.unix_microseconds(self.opts)?;
+        let offset = self.offset()?;
+        Ok(EpochOffset::new(microseconds, &offset, self.opts))
+    }
+}
+
 impl Serialize for DateTime {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        if opt_enabled!(self.opts, NAIVE_AS_NULL)
+            && opt_disabled!(self.opts, NAIVE_UTC)
+            && !self.has_tz()
+        {
+            cold_path!();
+            return serializer.serialize_unit();
+        }
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_ISOCALENDAR) {
+            cold_path!();
+            return IsoCalendar::new(self.year(), self.month(), self.day()).serialize(serializer);
+        }
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_EPOCH_OFFSET) {
+            cold_path!();
+            return match self.epoch_offset() {
+                Ok(val) => val.serialize(serializer),
+                Err(err) => err!(SerializeError::from(err)),
+            };
+        }
+        if opt_enabled!(self.opts, SERIALIZE_DATETIME_AS_UNIX) {
+            cold_path!();
+            return match self.unix_microseconds(self.opts) {
+                Ok(val) => UnixTimestamp::new(val, self.opts).serialize(serializer),
+                Err(err) => err!(SerializeError::from(err)),
+            };
+        }
         let mut buf = SmallFixedBuffer::new();
-        if self.write_buf(&mut buf, self.opts).is_err() {
-            err!(SerializeError::DatetimeLibraryUnsupported)
+        if let Err(err) = self.write_buf(&mut buf, self.opts) {
+            err!(SerializeError::from(err))
+        }
+        if opt_enabled!(self.opts, ZONE_NAME)
+            && let Some(name) = self.zone_name()
+        {
+            // RFC 9557, e.g., 2026-01-01T00:00:00-05:00[America/New_York]
+            let rfc3339 = str_from_slice!(buf.as_ptr(), buf.len());
+            return serializer.serialize_str(&format!("{rfc3339}[{name}]"));
         }
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
     }
 }
+
+/// Serialize a `datetime.datetime`, `datetime.date`, or `datetime.time`, with
+/// `datetime_formats` of `orjson.Encoder`, as the `str` returned by its
+/// `strftime()` with the format of its type, or as usual if its type has no
+/// format.
+#[repr(transparent)]
+pub(crate) struct DatetimeFormatSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> DatetimeFormatSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for DatetimeFormatSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ptr = self.previous.ptr;
+        let ob_type = ob_type!(ptr);
+        let opts = self.previous.state.opts();
+        let formats = self.previous.state.datetime_formats().unwrap();
+        // formats is a copy of str values held by the Encoder
+        let format = ffi!(PyDict_GetItemWithError(
+            formats.as_ptr(),
+            ob_type.cast::<crate::ffi::PyObject>()
+        ));
+        if format.is_null() {
+            if is_class_by_type!(ob_type, DATETIME_TYPE) {
+                return DateTime::new(ptr, opts).serialize(serializer);
+            } else if is_class_by_type!(ob_type, DATE_TYPE) {
+                return Date::new(ptr, opts).serialize(serializer);
+            }
+            debug_assert!(is_class_by_type!(ob_type, TIME_TYPE));
+            return Time::new(ptr, opts).serialize(serializer);
+        }
+        let formatted = call_method!(ptr, STRFTIME_STR, format);
+        if formatted.is_null() {
+            err!(SerializeError::DatetimeFormat(nonnull!(ptr)))
+        }
+        let ret = PyObjectSerializer::new(formatted, self.previous.state, self.previous.default)
+            .serialize(serializer);
+        ffi!(Py_DECREF(formatted));
+        ret
+    }
+}
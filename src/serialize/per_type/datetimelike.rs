@@ -1,10 +1,14 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
-// Copyright ijl (2020-2025), Ben Sully (2021)
+// Copyright ijl (2020-2026), Ben Sully (2021)
 
-use crate::opt::{NAIVE_UTC, OMIT_MICROSECONDS, Opt, UTC_Z};
+use crate::opt::{
+    NAIVE_UTC, NORMALIZE_UTC, OFFSET_NO_COLON, OMIT_MICROSECONDS, Opt, PAD_NANOSECONDS, UTC_Z,
+};
 
 pub(crate) enum DateTimeError {
     LibraryUnsupported,
+    NaiveUnix,
+    UtcOutOfRange,
 }
 
 macro_rules! write_double_digit {
@@ -34,6 +38,121 @@ pub(crate) struct Offset {
     pub second: i32,
 }
 
+impl Offset {
+    fn is_zero(&self) -> bool {
+        self.day == 0 && self.second == 0
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+// Days between 0000-03-01 and 1970-01-01 in the proleptic Gregorian calendar.
+const DAYS_FROM_0000_03_01_TO_EPOCH: i64 = 719_468;
+
+/// The number of days from 1970-01-01 to the date, negative if it is before.
+///
+/// This is the `days_from_civil` algorithm from
+/// <https://howardhinnant.github.io/date_algorithms.html>.
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let month = i64::from(month);
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - DAYS_FROM_0000_03_01_TO_EPOCH
+}
+
+/// The number of ISO 8601 weeks in the ISO year `year`, which is 53 if the
+/// calendar year starts or ends on a Thursday.
+fn iso_weeks_in_year(year: i64) -> i64 {
+    // the weekday of December 31, 0 is Sunday
+    let dec31 =
+        |y: i64| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+    if dec31(year) == 4 || dec31(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// The ISO 8601 year, week, and weekday (1 is Monday) of a date, as
+/// `datetime.date.isocalendar()` returns.
+pub(crate) fn iso_calendar(year: i32, month: u8, day: u8) -> (i32, i32, i32) {
+    let days = days_from_civil(year, month, day);
+    // 1970-01-01 was a Thursday
+    let weekday = (days + 3).rem_euclid(7) + 1;
+    let ordinal = days - days_from_civil(year, 1, 1) + 1;
+    let mut iso_year = i64::from(year);
+    let mut week = (ordinal - weekday + 10) / 7;
+    if week < 1 {
+        iso_year -= 1;
+        week = iso_weeks_in_year(iso_year);
+    } else if week > iso_weeks_in_year(iso_year) {
+        iso_year += 1;
+        week = 1;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let ret = (iso_year as i32, week as i32, weekday as i32);
+    ret
+}
+
+/// Date and time components excluding the fraction of a second.
+struct DateTimeFields {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl DateTimeFields {
+    /// Subtract `offset` to get the same instant in UTC, as
+    /// `datetime.astimezone(datetime.timezone.utc)` does.
+    ///
+    /// This uses the `civil_from_days` algorithm from
+    /// <https://howardhinnant.github.io/date_algorithms.html>.
+    #[cold]
+    #[inline(never)]
+    #[allow(clippy::cast_sign_loss)]
+    fn to_utc(&self, offset: &Offset) -> Result<Self, DateTimeError> {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let local = days * SECONDS_PER_DAY
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second);
+        let utc = local - (i64::from(offset.day) * SECONDS_PER_DAY + i64::from(offset.second));
+
+        let seconds_of_day = utc.rem_euclid(SECONDS_PER_DAY);
+        let days = utc.div_euclid(SECONDS_PER_DAY) + DAYS_FROM_0000_03_01_TO_EPOCH;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        if !(1..=9999).contains(&year) {
+            return Err(DateTimeError::UtcOutOfRange);
+        }
+        Ok(DateTimeFields {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+            hour: (seconds_of_day / 3600) as u8,
+            minute: (seconds_of_day % 3600 / 60) as u8,
+            second: (seconds_of_day % 60) as u8,
+        })
+    }
+}
+
 /// Trait providing a method to write a datetime-like object to a buffer in an RFC3339-compatible format.
 ///
 /// The provided `write_buf` method does not allocate, and is faster
@@ -55,6 +174,11 @@ pub(crate) trait DateTimeLike {
     fn microsecond(&self) -> u32;
     /// Returns the number of nanoseconds since the whole non-leap second.
     fn nanosecond(&self) -> u32;
+    /// Is the fractional second written to nanosecond precision rather than
+    /// truncated to microseconds?
+    fn has_nanosecond_precision(&self) -> bool {
+        false
+    }
 
     /// Is the object time-zone aware?
     fn has_tz(&self) -> bool;
@@ -65,6 +189,27 @@ pub(crate) trait DateTimeLike {
     /// The offset of the timezone.
     fn offset(&self) -> Result<Offset, DateTimeError>;
 
+    /// The number of microseconds since 1970-01-01T00:00:00 UTC, not counting
+    /// leap seconds. A naive datetime is UTC if `opts` has `NAIVE_UTC` and is
+    /// an error otherwise.
+    #[cold]
+    #[inline(never)]
+    fn unix_microseconds(&self, opts: Opt) -> Result<i64, DateTimeError> {
+        let offset = if self.has_tz() {
+            self.offset()?
+        } else if opt_enabled!(opts, NAIVE_UTC) {
+            Offset::default()
+        } else {
+            return Err(DateTimeError::NaiveUnix);
+        };
+        let seconds = days_from_civil(self.year(), self.month(), self.day()) * SECONDS_PER_DAY
+            + i64::from(self.hour()) * 3600
+            + i64::from(self.minute()) * 60
+            + i64::from(self.second())
+            - (i64::from(offset.day) * SECONDS_PER_DAY + i64::from(offset.second));
+        Ok(seconds * 1_000_000 + i64::from(self.microsecond()))
+    }
+
     /// Write `self` to a buffer in RFC3339 format, using `opts` to
     /// customise if desired.
     #[inline(never)]
@@ -72,8 +217,29 @@ pub(crate) trait DateTimeLike {
     where
         B: bytes::BufMut,
     {
+        let mut offset: Option<Offset> = None;
+        if self.has_tz() || opt_enabled!(opts, NAIVE_UTC) {
+            offset = Some(self.offset()?);
+        }
+        let mut fields = DateTimeFields {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+        };
+        if opt_enabled!(opts, NORMALIZE_UTC) {
+            match offset {
+                Some(ref val) if !val.is_zero() => {
+                    fields = fields.to_utc(val)?;
+                    offset = Some(Offset::default());
+                }
+                _ => {}
+            }
+        }
         {
-            let year = self.year();
+            let year = fields.year;
             let mut yearbuf = itoa::Buffer::new();
             let formatted = yearbuf.format(year);
             if year < 1000 {
@@ -84,67 +250,82 @@ pub(crate) trait DateTimeLike {
             buf.put_slice(formatted.as_bytes());
         }
         buf.put_u8(b'-');
-        write_double_digit!(buf, self.month());
+        write_double_digit!(buf, fields.month);
         buf.put_u8(b'-');
-        write_double_digit!(buf, self.day());
+        write_double_digit!(buf, fields.day);
         buf.put_u8(b'T');
-        write_double_digit!(buf, self.hour());
+        write_double_digit!(buf, fields.hour);
         buf.put_u8(b':');
-        write_double_digit!(buf, self.minute());
+        write_double_digit!(buf, fields.minute);
         buf.put_u8(b':');
-        write_double_digit!(buf, self.second());
+        write_double_digit!(buf, fields.second);
         if opt_disabled!(opts, OMIT_MICROSECONDS) {
             let microsecond = self.microsecond();
-            if microsecond != 0 {
+            let nanosecond = if self.has_nanosecond_precision() {
+                self.nanosecond() % 1_000
+            } else {
+                0
+            };
+            let pad = opt_enabled!(opts, PAD_NANOSECONDS);
+            if microsecond != 0 || nanosecond != 0 || pad {
                 buf.put_u8(b'.');
                 write_triple_digit!(buf, microsecond / 1_000);
                 write_triple_digit!(buf, microsecond % 1_000);
-                // Don't support writing nanoseconds for now.
-                // If requested, something like the following should work,
-                // and `SmallFixedBuffer` needs at least length 35.
-                // let nanosecond = self.nanosecond();
-                // if nanosecond % 1_000 != 0 {
-                //     write_triple_digit!(buf, nanosecond % 1_000);
-                // }
-            }
-        }
-        if self.has_tz() || opt_enabled!(opts, NAIVE_UTC) {
-            let offset = self.offset()?;
-            let mut offset_second = offset.second;
-            if offset_second == 0 {
-                if opt_enabled!(opts, UTC_Z) {
-                    buf.put_u8(b'Z');
-                } else {
-                    buf.put_slice(b"+00:00");
+                if nanosecond != 0 || pad {
+                    // `SmallFixedBuffer` holds the 35 bytes this needs
+                    write_triple_digit!(buf, nanosecond);
                 }
-            } else {
-                // This branch is only really hit by the Python datetime implementation,
-                // since numpy datetimes are all converted to UTC.
-                if offset.day == -1 {
-                    // datetime.timedelta(days=-1, seconds=68400) -> -05:00
-                    buf.put_u8(b'-');
-                    offset_second = 86400 - offset_second;
-                } else {
-                    // datetime.timedelta(seconds=37800) -> +10:30
-                    buf.put_u8(b'+');
-                }
-                let offset_minute = offset_second / 60;
-                let offset_hour = offset_minute / 60;
-                write_double_digit!(buf, offset_hour);
-                buf.put_u8(b':');
-                let mut offset_minute_print = offset_minute % 60;
-                // https://tools.ietf.org/html/rfc3339#section-5.8
-                // "exactly 19 minutes and 32.13 seconds ahead of UTC"
-                // "closest representable UTC offset"
-                //  "+20:00"
-                let offset_excess_second =
-                    offset_second - (offset_minute_print * 60 + offset_hour * 3600);
-                if offset_excess_second >= 30 {
-                    offset_minute_print += 1;
-                }
-                write_double_digit!(buf, offset_minute_print);
             }
         }
+        if let Some(offset) = offset {
+            write_offset(buf, &offset, opts);
+        }
         Ok(())
     }
 }
+
+/// Write `offset` in RFC3339 format, as `Z` if it is zero and `opts` has
+/// `UTC_Z`, or in the ISO 8601 basic format without a colon if `opts` has
+/// `OFFSET_NO_COLON`.
+pub(crate) fn write_offset<B>(buf: &mut B, offset: &Offset, opts: Opt)
+where
+    B: bytes::BufMut,
+{
+    let mut offset_second = offset.second;
+    if offset_second == 0 {
+        if opt_enabled!(opts, UTC_Z) {
+            buf.put_u8(b'Z');
+        } else if opt_enabled!(opts, OFFSET_NO_COLON) {
+            buf.put_slice(b"+0000");
+        } else {
+            buf.put_slice(b"+00:00");
+        }
+    } else {
+        // This branch is only really hit by the Python datetime implementation,
+        // since numpy datetimes are all converted to UTC.
+        if offset.day == -1 {
+            // datetime.timedelta(days=-1, seconds=68400) -> -05:00
+            buf.put_u8(b'-');
+            offset_second = 86400 - offset_second;
+        } else {
+            // datetime.timedelta(seconds=37800) -> +10:30
+            buf.put_u8(b'+');
+        }
+        let offset_minute = offset_second / 60;
+        let offset_hour = offset_minute / 60;
+        write_double_digit!(buf, offset_hour);
+        if opt_disabled!(opts, OFFSET_NO_COLON) {
+            buf.put_u8(b':');
+        }
+        let mut offset_minute_print = offset_minute % 60;
+        // https://tools.ietf.org/html/rfc3339#section-5.8
+        // "exactly 19 minutes and 32.13 seconds ahead of UTC"
+        // "closest representable UTC offset"
+        //  "+20:00"
+        let offset_excess_second = offset_second - (offset_minute_print * 60 + offset_hour * 3600);
+        if offset_excess_second >= 30 {
+            offset_minute_print += 1;
+        }
+        write_double_digit!(buf, offset_minute_print);
+    }
+}
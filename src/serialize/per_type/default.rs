@@ -1,11 +1,33 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2018-2026)
 
+use crate::opt::{DEFAULT_FIRST, SERIALIZE_JSON_METHOD, SERIALIZE_ORJSON_DEFAULT};
 use crate::serialize::error::SerializeError;
+use crate::serialize::obtype::{ObType, pyobject_to_obtype};
 use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::{JSON_METHOD_STR, ORJSON_DEFAULT_METHOD_STR};
 
+use core::ptr::NonNull;
 use serde::ser::{Serialize, Serializer};
 
+/// Call `default` with `ptr`. Returns a new reference or null with an
+/// exception set.
+#[inline]
+fn call_default(
+    callable: NonNull<crate::ffi::PyObject>,
+    ptr: *mut crate::ffi::PyObject,
+) -> *mut crate::ffi::PyObject {
+    let nargs = ffi!(PyVectorcall_NARGS(1)).cast_unsigned() as usize;
+    unsafe {
+        crate::ffi::PyObject_Vectorcall(
+            callable.as_ptr(),
+            &raw const ptr,
+            nargs,
+            core::ptr::null_mut(),
+        )
+    }
+}
+
 #[repr(transparent)]
 pub(crate) struct DefaultSerializer<'a> {
     previous: &'a PyObjectSerializer,
@@ -17,6 +39,102 @@ impl<'a> DefaultSerializer<'a> {
     }
 }
 
+impl DefaultSerializer<'_> {
+    /// The callable in `type_handlers` for the exact type of the object.
+    #[inline]
+    fn type_handler(&self) -> Option<NonNull<crate::ffi::PyObject>> {
+        let handlers = self.previous.state.type_handlers()?;
+        // handlers is a copy held by the Encoder, so the value is referenced
+        NonNull::new(ffi!(PyDict_GetItemWithError(
+            handlers.as_ptr(),
+            ob_type!(self.previous.ptr).cast::<crate::ffi::PyObject>()
+        )))
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn serialize_type_handler<S>(
+        &self,
+        callable: NonNull<crate::ffi::PyObject>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.default_calls_limit() {
+            cold_path!();
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let handler_obj = call_default(callable, self.previous.ptr);
+        if handler_obj.is_null() {
+            err!(SerializeError::TypeHandler(nonnull!(self.previous.ptr)))
+        }
+        let res = PyObjectSerializer::new(
+            handler_obj,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(handler_obj));
+        res
+    }
+
+    /// Whether the type of the object, not the instance, has the method
+    /// `name`.
+    #[inline]
+    fn has_method(&self, name: *mut crate::ffi::PyObject) -> bool {
+        ffi!(PyObject_HasAttr(
+            ob_type!(self.previous.ptr).cast::<crate::ffi::PyObject>(),
+            name
+        )) == 1
+    }
+
+    /// Whether the object has a handler in `type_handlers`, or
+    /// `__orjson_default__` or `__json__` with the option that enables it.
+    /// These take precedence over `OPT_SERIALIZE_ANNOTATED`.
+    #[cold]
+    #[inline(never)]
+    pub fn has_hook(&self) -> bool {
+        let opts = self.previous.state.opts();
+        self.type_handler().is_some()
+            || (opt_enabled!(opts, SERIALIZE_ORJSON_DEFAULT)
+                && self.has_method(unsafe { ORJSON_DEFAULT_METHOD_STR }))
+            || (opt_enabled!(opts, SERIALIZE_JSON_METHOD)
+                && self.has_method(unsafe { JSON_METHOD_STR }))
+    }
+
+    /// Serialize the object returned by calling the method `name`, which is
+    /// `__orjson_default__` or `__json__`, with `error` if it raises.
+    #[cold]
+    #[inline(never)]
+    fn serialize_method<S>(
+        &self,
+        name: *mut crate::ffi::PyObject,
+        error: fn(NonNull<crate::ffi::PyObject>) -> SerializeError,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.default_calls_limit() {
+            cold_path!();
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let method_obj = call_method!(self.previous.ptr, name);
+        if method_obj.is_null() {
+            err!(error(nonnull!(self.previous.ptr)))
+        }
+        let res = PyObjectSerializer::new(
+            method_obj,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(method_obj));
+        res
+    }
+}
+
 impl Serialize for DefaultSerializer<'_> {
     #[cold]
     #[inline(never)]
@@ -24,21 +142,33 @@ impl Serialize for DefaultSerializer<'_> {
     where
         S: Serializer,
     {
+        if let Some(callable) = self.type_handler() {
+            return self.serialize_type_handler(callable, serializer);
+        }
+        let opts = self.previous.state.opts();
+        if opt_enabled!(opts, SERIALIZE_ORJSON_DEFAULT) {
+            let name = unsafe { ORJSON_DEFAULT_METHOD_STR };
+            if self.has_method(name) {
+                return self.serialize_method(
+                    name,
+                    SerializeError::OrjsonDefaultMethod,
+                    serializer,
+                );
+            }
+        }
+        if opt_enabled!(opts, SERIALIZE_JSON_METHOD) {
+            let name = unsafe { JSON_METHOD_STR };
+            if self.has_method(name) {
+                return self.serialize_method(name, SerializeError::JsonMethod, serializer);
+            }
+        }
         match self.previous.default {
             Some(callable) => {
                 if self.previous.state.default_calls_limit() {
                     cold_path!();
                     err!(SerializeError::DefaultRecursionLimit)
                 }
-                let nargs = ffi!(PyVectorcall_NARGS(1)).cast_unsigned() as usize;
-                let default_obj = unsafe {
-                    crate::ffi::PyObject_Vectorcall(
-                        callable.as_ptr(),
-                        &raw const self.previous.ptr,
-                        nargs,
-                        core::ptr::null_mut(),
-                    )
-                };
+                let default_obj = call_default(callable, self.previous.ptr);
                 if default_obj.is_null() {
                     err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
                 } else {
@@ -56,3 +186,67 @@ impl Serialize for DefaultSerializer<'_> {
         }
     }
 }
+
+/// Pass an object to `default` before its built-in serializer, for
+/// `OPT_DEFAULT_FIRST`. If `default` raises `TypeError`, the object is
+/// serialized as it would be without the option, as is an object of the
+/// same type that `default` returns.
+#[repr(transparent)]
+pub(crate) struct DefaultFirstSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> DefaultFirstSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for DefaultFirstSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let obtype = pyobject_to_obtype(
+            self.previous.ptr,
+            self.previous.state.opts() & !DEFAULT_FIRST,
+        );
+        let callable = match self.previous.default {
+            // an unsupported type is passed to default only once
+            Some(callable) if !matches!(obtype, ObType::Unknown) => callable,
+            _ => return self.previous.serialize_obtype(obtype, serializer),
+        };
+        if self.previous.state.default_calls_limit() {
+            cold_path!();
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let default_obj = call_default(callable, self.previous.ptr);
+        if default_obj.is_null() {
+            if ffi!(PyErr_ExceptionMatches(crate::ffi::PyExc_TypeError)) == 0 {
+                err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
+            }
+            ffi!(PyErr_Clear());
+            return self.previous.serialize_obtype(obtype, serializer);
+        }
+        let default_ser = PyObjectSerializer::new(
+            default_obj,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        );
+        // an object of the same type, e.g., from `datetime.replace()`, is
+        // serialized as it would be without the option rather than passed
+        // to default again
+        let res = if core::ptr::eq(ob_type!(default_obj), ob_type!(self.previous.ptr)) {
+            default_ser.serialize_obtype(
+                pyobject_to_obtype(default_obj, self.previous.state.opts() & !DEFAULT_FIRST),
+                serializer,
+            )
+        } else {
+            default_ser.serialize(serializer)
+        };
+        ffi!(Py_DECREF(default_obj));
+        res
+    }
+}
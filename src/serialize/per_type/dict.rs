@@ -3,22 +3,38 @@
 
 use crate::ffi::{
     PyBoolRef, PyDictRef, PyFloatRef, PyFragmentRef, PyIntRef, PyListRef, PyStrRef,
-    PyStrSubclassRef, PyUuidRef,
+    PyStrSubclassRef, PyTupleRef, PyUuidRef,
+};
+use crate::opt::{
+    CANONICAL, DATETIME_FORMATS, DEFAULT_FIRST, ENUM_FULL, ENUM_REPR, FLOAT_TRAILING_ZERO,
+    LENGTH_TAGGED, NON_STR_KEYS, NONFINITE_AS_STRING, NOT_PASSTHROUGH, OMIT_NONE, Opt,
+    SERIALIZE_DATETIME_AS_UNIX, SORT_KEYS, SORT_KEYS_BY_HASH, SORT_KEYS_BY_VALUE,
+    SORT_OR_NON_STR_KEYS, UNIQUE_KEYS, ZONE_NAME,
 };
-use crate::opt::{NON_STR_KEYS, NOT_PASSTHROUGH, SORT_KEYS, SORT_OR_NON_STR_KEYS};
 use crate::serialize::buffer::SmallFixedBuffer;
 use crate::serialize::error::SerializeError;
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
+use crate::serialize::path::{ErrorPath, clear_error_path};
+use crate::serialize::per_type::datetime::UnixTimestamp;
 use crate::serialize::per_type::datetimelike::DateTimeLike;
+use crate::serialize::per_type::float::{write_canonical, write_fixed};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer, EnumSerializer,
-    FloatSerializer, FragmentSerializer, IntSerializer, ListTupleSerializer, NoneSerializer,
-    NumpyScalar, NumpySerializer, StrSerializer, StrSubclassSerializer, Time, UUID,
-    ZeroListSerializer,
+    AnnotatedSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date,
+    DateTime, DatetimeFormatSerializer, DefaultFirstSerializer, DefaultSerializer, EnumSerializer,
+    EpochNanosecondsSerializer, FloatSerializer, FractionSerializer, FragmentSerializer,
+    IntSerializer, IteratorSerializer, LengthTaggedSerializer, ListTupleSerializer,
+    MappingSerializer, NamedTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer,
+    PydanticSerializer, RangeSerializer, StrSerializer, StrSubclassSerializer, Time,
+    TimedeltaSerializer, UUID, WeakrefSerializer, ZeroListSerializer, bytes_key_to_string,
+    is_flag_as_int,
 };
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
-use crate::typeref::{STR_TYPE, TRUE, VALUE_STR};
+use crate::serialize::writer::{BytesWriter, WriteExt, nonfinite_str, to_writer};
+use crate::typeref::{
+    DATACLASS_PARAMS_STR, DICT_TYPE, FROZEN_STR, NAME_STR, NONE, ORDERED_DICT_TYPE, STR_TYPE, TRUE,
+    VALUE_STR,
+};
 use core::ptr::NonNull;
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use smallvec::SmallVec;
@@ -41,6 +57,13 @@ impl Serialize for ZeroDictSerializer {
     }
 }
 
+/// Whether a member of an object is omitted because its value is `None` and
+/// `OPT_OMIT_NONE` is set.
+#[inline(always)]
+pub(crate) fn is_omitted_none(value: *mut crate::ffi::PyObject, opts: Opt) -> bool {
+    opt_enabled!(opts, OMIT_NONE) && unsafe { core::ptr::eq(value, NONE) }
+}
+
 pub(crate) struct DictGenericSerializer {
     dict: PyDictRef,
     state: SerializerState,
@@ -62,6 +85,79 @@ impl DictGenericSerializer {
     }
 }
 
+/// Whether `ptr`, which is a `dict` or an instance of a subclass of `dict`,
+/// is a `collections.OrderedDict` or an instance of a subclass of it.
+#[inline(always)]
+fn is_ordered_dict(ptr: *mut crate::ffi::PyObject) -> bool {
+    let ob_type = ob_type!(ptr);
+    !is_class_by_type!(ob_type, DICT_TYPE)
+        && ffi!(PyType_IsSubtype(ob_type, ORDERED_DICT_TYPE)) == 1
+}
+
+/// Copy the items of a `collections.OrderedDict` into a new `dict` in the
+/// order the `OrderedDict` iterates them, which may differ from that of its
+/// underlying `dict`, e.g., after `move_to_end()`. Values are read from the
+/// underlying `dict`, so `__getitem__()` is not called.
+#[cold]
+#[inline(never)]
+fn ordered_dict_to_dict(
+    ptr: *mut crate::ffi::PyObject,
+) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+    let iter = ffi!(PyObject_GetIter(ptr));
+    if iter.is_null() {
+        return Err(SerializeError::Mapping(nonnull!(ptr)));
+    }
+    let dict = ffi!(PyDict_New(ffi!(PyDict_Size(ptr))));
+    loop {
+        let key = ffi!(PyIter_Next(iter));
+        if key.is_null() {
+            break;
+        }
+        let value = ffi!(PyDict_GetItemWithError(ptr, key));
+        let res = if value.is_null() {
+            -1
+        } else {
+            ffi!(PyDict_SetItem(dict, key, value))
+        };
+        ffi!(Py_DECREF(key));
+        if res != 0 {
+            break;
+        }
+    }
+    ffi!(Py_DECREF(iter));
+    if !ffi!(PyErr_Occurred()).is_null() {
+        cold_path!();
+        ffi!(Py_DECREF(dict));
+        return Err(SerializeError::Mapping(nonnull!(ptr)));
+    }
+    Ok(dict)
+}
+
+impl DictGenericSerializer {
+    /// Serialize a `collections.OrderedDict` in its order by serializing a
+    /// copy of it.
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize_ordered_dict<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let dict = match ordered_dict_to_dict(self.dict.as_ptr()) {
+            Ok(dict) => dict,
+            Err(err) => err!(err),
+        };
+        let ret = DictGenericSerializer {
+            dict: unsafe { PyDictRef::from_ptr_unchecked(dict) },
+            state: self.state,
+            default: self.default,
+        }
+        .serialize(serializer);
+        ffi!(Py_DECREF(dict));
+        ret
+    }
+}
+
 impl Serialize for DictGenericSerializer {
     #[inline(always)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -73,14 +169,16 @@ impl Serialize for DictGenericSerializer {
             err!(SerializeError::RecursionLimit)
         }
 
+        if is_ordered_dict(self.dict.as_ptr()) {
+            cold_path!();
+            return self.serialize_ordered_dict(serializer);
+        }
+
         #[cfg(Py_GIL_DISABLED)]
         {
             #[cfg(Py_GIL_DISABLED)]
             struct DictItemsSnapshot {
-                items: Vec<(
-                    NonNull<crate::ffi::PyObject>,
-                    NonNull<crate::ffi::PyObject>,
-                )>,
+                items: Vec<(NonNull<crate::ffi::PyObject>, NonNull<crate::ffi::PyObject>)>,
             }
 
             #[cfg(Py_GIL_DISABLED)]
@@ -107,6 +205,15 @@ impl Serialize for DictGenericSerializer {
                 }
             }
 
+            if self.state.key_transform().is_some() {
+                cold_path!();
+                return unsafe {
+                    (*(core::ptr::from_ref::<DictGenericSerializer>(self))
+                        .cast::<DictTransformKey>())
+                    .serialize(serializer)
+                };
+            }
+
             let snapshot = DictItemsSnapshot::new(self.dict.clone());
             if snapshot.items.len() == 0 {
                 cold_path!();
@@ -116,6 +223,9 @@ impl Serialize for DictGenericSerializer {
             if opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS) {
                 let mut map = serializer.serialize_map(None).unwrap();
                 for (key, value) in snapshot.items.iter() {
+                    if is_omitted_none(value.as_ptr(), self.state.opts()) {
+                        continue;
+                    }
                     let uni = PyStrRef::from_ptr(key.as_ptr())
                         .map_err(|_| serde::ser::Error::custom(SerializeError::KeyMustBeStr))?
                         .as_str();
@@ -123,10 +233,9 @@ impl Serialize for DictGenericSerializer {
                         cold_path!();
                         err!(SerializeError::InvalidStr);
                     }
-                    let pyvalue =
-                        PyObjectSerializer::new(value.as_ptr(), self.state, self.default);
+                    let pyvalue = PyObjectSerializer::new(value.as_ptr(), self.state, self.default);
                     map.serialize_key(uni.unwrap()).unwrap();
-                    map.serialize_value(&pyvalue)?;
+                    map.serialize_value(&pyvalue).at_key(uni.unwrap())?;
                 }
                 return map.end();
             }
@@ -138,6 +247,9 @@ impl Serialize for DictGenericSerializer {
                     SmallVec::with_capacity(len);
 
                 for (key, value) in snapshot.items.iter() {
+                    if is_omitted_none(value.as_ptr(), opts) {
+                        continue;
+                    }
                     match PyStrRef::from_ptr(key.as_ptr()) {
                         Ok(pystr) => match pystr.as_str() {
                             Some(uni) => {
@@ -145,7 +257,12 @@ impl Serialize for DictGenericSerializer {
                             }
                             None => err!(SerializeError::InvalidStr),
                         },
-                        Err(_) => match DictNonStrKey::pyobject_to_string(key.as_ptr(), opts) {
+                        Err(_) => match DictNonStrKey::pyobject_to_string(
+                            key.as_ptr(),
+                            opts,
+                            self.state.key_delimiter(),
+                            self.state,
+                        ) {
                             Ok(key_as_str) => items.push((key_as_str, value.as_ptr())),
                             Err(err) => err!(err),
                         },
@@ -158,15 +275,23 @@ impl Serialize for DictGenericSerializer {
                     .iter()
                     .for_each(|(key, val)| items_as_str.push(((*key).as_str(), *val)));
 
-                if opt_enabled!(opts, SORT_KEYS) {
-                    sort_dict_items(&mut items_as_str);
+                if opt_enabled!(opts, SORT_KEYS)
+                    && let Err(err) = sort_dict_items(&mut items_as_str, opts)
+                {
+                    err!(err)
+                }
+
+                if opt_enabled!(opts, UNIQUE_KEYS)
+                    && has_duplicate_key(&items_as_str, is_sorted_by_key(opts))
+                {
+                    err!(SerializeError::DictKeyDuplicate)
                 }
 
                 let mut map = serializer.serialize_map(None).unwrap();
                 for (key, val) in items_as_str.iter() {
                     let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
                     map.serialize_key(key).unwrap();
-                    map.serialize_value(&pyvalue)?;
+                    map.serialize_value(&pyvalue).at_key(key)?;
                 }
                 return map.end();
             }
@@ -176,6 +301,9 @@ impl Serialize for DictGenericSerializer {
                 SmallVec::with_capacity(len);
 
             for (key, value) in snapshot.items.iter() {
+                if is_omitted_none(value.as_ptr(), self.state.opts()) {
+                    continue;
+                }
                 if unsafe { !core::ptr::eq(ob_type!(key.as_ptr()), STR_TYPE) } {
                     err!(SerializeError::KeyMustBeStr)
                 }
@@ -187,13 +315,15 @@ impl Serialize for DictGenericSerializer {
                 items.push((uni.unwrap(), value.as_ptr()));
             }
 
-            sort_dict_items(&mut items);
+            if let Err(err) = sort_dict_items(&mut items, self.state.opts()) {
+                err!(err)
+            }
 
             let mut map = serializer.serialize_map(None).unwrap();
             for (key, val) in items.iter() {
                 let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
                 map.serialize_key(key).unwrap();
-                map.serialize_value(&pyvalue)?;
+                map.serialize_value(&pyvalue).at_key(key)?;
             }
             return map.end();
         }
@@ -203,6 +333,13 @@ impl Serialize for DictGenericSerializer {
             if self.dict.len() == 0 {
                 cold_path!();
                 ZeroDictSerializer::new().serialize(serializer)
+            } else if self.state.key_transform().is_some() {
+                cold_path!();
+                unsafe {
+                    (*(core::ptr::from_ref::<DictGenericSerializer>(self))
+                        .cast::<DictTransformKey>())
+                    .serialize(serializer)
+                }
             } else if opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS) {
                 unsafe {
                     (*(core::ptr::from_ref::<DictGenericSerializer>(self)).cast::<Dict>())
@@ -230,20 +367,23 @@ macro_rules! impl_serialize_entry {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&StrSerializer::new(unsafe {
                     PyStrRef::from_ptr_unchecked($value)
-                }))?;
+                }))
+                .at_key($key)?;
             }
             ObType::StrSubclass => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&StrSubclassSerializer::new(unsafe {
                     PyStrSubclassRef::from_ptr_unchecked($value)
-                }))?;
+                }))
+                .at_key($key)?;
             }
             ObType::Int => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&IntSerializer::new(
                     unsafe { PyIntRef::from_ptr_unchecked($value) },
                     $self.state.opts(),
-                ))?;
+                ))
+                .at_key($key)?;
             }
             ObType::None => {
                 $map.serialize_key($key).unwrap();
@@ -251,9 +391,11 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Float => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&FloatSerializer::new(unsafe {
-                    PyFloatRef::from_ptr_unchecked($value)
-                }))?;
+                $map.serialize_value(&FloatSerializer::new(
+                    unsafe { PyFloatRef::from_ptr_unchecked($value) },
+                    $self.state,
+                ))
+                .at_key($key)?;
             }
             ObType::Bool => {
                 $map.serialize_key($key).unwrap();
@@ -264,20 +406,35 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Datetime => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&DateTime::new($value, $self.state.opts()))?;
+                $map.serialize_value(&DateTime::new($value, $self.state.opts()))
+                    .at_key($key)?;
             }
             ObType::Date => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&Date::new($value))?;
+                $map.serialize_value(&Date::new($value, $self.state.opts()))
+                    .at_key($key)?;
             }
             ObType::Time => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&Time::new($value, $self.state.opts()))?;
+                $map.serialize_value(&Time::new($value, $self.state.opts()))
+                    .at_key($key)?;
+            }
+            ObType::DatetimeFormat => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&DatetimeFormatSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
             }
             ObType::Uuid => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&UUID::new(unsafe { PyUuidRef::from_ptr_unchecked($value) }))
-                    .unwrap();
+                $map.serialize_value(&UUID::new(
+                    unsafe { PyUuidRef::from_ptr_unchecked($value) },
+                    $self.state.opts(),
+                ))
+                .unwrap();
             }
             ObType::Dict => {
                 let pyvalue = DictGenericSerializer::new(
@@ -286,7 +443,7 @@ macro_rules! impl_serialize_entry {
                     $self.default,
                 );
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&pyvalue)?;
+                $map.serialize_value(&pyvalue).at_key($key)?;
             }
             ObType::List => {
                 $map.serialize_key($key).unwrap();
@@ -300,7 +457,7 @@ macro_rules! impl_serialize_entry {
                             $self.state,
                             $self.default,
                         );
-                        $map.serialize_value(&pyvalue)?;
+                        $map.serialize_value(&pyvalue).at_key($key)?;
                     }
                 }
                 #[cfg(Py_GIL_DISABLED)]
@@ -310,7 +467,7 @@ macro_rules! impl_serialize_entry {
                         $self.state,
                         $self.default,
                     );
-                    $map.serialize_value(&pyvalue)?;
+                    $map.serialize_value(&pyvalue).at_key($key)?;
                 }
             }
             ObType::Tuple => {
@@ -321,16 +478,94 @@ macro_rules! impl_serialize_entry {
                     let pyvalue =
                         ListTupleSerializer::from_tuple($value, $self.state, $self.default);
                     $map.serialize_key($key).unwrap();
-                    $map.serialize_value(&pyvalue)?;
+                    $map.serialize_value(&pyvalue).at_key($key)?;
                 }
             }
+            ObType::NamedTuple => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&NamedTupleSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                ))
+                .at_key($key)?;
+            }
+            ObType::Iterator => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&IteratorSerializer::new($value, $self.state, $self.default))
+                    .at_key($key)?;
+            }
             ObType::Dataclass => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&DataclassGenericSerializer::new(&PyObjectSerializer::new(
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .at_key($key)?;
+            }
+            ObType::Pydantic => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&PydanticSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::Mapping => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&MappingSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::Range => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&RangeSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::Fraction => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&FractionSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::LengthTagged => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&LengthTaggedSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::Weakref => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&WeakrefSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
+            }
+            ObType::Annotated => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&AnnotatedSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
             }
             ObType::Enum => {
                 $map.serialize_key($key).unwrap();
@@ -338,7 +573,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .at_key($key)?;
             }
             ObType::NumpyArray => {
                 $map.serialize_key($key).unwrap();
@@ -346,17 +582,44 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .at_key($key)?;
             }
             ObType::NumpyScalar => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&NumpyScalar::new($value, $self.state.opts()))?;
+                $map.serialize_value(&NumpyScalar::new($value, $self.state.opts()))
+                    .at_key($key)?;
             }
             ObType::Fragment => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&FragmentSerializer::new(unsafe {
                     PyFragmentRef::from_ptr_unchecked($value)
-                }))?;
+                }))
+                .at_key($key)?;
+            }
+            ObType::EpochNanoseconds => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&EpochNanosecondsSerializer::new($value, $self.state.opts()))
+                    .at_key($key)?;
+            }
+            ObType::Timedelta => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&TimedeltaSerializer::new($value, $self.state.opts()))
+                    .at_key($key)?;
+            }
+            ObType::Bytes => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&BytesSerializer::new($value, $self.state.opts()))
+                    .at_key($key)?;
+            }
+            ObType::DefaultFirst => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&DefaultFirstSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))
+                .at_key($key)?;
             }
             ObType::Unknown => {
                 $map.serialize_key($key).unwrap();
@@ -364,7 +627,8 @@ macro_rules! impl_serialize_entry {
                     $value,
                     $self.state,
                     $self.default,
-                )))?;
+                )))
+                .at_key($key)?;
             }
         }
     };
@@ -409,6 +673,10 @@ impl Serialize for Dict {
                 &raw mut next_value
             );
 
+            if is_omitted_none(value, self.state.opts()) {
+                continue;
+            }
+
             // key
             let uni = PyStrRef::from_ptr(key)
                 .map_err(|_| serde::ser::Error::custom(SerializeError::KeyMustBeStr))?
@@ -466,6 +734,9 @@ impl Serialize for DictSortedKey {
                 &raw mut next_value
             );
 
+            if is_omitted_none(value, self.state.opts()) {
+                continue;
+            }
             if unsafe { !core::ptr::eq(ob_type!(key), STR_TYPE) } {
                 err!(SerializeError::KeyMustBeStr)
             }
@@ -479,13 +750,15 @@ impl Serialize for DictSortedKey {
             items.push((key_as_str, value));
         }
 
-        sort_dict_items(&mut items);
+        if let Err(err) = sort_dict_items(&mut items, self.state.opts()) {
+            err!(err)
+        }
 
         let mut map = serializer.serialize_map(None).unwrap();
         for (key, val) in items.iter() {
             let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
             map.serialize_key(key).unwrap();
-            map.serialize_value(&pyvalue)?;
+            map.serialize_value(&pyvalue).at_key(key)?;
         }
         map.end()
     }
@@ -517,9 +790,12 @@ fn non_str_str_subclass(key: PyStrSubclassRef) -> Result<String, SerializeError>
 
 #[allow(clippy::unnecessary_wraps)]
 #[inline(never)]
-fn non_str_date(key: *mut crate::ffi::PyObject) -> Result<String, SerializeError> {
+fn non_str_date(
+    key: *mut crate::ffi::PyObject,
+    opts: crate::opt::Opt,
+) -> Result<String, SerializeError> {
     let mut buf = SmallFixedBuffer::new();
-    Date::new(key).write_buf(&mut buf);
+    Date::new(key, opts).write_buf(&mut buf);
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
     Ok(String::from(key_as_str))
 }
@@ -531,10 +807,18 @@ fn non_str_datetime(
 ) -> Result<String, SerializeError> {
     let mut buf = SmallFixedBuffer::new();
     let dt = DateTime::new(key, opts);
-    if dt.write_buf(&mut buf, opts).is_err() {
-        return Err(SerializeError::DatetimeLibraryUnsupported);
+    if opt_enabled!(opts, SERIALIZE_DATETIME_AS_UNIX) {
+        UnixTimestamp::new(dt.unix_microseconds(opts)?, opts).write_buf(&mut buf);
+    } else {
+        dt.write_buf(&mut buf, opts)?;
     }
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
+    if opt_enabled!(opts, ZONE_NAME)
+        && opt_disabled!(opts, SERIALIZE_DATETIME_AS_UNIX)
+        && let Some(name) = dt.zone_name()
+    {
+        return Ok(format!("{key_as_str}[{name}]"));
+    }
     Ok(String::from(key_as_str))
 }
 
@@ -547,7 +831,7 @@ fn non_str_time(
     let mut buf = SmallFixedBuffer::new();
     let time = Time::new(key, opts);
     if time.write_buf(&mut buf).is_err() {
-        return Err(SerializeError::TimeHasTzinfo);
+        return Err(SerializeError::TimeOffsetUnsupported);
     }
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
     Ok(String::from(key_as_str))
@@ -555,9 +839,9 @@ fn non_str_time(
 
 #[allow(clippy::unnecessary_wraps)]
 #[inline(never)]
-fn non_str_uuid(key: PyUuidRef) -> Result<String, SerializeError> {
+fn non_str_uuid(key: PyUuidRef, opts: crate::opt::Opt) -> Result<String, SerializeError> {
     let mut buf = SmallFixedBuffer::new();
-    UUID::new(key).write_buf(&mut buf);
+    UUID::new(key, opts).write_buf(&mut buf);
     let key_as_str = str_from_slice!(buf.as_ptr(), buf.len());
     Ok(String::from(key_as_str))
 }
@@ -565,10 +849,23 @@ fn non_str_uuid(key: PyUuidRef) -> Result<String, SerializeError> {
 #[allow(clippy::unnecessary_wraps)]
 #[cold]
 #[inline(never)]
-fn non_str_float(key: *mut crate::ffi::PyObject) -> Result<String, SerializeError> {
+fn non_str_float(
+    key: *mut crate::ffi::PyObject,
+    opts: crate::opt::Opt,
+) -> Result<String, SerializeError> {
     let val = ffi!(PyFloat_AS_DOUBLE(key));
-    if !val.is_finite() {
+    if !val.is_finite() && opt_enabled!(opts, NONFINITE_AS_STRING) {
+        Ok(String::from(nonfinite_str(val)))
+    } else if !val.is_finite() {
         Ok(String::from("null"))
+    } else if opt_enabled!(opts, CANONICAL) {
+        let mut buf = SmallFixedBuffer::new();
+        write_canonical(&mut buf, val);
+        Ok(String::from(str_from_slice!(buf.as_ptr(), buf.len())))
+    } else if opt_enabled!(opts, FLOAT_TRAILING_ZERO) {
+        let mut buf = SmallFixedBuffer::new();
+        write_fixed(&mut buf, val);
+        Ok(String::from(str_from_slice!(buf.as_ptr(), buf.len())))
     } else {
         Ok(String::from(zmij::Buffer::new().format_finite(val)))
     }
@@ -592,8 +889,113 @@ fn non_str_int(key: *mut crate::ffi::PyObject) -> Result<String, SerializeError>
 }
 
 #[inline(never)]
-fn sort_dict_items(items: &mut SmallVec<[(&str, *mut crate::ffi::PyObject); 8]>) {
-    items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+fn sort_dict_items(
+    items: &mut SmallVec<[(&str, *mut crate::ffi::PyObject); 8]>,
+    opts: Opt,
+) -> Result<(), SerializeError> {
+    if opt_enabled!(opts, SORT_KEYS_BY_VALUE) {
+        let mut scratch: SmallVec<[(&str, *mut crate::ffi::PyObject); 8]> =
+            SmallVec::from_slice(items);
+        return sort_dict_items_by_value(items, &mut scratch);
+    } else if opt_enabled!(opts, CANONICAL) {
+        // RFC 8785 sorts by UTF-16 code units, which differs from sorting by
+        // code point for characters above U+FFFF
+        items.sort_unstable_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16()));
+    } else if opt_enabled!(opts, SORT_KEYS_BY_HASH) {
+        // XXH3 is independent of PYTHONHASHSEED and the platform, and equal
+        // keys remain adjacent for `has_duplicate_key()`
+        items.sort_by_cached_key(|item| (xxhash_rust::xxh3::xxh3_64(item.0.as_bytes()), item.0));
+    } else {
+        items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    }
+    Ok(())
+}
+
+/// Merge sort items by value with the `<` of the values, keeping the order of
+/// equal values. This does not use `sort_by()` because `__lt__` may raise and
+/// need not be a total order, for which `sort_by()` may panic.
+fn sort_dict_items_by_value<'a>(
+    items: &mut [(&'a str, *mut crate::ffi::PyObject)],
+    scratch: &mut [(&'a str, *mut crate::ffi::PyObject)],
+) -> Result<(), SerializeError> {
+    let len = items.len();
+    if len < 2 {
+        return Ok(());
+    }
+    let mid = len / 2;
+    sort_dict_items_by_value(&mut items[..mid], &mut scratch[..mid])?;
+    sort_dict_items_by_value(&mut items[mid..], &mut scratch[mid..])?;
+    scratch.copy_from_slice(items);
+    let (left, right) = scratch.split_at(mid);
+    let (mut idx_left, mut idx_right) = (0, 0);
+    for item in items.iter_mut() {
+        let from_right = if idx_left == left.len() {
+            true
+        } else if idx_right == right.len() {
+            false
+        } else {
+            match ffi!(PyObject_RichCompareBool(
+                right[idx_right].1,
+                left[idx_left].1,
+                crate::ffi::Py_LT
+            )) {
+                -1 => {
+                    return Err(SerializeError::DictValueCompare(nonnull!(
+                        right[idx_right].1
+                    )));
+                }
+                val => val == 1,
+            }
+        };
+        if from_right {
+            *item = right[idx_right];
+            idx_right += 1;
+        } else {
+            *item = left[idx_left];
+            idx_left += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Whether items sorted by `sort_dict_items()` have equal keys adjacent.
+#[inline(always)]
+fn is_sorted_by_key(opts: Opt) -> bool {
+    opt_enabled!(opts, SORT_KEYS) && opt_disabled!(opts, SORT_KEYS_BY_VALUE)
+}
+
+/// Whether `key` is an instance of a dataclass with `frozen=True`. A
+/// dataclass that is hashable otherwise, e.g., by defining `__hash__()`, may
+/// be mutated, so it is not serialized as a key.
+#[cold]
+#[inline(never)]
+fn is_frozen_dataclass(key: *mut crate::ffi::PyObject) -> bool {
+    let params = ffi!(PyObject_GetAttr(key, DATACLASS_PARAMS_STR));
+    if params.is_null() {
+        ffi!(PyErr_Clear());
+        return false;
+    }
+    let frozen = ffi!(PyObject_GetAttr(params, FROZEN_STR));
+    ffi!(Py_DECREF(params));
+    if frozen.is_null() {
+        ffi!(PyErr_Clear());
+        return false;
+    }
+    let ret = unsafe { core::ptr::eq(frozen, TRUE) };
+    ffi!(Py_DECREF(frozen));
+    ret
+}
+
+#[cold]
+#[inline(never)]
+fn has_duplicate_key(items: &[(&str, *mut crate::ffi::PyObject)], sorted: bool) -> bool {
+    if sorted {
+        items.windows(2).any(|pair| pair[0].0 == pair[1].0)
+    } else {
+        let mut keys: SmallVec<[&str; 8]> = items.iter().map(|(key, _)| *key).collect();
+        keys.sort_unstable();
+        keys.windows(2).any(|pair| pair[0] == pair[1])
+    }
 }
 
 pub(crate) struct DictNonStrKey {
@@ -606,9 +1008,15 @@ impl DictNonStrKey {
     fn pyobject_to_string(
         key: *mut crate::ffi::PyObject,
         opts: crate::opt::Opt,
+        key_delimiter: Option<NonNull<crate::ffi::PyObject>>,
+        state: SerializerState,
     ) -> Result<String, SerializeError> {
         unsafe {
-            match pyobject_to_obtype(key, opts) {
+            // keys are not passed to default or formatted by datetime_formats
+            match pyobject_to_obtype(
+                key,
+                opts & !(DEFAULT_FIRST | DATETIME_FORMATS | LENGTH_TAGGED),
+            ) {
                 ObType::None => Ok(String::from("null")),
                 ObType::Bool => {
                     if unsafe { core::ptr::eq(key, TRUE) } {
@@ -618,15 +1026,36 @@ impl DictNonStrKey {
                     }
                 }
                 ObType::Int => non_str_int(key),
-                ObType::Float => non_str_float(key),
+                ObType::Float => non_str_float(key, opts),
                 ObType::Datetime => non_str_datetime(key, opts),
-                ObType::Date => non_str_date(key),
+                ObType::Date => non_str_date(key, opts),
                 ObType::Time => non_str_time(key, opts),
-                ObType::Uuid => non_str_uuid(PyUuidRef::from_ptr_unchecked(key)),
+                ObType::Uuid => non_str_uuid(PyUuidRef::from_ptr_unchecked(key), opts),
+                ObType::Enum
+                    if opt_enabled!(opts, ENUM_REPR) && !is_flag_as_int(ob_type!(key), opts) =>
+                {
+                    let repr = ffi!(PyObject_Repr(key));
+                    if repr.is_null() {
+                        return Err(SerializeError::EnumRepr(nonnull!(key)));
+                    }
+                    let ret = Self::pyobject_to_string(repr, opts, key_delimiter, state);
+                    ffi!(Py_DECREF(repr));
+                    ret
+                }
+                ObType::Enum
+                    if opt_enabled!(opts, ENUM_FULL) && !is_flag_as_int(ob_type!(key), opts) =>
+                {
+                    // a key cannot be an object, so it is the name of the member
+                    let name = ffi!(PyObject_GetAttr(key, NAME_STR));
+                    debug_assert!(!name.is_null());
+                    let ret = Self::pyobject_to_string(name, opts, key_delimiter, state);
+                    ffi!(Py_DECREF(name));
+                    ret
+                }
                 ObType::Enum => {
                     let value = ffi!(PyObject_GetAttr(key, VALUE_STR));
                     debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
-                    let ret = Self::pyobject_to_string(value, opts);
+                    let ret = Self::pyobject_to_string(value, opts, key_delimiter, state);
                     ffi!(Py_DECREF(value));
                     ret
                 }
@@ -634,17 +1063,112 @@ impl DictNonStrKey {
                 ObType::StrSubclass => {
                     non_str_str_subclass(PyStrSubclassRef::from_ptr_unchecked(key))
                 }
-                ObType::Tuple
+                ObType::Tuple => match key_delimiter {
+                    Some(delimiter) => Self::tuple_to_string(key, opts, delimiter, state),
+                    None => Err(SerializeError::DictKeyInvalidType),
+                },
+                ObType::Bytes => bytes_key_to_string(key, opts),
+                ObType::Dataclass if is_frozen_dataclass(key) => {
+                    Self::dataclass_to_string(key, state)
+                }
+                // a subclass of bytes, which is not serialized as a value
+                ObType::Unknown
+                    if is_subclass_by_flag!(
+                        tp_flags!(ob_type!(key)),
+                        Py_TPFLAGS_BYTES_SUBCLASS
+                    ) =>
+                {
+                    bytes_key_to_string(key, opts)
+                }
+                ObType::NamedTuple
+                | ObType::Iterator
                 | ObType::NumpyScalar
                 | ObType::NumpyArray
                 | ObType::Dict
                 | ObType::List
                 | ObType::Dataclass
+                | ObType::Pydantic
+                | ObType::Mapping
+                | ObType::Range
+                | ObType::LengthTagged
+                | ObType::Weakref
+                | ObType::DatetimeFormat
+                | ObType::Annotated
                 | ObType::Fragment
+                | ObType::EpochNanoseconds
+                | ObType::Timedelta
+                | ObType::Fraction
+                | ObType::DefaultFirst
                 | ObType::Unknown => Err(SerializeError::DictKeyInvalidType),
             }
         }
     }
+
+    /// Join the keys of the elements of a `tuple` with `key_delimiter`.
+    /// An element that is itself a `tuple` is not joined.
+    #[cold]
+    #[inline(never)]
+    fn tuple_to_string(
+        key: *mut crate::ffi::PyObject,
+        opts: crate::opt::Opt,
+        key_delimiter: NonNull<crate::ffi::PyObject>,
+        state: SerializerState,
+    ) -> Result<String, SerializeError> {
+        let delimiter =
+            match unsafe { PyStrRef::from_ptr_unchecked(key_delimiter.as_ptr()) }.as_str() {
+                Some(uni) => uni,
+                None => return Err(SerializeError::InvalidStr),
+            };
+        let tuple = unsafe { PyTupleRef::from_ptr_unchecked(key) };
+        let mut ret = String::new();
+        for idx in 0..tuple.len() {
+            if idx > 0 {
+                ret.push_str(delimiter);
+            }
+            match Self::pyobject_to_string(tuple.get(idx), opts, None, state) {
+                Ok(elem_as_str) => ret.push_str(&elem_as_str),
+                Err(SerializeError::DictKeyInvalidType) => {
+                    return Err(SerializeError::DictKeyTupleElement);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Serialize a frozen dataclass as compact JSON. Its fields are serialized as values are, but are not
+    /// passed to `default`.
+    #[cold]
+    #[inline(never)]
+    fn dataclass_to_string(
+        key: *mut crate::ffi::PyObject,
+        state: SerializerState,
+    ) -> Result<String, SerializeError> {
+        let obj = PyObjectSerializer::new(key, state, None);
+        let mut buf = BytesWriter::default();
+        let ret = match to_writer(
+            &mut buf,
+            &DataclassGenericSerializer::new(&obj),
+            false,
+            false,
+            opt_enabled!(state.opts(), NONFINITE_AS_STRING),
+        ) {
+            Ok(()) => {
+                let writer = &mut buf;
+                Ok(String::from(str_from_slice!(
+                    writer.written(0, writer.position()).as_ptr(),
+                    writer.position()
+                )))
+            }
+            Err(err) => {
+                // the error is of the key, not of a field of the key
+                clear_error_path();
+                Err(SerializeError::DictKeyDataclass(err.to_string()))
+            }
+        };
+        buf.abort();
+        ret
+    }
 }
 
 impl Serialize for DictNonStrKey {
@@ -683,6 +1207,9 @@ impl Serialize for DictNonStrKey {
                 &raw mut next_value
             );
 
+            if is_omitted_none(value, opts) {
+                continue;
+            }
             match PyStrRef::from_ptr(key) {
                 Ok(pystr) => match pystr.as_str() {
                     Some(uni) => {
@@ -690,7 +1217,12 @@ impl Serialize for DictNonStrKey {
                     }
                     None => err!(SerializeError::InvalidStr),
                 },
-                Err(_) => match Self::pyobject_to_string(key, opts) {
+                Err(_) => match Self::pyobject_to_string(
+                    key,
+                    opts,
+                    self.state.key_delimiter(),
+                    self.state,
+                ) {
                     Ok(key_as_str) => items.push((key_as_str, value)),
                     Err(err) => err!(err),
                 },
@@ -703,16 +1235,162 @@ impl Serialize for DictNonStrKey {
             .iter()
             .for_each(|(key, val)| items_as_str.push(((*key).as_str(), *val)));
 
-        if opt_enabled!(opts, SORT_KEYS) {
-            sort_dict_items(&mut items_as_str);
+        if opt_enabled!(opts, SORT_KEYS)
+            && let Err(err) = sort_dict_items(&mut items_as_str, opts)
+        {
+            err!(err)
+        }
+
+        if opt_enabled!(opts, UNIQUE_KEYS)
+            && has_duplicate_key(&items_as_str, is_sorted_by_key(opts))
+        {
+            err!(SerializeError::DictKeyDuplicate)
         }
 
         let mut map = serializer.serialize_map(None).unwrap();
         for (key, val) in items_as_str.iter() {
             let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
             map.serialize_key(key).unwrap();
-            map.serialize_value(&pyvalue)?;
+            map.serialize_value(&pyvalue).at_key(key)?;
+        }
+        map.end()
+    }
+}
+
+/// Serialize a `dict` with each key replaced by the result of calling
+/// `key_transform` with it. A key that is not a `str` is converted as it is
+/// by `OPT_NON_STR_KEYS` before it is transformed. Each transformed key is
+/// cached for the duration of the call to `dumps()`, and the cache keeps it
+/// referenced while serializing.
+pub(crate) struct DictTransformKey {
+    dict: PyDictRef,
+    state: SerializerState,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+}
+
+impl DictTransformKey {
+    /// The transformed key of `key`, a `str`.
+    #[cold]
+    #[inline(never)]
+    fn transform(&self, key: *mut crate::ffi::PyObject) -> Result<&'static str, SerializeError> {
+        let cache = self.state.key_transform_cache().unwrap().as_ptr();
+        let mut transformed = ffi!(PyDict_GetItemWithError(cache, key));
+        if transformed.is_null() {
+            let args = [key];
+            transformed = unsafe {
+                crate::ffi::PyObject_Vectorcall(
+                    self.state.key_transform().unwrap().as_ptr(),
+                    args.as_ptr(),
+                    1,
+                    core::ptr::null_mut(),
+                )
+            };
+            if transformed.is_null() {
+                cold_path!();
+                return Err(SerializeError::KeyTransform);
+            }
+            if unsafe { !core::ptr::eq(ob_type!(transformed), STR_TYPE) } {
+                cold_path!();
+                ffi!(Py_DECREF(transformed));
+                return Err(SerializeError::KeyTransformNotStr);
+            }
+            let res = ffi!(PyDict_SetItem(cache, key, transformed));
+            ffi!(Py_DECREF(transformed));
+            if res != 0 {
+                cold_path!();
+                return Err(SerializeError::KeyTransform);
+            }
+        }
+        match unsafe { PyStrRef::from_ptr_unchecked(transformed) }.as_str() {
+            Some(uni) => Ok(uni),
+            None => Err(SerializeError::InvalidStr),
+        }
+    }
+
+    fn key_to_str(
+        &self,
+        key: *mut crate::ffi::PyObject,
+        opts: Opt,
+    ) -> Result<&'static str, SerializeError> {
+        if unsafe { core::ptr::eq(ob_type!(key), STR_TYPE) } {
+            self.transform(key)
+        } else if opt_enabled!(opts, NON_STR_KEYS) {
+            let key_as_str = DictNonStrKey::pyobject_to_string(
+                key,
+                opts,
+                self.state.key_delimiter(),
+                self.state,
+            )?;
+            let pystr = PyStrRef::from_str(&key_as_str);
+            let ret = self.transform(pystr.as_ptr());
+            ffi!(Py_DECREF(pystr.as_ptr()));
+            ret
+        } else {
+            Err(SerializeError::KeyMustBeStr)
+        }
+    }
+
+    fn serialize_copy<S>(
+        &self,
+        dict: *mut crate::ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let opts = self.state.opts() & NOT_PASSTHROUGH;
+
+        let mut items: SmallVec<[(&str, *mut crate::ffi::PyObject); 8]> = SmallVec::new();
+        let mut pos = 0;
+        let mut key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut value: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        while pydict_next!(dict, &raw mut pos, &raw mut key, &raw mut value) == 1 {
+            if is_omitted_none(value, opts) {
+                continue;
+            }
+            match self.key_to_str(key, opts) {
+                Ok(key_as_str) => items.push((key_as_str, value)),
+                Err(err) => err!(err),
+            }
+        }
+
+        if opt_enabled!(opts, SORT_KEYS)
+            && let Err(err) = sort_dict_items(&mut items, opts)
+        {
+            err!(err)
+        }
+
+        if opt_enabled!(opts, UNIQUE_KEYS) && has_duplicate_key(&items, is_sorted_by_key(opts)) {
+            err!(SerializeError::DictKeyDuplicate)
+        }
+
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, val) in items.iter() {
+            let pyvalue = PyObjectSerializer::new(*val, self.state, self.default);
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&pyvalue).at_key(key)?;
         }
         map.end()
     }
 }
+
+impl Serialize for DictTransformKey {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // key_transform may change the dict, so iterate a copy that
+        // references its keys and values
+        let dict = ffi!(PyDict_Copy(self.dict.as_ptr()));
+        if dict.is_null() {
+            cold_path!();
+            err!(SerializeError::KeyTransform)
+        }
+        let ret = self.serialize_copy(dict, serializer);
+        ffi!(Py_DECREF(dict));
+        ret
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::EpochNanoseconds;
+use crate::opt::Opt;
+use crate::serialize::buffer::SmallFixedBuffer;
+use crate::serialize::per_type::datetimelike::{DateTimeError, DateTimeLike, Offset};
+use jiff::Timestamp;
+use jiff::civil::DateTime;
+use serde::ser::{Serialize, Serializer};
+
+pub(crate) struct EpochNanosecondsSerializer {
+    ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
+}
+
+impl EpochNanosecondsSerializer {
+    pub fn new(ptr: *mut crate::ffi::PyObject, opts: Opt) -> Self {
+        EpochNanosecondsSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+}
+
+struct EpochNanosecondsRepr {
+    dt: DateTime,
+}
+
+macro_rules! forward_inner {
+    ($meth: ident, $ty: ident) => {
+        fn $meth(&self) -> $ty {
+            debug_assert!(self.dt.$meth() >= 0);
+            #[allow(clippy::cast_sign_loss)]
+            let ret = self.dt.$meth() as $ty; // stmt_expr_attributes
+            ret
+        }
+    };
+}
+
+impl DateTimeLike for EpochNanosecondsRepr {
+    forward_inner!(year, i32);
+    forward_inner!(month, u8);
+    forward_inner!(day, u8);
+    forward_inner!(hour, u8);
+    forward_inner!(minute, u8);
+    forward_inner!(second, u8);
+
+    fn nanosecond(&self) -> u32 {
+        debug_assert!(self.dt.subsec_nanosecond() >= 0);
+        self.dt.subsec_nanosecond().cast_unsigned()
+    }
+
+    fn microsecond(&self) -> u32 {
+        self.nanosecond() / 1_000
+    }
+
+    fn has_nanosecond_precision(&self) -> bool {
+        true
+    }
+
+    fn has_tz(&self) -> bool {
+        true
+    }
+
+    fn slow_offset(&self) -> Result<Offset, DateTimeError> {
+        unreachable!()
+    }
+
+    fn offset(&self) -> Result<Offset, DateTimeError> {
+        Ok(Offset::default())
+    }
+}
+
+impl Serialize for EpochNanosecondsSerializer {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = unsafe { (*self.ptr.cast::<EpochNanoseconds>()).value };
+        // every i64 of nanoseconds is within the range of `Timestamp`
+        let timestamp = Timestamp::from_nanosecond(i128::from(value)).unwrap();
+        let repr = EpochNanosecondsRepr {
+            dt: timestamp.to_zoned(jiff::tz::TimeZone::UTC).datetime(),
+        };
+        let mut buf = SmallFixedBuffer::new();
+        let _ = repr.write_buf(&mut buf, self.opts);
+        serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
+    }
+}
@@ -2,16 +2,24 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyFloatRef;
+use crate::opt::{CANONICAL, FLOAT_TRAILING_ZERO};
+use crate::serialize::buffer::SmallFixedBuffer;
+use crate::serialize::error::SerializeError;
+use crate::serialize::state::SerializerState;
 use serde::ser::{Serialize, Serializer};
+use std::io::Write;
 
-#[repr(transparent)]
 pub(crate) struct FloatSerializer {
     ob: PyFloatRef,
+    state: SerializerState,
 }
 
 impl FloatSerializer {
-    pub fn new(ptr: PyFloatRef) -> Self {
-        FloatSerializer { ob: ptr }
+    pub fn new(ptr: PyFloatRef, state: SerializerState) -> Self {
+        FloatSerializer {
+            ob: ptr,
+            state: state,
+        }
     }
 }
 
@@ -21,6 +29,207 @@ impl Serialize for FloatSerializer {
     where
         S: Serializer,
     {
-        serializer.serialize_f64(self.ob.value())
+        let mut value = self.ob.value();
+        if let Some(sigfigs) = self.state.float_sigfigs() {
+            cold_path!();
+            value = round_to_sigfigs(value, sigfigs);
+        }
+        if opt_enabled!(self.state.opts(), CANONICAL) {
+            cold_path!();
+            if !value.is_finite() {
+                err!(SerializeError::FloatNotFinite)
+            }
+            let mut buf = SmallFixedBuffer::new();
+            write_canonical(&mut buf, value);
+            return serializer
+                .serialize_bytes(unsafe { core::slice::from_raw_parts(buf.as_ptr(), buf.len()) });
+        }
+        if opt_enabled!(self.state.opts(), FLOAT_TRAILING_ZERO) && value.is_finite() {
+            cold_path!();
+            let mut buf = SmallFixedBuffer::new();
+            write_fixed(&mut buf, value);
+            return serializer
+                .serialize_bytes(unsafe { core::slice::from_raw_parts(buf.as_ptr(), buf.len()) });
+        }
+        serializer.serialize_f64(value)
+    }
+}
+
+/// Round `value` to `sigfigs` significant decimal digits, half to even, as
+/// `float(f"{value:.{sigfigs - 1}e}")` does, except that a finite value is
+/// not rounded to infinity.
+#[cold]
+#[inline(never)]
+fn round_to_sigfigs(value: f64, sigfigs: u8) -> f64 {
+    debug_assert!(sigfigs >= 1);
+    if !value.is_finite() {
+        return value;
+    }
+    // "-1.2345678901234567e-308" is the longest
+    let mut buf = [0u8; 32];
+    let mut writer: &mut [u8] = &mut buf;
+    write!(writer, "{:.*e}", usize::from(sigfigs - 1), value).unwrap();
+    let len = 32 - writer.len();
+    let rounded = str_from_slice!(buf.as_ptr(), len).parse::<f64>().unwrap();
+    if rounded.is_finite() { rounded } else { value }
+}
+
+/// The shortest digits of a finite, nonzero `value` that round-trip, which
+/// are the same as for the default format, without leading or trailing
+/// zeros, their count, and the exponent `point` such that the magnitude of
+/// the value is 0.DIGITS * 10^point.
+fn shortest_digits(value: f64) -> ([u8; 20], usize, i32) {
+    let mut formatbuf = zmij::Buffer::new();
+    let formatted = formatbuf.format_finite(value.abs()).as_bytes();
+    let (mantissa, exponent) = match formatted.iter().position(|&each| each == b'e') {
+        Some(idx) => {
+            let (negative, exponent_digits) = match &formatted[idx + 1..] {
+                [b'-', rest @ ..] => (true, rest),
+                [b'+', rest @ ..] => (false, rest),
+                rest => (false, rest),
+            };
+            let magnitude = exponent_digits
+                .iter()
+                .fold(0, |acc, &each| acc * 10 + i32::from(each - b'0'));
+            (
+                &formatted[..idx],
+                if negative { -magnitude } else { magnitude },
+            )
+        }
+        None => (formatted, 0),
+    };
+    let mut digits = [0u8; 20];
+    let mut len = 0;
+    let mut point = exponent;
+    let mut seen_point = false;
+    for &each in mantissa {
+        if each == b'.' {
+            seen_point = true;
+        } else if len == 0 && each == b'0' {
+            if seen_point {
+                point -= 1;
+            }
+        } else {
+            digits[len] = each;
+            len += 1;
+            if !seen_point {
+                point += 1;
+            }
+        }
+    }
+    while digits[len - 1] == b'0' {
+        len -= 1;
+    }
+    (digits, len, point)
+}
+
+/// Write a finite `value` as ECMAScript's `Number.prototype.toString()`
+/// does, which is how RFC 8785 serializes numbers.
+#[cold]
+#[inline(never)]
+pub(crate) fn write_canonical<B>(buf: &mut B, value: f64)
+where
+    B: bytes::BufMut,
+{
+    debug_assert!(value.is_finite());
+    if value == 0.0 {
+        // including -0.0
+        buf.put_u8(b'0');
+        return;
+    }
+    if value < 0.0 {
+        buf.put_u8(b'-');
+    }
+
+    let (digits, len, point) = shortest_digits(value);
+    let digits = &digits[..len];
+    let count = i32::try_from(len).unwrap();
+
+    if count <= point && point <= 21 {
+        // 1e+20 -> 100000000000000000000
+        buf.put_slice(digits);
+        for _ in 0..point - count {
+            buf.put_u8(b'0');
+        }
+    } else if 0 < point && point <= 21 {
+        // 1.5
+        let split = usize::try_from(point).unwrap();
+        buf.put_slice(&digits[..split]);
+        buf.put_u8(b'.');
+        buf.put_slice(&digits[split..]);
+    } else if -6 < point && point <= 0 {
+        // 0.000001
+        buf.put_slice(b"0.");
+        for _ in 0..-point {
+            buf.put_u8(b'0');
+        }
+        buf.put_slice(digits);
+    } else {
+        // 1e+21, 1.5e-7
+        buf.put_u8(digits[0]);
+        if len > 1 {
+            buf.put_u8(b'.');
+            buf.put_slice(&digits[1..]);
+        }
+        buf.put_u8(b'e');
+        let exponent = point - 1;
+        buf.put_u8(if exponent < 0 { b'-' } else { b'+' });
+        buf.put_slice(
+            itoa::Buffer::new()
+                .format(exponent.unsigned_abs())
+                .as_bytes(),
+        );
+    }
+}
+
+/// Write a finite `value` in fixed notation with at least one digit after
+/// the decimal point if its magnitude is at least 1e-6 and less than 1e21,
+/// which is where ECMAScript's `Number.prototype.toString()` uses fixed
+/// notation, and as the default format does otherwise.
+#[cold]
+#[inline(never)]
+pub(crate) fn write_fixed<B>(buf: &mut B, value: f64)
+where
+    B: bytes::BufMut,
+{
+    debug_assert!(value.is_finite());
+    if value == 0.0 {
+        buf.put_slice(if value.is_sign_negative() {
+            b"-0.0"
+        } else {
+            b"0.0"
+        });
+        return;
+    }
+    let (digits, len, point) = shortest_digits(value);
+    if !(-6 < point && point <= 21) {
+        buf.put_slice(zmij::Buffer::new().format_finite(value).as_bytes());
+        return;
+    }
+    if value < 0.0 {
+        buf.put_u8(b'-');
+    }
+    let digits = &digits[..len];
+    let count = i32::try_from(len).unwrap();
+    if count <= point {
+        // 1e+20 -> 100000000000000000000.0
+        buf.put_slice(digits);
+        for _ in 0..point - count {
+            buf.put_u8(b'0');
+        }
+        buf.put_slice(b".0");
+    } else if 0 < point {
+        // 1.5
+        let split = usize::try_from(point).unwrap();
+        buf.put_slice(&digits[..split]);
+        buf.put_u8(b'.');
+        buf.put_slice(&digits[split..]);
+    } else {
+        // 1e-6 -> 0.000001
+        buf.put_slice(b"0.");
+        for _ in 0..-point {
+            buf.put_u8(b'0');
+        }
+        buf.put_slice(digits);
     }
 }
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::opt::FRACTION_AS_FLOAT;
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::{DENOMINATOR_STR, FRACTION_TYPE, NUMERATOR_STR, load_fraction_type};
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+#[cold]
+pub(crate) fn is_fraction(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    match unsafe { *FRACTION_TYPE.get_or_init(load_fraction_type) } {
+        Some(fraction_type) => core::ptr::eq(ob_type, fraction_type.as_ptr()),
+        None => false,
+    }
+}
+
+/// Serialize a `fractions.Fraction` as an array of its `numerator` and
+/// `denominator` or, with `OPT_FRACTION_AS_FLOAT`, as the `float` of it.
+#[repr(transparent)]
+pub(crate) struct FractionSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> FractionSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+
+    fn error(&self) -> SerializeError {
+        SerializeError::Fraction(nonnull!(self.previous.ptr))
+    }
+}
+
+impl Serialize for FractionSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_enabled!(self.previous.state.opts(), FRACTION_AS_FLOAT) {
+            // float() is correctly rounded for a numerator or denominator of
+            // any size
+            let value = ffi!(PyNumber_Float(self.previous.ptr));
+            if value.is_null() {
+                err!(self.error())
+            }
+            let ret = PyObjectSerializer::new(value, self.previous.state, self.previous.default)
+                .serialize(serializer);
+            ffi!(Py_DECREF(value));
+            return ret;
+        }
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for (idx, name) in unsafe { [NUMERATOR_STR, DENOMINATOR_STR] }
+            .into_iter()
+            .enumerate()
+        {
+            let value = ffi!(PyObject_GetAttr(self.previous.ptr, name));
+            if value.is_null() {
+                err!(self.error())
+            }
+            // an int of more than 64 bits raises as it does elsewhere
+            let ret = seq
+                .serialize_element(&PyObjectSerializer::new(
+                    value,
+                    self.previous.state,
+                    self.previous.default,
+                ))
+                .at_index(idx);
+            ffi!(Py_DECREF(value));
+            ret?;
+        }
+        seq.end()
+    }
+}
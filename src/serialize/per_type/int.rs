@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2018-2026)
 
+use crate::base62::{BASE62_MAX_LEN, encode};
 use crate::ffi::PyIntRef;
-use crate::opt::{Opt, STRICT_INTEGER};
+use crate::opt::{INT_BASE62, Opt, STRICT_INTEGER};
 use crate::serialize::error::SerializeError;
 use serde::ser::{Serialize, Serializer};
 
@@ -22,6 +23,22 @@ impl IntSerializer {
     }
 }
 
+/// Serialize an integer outside the 53-bit range as a base-62 `str` for
+/// `OPT_INT_BASE62`.
+#[cold]
+#[inline(never)]
+fn serialize_base62<S>(negative: bool, magnitude: u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut buf = [0u8; BASE62_MAX_LEN];
+    let start = encode(negative, magnitude, &mut buf);
+    serializer.serialize_unit_struct(str_from_slice!(
+        unsafe { buf.as_ptr().add(start) },
+        BASE62_MAX_LEN - start
+    ))
+}
+
 impl Serialize for IntSerializer {
     #[inline(always)]
     #[cfg(feature = "inline_int")]
@@ -38,10 +55,13 @@ impl Serialize for IntSerializer {
                         .ob
                         .as_i64()
                         .map_err(|_| serde::ser::Error::custom(SerializeError::Integer64Bits))?;
-                    if opt_enabled!(self.opts, STRICT_INTEGER)
+                    if opt_enabled!(self.opts, INT_BASE62 | STRICT_INTEGER)
                         && !(STRICT_INT_MIN..=STRICT_INT_MAX).contains(&value)
                     {
                         cold_path!();
+                        if opt_enabled!(self.opts, INT_BASE62) {
+                            return serialize_base62(value < 0, value.unsigned_abs(), serializer);
+                        }
                         err!(SerializeError::Integer53Bits);
                     }
                     serializer.serialize_i64(value)
@@ -51,8 +71,13 @@ impl Serialize for IntSerializer {
                         .ob
                         .as_u64()
                         .map_err(|_| serde::ser::Error::custom(SerializeError::Integer64Bits))?;
-                    if opt_enabled!(self.opts, STRICT_INTEGER) && value > STRICT_INT_MAX as u64 {
+                    if opt_enabled!(self.opts, INT_BASE62 | STRICT_INTEGER)
+                        && value > STRICT_INT_MAX as u64
+                    {
                         cold_path!();
+                        if opt_enabled!(self.opts, INT_BASE62) {
+                            return serialize_base62(false, value, serializer);
+                        }
                         err!(SerializeError::Integer53Bits);
                     }
                     serializer.serialize_u64(value)
@@ -70,19 +95,26 @@ impl Serialize for IntSerializer {
         unsafe {
             match self.ob.as_i64() {
                 Ok(value) => {
-                    if opt_enabled!(self.opts, STRICT_INTEGER)
+                    if opt_enabled!(self.opts, INT_BASE62 | STRICT_INTEGER)
                         && !(STRICT_INT_MIN..=STRICT_INT_MAX).contains(&value)
                     {
                         cold_path!();
+                        if opt_enabled!(self.opts, INT_BASE62) {
+                            return serialize_base62(value < 0, value.unsigned_abs(), serializer);
+                        }
                         err!(SerializeError::Integer53Bits);
                     }
                     serializer.serialize_i64(value)
                 }
                 Err(_) => match self.ob.as_u64() {
                     Ok(value) => {
-                        if opt_enabled!(self.opts, STRICT_INTEGER) && value > STRICT_INT_MAX as u64
+                        if opt_enabled!(self.opts, INT_BASE62 | STRICT_INTEGER)
+                            && value > STRICT_INT_MAX as u64
                         {
                             cold_path!();
+                            if opt_enabled!(self.opts, INT_BASE62) {
+                                return serialize_base62(false, value, serializer);
+                            }
                             err!(SerializeError::Integer53Bits);
                         }
                         serializer.serialize_u64(value)
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::SerializerState;
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use core::ptr::NonNull;
+
+/// Whether `ob_type` implements the iterator protocol and so is serialized
+/// with `OPT_SERIALIZE_ITERATORS`. A subclass of `str`, `bytes`, `dict`,
+/// `list`, or `tuple` that also defines `__next__()` is not, so that it is
+/// not consumed as an iterator with `OPT_PASSTHROUGH_SUBCLASS`.
+#[inline]
+pub(crate) fn is_iterator(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    if unsafe { (*ob_type).tp_iternext.is_none() } {
+        return false;
+    }
+    let tp_flags = tp_flags!(ob_type);
+    !(is_subclass_by_flag!(tp_flags, Py_TPFLAGS_UNICODE_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_BYTES_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_DICT_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_LIST_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_TUPLE_SUBCLASS))
+}
+
+/// Serialize an iterator, e.g., a generator, as an array by consuming it.
+/// Each item is serialized as it is produced and released before the next,
+/// so `max_sequence_len` is checked as items are counted.
+pub(crate) struct IteratorSerializer {
+    ptr: *mut crate::ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+}
+
+impl IteratorSerializer {
+    pub fn new(
+        ptr: *mut crate::ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<crate::ffi::PyObject>>,
+    ) -> Self {
+        IteratorSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for IteratorSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let iter = ffi!(PyObject_GetIter(self.ptr));
+        if iter.is_null() {
+            err!(SerializeError::Iterator(nonnull!(self.ptr)))
+        }
+        let max_sequence_len = self.state.max_sequence_len();
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for idx in 0.. {
+            let item = ffi!(PyIter_Next(iter));
+            if item.is_null() {
+                break;
+            }
+            if idx == max_sequence_len {
+                cold_path!();
+                ffi!(Py_DECREF(item));
+                ffi!(Py_DECREF(iter));
+                err!(SerializeError::SequenceTooLong(max_sequence_len))
+            }
+            let res = seq
+                .serialize_element(&PyObjectSerializer::new(item, self.state, self.default))
+                .at_index(idx);
+            ffi!(Py_DECREF(item));
+            if let Err(err) = res {
+                ffi!(Py_DECREF(iter));
+                return Err(err);
+            }
+        }
+        ffi!(Py_DECREF(iter));
+        if !ffi!(PyErr_Occurred()).is_null() {
+            cold_path!();
+            err!(SerializeError::Iterator(nonnull!(self.ptr)))
+        }
+        seq.end()
+    }
+}
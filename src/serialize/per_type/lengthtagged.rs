@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::opt::{OMIT_NONE, Opt};
+use crate::serialize::obtype::ObType;
+use crate::serialize::per_type::dict::is_omitted_none;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::util::isize_to_usize;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// The `ObType` the items of a `list`, `tuple`, or `dict` are serialized as.
+fn items_obtype(ptr: *mut crate::ffi::PyObject) -> ObType {
+    let tp_flags = tp_flags!(ob_type!(ptr));
+    if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_DICT_SUBCLASS) {
+        ObType::Dict
+    } else if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_LIST_SUBCLASS) {
+        ObType::List
+    } else {
+        ObType::Tuple
+    }
+}
+
+/// The number of members of a `dict` that are serialized, which does not
+/// count those that `OPT_OMIT_NONE` omits.
+fn dict_len(ptr: *mut crate::ffi::PyObject, opts: Opt) -> isize {
+    if opt_disabled!(opts, OMIT_NONE) {
+        return ffi!(PyDict_Size(ptr));
+    }
+    let mut len = 0;
+    let mut pos = 0;
+    let mut key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+    let mut value: *mut crate::ffi::PyObject = core::ptr::null_mut();
+    while pydict_next!(ptr, &raw mut pos, &raw mut key, &raw mut value) == 1 {
+        if !is_omitted_none(value, opts) {
+            len += 1;
+        }
+    }
+    len
+}
+
+#[repr(transparent)]
+struct LengthTaggedItems<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl Serialize for LengthTaggedItems<'_> {
+    #[inline(always)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.previous
+            .serialize_obtype(items_obtype(self.previous.ptr), serializer)
+    }
+}
+
+/// Serialize a `list`, `tuple`, or `dict` with `OPT_LENGTH_TAGGED` as an
+/// object of its number of items, `len`, and the array or object it would
+/// otherwise be serialized as, `items`.
+#[repr(transparent)]
+pub(crate) struct LengthTaggedSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> LengthTaggedSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for LengthTaggedSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = match items_obtype(self.previous.ptr) {
+            ObType::Dict => dict_len(self.previous.ptr, self.previous.state.opts()),
+            _ => ffi!(Py_SIZE(self.previous.ptr)),
+        };
+        let mut map = serializer.serialize_map(None).unwrap();
+        map.serialize_key("len").unwrap();
+        map.serialize_value(&isize_to_usize(len)).unwrap();
+        map.serialize_key("items").unwrap();
+        // the path of an error is that of the list, tuple, or dict itself
+        map.serialize_value(&LengthTaggedItems {
+            previous: self.previous,
+        })?;
+        map.end()
+    }
+}
@@ -5,12 +5,18 @@ use crate::ffi::{
     PyBoolRef, PyDictRef, PyFloatRef, PyFragmentRef, PyIntRef, PyListRef, PyStrRef,
     PyStrSubclassRef, PyUuidRef,
 };
+use crate::opt::DEDUPLICATE_ARRAYS;
 use crate::serialize::error::SerializeError;
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
+use crate::serialize::path::ErrorPath;
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, IntSerializer,
-    NoneSerializer, NumpyScalar, NumpySerializer, StrSerializer, StrSubclassSerializer, Time, UUID,
+    AnnotatedSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date,
+    DateTime, DatetimeFormatSerializer, DefaultFirstSerializer, DefaultSerializer,
+    DictGenericSerializer, EnumSerializer, EpochNanosecondsSerializer, FloatSerializer,
+    FractionSerializer, FragmentSerializer, IntSerializer, IteratorSerializer,
+    LengthTaggedSerializer, MappingSerializer, NamedTupleSerializer, NoneSerializer, NumpyScalar,
+    NumpySerializer, PydanticSerializer, RangeSerializer, StrSerializer, StrSubclassSerializer,
+    Time, TimedeltaSerializer, UUID, WeakrefSerializer,
 };
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
@@ -18,7 +24,7 @@ use crate::typeref::TUPLE_TYPE;
 use crate::util::isize_to_usize;
 
 use core::ptr::NonNull;
-use serde::ser::{Serialize, SerializeSeq, Serializer};
+use serde::ser::{Serialize, SerializeSeq, SerializeTuple, Serializer};
 
 pub(crate) struct ZeroListSerializer;
 
@@ -152,6 +158,32 @@ impl ListTupleSerializer {
     }
 }
 
+impl ListTupleSerializer {
+    #[inline(never)]
+    fn serialize_unique<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_tuple(self.len).unwrap();
+        #[cfg(not(Py_GIL_DISABLED))]
+        for idx in 0..self.len {
+            let value = unsafe { *((self.data_ptr).add(idx)) };
+            seq.serialize_element(&PyObjectSerializer::new(value, self.state, self.default))
+                .at_index(idx)?;
+        }
+        #[cfg(Py_GIL_DISABLED)]
+        for (idx, ptr) in self.items.iter().enumerate() {
+            seq.serialize_element(&PyObjectSerializer::new(
+                ptr.as_ptr(),
+                self.state,
+                self.default,
+            ))
+            .at_index(idx)?;
+        }
+        seq.end()
+    }
+}
+
 #[cfg(Py_GIL_DISABLED)]
 impl Drop for ListTupleSerializer {
     fn drop(&mut self) {
@@ -177,6 +209,16 @@ impl Serialize for ListTupleSerializer {
             cold_path!();
             return ZeroListSerializer::new().serialize(serializer);
         }
+        if self.len > self.state.max_sequence_len() {
+            cold_path!();
+            err!(SerializeError::SequenceTooLong(
+                self.state.max_sequence_len()
+            ))
+        }
+        if opt_enabled!(self.state.opts(), DEDUPLICATE_ARRAYS) {
+            cold_path!();
+            return self.serialize_unique(serializer);
+        }
         let mut seq = serializer.serialize_seq(None).unwrap();
         #[cfg(not(Py_GIL_DISABLED))]
         for idx in 0..self.len {
@@ -185,26 +227,31 @@ impl Serialize for ListTupleSerializer {
                 ObType::Str => {
                     seq.serialize_element(&StrSerializer::new(unsafe {
                         PyStrRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
                 }
                 ObType::StrSubclass => {
                     seq.serialize_element(&StrSubclassSerializer::new(unsafe {
                         PyStrSubclassRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
                 }
                 ObType::Int => {
                     seq.serialize_element(&IntSerializer::new(
                         unsafe { PyIntRef::from_ptr_unchecked(value) },
                         self.state.opts(),
-                    ))?;
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::None => {
                     seq.serialize_element(&NoneSerializer::new()).unwrap();
                 }
                 ObType::Float => {
-                    seq.serialize_element(&FloatSerializer::new(unsafe {
-                        PyFloatRef::from_ptr_unchecked(value)
-                    }))?;
+                    seq.serialize_element(&FloatSerializer::new(
+                        unsafe { PyFloatRef::from_ptr_unchecked(value) },
+                        self.state,
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::Bool => {
                     seq.serialize_element(&BoolSerializer::new(unsafe {
@@ -213,18 +260,28 @@ impl Serialize for ListTupleSerializer {
                     .unwrap();
                 }
                 ObType::Datetime => {
-                    seq.serialize_element(&DateTime::new(value, self.state.opts()))?;
+                    seq.serialize_element(&DateTime::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Date => {
-                    seq.serialize_element(&Date::new(value))?;
+                    seq.serialize_element(&Date::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Time => {
-                    seq.serialize_element(&Time::new(value, self.state.opts()))?;
+                    seq.serialize_element(&Time::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::DatetimeFormat => {
+                    seq.serialize_element(&DatetimeFormatSerializer::new(
+                        &PyObjectSerializer::new(value, self.state, self.default),
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::Uuid => {
-                    seq.serialize_element(&UUID::new(unsafe {
-                        PyUuidRef::from_ptr_unchecked(value)
-                    }))
+                    seq.serialize_element(&UUID::new(
+                        unsafe { PyUuidRef::from_ptr_unchecked(value) },
+                        self.state.opts(),
+                    ))
                     .unwrap();
                 }
                 ObType::Dict => {
@@ -233,7 +290,7 @@ impl Serialize for ListTupleSerializer {
                         self.state,
                         self.default,
                     );
-                    seq.serialize_element(&pyvalue)?;
+                    seq.serialize_element(&pyvalue).at_index(idx)?;
                 }
                 ObType::List => {
                     if ffi!(Py_SIZE(value)) == 0 {
@@ -244,7 +301,7 @@ impl Serialize for ListTupleSerializer {
                             self.state,
                             self.default,
                         );
-                        seq.serialize_element(&pyvalue)?;
+                        seq.serialize_element(&pyvalue).at_index(idx)?;
                     }
                 }
                 ObType::Tuple => {
@@ -253,72 +310,178 @@ impl Serialize for ListTupleSerializer {
                     } else {
                         let pyvalue =
                             ListTupleSerializer::from_tuple(value, self.state, self.default);
-                        seq.serialize_element(&pyvalue)?;
+                        seq.serialize_element(&pyvalue).at_index(idx)?;
                     }
                 }
+                ObType::NamedTuple => {
+                    seq.serialize_element(&NamedTupleSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Iterator => {
+                    seq.serialize_element(&IteratorSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .at_index(idx)?;
+                }
                 ObType::Dataclass => {
                     seq.serialize_element(&DataclassGenericSerializer::new(
                         &PyObjectSerializer::new(value, self.state, self.default),
-                    ))?;
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Pydantic => {
+                    seq.serialize_element(&PydanticSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Mapping => {
+                    seq.serialize_element(&MappingSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Range => {
+                    seq.serialize_element(&RangeSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Fraction => {
+                    seq.serialize_element(&FractionSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::LengthTagged => {
+                    seq.serialize_element(&LengthTaggedSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Weakref => {
+                    seq.serialize_element(&WeakrefSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Annotated => {
+                    seq.serialize_element(&AnnotatedSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::Enum => {
                     seq.serialize_element(&EnumSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::NumpyArray => {
                     seq.serialize_element(&NumpySerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::NumpyScalar => {
-                    seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))?;
+                    seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Fragment => {
                     seq.serialize_element(&FragmentSerializer::new(unsafe {
                         PyFragmentRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
+                }
+                ObType::EpochNanoseconds => {
+                    seq.serialize_element(&EpochNanosecondsSerializer::new(
+                        value,
+                        self.state.opts(),
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Timedelta => {
+                    seq.serialize_element(&TimedeltaSerializer::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::Bytes => {
+                    seq.serialize_element(&BytesSerializer::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::DefaultFirst => {
+                    seq.serialize_element(&DefaultFirstSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::Unknown => {
                     seq.serialize_element(&DefaultSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
             }
         }
         #[cfg(Py_GIL_DISABLED)]
-        for ptr in self.items.iter() {
+        for (idx, ptr) in self.items.iter().enumerate() {
             let value = ptr.as_ptr();
             match pyobject_to_obtype(value, self.state.opts()) {
                 ObType::Str => {
                     seq.serialize_element(&StrSerializer::new(unsafe {
                         PyStrRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
                 }
                 ObType::StrSubclass => {
                     seq.serialize_element(&StrSubclassSerializer::new(unsafe {
                         PyStrSubclassRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
                 }
                 ObType::Int => {
                     seq.serialize_element(&IntSerializer::new(
                         unsafe { PyIntRef::from_ptr_unchecked(value) },
                         self.state.opts(),
-                    ))?;
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::None => {
                     seq.serialize_element(&NoneSerializer::new()).unwrap();
                 }
                 ObType::Float => {
-                    seq.serialize_element(&FloatSerializer::new(unsafe {
-                        PyFloatRef::from_ptr_unchecked(value)
-                    }))?;
+                    seq.serialize_element(&FloatSerializer::new(
+                        unsafe { PyFloatRef::from_ptr_unchecked(value) },
+                        self.state,
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::Bool => {
                     seq.serialize_element(&BoolSerializer::new(unsafe {
@@ -327,18 +490,28 @@ impl Serialize for ListTupleSerializer {
                     .unwrap();
                 }
                 ObType::Datetime => {
-                    seq.serialize_element(&DateTime::new(value, self.state.opts()))?;
+                    seq.serialize_element(&DateTime::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Date => {
-                    seq.serialize_element(&Date::new(value))?;
+                    seq.serialize_element(&Date::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Time => {
-                    seq.serialize_element(&Time::new(value, self.state.opts()))?;
+                    seq.serialize_element(&Time::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::DatetimeFormat => {
+                    seq.serialize_element(&DatetimeFormatSerializer::new(
+                        &PyObjectSerializer::new(value, self.state, self.default),
+                    ))
+                    .at_index(idx)?;
                 }
                 ObType::Uuid => {
-                    seq.serialize_element(&UUID::new(unsafe {
-                        PyUuidRef::from_ptr_unchecked(value)
-                    }))
+                    seq.serialize_element(&UUID::new(
+                        unsafe { PyUuidRef::from_ptr_unchecked(value) },
+                        self.state.opts(),
+                    ))
                     .unwrap();
                 }
                 ObType::Dict => {
@@ -347,7 +520,7 @@ impl Serialize for ListTupleSerializer {
                         self.state,
                         self.default,
                     );
-                    seq.serialize_element(&pyvalue)?;
+                    seq.serialize_element(&pyvalue).at_index(idx)?;
                 }
                 ObType::List => {
                     let pyvalue = ListTupleSerializer::from_list(
@@ -355,49 +528,150 @@ impl Serialize for ListTupleSerializer {
                         self.state,
                         self.default,
                     );
-                    seq.serialize_element(&pyvalue)?;
+                    seq.serialize_element(&pyvalue).at_index(idx)?;
                 }
                 ObType::Tuple => {
                     if ffi!(Py_SIZE(value)) == 0 {
                         seq.serialize_element(&ZeroListSerializer::new()).unwrap();
                     } else {
                         let pyvalue = ListTupleSerializer::from_tuple(value, self.state, self.default);
-                        seq.serialize_element(&pyvalue)?;
+                        seq.serialize_element(&pyvalue).at_index(idx)?;
                     }
                 }
+                ObType::NamedTuple => {
+                    seq.serialize_element(&NamedTupleSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Iterator => {
+                    seq.serialize_element(&IteratorSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    ))
+                    .at_index(idx)?;
+                }
                 ObType::Dataclass => {
                     seq.serialize_element(&DataclassGenericSerializer::new(
                         &PyObjectSerializer::new(value, self.state, self.default),
-                    ))?;
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Pydantic => {
+                    seq.serialize_element(&PydanticSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Mapping => {
+                    seq.serialize_element(&MappingSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Range => {
+                    seq.serialize_element(&RangeSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Fraction => {
+                    seq.serialize_element(&FractionSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::LengthTagged => {
+                    seq.serialize_element(&LengthTaggedSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Weakref => {
+                    seq.serialize_element(&WeakrefSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
+                }
+                ObType::Annotated => {
+                    seq.serialize_element(&AnnotatedSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::Enum => {
                     seq.serialize_element(&EnumSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::NumpyArray => {
                     seq.serialize_element(&NumpySerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::NumpyScalar => {
-                    seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))?;
+                    seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))
+                        .at_index(idx)?;
                 }
                 ObType::Fragment => {
                     seq.serialize_element(&FragmentSerializer::new(unsafe {
                         PyFragmentRef::from_ptr_unchecked(value)
-                    }))?;
+                    }))
+                    .at_index(idx)?;
+                }
+                ObType::EpochNanoseconds => {
+                    seq.serialize_element(&EpochNanosecondsSerializer::new(
+                        value,
+                        self.state.opts(),
+                    ))
+                    .at_index(idx)?;
+                }
+                ObType::Timedelta => {
+                    seq.serialize_element(&TimedeltaSerializer::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::Bytes => {
+                    seq.serialize_element(&BytesSerializer::new(value, self.state.opts()))
+                        .at_index(idx)?;
+                }
+                ObType::DefaultFirst => {
+                    seq.serialize_element(&DefaultFirstSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))
+                    .at_index(idx)?;
                 }
                 ObType::Unknown => {
                     seq.serialize_element(&DefaultSerializer::new(&PyObjectSerializer::new(
                         value,
                         self.state,
                         self.default,
-                    )))?;
+                    )))
+                    .at_index(idx)?;
                 }
             }
         }
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyDictRef;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::DictGenericSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::{DATA_STR, DICT_TYPE, KEYS_STR, NONE, WEAK_VALUE_DICTIONARY_TYPE};
+
+use serde::ser::{Serialize, Serializer};
+
+/// The layout of `types.MappingProxyType`, which is not in the limited API.
+#[repr(C)]
+struct MappingProxyObject {
+    ob_base: crate::ffi::PyObject,
+    mapping: *mut crate::ffi::PyObject,
+}
+
+#[inline(always)]
+fn is_mapping_proxy(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    core::ptr::eq(ob_type, &raw mut crate::ffi::PyDictProxy_Type)
+}
+
+/// Whether `obj` is a mapping, i.e., `types.MappingProxyType` or an object
+/// that passes `PyMapping_Check()` and has a `keys()` method. Subclasses of
+/// `str`, `list`, `tuple`, and `dict` are not.
+#[cold]
+#[inline(never)]
+pub(crate) fn is_mapping(
+    obj: *mut crate::ffi::PyObject,
+    ob_type: *mut crate::ffi::PyTypeObject,
+) -> bool {
+    if is_mapping_proxy(ob_type) {
+        return true;
+    }
+    let tp_flags = tp_flags!(ob_type);
+    if is_subclass_by_flag!(tp_flags, Py_TPFLAGS_UNICODE_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_LIST_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_TUPLE_SUBCLASS)
+        || is_subclass_by_flag!(tp_flags, Py_TPFLAGS_DICT_SUBCLASS)
+    {
+        return false;
+    }
+    ffi!(PyMapping_Check(obj)) == 1 && ffi!(PyObject_HasAttr(obj, KEYS_STR)) == 1
+}
+
+/// Serialize a mapping as an object. The `dict` underlying a
+/// `types.MappingProxyType` is serialized directly. A
+/// `weakref.WeakValueDictionary` is copied into a new `dict` of its live
+/// entries. Other mappings are copied into a new `dict` by calling `keys()`
+/// and getting each item, so that keys and values are referenced while
+/// serializing and options such as `OPT_SORT_KEYS` and `OPT_NON_STR_KEYS`
+/// apply as they do to a `dict`.
+#[repr(transparent)]
+pub(crate) struct MappingSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> MappingSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl MappingSerializer<'_> {
+    fn error(&self) -> SerializeError {
+        SerializeError::Mapping(nonnull!(self.previous.ptr))
+    }
+
+    /// The `dict` underlying a `types.MappingProxyType`, or null.
+    fn proxied_dict(&self) -> *mut crate::ffi::PyObject {
+        if is_mapping_proxy(ob_type!(self.previous.ptr)) {
+            let mapping = unsafe { (*self.previous.ptr.cast::<MappingProxyObject>()).mapping };
+            if is_class_by_type!(ob_type!(mapping), DICT_TYPE) {
+                return mapping;
+            }
+        }
+        core::ptr::null_mut()
+    }
+
+    /// Copy the entries of a `weakref.WeakValueDictionary` whose value is
+    /// alive into a new `dict`. Its `data` is a `dict` of key to
+    /// `weakref.KeyedRef` and entries whose value was collected may not yet
+    /// have been removed from it.
+    fn weak_values_to_dict(&self) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        let data = ffi!(PyObject_GetAttr(self.previous.ptr, DATA_STR));
+        if data.is_null() {
+            cold_path!();
+            return Err(self.error());
+        }
+        if !is_class_by_type!(ob_type!(data), DICT_TYPE) {
+            cold_path!();
+            ffi!(Py_DECREF(data));
+            return self.to_dict();
+        }
+        // the callback of a KeyedRef removes its entry when its value is
+        // collected, which may happen while iterating, so iterate a copy
+        let refs = ffi!(PyDict_Copy(data));
+        ffi!(Py_DECREF(data));
+        if refs.is_null() {
+            cold_path!();
+            return Err(self.error());
+        }
+        let dict = ffi!(PyDict_New(0));
+        let mut pos = 0;
+        let mut key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut weakref: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        while pydict_next!(refs, &raw mut pos, &raw mut key, &raw mut weakref) == 1 {
+            let value = unsafe {
+                crate::ffi::PyObject_Vectorcall(
+                    weakref,
+                    core::ptr::null(),
+                    0,
+                    core::ptr::null_mut(),
+                )
+            };
+            if value.is_null() {
+                cold_path!();
+                ffi!(Py_DECREF(dict));
+                ffi!(Py_DECREF(refs));
+                return Err(self.error());
+            }
+            // a dead reference returns None, which cannot be weakly referenced
+            let res = if core::ptr::eq(value, unsafe { NONE }) {
+                0
+            } else {
+                ffi!(PyDict_SetItem(dict, key, value))
+            };
+            ffi!(Py_DECREF(value));
+            if res != 0 {
+                cold_path!();
+                ffi!(Py_DECREF(dict));
+                ffi!(Py_DECREF(refs));
+                return Err(self.error());
+            }
+        }
+        ffi!(Py_DECREF(refs));
+        Ok(dict)
+    }
+
+    /// Copy the items of the mapping into a new `dict`.
+    fn to_dict(&self) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        let keys = ffi!(PyMapping_Keys(self.previous.ptr));
+        if keys.is_null() {
+            cold_path!();
+            return Err(self.error());
+        }
+        // keys is a new list, so iterating it directly is safe
+        let len = ffi!(Py_SIZE(keys));
+        let dict = ffi!(PyDict_New(len));
+        for idx in 0..len {
+            let key = ffi!(PyList_GET_ITEM(keys, idx));
+            let value = ffi!(PyObject_GetItem(self.previous.ptr, key));
+            if value.is_null() {
+                cold_path!();
+                ffi!(Py_DECREF(dict));
+                ffi!(Py_DECREF(keys));
+                return Err(self.error());
+            }
+            let res = ffi!(PyDict_SetItem(dict, key, value));
+            ffi!(Py_DECREF(value));
+            if res != 0 {
+                cold_path!();
+                ffi!(Py_DECREF(dict));
+                ffi!(Py_DECREF(keys));
+                return Err(self.error());
+            }
+        }
+        ffi!(Py_DECREF(keys));
+        Ok(dict)
+    }
+}
+
+impl Serialize for MappingSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut dict = self.proxied_dict();
+        if dict.is_null() {
+            let res = if is_class_by_type!(ob_type!(self.previous.ptr), WEAK_VALUE_DICTIONARY_TYPE)
+            {
+                self.weak_values_to_dict()
+            } else {
+                self.to_dict()
+            };
+            dict = match res {
+                Ok(dict) => dict,
+                Err(err) => err!(err),
+            };
+        } else {
+            ffi!(Py_INCREF(dict));
+        }
+        let ret = DictGenericSerializer::new(
+            unsafe { PyDictRef::from_ptr_unchecked(dict) },
+            self.previous.state,
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(dict));
+        ret
+    }
+}
@@ -1,35 +1,61 @@
 // SPDX-License-Identifier: (Apache-2.0 OR MIT)
-// Copyright ijl (2020-2025), Ben Sully (2021)
+// Copyright ijl (2020-2026), Ben Sully (2021)
 
+mod annotated;
+mod bytes;
 mod dataclass;
 mod datetime;
 mod pybool;
+mod pydantic;
 #[macro_use]
 mod datetimelike;
 mod default;
 mod dict;
+mod epochnanoseconds;
 mod float;
+mod fraction;
 mod fragment;
 mod int;
+mod iterator;
+mod lengthtagged;
 mod list;
+mod mapping;
+mod namedtuple;
 mod none;
 mod numpy;
 mod pyenum;
+mod range;
+mod timedelta;
 mod unicode;
 mod uuid;
+mod weakref;
 
+pub(crate) use annotated::AnnotatedSerializer;
+pub(crate) use bytes::{BytesSerializer, bytes_key_to_string, is_bytes_like, write_base64};
 pub(crate) use dataclass::DataclassGenericSerializer;
-pub(crate) use datetime::{Date, DateTime, Time};
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) use dataclass::{DATACLASS_FIELDS_MAP, DataclassFieldsMap};
+pub(crate) use datetime::{Date, DateTime, DatetimeFormatSerializer, Time};
 pub(crate) use datetimelike::{DateTimeError, DateTimeLike, Offset};
-pub(crate) use default::DefaultSerializer;
+pub(crate) use default::{DefaultFirstSerializer, DefaultSerializer};
 pub(crate) use dict::DictGenericSerializer;
+pub(crate) use epochnanoseconds::EpochNanosecondsSerializer;
 pub(crate) use float::FloatSerializer;
+pub(crate) use fraction::{FractionSerializer, is_fraction};
 pub(crate) use fragment::FragmentSerializer;
 pub(crate) use int::IntSerializer;
+pub(crate) use iterator::{IteratorSerializer, is_iterator};
+pub(crate) use lengthtagged::LengthTaggedSerializer;
 pub(crate) use list::{ListTupleSerializer, ZeroListSerializer};
+pub(crate) use mapping::{MappingSerializer, is_mapping};
+pub(crate) use namedtuple::NamedTupleSerializer;
 pub(crate) use none::NoneSerializer;
 pub(crate) use numpy::{NumpyScalar, NumpySerializer, is_numpy_array, is_numpy_scalar};
 pub(crate) use pybool::BoolSerializer;
-pub(crate) use pyenum::EnumSerializer;
+pub(crate) use pydantic::{PydanticSerializer, is_pydantic_model};
+pub(crate) use pyenum::{EnumSerializer, is_enum_type, is_flag_as_int};
+pub(crate) use range::{RangeSerializer, is_range};
+pub(crate) use timedelta::TimedeltaSerializer;
 pub(crate) use unicode::{StrSerializer, StrSubclassSerializer};
 pub(crate) use uuid::UUID;
+pub(crate) use weakref::{WeakrefSerializer, is_weakref_proxy};
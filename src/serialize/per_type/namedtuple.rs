@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyStrRef;
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::per_type::dict::ZeroDictSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::SerializerState;
+use crate::typeref::{NAMEDTUPLE_FIELDS_STR, STR_TYPE, TUPLE_TYPE};
+use crate::util::isize_to_usize;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use core::ptr::NonNull;
+
+/// Serialize a `tuple` subclass with `_fields`, e.g., from
+/// `collections.namedtuple()` or `typing.NamedTuple`, as an object mapping
+/// each field name to the item at the same index.
+pub(crate) struct NamedTupleSerializer {
+    ptr: *mut crate::ffi::PyObject,
+    state: SerializerState,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+}
+
+impl NamedTupleSerializer {
+    pub fn new(
+        ptr: *mut crate::ffi::PyObject,
+        state: SerializerState,
+        default: Option<NonNull<crate::ffi::PyObject>>,
+    ) -> Self {
+        debug_assert!(is_subclass_by_flag!(
+            tp_flags!(ob_type!(ptr)),
+            Py_TPFLAGS_TUPLE_SUBCLASS
+        ));
+        NamedTupleSerializer {
+            ptr: ptr,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for NamedTupleSerializer {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let fields = ffi!(PyObject_GetAttr(
+            ob_type!(self.ptr).cast::<crate::ffi::PyObject>(),
+            NAMEDTUPLE_FIELDS_STR
+        ));
+        if fields.is_null() {
+            cold_path!();
+            ffi!(PyErr_Clear());
+            err!(SerializeError::NamedTupleFields)
+        }
+        // _fields is immutable and kept alive by the type
+        debug_assert!(ffi!(Py_REFCNT(fields)) >= 2);
+        ffi!(Py_DECREF(fields));
+
+        let len = isize_to_usize(ffi!(Py_SIZE(self.ptr)));
+        if !is_class_by_type!(ob_type!(fields), TUPLE_TYPE)
+            || isize_to_usize(ffi!(Py_SIZE(fields))) != len
+        {
+            cold_path!();
+            err!(SerializeError::NamedTupleFields)
+        }
+        if len == 0 {
+            cold_path!();
+            return ZeroDictSerializer::new().serialize(serializer);
+        }
+
+        let keys_ptr = unsafe {
+            (*fields.cast::<crate::ffi::PyTupleObject>())
+                .ob_item
+                .as_ptr()
+        };
+        let values_ptr = unsafe {
+            (*self.ptr.cast::<crate::ffi::PyTupleObject>())
+                .ob_item
+                .as_ptr()
+        };
+
+        let mut map = serializer.serialize_map(None).unwrap();
+        for idx in 0..len {
+            let key = unsafe { *keys_ptr.add(idx) };
+            let value = unsafe { *values_ptr.add(idx) };
+            if !is_class_by_type!(ob_type!(key), STR_TYPE) {
+                cold_path!();
+                err!(SerializeError::KeyMustBeStr)
+            }
+            let key_as_str = match unsafe { PyStrRef::from_ptr_unchecked(key).as_str() } {
+                Some(uni) => uni,
+                None => err!(SerializeError::InvalidStr),
+            };
+            let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
+            map.serialize_key(key_as_str).unwrap();
+            map.serialize_value(&pyvalue).at_key(key_as_str)?;
+        }
+        map.end()
+    }
+}
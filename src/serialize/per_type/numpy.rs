@@ -4,20 +4,28 @@
 use crate::ffi::{
     Py_intptr_t, PyListRef, PyObject, PyStrRef, PyTupleRef, PyTypeObject,
 };
-use crate::opt::Opt;
+use crate::opt::{NUMPY_BASE64, NUMPY_SHORTEST_FLOAT, Opt};
 use crate::serialize::buffer::SmallFixedBuffer;
 use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
 use crate::serialize::per_type::{
-    DateTimeError, DateTimeLike, DefaultSerializer, Offset, ZeroListSerializer,
+    DateTimeError, DateTimeLike, DefaultSerializer, NoneSerializer, Offset, ZeroListSerializer,
+    write_base64,
 };
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::typeref::{ARRAY_STRUCT_STR, DESCR_STR, DTYPE_STR, NUMPY_TYPES, load_numpy_types};
+use crate::serialize::state::SerializerState;
+use crate::typeref::{
+    ARRAY_STRUCT_STR, DESCR_STR, DTYPE_STR, FIELDS_STR, MASK_STR, NAMES_STR, NUMPY_TYPES,
+    STR_ATTR_STR, TUPLE_TYPE, load_numpy_types,
+};
 use crate::util::isize_to_usize;
 use core::ffi::{c_char, c_int, c_void};
+use core::ptr::NonNull;
 use jiff::Timestamp;
 use jiff::civil::DateTime;
-use serde::ser::{self, Serialize, SerializeSeq, Serializer};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, Serializer};
 use std::fmt;
+use std::rc::Rc;
 
 #[repr(transparent)]
 pub(crate) struct NumpySerializer<'a> {
@@ -38,23 +46,42 @@ impl Serialize for NumpySerializer<'_> {
     where
         S: Serializer,
     {
-        match NumpyArray::new(self.previous.ptr, self.previous.state.opts()) {
-            Ok(val) => val.serialize(serializer),
-            Err(PyArrayError::Malformed) => err!(SerializeError::NumpyMalformed),
-            Err(PyArrayError::NotContiguous | PyArrayError::UnsupportedDataType)
+        let error = if opt_enabled!(self.previous.state.opts(), NUMPY_BASE64) {
+            match NumpyBase64Array::new(self.previous.ptr) {
+                Ok(val) => return val.serialize(serializer),
+                Err(err) => err,
+            }
+        } else {
+            match NumpyArray::new(
+                self.previous.ptr,
+                self.previous.state,
+                self.previous.default,
+            ) {
+                Ok(val) => return val.serialize(serializer),
+                Err(err) => err,
+            }
+        };
+        match error {
+            PyArrayError::Malformed => err!(SerializeError::NumpyMalformed),
+            PyArrayError::NotContiguous | PyArrayError::UnsupportedDataType
                 if self.previous.default.is_some() =>
             {
                 DefaultSerializer::new(self.previous).serialize(serializer)
             }
-            Err(PyArrayError::NotContiguous) => {
+            PyArrayError::NotContiguous => {
                 err!(SerializeError::NumpyNotCContiguous)
             }
-            Err(PyArrayError::NotNativeEndian) => {
+            PyArrayError::NotNativeEndian => {
                 err!(SerializeError::NumpyNotNativeEndian)
             }
-            Err(PyArrayError::UnsupportedDataType) => {
+            PyArrayError::UnsupportedDataType => {
                 err!(SerializeError::NumpyUnsupportedDatatype)
             }
+            PyArrayError::SequenceTooLong => {
+                err!(SerializeError::SequenceTooLong(
+                    self.previous.state.max_sequence_len()
+                ))
+            }
         }
     }
 }
@@ -95,7 +122,18 @@ pub(crate) fn is_numpy_array(ob_type: *mut PyTypeObject) -> bool {
         false
     } else {
         let scalar_types = unsafe { numpy_types.unwrap().as_ref() };
-        unsafe { core::ptr::eq(ob_type, scalar_types.array) }
+        core::ptr::eq(ob_type, scalar_types.array)
+            || core::ptr::eq(ob_type, scalar_types.recarray)
+            || core::ptr::eq(ob_type, scalar_types.masked_array)
+    }
+}
+
+#[cold]
+fn is_numpy_masked_array(ob_type: *mut PyTypeObject) -> bool {
+    let numpy_types = unsafe { NUMPY_TYPES.get_or_init(load_numpy_types) };
+    match numpy_types {
+        Some(numpy_types) => core::ptr::eq(ob_type, unsafe { numpy_types.as_ref() }.masked_array),
+        None => false,
     }
 }
 
@@ -113,6 +151,10 @@ pub(crate) struct PyCapsule {
 const NPY_ARRAY_C_CONTIGUOUS: c_int = 0x1;
 const NPY_ARRAY_NOTSWAPPED: c_int = 0x200;
 
+// the itemsize of `dtype=object`, whose items are pointers
+#[allow(clippy::cast_possible_wrap)]
+const NPY_OBJECT_ITEMSIZE: c_int = size_of::<*mut PyObject>() as c_int;
+
 #[repr(C)]
 pub(crate) struct PyArrayInterface {
     pub two: c_int,
@@ -126,6 +168,95 @@ pub(crate) struct PyArrayInterface {
     pub descr: *mut PyObject,
 }
 
+/// The mask of a `numpy.ma.MaskedArray`. A mask with no element set is
+/// `None` and a mask with every element set is `All`, so that only a
+/// `Partial` mask is read while serializing.
+#[derive(Clone, Copy)]
+enum NumpyMask {
+    None,
+    All,
+    Partial(*mut PyCapsule),
+}
+
+impl NumpyMask {
+    /// Read `_mask` of a masked array. It is either `numpy.ma.nomask`, a
+    /// `numpy.bool_`, or a C-contiguous `bool` array of the same shape as
+    /// the data. A structured mask, i.e., of a structured array, is
+    /// unsupported.
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn new(ptr: *mut PyObject, array: *mut PyArrayInterface) -> Result<Self, PyArrayError> {
+        let mask = ffi!(PyObject_GetAttr(ptr, MASK_STR));
+        if mask.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        if is_numpy_scalar(ob_type!(mask)) {
+            let is_true = ffi!(PyObject_IsTrue(mask));
+            ffi!(Py_DECREF(mask));
+            return match is_true {
+                0 => Ok(NumpyMask::None),
+                1 => Ok(NumpyMask::All),
+                _ => {
+                    ffi!(PyErr_Clear());
+                    Err(PyArrayError::Malformed)
+                }
+            };
+        }
+        let capsule = ffi!(PyObject_GetAttr(mask, ARRAY_STRUCT_STR));
+        // the capsule holds a reference to the mask
+        ffi!(Py_DECREF(mask));
+        if capsule.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        let mask_array = unsafe {
+            (*capsule.cast::<PyCapsule>())
+                .pointer
+                .cast::<PyArrayInterface>()
+        };
+        let res = unsafe {
+            if (*mask_array).two != 2 || (*mask_array).nd != (*array).nd {
+                Err(PyArrayError::Malformed)
+            } else if ((*mask_array).typekind, (*mask_array).itemsize) != (098, 1) {
+                Err(PyArrayError::UnsupportedDataType)
+            } else if (*mask_array).flags & NPY_ARRAY_C_CONTIGUOUS != NPY_ARRAY_C_CONTIGUOUS {
+                Err(PyArrayError::NotContiguous)
+            } else {
+                let num_dimensions = (*array).nd.cast_unsigned() as usize;
+                let shape = slice!((*array).shape.cast_const(), num_dimensions);
+                if shape != slice!((*mask_array).shape.cast_const(), num_dimensions) {
+                    Err(PyArrayError::Malformed)
+                } else {
+                    let len = shape.iter().map(|&each| isize_to_usize(each)).product();
+                    let mask_data = slice!((*mask_array).data.cast::<u8>().cast_const(), len);
+                    if mask_data.iter().all(|&each| each == 0) {
+                        Ok(NumpyMask::None)
+                    } else if mask_data.iter().all(|&each| each != 0) {
+                        Ok(NumpyMask::All)
+                    } else {
+                        Ok(NumpyMask::Partial(capsule.cast::<PyCapsule>()))
+                    }
+                }
+            }
+        };
+        if !matches!(res, Ok(NumpyMask::Partial(_))) {
+            ffi!(Py_DECREF(capsule));
+        }
+        res
+    }
+
+    fn array(self) -> Option<*mut PyArrayInterface> {
+        match self {
+            NumpyMask::Partial(capsule) => {
+                Some(unsafe { (*capsule).pointer.cast::<PyArrayInterface>() })
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum ItemType {
     BOOL,
@@ -137,6 +268,8 @@ pub(crate) enum ItemType {
     I16,
     I32,
     I64,
+    OBJECT,
+    RECORD,
     U8,
     U16,
     U32,
@@ -158,6 +291,8 @@ impl ItemType {
             (105, 2) => Some(ItemType::I16),
             (105, 4) => Some(ItemType::I32),
             (105, 8) => Some(ItemType::I64),
+            (079, NPY_OBJECT_ITEMSIZE) => Some(ItemType::OBJECT),
+            (086, _) => Some(ItemType::RECORD),
             (117, 1) => Some(ItemType::U8),
             (117, 2) => Some(ItemType::U16),
             (117, 4) => Some(ItemType::U32),
@@ -165,6 +300,271 @@ impl ItemType {
             _ => None,
         }
     }
+
+    /// Find the type of a field of a structured dtype from its type string,
+    /// e.g., `<f8`, `|b1`, `|O`, or `<M8[us]`.
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_typestr(typestr: &str) -> Result<ItemType, PyArrayError> {
+        let (byteorder, kind, itemsize) = match typestr.as_bytes() {
+            [byteorder, kind, rest @ ..] => {
+                let digits = rest.split(|&each| each == b'[').next().unwrap_or(&[]);
+                let itemsize = str_from_slice!(digits.as_ptr(), digits.len())
+                    .parse::<usize>()
+                    .unwrap_or(size_of::<*mut PyObject>());
+                (*byteorder, *kind, itemsize)
+            }
+            _ => return Err(PyArrayError::UnsupportedDataType),
+        };
+        #[cfg(target_endian = "little")]
+        let swapped = byteorder == b'>';
+        #[cfg(target_endian = "big")]
+        let swapped = byteorder == b'<';
+        if swapped {
+            return Err(PyArrayError::NotNativeEndian);
+        }
+        match (kind, itemsize) {
+            (b'b', 1) => Ok(ItemType::BOOL),
+            (b'M', 8) => Ok(ItemType::DATETIME64(NumpyDatetimeUnit::from_typestr(
+                typestr,
+            ))),
+            (b'f', 2) => Ok(ItemType::F16),
+            (b'f', 4) => Ok(ItemType::F32),
+            (b'f', 8) => Ok(ItemType::F64),
+            (b'i', 1) => Ok(ItemType::I8),
+            (b'i', 2) => Ok(ItemType::I16),
+            (b'i', 4) => Ok(ItemType::I32),
+            (b'i', 8) => Ok(ItemType::I64),
+            (b'O', itemsize) if itemsize == size_of::<*mut PyObject>() => Ok(ItemType::OBJECT),
+            (b'V', _) => Ok(ItemType::RECORD),
+            (b'u', 1) => Ok(ItemType::U8),
+            (b'u', 2) => Ok(ItemType::U16),
+            (b'u', 4) => Ok(ItemType::U32),
+            (b'u', 8) => Ok(ItemType::U64),
+            _ => Err(PyArrayError::UnsupportedDataType),
+        }
+    }
+}
+
+/// A field of a structured dtype, located by its offset in each record.
+/// `fields` is empty unless `kind` is `ItemType::RECORD`.
+pub(crate) struct NumpyRecordField {
+    name: String,
+    offset: usize,
+    kind: ItemType,
+    fields: Vec<NumpyRecordField>,
+}
+
+impl NumpyRecordField {
+    /// Read the fields of a structured dtype in the order of `dtype.names`
+    /// using the offsets in `dtype.fields`, recursing into nested structured
+    /// dtypes. A dtype without `names`, e.g., a subarray, is unsupported.
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_dtype(dtype: *mut PyObject) -> Result<Vec<NumpyRecordField>, PyArrayError> {
+        let names = ffi!(PyObject_GetAttr(dtype, NAMES_STR));
+        if names.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::UnsupportedDataType);
+        }
+        if !is_class_by_type!(ob_type!(names), TUPLE_TYPE) {
+            ffi!(Py_DECREF(names));
+            return Err(PyArrayError::UnsupportedDataType);
+        }
+        let fields = ffi!(PyObject_GetAttr(dtype, FIELDS_STR));
+        if fields.is_null() {
+            ffi!(PyErr_Clear());
+            ffi!(Py_DECREF(names));
+            return Err(PyArrayError::UnsupportedDataType);
+        }
+        let names_tuple = unsafe { PyTupleRef::from_ptr_unchecked(names) };
+        let mut ret = Vec::with_capacity(names_tuple.len());
+        let mut res = Ok(());
+        for idx in 0..names_tuple.len() {
+            match Self::from_field(names_tuple.get(idx), fields) {
+                Ok(field) => ret.push(field),
+                Err(err) => {
+                    res = Err(err);
+                    break;
+                }
+            }
+        }
+        ffi!(Py_DECREF(fields));
+        ffi!(Py_DECREF(names));
+        res.map(|()| ret)
+    }
+
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_field(
+        name: *mut PyObject,
+        fields: *mut PyObject,
+    ) -> Result<NumpyRecordField, PyArrayError> {
+        let name_str = match PyStrRef::from_ptr(name).ok().and_then(|uni| uni.as_str()) {
+            Some(uni) => String::from(uni),
+            None => return Err(PyArrayError::UnsupportedDataType),
+        };
+        // (dtype, offset) or (dtype, offset, title)
+        let field = ffi!(PyObject_GetItem(fields, name));
+        if field.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        let field_tuple = unsafe { PyTupleRef::from_ptr_unchecked(field) };
+        let field_dtype = field_tuple.get(0);
+        let offset = ffi!(PyLong_AsLongLong(field_tuple.get(1)));
+        let typestr = ffi!(PyObject_GetAttr(field_dtype, STR_ATTR_STR));
+        let res = if offset < 0 || typestr.is_null() {
+            ffi!(PyErr_Clear());
+            Err(PyArrayError::Malformed)
+        } else {
+            match PyStrRef::from_ptr(typestr)
+                .ok()
+                .and_then(|uni| uni.as_str())
+            {
+                Some(as_str) => ItemType::from_typestr(as_str),
+                None => Err(PyArrayError::Malformed),
+            }
+        };
+        let res = match res {
+            Ok(ItemType::RECORD) => {
+                Self::from_dtype(field_dtype).map(|nested| (ItemType::RECORD, nested))
+            }
+            Ok(kind) => Ok((kind, Vec::new())),
+            Err(err) => Err(err),
+        };
+        if !typestr.is_null() {
+            ffi!(Py_DECREF(typestr));
+        }
+        ffi!(Py_DECREF(field));
+        let (kind, nested) = res?;
+        Ok(NumpyRecordField {
+            name: name_str,
+            offset: offset.cast_unsigned() as usize,
+            kind: kind,
+            fields: nested,
+        })
+    }
+}
+
+/// A `numpy.ndarray` serialized with `OPT_NUMPY_BASE64` as an object of its
+/// `dtype.str`, e.g., `<f8` or `>i4`, its shape, and the base64 of its data.
+/// The byte order is in the `dtype.str`, so an array not in the native
+/// endianness is supported and reconstructed exactly by
+/// `numpy.frombuffer(base64.b64decode(data), dtype).reshape(shape)`.
+pub(crate) struct NumpyBase64Array {
+    capsule: *mut PyCapsule,
+    dtype: String,
+}
+
+impl NumpyBase64Array {
+    /// The array must be C-contiguous. An array of `dtype=object` holds
+    /// pointers, the `dtype.str` of a structured dtype does not have its
+    /// fields, and the mask of a `numpy.ma.MaskedArray` is not in its data, so
+    /// they are unsupported.
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    pub fn new(ptr: *mut PyObject) -> Result<Self, PyArrayError> {
+        if is_numpy_masked_array(ob_type!(ptr)) {
+            return Err(PyArrayError::UnsupportedDataType);
+        }
+        let capsule = ffi!(PyObject_GetAttr(ptr, ARRAY_STRUCT_STR));
+        debug_assert!(!capsule.is_null());
+        let array = unsafe {
+            (*capsule.cast::<PyCapsule>())
+                .pointer
+                .cast::<PyArrayInterface>()
+        };
+        debug_assert!(!array.is_null());
+        let res = if unsafe { (*array).two != 2 } {
+            Err(PyArrayError::Malformed)
+        } else if unsafe { (*array).flags } & NPY_ARRAY_C_CONTIGUOUS != NPY_ARRAY_C_CONTIGUOUS {
+            Err(PyArrayError::NotContiguous)
+        } else if matches!(unsafe { (*array).typekind }, 079 | 086) {
+            Err(PyArrayError::UnsupportedDataType)
+        } else {
+            Self::dtype_str(ptr)
+        };
+        match res {
+            Ok(dtype) => Ok(NumpyBase64Array {
+                capsule: capsule.cast::<PyCapsule>(),
+                dtype: dtype,
+            }),
+            Err(err) => {
+                ffi!(Py_DECREF(capsule));
+                Err(err)
+            }
+        }
+    }
+
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn dtype_str(ptr: *mut PyObject) -> Result<String, PyArrayError> {
+        let dtype = ffi!(PyObject_GetAttr(ptr, DTYPE_STR));
+        if dtype.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        let typestr = ffi!(PyObject_GetAttr(dtype, STR_ATTR_STR));
+        ffi!(Py_DECREF(dtype));
+        if typestr.is_null() {
+            ffi!(PyErr_Clear());
+            return Err(PyArrayError::Malformed);
+        }
+        let res = match PyStrRef::from_ptr(typestr)
+            .ok()
+            .and_then(|uni| uni.as_str())
+        {
+            Some(as_str) => Ok(String::from(as_str)),
+            None => Err(PyArrayError::Malformed),
+        };
+        ffi!(Py_DECREF(typestr));
+        res
+    }
+}
+
+impl Drop for NumpyBase64Array {
+    fn drop(&mut self) {
+        ffi!(Py_DECREF(self.capsule.cast::<PyObject>()));
+    }
+}
+
+impl Serialize for NumpyBase64Array {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let array = unsafe { (*self.capsule).pointer.cast::<PyArrayInterface>() };
+        let shape = slice!(
+            (*array).shape.cast_const(),
+            (*array).nd.cast_unsigned() as usize
+        );
+        let len = shape
+            .iter()
+            .map(|&each| isize_to_usize(each))
+            .product::<usize>()
+            * unsafe { (*array).itemsize.cast_unsigned() as usize };
+        let mut buf: Vec<u8> = Vec::new();
+        if len > 0 {
+            write_base64(
+                slice!((*array).data.cast::<u8>().cast_const(), len),
+                &mut buf,
+            );
+        }
+        let mut map = serializer.serialize_map(None).unwrap();
+        map.serialize_key("dtype").unwrap();
+        map.serialize_value(self.dtype.as_str())?;
+        map.serialize_key("shape").unwrap();
+        map.serialize_value(shape)?;
+        map.serialize_key("data").unwrap();
+        map.serialize_value(str_from_slice!(buf.as_ptr(), buf.len()))?;
+        map.end()
+    }
 }
 
 pub(crate) enum PyArrayError {
@@ -172,6 +572,7 @@ pub(crate) enum PyArrayError {
     NotContiguous,
     NotNativeEndian,
     UnsupportedDataType,
+    SequenceTooLong,
 }
 
 // >>> arr = numpy.array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]], numpy.int32)
@@ -188,14 +589,21 @@ pub(crate) struct NumpyArray {
     depth: usize,
     capsule: *mut PyCapsule,
     kind: ItemType,
-    opts: Opt,
+    fields: Option<Rc<[NumpyRecordField]>>,
+    mask: NumpyMask,
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
 }
 
 impl NumpyArray {
     #[cold]
     #[inline(never)]
     #[cfg_attr(feature = "optimize", optimize(size))]
-    pub fn new(ptr: *mut PyObject, opts: Opt) -> Result<Self, PyArrayError> {
+    pub fn new(
+        ptr: *mut PyObject,
+        state: SerializerState,
+        default: Option<NonNull<PyObject>>,
+    ) -> Result<Self, PyArrayError> {
         let capsule = ffi!(PyObject_GetAttr(ptr, ARRAY_STRUCT_STR));
         debug_assert!(!capsule.is_null());
         let array = unsafe {
@@ -213,19 +621,50 @@ impl NumpyArray {
         } else if unsafe { (*array).flags } & NPY_ARRAY_NOTSWAPPED != NPY_ARRAY_NOTSWAPPED {
             ffi!(Py_DECREF(capsule));
             Err(PyArrayError::NotNativeEndian)
+        } else if slice!(
+            (*array).shape.cast_const(),
+            (*array).nd.cast_unsigned() as usize
+        )
+        .iter()
+        .any(|&each| isize_to_usize(each) > state.max_sequence_len())
+        {
+            ffi!(Py_DECREF(capsule));
+            Err(PyArrayError::SequenceTooLong)
         } else {
             debug_assert!(unsafe { (*array).nd >= 0 });
             let num_dimensions = unsafe { (*array).nd.cast_unsigned() as usize };
-            if num_dimensions == 0 {
-                ffi!(Py_DECREF(capsule));
-                return Err(PyArrayError::UnsupportedDataType);
-            }
             match ItemType::find(array, ptr) {
                 None => {
                     ffi!(Py_DECREF(capsule));
                     Err(PyArrayError::UnsupportedDataType)
                 }
                 Some(kind) => {
+                    let fields = match kind {
+                        ItemType::RECORD => {
+                            let dtype = ffi!(PyObject_GetAttr(ptr, DTYPE_STR));
+                            let fields = NumpyRecordField::from_dtype(dtype);
+                            ffi!(Py_DECREF(dtype));
+                            match fields {
+                                Ok(fields) => Some(Rc::from(fields)),
+                                Err(err) => {
+                                    ffi!(Py_DECREF(capsule));
+                                    return Err(err);
+                                }
+                            }
+                        }
+                        _ => None,
+                    };
+                    let mask = if is_numpy_masked_array(ob_type!(ptr)) {
+                        match NumpyMask::new(ptr, array) {
+                            Ok(mask) => mask,
+                            Err(err) => {
+                                ffi!(Py_DECREF(capsule));
+                                return Err(err);
+                            }
+                        }
+                    } else {
+                        NumpyMask::None
+                    };
                     let mut pyarray = NumpyArray {
                         array: array,
                         position: vec![0; num_dimensions],
@@ -233,7 +672,10 @@ impl NumpyArray {
                         depth: 0,
                         capsule: capsule.cast::<PyCapsule>(),
                         kind: kind,
-                        opts,
+                        fields: fields,
+                        mask: mask,
+                        state: state,
+                        default: default,
                     };
                     if pyarray.dimensions() > 1 {
                         pyarray.build();
@@ -253,7 +695,10 @@ impl NumpyArray {
             depth: self.depth + 1,
             capsule: self.capsule,
             kind: self.kind,
-            opts: self.opts,
+            fields: self.fields.clone(),
+            mask: self.mask,
+            state: self.state,
+            default: self.default,
         };
         arr.build();
         arr
@@ -277,15 +722,36 @@ impl NumpyArray {
     }
 
     #[inline(always)]
-    fn data(&self) -> *const c_void {
-        let offset = self
-            .strides()
+    fn offset(&self, strides: &[isize]) -> isize {
+        strides
             .iter()
             .zip(self.position.iter().copied())
             .take(self.depth)
             .map(|(a, b)| a * b)
-            .sum::<isize>();
-        unsafe { (*self.array).data.offset(offset) }
+            .sum::<isize>()
+    }
+
+    #[inline(always)]
+    fn data(&self) -> *const c_void {
+        unsafe { (*self.array).data.offset(self.offset(self.strides())) }
+    }
+
+    /// The mask of the items of `data()`, if only some items of a masked
+    /// array are masked.
+    fn mask_data(&self) -> Option<&[u8]> {
+        self.mask.array().map(|mask| {
+            let strides = slice!((*mask).strides.cast_const(), self.dimensions());
+            slice!(
+                unsafe { (*mask).data.offset(self.offset(strides)) }
+                    .cast::<u8>()
+                    .cast_const(),
+                self.num_items()
+            )
+        })
+    }
+
+    fn itemsize(&self) -> usize {
+        unsafe { (*self.array).itemsize.cast_unsigned() as usize }
     }
 
     fn num_items(&self) -> usize {
@@ -309,6 +775,9 @@ impl Drop for NumpyArray {
     fn drop(&mut self) {
         if self.depth == 0 {
             ffi!(Py_DECREF(self.capsule.cast::<PyObject>()));
+            if let NumpyMask::Partial(capsule) = self.mask {
+                ffi!(Py_DECREF(capsule.cast::<PyObject>()));
+            }
         }
     }
 }
@@ -321,16 +790,54 @@ impl Serialize for NumpyArray {
     where
         S: Serializer,
     {
-        if !(self.depth >= self.dimensions() || self.shape()[self.depth] != 0) {
+        if self.dimensions() == 0 {
+            cold_path!();
+            // a 0-dimensional array, e.g., `numpy.array(5)`, is its item
+            if matches!(self.mask, NumpyMask::All) {
+                return NoneSerializer::new().serialize(serializer);
+            }
+            let state = self.state.copy_for_recursive_call();
+            if state.recursion_limit() {
+                cold_path!();
+                err!(SerializeError::RecursionLimit)
+            }
+            NumpyItem {
+                data: slice!(self.data().cast::<u8>(), self.itemsize()),
+                kind: self.kind,
+                fields: self.fields.as_deref().unwrap_or(&[]),
+                state: state,
+                default: self.default,
+            }
+            .serialize(serializer)
+        } else if !(self.depth >= self.dimensions() || self.shape()[self.depth] != 0) {
             cold_path!();
             ZeroListSerializer::new().serialize(serializer)
         } else if !self.children.is_empty() {
             cold_path!();
             let mut seq = serializer.serialize_seq(None).unwrap();
-            for child in &self.children {
-                seq.serialize_element(child).unwrap();
+            for (idx, child) in self.children.iter().enumerate() {
+                seq.serialize_element(child).at_index(idx)?;
+            }
+            seq.end()
+        } else if matches!(self.mask, NumpyMask::All) {
+            cold_path!();
+            let mut seq = serializer.serialize_seq(None).unwrap();
+            for _ in 0..self.num_items() {
+                seq.serialize_element(&NoneSerializer::new()).unwrap();
             }
             seq.end()
+        } else if let Some(mask) = self.mask_data() {
+            cold_path!();
+            NumpyMaskedArray::new(
+                slice!(self.data().cast::<u8>(), self.num_items() * self.itemsize()),
+                mask,
+                self.itemsize(),
+                self.kind,
+                self.fields.as_deref().unwrap_or(&[]),
+                self.state,
+                self.default,
+            )
+            .serialize(serializer)
         } else {
             match self.kind {
                 ItemType::F64 => {
@@ -341,10 +848,11 @@ impl Serialize for NumpyArray {
                     NumpyF32Array::new(slice!(self.data().cast::<f32>(), self.num_items()))
                         .serialize(serializer)
                 }
-                ItemType::F16 => {
-                    NumpyF16Array::new(slice!(self.data().cast::<u16>(), self.num_items()))
-                        .serialize(serializer)
-                }
+                ItemType::F16 => NumpyF16Array::new(
+                    slice!(self.data().cast::<u16>(), self.num_items()),
+                    opt_enabled!(self.state.opts(), NUMPY_SHORTEST_FLOAT),
+                )
+                .serialize(serializer),
                 ItemType::U64 => {
                     NumpyU64Array::new(slice!(self.data().cast::<u64>(), self.num_items()))
                         .serialize(serializer)
@@ -384,7 +892,22 @@ impl Serialize for NumpyArray {
                 ItemType::DATETIME64(unit) => NumpyDatetime64Array::new(
                     slice!(self.data().cast::<i64>(), self.num_items()),
                     unit,
-                    self.opts,
+                    self.state.opts(),
+                )
+                .serialize(serializer),
+                ItemType::OBJECT => NumpyObjectArray::new(
+                    slice!(self.data().cast::<*mut PyObject>(), self.num_items()),
+                    self.state,
+                    self.default,
+                )
+                .serialize(serializer),
+                ItemType::RECORD => NumpyRecordArray::new(
+                    slice!(self.data().cast::<u8>(), self.num_items() * self.itemsize()),
+                    self.num_items(),
+                    self.itemsize(),
+                    self.fields.as_deref().unwrap_or(&[]),
+                    self.state,
+                    self.default,
                 )
                 .serialize(serializer),
             }
@@ -392,6 +915,298 @@ impl Serialize for NumpyArray {
     }
 }
 
+struct NumpyObjectArray<'a> {
+    data: &'a [*mut PyObject],
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
+}
+
+impl<'a> NumpyObjectArray<'a> {
+    fn new(
+        data: &'a [*mut PyObject],
+        state: SerializerState,
+        default: Option<NonNull<PyObject>>,
+    ) -> Self {
+        Self {
+            data: data,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for NumpyObjectArray<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for (idx, &each) in self.data.iter().enumerate() {
+            debug_assert!(!each.is_null());
+            seq.serialize_element(&PyObjectSerializer::new(each, self.state, self.default))
+                .at_index(idx)?;
+        }
+        seq.end()
+    }
+}
+
+struct NumpyRecordArray<'a> {
+    data: &'a [u8],
+    len: usize,
+    itemsize: usize,
+    fields: &'a [NumpyRecordField],
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
+}
+
+impl<'a> NumpyRecordArray<'a> {
+    fn new(
+        data: &'a [u8],
+        len: usize,
+        itemsize: usize,
+        fields: &'a [NumpyRecordField],
+        state: SerializerState,
+        default: Option<NonNull<PyObject>>,
+    ) -> Self {
+        Self {
+            data: data,
+            len: len,
+            itemsize: itemsize,
+            fields: fields,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for NumpyRecordArray<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        // a dtype without fields has an itemsize of 0
+        for idx in 0..self.len {
+            seq.serialize_element(&NumpyRecord {
+                data: &self.data[idx * self.itemsize..(idx + 1) * self.itemsize],
+                fields: self.fields,
+                state: self.state,
+                default: self.default,
+            })
+            .at_index(idx)?;
+        }
+        seq.end()
+    }
+}
+
+/// Items of a masked array with a `Partial` mask, as `null` if masked.
+struct NumpyMaskedArray<'a> {
+    data: &'a [u8],
+    mask: &'a [u8],
+    itemsize: usize,
+    kind: ItemType,
+    fields: &'a [NumpyRecordField],
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
+}
+
+impl<'a> NumpyMaskedArray<'a> {
+    fn new(
+        data: &'a [u8],
+        mask: &'a [u8],
+        itemsize: usize,
+        kind: ItemType,
+        fields: &'a [NumpyRecordField],
+        state: SerializerState,
+        default: Option<NonNull<PyObject>>,
+    ) -> Self {
+        Self {
+            data: data,
+            mask: mask,
+            itemsize: itemsize,
+            kind: kind,
+            fields: fields,
+            state: state.copy_for_recursive_call(),
+            default: default,
+        }
+    }
+}
+
+impl Serialize for NumpyMaskedArray<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let mut seq = serializer.serialize_seq(None).unwrap();
+        for (idx, &masked) in self.mask.iter().enumerate() {
+            if masked == 0 {
+                seq.serialize_element(&NumpyItem {
+                    data: &self.data[idx * self.itemsize..(idx + 1) * self.itemsize],
+                    kind: self.kind,
+                    fields: self.fields,
+                    state: self.state,
+                    default: self.default,
+                })
+                .at_index(idx)?;
+            } else {
+                seq.serialize_element(&NoneSerializer::new()).unwrap();
+            }
+        }
+        seq.end()
+    }
+}
+
+/// A record of a structured array as an object keyed by field name.
+struct NumpyRecord<'a> {
+    data: &'a [u8],
+    fields: &'a [NumpyRecordField],
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
+}
+
+macro_rules! read_field {
+    ($data:expr, $ty:ty) => {
+        unsafe { core::ptr::read_unaligned($data.as_ptr().cast::<$ty>()) }
+    };
+}
+
+impl Serialize for NumpyRecord<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None).unwrap();
+        for field in self.fields {
+            map.serialize_key(field.name.as_str()).unwrap();
+            map.serialize_value(&NumpyItem {
+                data: &self.data[field.offset..],
+                kind: field.kind,
+                fields: &field.fields,
+                state: self.state,
+                default: self.default,
+            })
+            .at_key(field.name.as_str())?;
+        }
+        map.end()
+    }
+}
+
+/// A single item of an array, or a field of a record, read from `data`.
+/// Items may be unaligned in a packed dtype.
+struct NumpyItem<'a> {
+    data: &'a [u8],
+    kind: ItemType,
+    fields: &'a [NumpyRecordField],
+    state: SerializerState,
+    default: Option<NonNull<PyObject>>,
+}
+
+impl Serialize for NumpyItem<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let data = self.data;
+        match self.kind {
+            ItemType::F64 => DataTypeF64 {
+                obj: read_field!(data, f64),
+            }
+            .serialize(serializer),
+            ItemType::F32 => DataTypeF32 {
+                obj: read_field!(data, f32),
+            }
+            .serialize(serializer),
+            ItemType::F16 => DataTypeF16 {
+                obj: read_field!(data, u16),
+                shortest: opt_enabled!(self.state.opts(), NUMPY_SHORTEST_FLOAT),
+            }
+            .serialize(serializer),
+            ItemType::U64 => DataTypeU64 {
+                obj: read_field!(data, u64),
+            }
+            .serialize(serializer),
+            ItemType::U32 => DataTypeU32 {
+                obj: read_field!(data, u32),
+            }
+            .serialize(serializer),
+            ItemType::U16 => DataTypeU16 {
+                obj: read_field!(data, u16),
+            }
+            .serialize(serializer),
+            ItemType::U8 => DataTypeU8 {
+                obj: read_field!(data, u8),
+            }
+            .serialize(serializer),
+            ItemType::I64 => DataTypeI64 {
+                obj: read_field!(data, i64),
+            }
+            .serialize(serializer),
+            ItemType::I32 => DataTypeI32 {
+                obj: read_field!(data, i32),
+            }
+            .serialize(serializer),
+            ItemType::I16 => DataTypeI16 {
+                obj: read_field!(data, i16),
+            }
+            .serialize(serializer),
+            ItemType::I8 => DataTypeI8 {
+                obj: read_field!(data, i8),
+            }
+            .serialize(serializer),
+            ItemType::BOOL => DataTypeBool {
+                obj: read_field!(data, u8),
+            }
+            .serialize(serializer),
+            ItemType::DATETIME64(unit) => {
+                match unit.datetime(read_field!(data, i64), self.state.opts()) {
+                    Ok(dt) => dt.serialize(serializer),
+                    Err(err) => Err(err.into_serde_err()),
+                }
+            }
+            ItemType::OBJECT => {
+                let ptr = read_field!(data, *mut PyObject);
+                if ptr.is_null() {
+                    NoneSerializer::new().serialize(serializer)
+                } else {
+                    PyObjectSerializer::new(ptr, self.state, self.default).serialize(serializer)
+                }
+            }
+            ItemType::RECORD => NumpyRecord {
+                data: data,
+                fields: self.fields,
+                state: self.state,
+                default: self.default,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
 #[repr(transparent)]
 struct NumpyF64Array<'a> {
     data: &'a [f64],
@@ -474,14 +1289,14 @@ impl Serialize for DataTypeF32 {
     }
 }
 
-#[repr(transparent)]
 struct NumpyF16Array<'a> {
     data: &'a [u16],
+    shortest: bool,
 }
 
 impl<'a> NumpyF16Array<'a> {
-    fn new(data: &'a [u16]) -> Self {
-        Self { data }
+    fn new(data: &'a [u16], shortest: bool) -> Self {
+        Self { data, shortest }
     }
 }
 
@@ -494,15 +1309,19 @@ impl Serialize for NumpyF16Array<'_> {
     {
         let mut seq = serializer.serialize_seq(None).unwrap();
         for &each in self.data.iter() {
-            seq.serialize_element(&DataTypeF16 { obj: each }).unwrap();
+            seq.serialize_element(&DataTypeF16 {
+                obj: each,
+                shortest: self.shortest,
+            })
+            .unwrap();
         }
         seq.end()
     }
 }
 
-#[repr(transparent)]
 struct DataTypeF16 {
     obj: u16,
+    shortest: bool,
 }
 
 impl Serialize for DataTypeF16 {
@@ -512,11 +1331,63 @@ impl Serialize for DataTypeF16 {
     where
         S: Serializer,
     {
-        let as_f16 = half::f16::from_bits(self.obj);
-        serializer.serialize_f32(as_f16.to_f32())
+        serializer.serialize_f32(f16_to_f32(self.obj, self.shortest))
     }
 }
 
+#[inline(always)]
+fn scale_by_pow10(val: f64, exp: i32) -> f64 {
+    // powers of ten in float16 range are exact
+    if exp >= 0 {
+        val * 10f64.powi(exp)
+    } else {
+        val / 10f64.powi(-exp)
+    }
+}
+
+/// Convert a float16 to float32. If `shortest`, this is the float32 nearest
+/// to the shortest decimal that reads back as the same float16, so that
+/// it serializes as, e.g., `0.1` rather than `0.099975586`, as
+/// `numpy.float16.__repr__()` does.
+fn f16_to_f32(bits: u16, shortest: bool) -> f32 {
+    let val = half::f16::from_bits(bits);
+    if !shortest || !val.is_finite() || bits & 0x7fff == 0 {
+        return val.to_f32();
+    }
+    cold_path!();
+    let abs = bits & 0x7fff;
+    let exact = half::f16::from_bits(abs).to_f64();
+    // a decimal reads back as this float16 if it rounds to it, with ties to
+    // an even mantissa; the bound above the largest float16 is 65520
+    let lower = (exact + half::f16::from_bits(abs - 1).to_f64()) / 2.0;
+    let upper = if abs == half::f16::MAX.to_bits() {
+        65520.0
+    } else {
+        (exact + half::f16::from_bits(abs + 1).to_f64()) / 2.0
+    };
+    let inclusive = abs & 1 == 0;
+    let mut exp: i32 = 0;
+    while scale_by_pow10(1.0, exp + 1) <= exact {
+        exp += 1;
+    }
+    while scale_by_pow10(1.0, exp) > exact {
+        exp -= 1;
+    }
+    // float16 has at most 5 significant digits
+    for digits in 1..=5 {
+        let scale = exp - digits + 1;
+        let candidate = scale_by_pow10(scale_by_pow10(exact, -scale).round(), scale);
+        if (lower < candidate && candidate < upper)
+            || (inclusive && (candidate == lower || candidate == upper))
+        {
+            #[allow(clippy::cast_possible_truncation)]
+            let ret = candidate as f32;
+            return if val.is_sign_negative() { -ret } else { ret };
+        }
+    }
+    val.to_f32()
+}
+
 #[repr(transparent)]
 struct NumpyU64Array<'a> {
     data: &'a [u64],
@@ -913,7 +1784,7 @@ impl Serialize for NumpyScalar {
             } else if core::ptr::eq(ob_type, scalar_types.float32) {
                 (*(self.ptr.cast::<NumpyFloat32>())).serialize(serializer)
             } else if core::ptr::eq(ob_type, scalar_types.float16) {
-                (*(self.ptr.cast::<NumpyFloat16>())).serialize(serializer)
+                (*(self.ptr.cast::<NumpyFloat16>())).serialize(serializer, self.opts)
             } else if core::ptr::eq(ob_type, scalar_types.int64) {
                 (*(self.ptr.cast::<NumpyInt64>())).serialize(serializer)
             } else if core::ptr::eq(ob_type, scalar_types.int32) {
@@ -1080,14 +1951,16 @@ pub(crate) struct NumpyFloat16 {
     value: u16,
 }
 
-impl Serialize for NumpyFloat16 {
+impl NumpyFloat16 {
     #[cold]
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S, opts: Opt) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let as_f16 = half::f16::from_bits(self.value);
-        serializer.serialize_f32(as_f16.to_f32())
+        serializer.serialize_f32(f16_to_f32(
+            self.value,
+            opt_enabled!(opts, NUMPY_SHORTEST_FLOAT),
+        ))
     }
 }
 
@@ -1236,42 +2109,48 @@ impl NumpyDatetimeUnit {
         let el0 = unsafe { PyListRef::from_ptr_unchecked(descr).get(0) };
         let descr_str = unsafe { PyTupleRef::from_ptr_unchecked(el0).get(1) };
         match PyStrRef::from_ptr(descr_str) {
-            Ok(uni) => {
-                match uni.as_str() {
-                    Some(as_str) => {
-                        if as_str.len() < 5 {
-                            return Self::NaT;
-                        }
-                        // unit descriptions are found at
-                        // https://github.com/numpy/numpy/blob/b235f9e701e14ed6f6f6dcba885f7986a833743f/numpy/core/src/multiarray/datetime.c#L79-L96.
-                        let ret = match &as_str[4..as_str.len() - 1] {
-                            "Y" => Self::Years,
-                            "M" => Self::Months,
-                            "W" => Self::Weeks,
-                            "D" => Self::Days,
-                            "h" => Self::Hours,
-                            "m" => Self::Minutes,
-                            "s" => Self::Seconds,
-                            "ms" => Self::Milliseconds,
-                            "us" => Self::Microseconds,
-                            "ns" => Self::Nanoseconds,
-                            "ps" => Self::Picoseconds,
-                            "fs" => Self::Femtoseconds,
-                            "as" => Self::Attoseconds,
-                            "generic" => Self::Generic,
-                            _ => unreachable!(),
-                        };
-                        ffi!(Py_DECREF(dtype));
-                        ffi!(Py_DECREF(descr));
-                        ret
-                    }
-                    None => Self::NaT,
+            Ok(uni) => match uni.as_str() {
+                Some(as_str) => {
+                    let ret = Self::from_typestr(as_str);
+                    ffi!(Py_DECREF(dtype));
+                    ffi!(Py_DECREF(descr));
+                    ret
                 }
-            }
+                None => Self::NaT,
+            },
             Err(_) => Self::NaT,
         }
     }
 
+    /// Create a `NumpyDatetimeUnit` from a datetime64 type string such as
+    /// `<M8[us]`.
+    #[cold]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn from_typestr(as_str: &str) -> Self {
+        if as_str.len() < 5 {
+            return Self::NaT;
+        }
+        // unit descriptions are found at
+        // https://github.com/numpy/numpy/blob/b235f9e701e14ed6f6f6dcba885f7986a833743f/numpy/core/src/multiarray/datetime.c#L79-L96.
+        match &as_str[4..as_str.len() - 1] {
+            "Y" => Self::Years,
+            "M" => Self::Months,
+            "W" => Self::Weeks,
+            "D" => Self::Days,
+            "h" => Self::Hours,
+            "m" => Self::Minutes,
+            "s" => Self::Seconds,
+            "ms" => Self::Milliseconds,
+            "us" => Self::Microseconds,
+            "ns" => Self::Nanoseconds,
+            "ps" => Self::Picoseconds,
+            "fs" => Self::Femtoseconds,
+            "as" => Self::Attoseconds,
+            "generic" => Self::Generic,
+            _ => unreachable!(),
+        }
+    }
+
     /// Return a `NumpyDatetime64Repr` for a value in array with this unit.
     ///
     /// Returns an `Err(NumpyDateTimeError)` if the value is invalid for this unit.
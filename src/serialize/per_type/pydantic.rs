@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyStrRef;
+use crate::opt::PYDANTIC_EXCLUDE_UNSET;
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::state::SerializerState;
+use crate::typeref::{
+    ALIAS_STR, DICT_STR, DICT_TYPE, PYDANTIC_EXTRA_STR, PYDANTIC_FIELDS_SET_STR,
+    PYDANTIC_FIELDS_STR, PYDANTIC_MODEL_FIELDS_STR, PYDANTIC_ROOT_MODEL_STR, ROOT_STR,
+    SERIALIZATION_ALIAS_STR, STR_TYPE, TRUE, TUPLE_TYPE,
+};
+use crate::util::isize_to_usize;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Whether `ob_type` is a subclass of pydantic's `BaseModel`, which is the
+/// class in the MRO that declares the `__pydantic_fields_set__` slot.
+#[cold]
+#[inline(never)]
+pub(crate) fn is_pydantic_model(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    let mro = unsafe { (*ob_type).tp_mro };
+    if mro.is_null() {
+        return false;
+    }
+    debug_assert!(is_class_by_type!(ob_type!(mro), TUPLE_TYPE));
+    let mro_ptr = unsafe { (*mro.cast::<crate::ffi::PyTupleObject>()).ob_item.as_ptr() };
+    for idx in 0..isize_to_usize(ffi!(Py_SIZE(mro))) {
+        let base = unsafe { *mro_ptr.add(idx) };
+        if pydict_contains!(
+            base.cast::<crate::ffi::PyTypeObject>(),
+            PYDANTIC_FIELDS_SET_STR
+        ) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Serialize an instance of a pydantic v2 `BaseModel` as an object of its
+/// fields in declaration order, keyed by serialization alias or alias if
+/// either is set, followed by extra fields if the model allows them. The
+/// values are read from the instance `__dict__` and serialized as orjson
+/// serializes them, so field and model serializers, computed fields, and
+/// `exclude` are not applied. A `RootModel` is serialized as its `root`.
+#[repr(transparent)]
+pub(crate) struct PydanticSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> PydanticSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl PydanticSerializer<'_> {
+    fn error(&self) -> SerializeError {
+        SerializeError::PydanticAttribute(nonnull!(self.previous.ptr))
+    }
+
+    /// Get `name` of the instance, clearing an `AttributeError`. Returns null
+    /// if it is not set and an error if getting it raised something else.
+    fn get_attr(
+        &self,
+        ptr: *mut crate::ffi::PyObject,
+        name: *mut crate::ffi::PyObject,
+    ) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        let value = ffi!(PyObject_GetAttr(ptr, name));
+        if value.is_null() {
+            if ffi!(PyErr_ExceptionMatches(crate::ffi::PyExc_AttributeError)) == 0 {
+                cold_path!();
+                return Err(self.error());
+            }
+            ffi!(PyErr_Clear());
+        }
+        Ok(value)
+    }
+
+    /// Copy the `dict` of field name to `FieldInfo` of the class so that it
+    /// can be iterated while `default` runs. Pydantic 2.10 and later name it
+    /// `__pydantic_fields__` and earlier versions `model_fields`.
+    fn fields(&self) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        let ob_type = ob_type!(self.previous.ptr).cast::<crate::ffi::PyObject>();
+        let mut fields = self.get_attr(ob_type, unsafe { PYDANTIC_FIELDS_STR })?;
+        if fields.is_null() {
+            fields = self.get_attr(ob_type, unsafe { PYDANTIC_MODEL_FIELDS_STR })?;
+        }
+        if fields.is_null() || !is_class_by_type!(ob_type!(fields), DICT_TYPE) {
+            cold_path!();
+            if !fields.is_null() {
+                ffi!(Py_DECREF(fields));
+            }
+            return Err(self.error());
+        }
+        let copy = ffi!(PyDict_Copy(fields));
+        ffi!(Py_DECREF(fields));
+        if copy.is_null() {
+            cold_path!();
+            return Err(self.error());
+        }
+        Ok(copy)
+    }
+
+    /// The key of a field: its `serialization_alias`, else its `alias`, else
+    /// its name. The returned object is a new reference.
+    fn key(
+        &self,
+        name: *mut crate::ffi::PyObject,
+        info: *mut crate::ffi::PyObject,
+    ) -> Result<*mut crate::ffi::PyObject, SerializeError> {
+        for attr in unsafe { [SERIALIZATION_ALIAS_STR, ALIAS_STR] } {
+            let alias = self.get_attr(info, attr)?;
+            if !alias.is_null() {
+                if is_class_by_type!(ob_type!(alias), STR_TYPE) {
+                    return Ok(alias);
+                }
+                ffi!(Py_DECREF(alias));
+            }
+        }
+        ffi!(Py_INCREF(name));
+        Ok(name)
+    }
+
+    fn serialize_root<S>(
+        &self,
+        dict: *mut crate::ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = ffi!(PyDict_GetItemWithError(dict, ROOT_STR));
+        if value.is_null() {
+            cold_path!();
+            ffi!(PyErr_Clear());
+            err!(self.error())
+        }
+        ffi!(Py_INCREF(value));
+        let state = self.previous.state.copy_for_recursive_call();
+        let ret =
+            PyObjectSerializer::new(value, state, self.previous.default).serialize(serializer);
+        ffi!(Py_DECREF(value));
+        ret
+    }
+
+    fn serialize_fields<S>(
+        &self,
+        dict: *mut crate::ffi::PyObject,
+        fields: *mut crate::ffi::PyObject,
+        fields_set: *mut crate::ffi::PyObject,
+        extra: *mut crate::ffi::PyObject,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = self.previous.state.copy_for_recursive_call();
+
+        let mut map = serializer.serialize_map(None).unwrap();
+
+        let mut pos = 0;
+        let mut name: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut info: *mut crate::ffi::PyObject = core::ptr::null_mut();
+
+        // fields is a copy, so iterating it directly is safe
+        while pydict_next!(fields, &raw mut pos, &raw mut name, &raw mut info) == 1 {
+            if !fields_set.is_null() && ffi!(PySet_Contains(fields_set, name)) != 1 {
+                continue;
+            }
+            let value = ffi!(PyDict_GetItemWithError(dict, name));
+            if value.is_null() {
+                // e.g., omitted from `model_construct()`
+                ffi!(PyErr_Clear());
+                continue;
+            }
+            let key = match self.key(name, info) {
+                Ok(key) => key,
+                Err(err) => err!(err),
+            };
+            ffi!(Py_INCREF(value));
+            let res = serialize_entry(&mut map, key, value, state, self.previous);
+            ffi!(Py_DECREF(value));
+            ffi!(Py_DECREF(key));
+            res?;
+        }
+
+        if !extra.is_null() {
+            pos = 0;
+            let mut key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+            let mut value: *mut crate::ffi::PyObject = core::ptr::null_mut();
+            // extra is a copy
+            while pydict_next!(extra, &raw mut pos, &raw mut key, &raw mut value) == 1 {
+                if !fields_set.is_null() && ffi!(PySet_Contains(fields_set, key)) != 1 {
+                    continue;
+                }
+                serialize_entry(&mut map, key, value, state, self.previous)?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+fn serialize_entry<M>(
+    map: &mut M,
+    key: *mut crate::ffi::PyObject,
+    value: *mut crate::ffi::PyObject,
+    state: SerializerState,
+    previous: &PyObjectSerializer,
+) -> Result<(), M::Error>
+where
+    M: SerializeMap,
+{
+    if !is_class_by_type!(ob_type!(key), STR_TYPE) {
+        cold_path!();
+        err!(SerializeError::KeyMustBeStr)
+    }
+    let key_as_str = match unsafe { PyStrRef::from_ptr_unchecked(key).as_str() } {
+        Some(uni) => uni,
+        None => err!(SerializeError::InvalidStr),
+    };
+    let pyvalue = PyObjectSerializer::new(value, state, previous.default);
+    map.serialize_key(key_as_str).unwrap();
+    map.serialize_value(&pyvalue).at_key(key_as_str)
+}
+
+impl Serialize for PydanticSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.recursion_limit() {
+            cold_path!();
+            err!(SerializeError::RecursionLimit)
+        }
+        let dict = match self.get_attr(self.previous.ptr, unsafe { DICT_STR }) {
+            Ok(dict) if !dict.is_null() && is_class_by_type!(ob_type!(dict), DICT_TYPE) => dict,
+            Ok(dict) => {
+                if !dict.is_null() {
+                    ffi!(Py_DECREF(dict));
+                }
+                err!(self.error())
+            }
+            Err(err) => err!(err),
+        };
+
+        let ob_type = ob_type!(self.previous.ptr).cast::<crate::ffi::PyObject>();
+        let is_root = match self.get_attr(ob_type, unsafe { PYDANTIC_ROOT_MODEL_STR }) {
+            Ok(val) if val.is_null() => false,
+            Ok(val) => {
+                ffi!(Py_DECREF(val));
+                core::ptr::eq(val, unsafe { TRUE })
+            }
+            Err(err) => {
+                ffi!(Py_DECREF(dict));
+                err!(err)
+            }
+        };
+        if is_root {
+            let ret = self.serialize_root(dict, serializer);
+            ffi!(Py_DECREF(dict));
+            return ret;
+        }
+
+        let fields = match self.fields() {
+            Ok(fields) => fields,
+            Err(err) => {
+                ffi!(Py_DECREF(dict));
+                err!(err)
+            }
+        };
+
+        let mut fields_set: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut extra: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut res = Ok(());
+        if opt_enabled!(self.previous.state.opts(), PYDANTIC_EXCLUDE_UNSET) {
+            match self.get_attr(self.previous.ptr, unsafe { PYDANTIC_FIELDS_SET_STR }) {
+                Ok(val) => fields_set = val,
+                Err(err) => res = Err(err),
+            }
+        }
+        if res.is_ok() {
+            match self.get_attr(self.previous.ptr, unsafe { PYDANTIC_EXTRA_STR }) {
+                Ok(val) if !val.is_null() && is_class_by_type!(ob_type!(val), DICT_TYPE) => {
+                    extra = ffi!(PyDict_Copy(val));
+                    ffi!(Py_DECREF(val));
+                    if extra.is_null() {
+                        res = Err(self.error());
+                    }
+                }
+                Ok(val) => {
+                    if !val.is_null() {
+                        ffi!(Py_DECREF(val));
+                    }
+                }
+                Err(err) => res = Err(err),
+            }
+        }
+
+        let ret = match res {
+            Ok(()) => self.serialize_fields(dict, fields, fields_set, extra, serializer),
+            Err(err) => Err(serde::ser::Error::custom(err)),
+        };
+        for each in [fields_set, extra] {
+            if !each.is_null() {
+                ffi!(Py_DECREF(each));
+            }
+        }
+        ffi!(Py_DECREF(fields));
+        ffi!(Py_DECREF(dict));
+        ret
+    }
+}
@@ -1,9 +1,39 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2018-2025)
+// Copyright ijl (2018-2026)
 
+use crate::opt::{ENUM_FULL, ENUM_REPR, FLAG_AS_INT, Opt};
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
 use crate::serialize::serializer::PyObjectSerializer;
-use crate::typeref::VALUE_STR;
-use serde::ser::{Serialize, Serializer};
+use crate::typeref::{ENUM_TYPE, FLAG_TYPE, NAME_STR, VALUE_STR};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Whether `ob_type` is an enum, i.e., its metaclass is `enum.EnumType`. The
+/// metaclass of an enum is that of its bases however many levels of
+/// inheritance there are, and may be a subclass of `enum.EnumType`.
+#[inline(always)]
+pub(crate) fn is_enum_type(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    let metaclass = ob_type!(ob_type.cast::<crate::ffi::PyObject>());
+    unsafe {
+        core::ptr::eq(metaclass, ENUM_TYPE)
+            || (!core::ptr::eq(metaclass, &raw mut crate::ffi::PyType_Type)
+                && is_enum_metaclass(metaclass))
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn is_enum_metaclass(metaclass: *mut crate::ffi::PyTypeObject) -> bool {
+    ffi!(PyType_IsSubtype(metaclass, ENUM_TYPE)) == 1
+}
+
+/// Whether a member of `ob_type`, an enum, is serialized as its value
+/// regardless of `OPT_ENUM_REPR` and `OPT_PASSTHROUGH_ENUM` because it is an
+/// `enum.Flag`, including `enum.IntFlag`, and `OPT_FLAG_AS_INT` is set.
+#[inline]
+pub(crate) fn is_flag_as_int(ob_type: *mut crate::ffi::PyTypeObject, opts: Opt) -> bool {
+    opt_enabled!(opts, FLAG_AS_INT) && ffi!(PyType_IsSubtype(ob_type, FLAG_TYPE)) == 1
+}
 
 #[repr(transparent)]
 pub(crate) struct EnumSerializer<'a> {
@@ -14,6 +44,51 @@ impl<'a> EnumSerializer<'a> {
     pub fn new(previous: &'a PyObjectSerializer) -> Self {
         Self { previous: previous }
     }
+
+    #[cold]
+    #[inline(never)]
+    fn serialize_repr<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let repr = ffi!(PyObject_Repr(self.previous.ptr));
+        if repr.is_null() {
+            err!(SerializeError::EnumRepr(nonnull!(self.previous.ptr)))
+        }
+        let ret = PyObjectSerializer::new(repr, self.previous.state, self.previous.default)
+            .serialize(serializer);
+        ffi!(Py_DECREF(repr));
+        ret
+    }
+
+    /// Serialize the member as an object of its `name` and `value`, for
+    /// `OPT_ENUM_FULL`.
+    #[cold]
+    #[inline(never)]
+    fn serialize_full<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, attr) in [
+            ("name", unsafe { NAME_STR }),
+            ("value", unsafe { VALUE_STR }),
+        ] {
+            let value = ffi!(PyObject_GetAttr(self.previous.ptr, attr));
+            debug_assert!(!value.is_null());
+            map.serialize_key(key).unwrap();
+            let ret = map
+                .serialize_value(&PyObjectSerializer::new(
+                    value,
+                    self.previous.state,
+                    self.previous.default,
+                ))
+                .at_key(key);
+            ffi!(Py_DECREF(value));
+            ret?;
+        }
+        map.end()
+    }
 }
 
 impl Serialize for EnumSerializer<'_> {
@@ -22,6 +97,18 @@ impl Serialize for EnumSerializer<'_> {
     where
         S: Serializer,
     {
+        if opt_enabled!(self.previous.state.opts(), ENUM_REPR)
+            && !is_flag_as_int(ob_type!(self.previous.ptr), self.previous.state.opts())
+        {
+            cold_path!();
+            return self.serialize_repr(serializer);
+        }
+        if opt_enabled!(self.previous.state.opts(), ENUM_FULL)
+            && !is_flag_as_int(ob_type!(self.previous.ptr), self.previous.state.opts())
+        {
+            cold_path!();
+            return self.serialize_full(serializer);
+        }
         let value = ffi!(PyObject_GetAttr(self.previous.ptr, VALUE_STR));
         debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
         let ret = PyObjectSerializer::new(value, self.previous.state, self.previous.default)
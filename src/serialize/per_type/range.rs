@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::opt::RANGE_AS_ARRAY;
+use crate::serialize::error::SerializeError;
+use crate::serialize::path::ErrorPath;
+use crate::serialize::per_type::IteratorSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// The layout of `range`, which is not in the limited API.
+#[repr(C)]
+struct RangeObject {
+    ob_base: crate::ffi::PyObject,
+    start: *mut crate::ffi::PyObject,
+    stop: *mut crate::ffi::PyObject,
+    step: *mut crate::ffi::PyObject,
+    length: *mut crate::ffi::PyObject,
+}
+
+#[inline(always)]
+pub(crate) fn is_range(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    core::ptr::eq(ob_type, &raw mut crate::ffi::PyRange_Type)
+}
+
+/// Serialize a `range` as an object of its `start`, `stop`, and `step`, or,
+/// with `OPT_RANGE_AS_ARRAY`, as an array of its values, whose length is
+/// checked against `max_sequence_len` before any is serialized.
+#[repr(transparent)]
+pub(crate) struct RangeSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> RangeSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for RangeSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let range = unsafe { &*self.previous.ptr.cast::<RangeObject>() };
+        if opt_enabled!(self.previous.state.opts(), RANGE_AS_ARRAY) {
+            let max_sequence_len = self.previous.state.max_sequence_len();
+            // a length that overflows is u64::MAX and sets an exception
+            if ffi!(PyLong_AsUnsignedLongLong(range.length)) > max_sequence_len as u64 {
+                ffi!(PyErr_Clear());
+                err!(SerializeError::SequenceTooLong(max_sequence_len))
+            }
+            return IteratorSerializer::new(
+                self.previous.ptr,
+                self.previous.state,
+                self.previous.default,
+            )
+            .serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None).unwrap();
+        for (key, value) in [
+            ("start", range.start),
+            ("stop", range.stop),
+            ("step", range.step),
+        ] {
+            map.serialize_key(key).unwrap();
+            map.serialize_value(&PyObjectSerializer::new(
+                value,
+                self.previous.state,
+                self.previous.default,
+            ))
+            .at_key(key)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::opt::{Opt, TIMEDELTA_ISO8601};
+use crate::serialize::buffer::SmallFixedBuffer;
+
+use bytes::BufMut;
+use serde::ser::{Serialize, Serializer};
+
+const MICROSECONDS_PER_SECOND: u128 = 1_000_000;
+const SECONDS_PER_DAY: u128 = 86_400;
+
+/// Serialize a `datetime.timedelta` with `OPT_SERIALIZE_TIMEDELTA` as a
+/// number of seconds or, with `OPT_TIMEDELTA_ISO8601`, as an ISO 8601
+/// duration `str`.
+pub(crate) struct TimedeltaSerializer {
+    ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
+}
+
+impl TimedeltaSerializer {
+    pub fn new(ptr: *mut crate::ffi::PyObject, opts: Opt) -> Self {
+        TimedeltaSerializer {
+            ptr: ptr,
+            opts: opts,
+        }
+    }
+
+    /// The sign and absolute value of the duration in microseconds. A
+    /// negative `timedelta` has negative `days` and non-negative `seconds`
+    /// and `microseconds`, so it is converted to a total first.
+    fn total_microseconds(&self) -> (bool, u128) {
+        let days = i128::from(ffi!(PyDateTime_DELTA_GET_DAYS(self.ptr)));
+        let seconds = i128::from(ffi!(PyDateTime_DELTA_GET_SECONDS(self.ptr)));
+        let microseconds = i128::from(ffi!(PyDateTime_DELTA_GET_MICROSECONDS(self.ptr)));
+        // |days| is at most 999999999, which overflows i64 in microseconds
+        let total = (days * 86_400 + seconds) * 1_000_000 + microseconds;
+        (total < 0, total.unsigned_abs())
+    }
+}
+
+/// Write the fraction of a second without trailing zeros.
+fn write_fraction<B>(buf: &mut B, microseconds: u128)
+where
+    B: BufMut,
+{
+    debug_assert!(microseconds > 0 && microseconds < MICROSECONDS_PER_SECOND);
+    // zero-padded to six digits by the leading 1, which is not written
+    let mut itoa_buf = itoa::Buffer::new();
+    let digits = &itoa_buf
+        .format(MICROSECONDS_PER_SECOND + microseconds)
+        .as_bytes()[1..];
+    let len = digits.iter().rposition(|&digit| digit != b'0').unwrap() + 1;
+    buf.put_u8(b'.');
+    buf.put_slice(&digits[..len]);
+}
+
+impl Serialize for TimedeltaSerializer {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (negative, total) = self.total_microseconds();
+        let seconds = total / MICROSECONDS_PER_SECOND;
+        let microseconds = total % MICROSECONDS_PER_SECOND;
+        let mut buf = SmallFixedBuffer::new();
+        if negative {
+            buf.put_u8(b'-');
+        }
+        if opt_enabled!(self.opts, TIMEDELTA_ISO8601) {
+            // e.g., P1DT2H3M4.5S, omitting components that are zero
+            buf.put_u8(b'P');
+            let days = seconds / SECONDS_PER_DAY;
+            if days > 0 {
+                buf.put_slice(itoa::Buffer::new().format(days).as_bytes());
+                buf.put_u8(b'D');
+            }
+            let seconds = seconds % SECONDS_PER_DAY;
+            if seconds > 0 || microseconds > 0 || days == 0 {
+                buf.put_u8(b'T');
+                let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+                for (value, designator) in [(hours, b'H'), (minutes, b'M')] {
+                    if value > 0 {
+                        buf.put_slice(itoa::Buffer::new().format(value).as_bytes());
+                        buf.put_u8(designator);
+                    }
+                }
+                if seconds > 0 || microseconds > 0 || (hours == 0 && minutes == 0) {
+                    buf.put_slice(itoa::Buffer::new().format(seconds).as_bytes());
+                    if microseconds > 0 {
+                        write_fraction(&mut buf, microseconds);
+                    }
+                    buf.put_u8(b'S');
+                }
+            }
+            serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
+        } else {
+            // the exact decimal, so that it is parsed as the nearest float to
+            // the value returned by total_seconds()
+            buf.put_slice(itoa::Buffer::new().format(seconds).as_bytes());
+            if microseconds > 0 {
+                write_fraction(&mut buf, microseconds);
+            } else {
+                buf.put_slice(b".0");
+            }
+            serializer
+                .serialize_bytes(unsafe { core::slice::from_raw_parts(buf.as_ptr(), buf.len()) })
+        }
+    }
+}
@@ -2,17 +2,21 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyUuidRef;
+use crate::opt::{Opt, UUID_BRACED, UUID_HEX, UUID_URN};
 use crate::serialize::buffer::SmallFixedBuffer;
 use serde::ser::{Serialize, Serializer};
 
-#[repr(transparent)]
 pub(crate) struct UUID {
     ob: PyUuidRef,
+    opts: Opt,
 }
 
 impl UUID {
-    pub fn new(ptr: PyUuidRef) -> Self {
-        UUID { ob: ptr }
+    pub fn new(ptr: PyUuidRef, opts: Opt) -> Self {
+        UUID {
+            ob: ptr,
+            opts: opts,
+        }
     }
 
     #[inline(never)]
@@ -20,18 +24,31 @@ impl UUID {
     where
         B: bytes::BufMut,
     {
-        unsafe {
-            let buffer_length: usize = 40;
-            debug_assert!(buf.remaining_mut() >= buffer_length);
-            let len = uuid::Uuid::from_u128(self.ob.value())
-                .hyphenated()
-                .encode_lower(core::slice::from_raw_parts_mut(
-                    buf.chunk_mut().as_mut_ptr(),
-                    buffer_length,
-                ))
-                .len();
-            buf.advance_mut(len);
-        }
+        write_uuid(buf, self.ob.value(), self.opts);
+    }
+}
+
+/// Write the UUID of `value` in the format selected by `opts`.
+#[inline(always)]
+pub(crate) fn write_uuid<B>(buf: &mut B, value: u128, opts: Opt)
+where
+    B: bytes::BufMut,
+{
+    unsafe {
+        let buffer_length: usize = uuid::fmt::Urn::LENGTH;
+        debug_assert!(buf.remaining_mut() >= buffer_length);
+        let uuid = uuid::Uuid::from_u128(value);
+        let slice = core::slice::from_raw_parts_mut(buf.chunk_mut().as_mut_ptr(), buffer_length);
+        let len = if opt_enabled!(opts, UUID_HEX) {
+            uuid.simple().encode_lower(slice).len()
+        } else if opt_enabled!(opts, UUID_URN) {
+            uuid.urn().encode_lower(slice).len()
+        } else if opt_enabled!(opts, UUID_BRACED) {
+            uuid.braced().encode_lower(slice).len()
+        } else {
+            uuid.hyphenated().encode_lower(slice).len()
+        };
+        buf.advance_mut(len);
     }
 }
 impl Serialize for UUID {
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::serialize::error::SerializeError;
+use crate::serialize::serializer::PyObjectSerializer;
+
+use serde::ser::{Serialize, Serializer};
+
+/// Whether `obj` is a `weakref.proxy`, which is of one of two types depending
+/// on whether its referent is callable.
+#[inline(always)]
+pub(crate) fn is_weakref_proxy(obj: *mut crate::ffi::PyObject) -> bool {
+    ffi!(PyWeakref_CheckProxy(obj)) == 1
+}
+
+/// Serialize the referent of a `weakref.proxy` as if it were serialized
+/// directly. A proxy whose referent was garbage collected raises.
+#[repr(transparent)]
+pub(crate) struct WeakrefSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> WeakrefSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for WeakrefSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    #[cfg_attr(feature = "optimize", optimize(size))]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // a strong reference keeps the referent alive while serializing; this
+        // does not fail as the type of the proxy was checked
+        let mut referent: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        if ffi!(PyWeakref_GetRef(self.previous.ptr, &raw mut referent)) != 1 {
+            cold_path!();
+            err!(SerializeError::WeakrefDead)
+        }
+        let ret = PyObjectSerializer::new(referent, self.previous.state, self.previous.default)
+            .serialize(serializer);
+        ffi!(Py_DECREF(referent));
+        ret
+    }
+}
@@ -5,16 +5,27 @@ use crate::ffi::{
     PyBoolRef, PyDictRef, PyFloatRef, PyFragmentRef, PyIntRef, PyListRef, PyStrRef,
     PyStrSubclassRef, PyUuidRef,
 };
-use crate::opt::{APPEND_NEWLINE, INDENT_2, Opt};
+use crate::opt::{
+    APPEND_NEWLINE, CANONICAL, ENSURE_ASCII, ESCAPE_HTML, HighOpt, INDENT_2, NONFINITE_AS_STRING,
+    OMIT_TOP_LEVEL_NONE, Opt,
+};
+use crate::serialize::error::{RECURSION_LIMIT_MESSAGE, SerializeError};
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
+use crate::serialize::path::format_error_path;
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, IntSerializer,
-    ListTupleSerializer, NoneSerializer, NumpyScalar, NumpySerializer, StrSerializer,
-    StrSubclassSerializer, Time, UUID, ZeroListSerializer,
+    AnnotatedSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date,
+    DateTime, DatetimeFormatSerializer, DefaultFirstSerializer, DefaultSerializer,
+    DictGenericSerializer, EnumSerializer, EpochNanosecondsSerializer, FloatSerializer,
+    FractionSerializer, FragmentSerializer, IntSerializer, IteratorSerializer,
+    LengthTaggedSerializer, ListTupleSerializer, MappingSerializer, NamedTupleSerializer,
+    NoneSerializer, NumpyScalar, NumpySerializer, PydanticSerializer, RangeSerializer,
+    StrSerializer, StrSubclassSerializer, Time, TimedeltaSerializer, UUID, WeakrefSerializer,
+    ZeroListSerializer,
 };
-use crate::serialize::state::SerializerState;
-use crate::serialize::writer::{BytesWriter, to_writer, to_writer_pretty};
+use crate::serialize::state::{DumpsConfig, SerializerState};
+use crate::serialize::validate::Validator;
+use crate::serialize::writer::{BoundedBytesWriter, BytesWriter, to_writer, to_writer_pretty};
+use crate::typeref::NONE;
 use core::ptr::NonNull;
 use serde::ser::{Serialize, Serializer};
 
@@ -22,23 +33,147 @@ pub(crate) fn serialize(
     ptr: *mut crate::ffi::PyObject,
     default: Option<NonNull<crate::ffi::PyObject>>,
     opts: Opt,
+    high_opts: HighOpt,
+    config: DumpsConfig,
+    indent: Option<usize>,
+    max_output_size: Option<usize>,
 ) -> Result<NonNull<crate::ffi::PyObject>, String> {
-    let mut buf = BytesWriter::default();
-    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts), default);
-    let res = if opt_disabled!(opts, INDENT_2) {
-        to_writer(&mut buf, &obj)
-    } else {
-        to_writer_pretty(&mut buf, &obj)
+    if indent.is_some() && opt_enabled!(opts, CANONICAL) {
+        cold_path!();
+        return Err(String::from("indent is incompatible with OPT_CANONICAL"));
+    }
+    let mut buf = match max_output_size {
+        Some(max_size) => BytesWriter::with_max_size(max_size),
+        None => BytesWriter::default(),
+    };
+    let mut config = config;
+    if config.key_transform().is_some() {
+        cold_path!();
+        config = config.with_key_transform_cache(nonnull!(ffi!(PyDict_New(0))));
+    }
+    let state = SerializerState::new(opts, &config);
+    let obj = PyObjectSerializer::new(ptr, state, default);
+    let indent = match indent {
+        Some(width) => Some(width),
+        None if opt_enabled!(opts, INDENT_2) => Some(2),
+        None => None,
     };
+    let ensure_ascii = opt_enabled!(high_opts, ENSURE_ASCII);
+    let escape_html = opt_enabled!(high_opts, ESCAPE_HTML);
+    let nonfinite_as_string = opt_enabled!(opts, NONFINITE_AS_STRING);
+    // with OPT_OMIT_TOP_LEVEL_NONE, the output for None is empty, without
+    // the newline of OPT_APPEND_NEWLINE
+    let omit = opt_enabled!(high_opts, OMIT_TOP_LEVEL_NONE) && unsafe { core::ptr::eq(ptr, NONE) };
+    let res = match indent {
+        _ if omit => Ok(()),
+        _ if max_output_size.is_some() => to_bounded_writer(
+            &mut buf,
+            &obj,
+            indent,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        ),
+        None => to_writer(
+            &mut buf,
+            &obj,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        ),
+        Some(width) => to_writer_pretty(
+            &mut buf,
+            &obj,
+            width,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        ),
+    };
+    if let Some(cache) = config.key_transform_cache() {
+        ffi!(Py_DECREF(cache.as_ptr()));
+    }
+    let append = opt_enabled!(opts, APPEND_NEWLINE) && !omit;
     match res {
-        Ok(()) => Ok(buf.finish(opt_enabled!(opts, APPEND_NEWLINE))),
+        Ok(()) if buf.exceeds_max_size(append) => {
+            cold_path!();
+            buf.abort();
+            Err(SerializeError::OutputTooLarge.to_string())
+        }
+        Ok(()) => Ok(buf.finish(append)),
         Err(err) => {
             buf.abort();
-            Err(err.to_string())
+            Err(error_message(&err))
         }
     }
 }
 
+/// Serialize with `max_output_size`, checking the size of the output after
+/// each array element and object value. This is separate from the unbounded
+/// writer so that `dumps()` without a limit does not check.
+#[cold]
+#[inline(never)]
+fn to_bounded_writer(
+    buf: &mut BytesWriter,
+    obj: &PyObjectSerializer,
+    indent: Option<usize>,
+    ensure_ascii: bool,
+    escape_html: bool,
+    nonfinite_as_string: bool,
+) -> serde_json::Result<()> {
+    match indent {
+        None => to_writer(
+            BoundedBytesWriter::new(buf),
+            obj,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        ),
+        Some(width) => to_writer_pretty(
+            BoundedBytesWriter::new(buf),
+            obj,
+            width,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        ),
+    }
+}
+
+/// Walk `ptr` as `serialize()` would without writing output, raising the
+/// first error.
+pub(crate) fn validate(
+    ptr: *mut crate::ffi::PyObject,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+    opts: Opt,
+    config: DumpsConfig,
+) -> Result<(), String> {
+    let mut config = config;
+    if config.key_transform().is_some() {
+        cold_path!();
+        config = config.with_key_transform_cache(nonnull!(ffi!(PyDict_New(0))));
+    }
+    let state = SerializerState::new(opts, &config);
+    let res = PyObjectSerializer::new(ptr, state, default).serialize(&mut Validator);
+    if let Some(cache) = config.key_transform_cache() {
+        ffi!(Py_DECREF(cache.as_ptr()));
+    }
+    res.map_err(|err| error_message(&err))
+}
+
+/// The message of an error from serializing. The recursion limit is usually
+/// reached deep in an object, so its message includes the path to where.
+#[cold]
+#[inline(never)]
+fn error_message(err: &impl core::fmt::Display) -> String {
+    let msg = err.to_string();
+    if msg == RECURSION_LIMIT_MESSAGE {
+        format!("{msg} at {}", format_error_path())
+    } else {
+        msg
+    }
+}
+
 pub(crate) struct PyObjectSerializer {
     pub ptr: *mut crate::ffi::PyObject,
     pub state: SerializerState,
@@ -61,11 +196,21 @@ impl PyObjectSerializer {
 
 impl Serialize for PyObjectSerializer {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_obtype(pyobject_to_obtype(self.ptr, self.state.opts()), serializer)
+    }
+}
+
+impl PyObjectSerializer {
+    #[inline(always)]
+    pub fn serialize_obtype<S>(&self, obtype: ObType, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         unsafe {
-            match pyobject_to_obtype(self.ptr, self.state.opts()) {
+            match obtype {
                 ObType::Str => {
                     StrSerializer::new(PyStrRef::from_ptr_unchecked(self.ptr)).serialize(serializer)
                 }
@@ -79,8 +224,10 @@ impl Serialize for PyObjectSerializer {
                 )
                 .serialize(serializer),
                 ObType::None => NoneSerializer::new().serialize(serializer),
-                ObType::Float => FloatSerializer::new(PyFloatRef::from_ptr_unchecked(self.ptr))
-                    .serialize(serializer),
+                ObType::Float => {
+                    FloatSerializer::new(PyFloatRef::from_ptr_unchecked(self.ptr), self.state)
+                        .serialize(serializer)
+                }
                 ObType::Bool => {
                     BoolSerializer::new(unsafe { PyBoolRef::from_ptr_unchecked(self.ptr) })
                         .serialize(serializer)
@@ -88,10 +235,12 @@ impl Serialize for PyObjectSerializer {
                 ObType::Datetime => {
                     DateTime::new(self.ptr, self.state.opts()).serialize(serializer)
                 }
-                ObType::Date => Date::new(self.ptr).serialize(serializer),
+                ObType::Date => Date::new(self.ptr, self.state.opts()).serialize(serializer),
                 ObType::Time => Time::new(self.ptr, self.state.opts()).serialize(serializer),
+                ObType::DatetimeFormat => DatetimeFormatSerializer::new(self).serialize(serializer),
                 ObType::Uuid => {
-                    UUID::new(PyUuidRef::from_ptr_unchecked(self.ptr)).serialize(serializer)
+                    UUID::new(PyUuidRef::from_ptr_unchecked(self.ptr), self.state.opts())
+                        .serialize(serializer)
                 }
                 ObType::Dict => DictGenericSerializer::new(
                     PyDictRef::from_ptr_unchecked(self.ptr),
@@ -131,7 +280,21 @@ impl Serialize for PyObjectSerializer {
                             .serialize(serializer)
                     }
                 }
+                ObType::NamedTuple => NamedTupleSerializer::new(self.ptr, self.state, self.default)
+                    .serialize(serializer),
+                ObType::Iterator => IteratorSerializer::new(self.ptr, self.state, self.default)
+                    .serialize(serializer),
                 ObType::Dataclass => DataclassGenericSerializer::new(self).serialize(serializer),
+                ObType::Pydantic => PydanticSerializer::new(self).serialize(serializer),
+                ObType::Mapping => MappingSerializer::new(self).serialize(serializer),
+                ObType::Range => RangeSerializer::new(self).serialize(serializer),
+                ObType::Fraction => FractionSerializer::new(self).serialize(serializer),
+                ObType::Timedelta => {
+                    TimedeltaSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+                }
+                ObType::LengthTagged => LengthTaggedSerializer::new(self).serialize(serializer),
+                ObType::Weakref => WeakrefSerializer::new(self).serialize(serializer),
+                ObType::Annotated => AnnotatedSerializer::new(self).serialize(serializer),
                 ObType::Enum => EnumSerializer::new(self).serialize(serializer),
                 ObType::NumpyArray => NumpySerializer::new(self).serialize(serializer),
                 ObType::NumpyScalar => {
@@ -141,6 +304,14 @@ impl Serialize for PyObjectSerializer {
                     FragmentSerializer::new(unsafe { PyFragmentRef::from_ptr_unchecked(self.ptr) })
                         .serialize(serializer)
                 }
+                ObType::EpochNanoseconds => {
+                    EpochNanosecondsSerializer::new(self.ptr, self.state.opts())
+                        .serialize(serializer)
+                }
+                ObType::Bytes => {
+                    BytesSerializer::new(self.ptr, self.state.opts()).serialize(serializer)
+                }
+                ObType::DefaultFirst => DefaultFirstSerializer::new(self).serialize(serializer),
                 ObType::Unknown => DefaultSerializer::new(self).serialize(serializer),
             }
         }
@@ -1,60 +1,185 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2024-2025)
+// Copyright ijl (2024-2026)
 
 use crate::opt::Opt;
+use core::ptr::NonNull;
 
-const RECURSION_SHIFT: usize = 24;
-const RECURSION_MASK: u32 = 255 << RECURSION_SHIFT;
+/// The configuration of one call of `dumps()` or of an `orjson.Encoder`,
+/// which does not change while serializing.
+pub(crate) struct DumpsConfig {
+    key_delimiter: Option<NonNull<crate::ffi::PyObject>>,
+    float_sigfigs: Option<u8>,
+    datetime_formats: Option<NonNull<crate::ffi::PyObject>>,
+    key_transform: Option<NonNull<crate::ffi::PyObject>>,
+    key_transform_cache: Option<NonNull<crate::ffi::PyObject>>,
+    type_handlers: Option<NonNull<crate::ffi::PyObject>>,
+    max_sequence_len: usize,
+    field_schemas: Option<NonNull<crate::ffi::PyObject>>,
+}
+
+impl DumpsConfig {
+    #[inline(always)]
+    pub fn new(
+        key_delimiter: Option<NonNull<crate::ffi::PyObject>>,
+        float_sigfigs: Option<u8>,
+        datetime_formats: Option<NonNull<crate::ffi::PyObject>>,
+        key_transform: Option<NonNull<crate::ffi::PyObject>>,
+        type_handlers: Option<NonNull<crate::ffi::PyObject>>,
+    ) -> Self {
+        Self {
+            key_delimiter,
+            float_sigfigs,
+            datetime_formats,
+            key_transform,
+            key_transform_cache: None,
+            type_handlers,
+            max_sequence_len: usize::MAX,
+            field_schemas: None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_max_sequence_len(self, max_sequence_len: usize) -> Self {
+        Self {
+            max_sequence_len,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_field_schemas(self, field_schemas: Option<NonNull<crate::ffi::PyObject>>) -> Self {
+        Self {
+            field_schemas,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_key_transform_cache(self, cache: NonNull<crate::ffi::PyObject>) -> Self {
+        Self {
+            key_transform_cache: Some(cache),
+            ..self
+        }
+    }
 
-const DEFAULT_SHIFT: usize = 16;
-const DEFAULT_MASK: u32 = 255 << DEFAULT_SHIFT;
+    #[inline(always)]
+    pub fn key_transform(&self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.key_transform
+    }
 
-#[repr(transparent)]
+    #[inline(always)]
+    pub fn key_transform_cache(&self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.key_transform_cache
+    }
+}
+
+/// The state copied into each nested serializer. Only what changes while
+/// recursing is held by value; the rest is read through `config`, which
+/// outlives the call of `serialize()` that created the state.
 #[derive(Copy, Clone)]
 pub(crate) struct SerializerState {
-    // recursion: u8,
-    // default_calls: u8,
-    // opts: u16,
-    state: u32,
+    opts: Opt,
+    recursion: u8,
+    default_calls: u8,
+    config: NonNull<DumpsConfig>,
 }
 
 impl SerializerState {
     #[inline(always)]
-    pub fn new(opts: Opt) -> Self {
-        debug_assert!(opts < u32::from(u16::MAX));
-        Self { state: opts }
+    pub fn new(opts: Opt, config: &DumpsConfig) -> Self {
+        Self {
+            opts,
+            recursion: 0,
+            default_calls: 0,
+            config: NonNull::from(config),
+        }
+    }
+
+    #[inline(always)]
+    fn config(&self) -> &DumpsConfig {
+        unsafe { self.config.as_ref() }
+    }
+
+    #[inline(always)]
+    pub fn opts(self) -> Opt {
+        self.opts
+    }
+
+    #[inline(always)]
+    pub fn key_delimiter(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().key_delimiter
+    }
+
+    #[inline(always)]
+    pub fn float_sigfigs(self) -> Option<u8> {
+        self.config().float_sigfigs
+    }
+
+    /// The `dict` of `datetime.datetime`, `datetime.date`, or `datetime.time`
+    /// to a format for `strftime()`, set by `orjson.Encoder`.
+    #[inline(always)]
+    pub fn datetime_formats(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().datetime_formats
+    }
+
+    /// The callable passed as `key_transform` to transform each `dict` key.
+    #[inline(always)]
+    pub fn key_transform(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().key_transform
+    }
+
+    /// The `dict` of each key to its transformed key, which lives for one
+    /// call of `serialize()`.
+    #[inline(always)]
+    pub fn key_transform_cache(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().key_transform_cache
+    }
+
+    /// The `dict` of a type to the callable that converts an object of that
+    /// type, set by `orjson.Encoder`.
+    #[inline(always)]
+    pub fn type_handlers(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().type_handlers
+    }
+
+    /// The maximum number of elements of a `list` or `tuple`, which is
+    /// `usize::MAX` unless `max_sequence_len` is given.
+    #[inline(always)]
+    pub fn max_sequence_len(self) -> usize {
+        self.config().max_sequence_len
     }
 
+    /// The `dict` of a dataclass type to a `tuple` of the `frozenset` of
+    /// field names to include and to exclude, either of which may be `None`,
+    /// set by `orjson.Encoder`.
     #[inline(always)]
-    pub fn opts(self) -> u32 {
-        self.state
+    pub fn field_schemas(self) -> Option<NonNull<crate::ffi::PyObject>> {
+        self.config().field_schemas
     }
 
     #[inline(always)]
     pub fn recursion_limit(self) -> bool {
-        self.state & RECURSION_MASK == RECURSION_MASK
+        self.recursion == u8::MAX
     }
 
     #[inline(always)]
     pub fn default_calls_limit(self) -> bool {
-        self.state & DEFAULT_MASK == DEFAULT_MASK
+        self.default_calls == u8::MAX
     }
 
     #[inline(always)]
     pub fn copy_for_recursive_call(self) -> Self {
-        let opt = self.state & !RECURSION_MASK;
-        let recursion = (((self.state & RECURSION_MASK) >> RECURSION_SHIFT) + 1) << RECURSION_SHIFT;
         Self {
-            state: opt | recursion,
+            recursion: self.recursion + 1,
+            ..self
         }
     }
 
     #[inline(always)]
     pub fn copy_for_default_call(self) -> Self {
-        let opt = self.state & !DEFAULT_MASK;
-        let default_calls = (((self.state & DEFAULT_MASK) >> DEFAULT_SHIFT) + 1) << DEFAULT_SHIFT;
         Self {
-            state: opt | default_calls,
+            default_calls: self.default_calls + 1,
+            ..self
         }
     }
 }
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use serde::ser::{self, Impossible, Serialize};
+use serde_json::error::{Error, Result};
+
+/// A `Serializer` that writes nothing, so that serializing a
+/// `PyObjectSerializer` to it walks the object and raises the same errors as
+/// `dumps()` without producing output.
+pub(crate) struct Validator;
+
+impl ser::Serializer for &mut Validator {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Self;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, _value: bool) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_i8(self, _value: i8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_i16(self, _value: i16) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_i32(self, _value: i32) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_i64(self, _value: i64) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_i128(self, _value: i128) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u8(self, _value: u8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u16(self, _value: u16) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u32(self, _value: u32) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_u64(self, _value: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_u128(self, _value: u128) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_f32(self, _value: f32) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_f64(self, _value: f64) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_char(self, _value: char) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_str(self, _value: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        unreachable!();
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        unreachable!();
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unreachable!();
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unreachable!();
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        unreachable!();
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unreachable!();
+    }
+}
+
+impl ser::SerializeSeq for &mut Validator {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut Validator {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut Validator {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_entry<K, V>(&mut self, _key: &K, _value: &V) -> Result<()>
+    where
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
+    {
+        unreachable!()
+    }
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        key.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
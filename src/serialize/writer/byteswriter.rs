@@ -16,6 +16,8 @@ const BUFFER_LENGTH: usize = 4096;
 pub(crate) struct BytesWriter {
     cap: usize,
     len: usize,
+    max_size: usize,
+    too_large: bool,
     #[cfg(CPython)]
     bytes: *mut crate::ffi::PyBytesObject,
     #[cfg(not(CPython))]
@@ -28,6 +30,8 @@ impl BytesWriter {
         BytesWriter {
             cap: BUFFER_LENGTH,
             len: 0,
+            max_size: usize::MAX,
+            too_large: false,
             #[cfg(CPython)]
             bytes: unsafe {
                 PyBytes_FromStringAndSize(core::ptr::null_mut(), usize_to_isize(BUFFER_LENGTH))
@@ -38,6 +42,23 @@ impl BytesWriter {
         }
     }
 
+    /// A writer that records when growing past `max_size` bytes. Serialize
+    /// to it through `BoundedBytesWriter` so that serializing is aborted
+    /// before the output grows further.
+    #[inline]
+    pub fn with_max_size(max_size: usize) -> Self {
+        let mut writer = Self::default();
+        writer.max_size = max_size;
+        writer
+    }
+
+    /// Whether the output, including a trailing newline if `append`, is
+    /// longer than the maximum size.
+    #[inline]
+    pub fn exceeds_max_size(&self, append: bool) -> bool {
+        self.len + usize::from(append) > self.max_size
+    }
+
     #[cfg(CPython)]
     pub fn abort(&mut self) {
         ffi!(Py_DECREF(self.bytes.cast::<PyObject>()));
@@ -129,6 +150,11 @@ impl BytesWriter {
     #[cold]
     #[inline(never)]
     fn grow(&mut self, len: usize) {
+        if len > self.max_size {
+            // reserving overestimates and the caller writes after reserving,
+            // so this still grows and the length is checked after each element
+            self.too_large = true;
+        }
         let mut cap = self.cap;
         while len >= cap {
             cap *= 2;
@@ -197,6 +223,27 @@ pub(crate) trait WriteExt {
     fn reserve(&mut self, len: usize) {
         let _ = len;
     }
+
+    #[inline]
+    fn position(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn written(&self, start: usize, end: usize) -> &[u8] {
+        let _ = (start, end);
+        &[]
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        let _ = len;
+    }
+
+    #[inline]
+    fn is_too_large(&self) -> bool {
+        false
+    }
 }
 
 impl WriteExt for &mut BytesWriter {
@@ -213,4 +260,99 @@ impl WriteExt for &mut BytesWriter {
             self.grow(end_length);
         }
     }
+
+    #[inline]
+    fn position(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn written(&self, start: usize, end: usize) -> &[u8] {
+        debug_assert!(start <= end && end <= self.len);
+        unsafe { core::slice::from_raw_parts(self.buffer_ptr().sub(self.len - start), end - start) }
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        debug_assert!(len <= self.len);
+        self.len = len;
+    }
+}
+
+/// A `BytesWriter` with a maximum size that serializing checks after each
+/// array element and object value. It is a distinct type so that the check
+/// is only compiled into serializing with a limit.
+pub(crate) struct BoundedBytesWriter<'a>(&'a mut BytesWriter);
+
+impl<'a> BoundedBytesWriter<'a> {
+    #[inline]
+    pub fn new(writer: &'a mut BytesWriter) -> Self {
+        BoundedBytesWriter(writer)
+    }
+}
+
+unsafe impl BufMut for BoundedBytesWriter<'_> {
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        unsafe {
+            self.0.advance_mut(cnt);
+        }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        self.0.chunk_mut()
+    }
+
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.0.remaining_mut()
+    }
+
+    #[inline]
+    fn put_u8(&mut self, value: u8) {
+        self.0.put_u8(value);
+    }
+
+    #[inline]
+    fn put_bytes(&mut self, val: u8, cnt: usize) {
+        self.0.put_bytes(val, cnt);
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.0.put_slice(src);
+    }
+}
+
+impl WriteExt for BoundedBytesWriter<'_> {
+    #[inline(always)]
+    fn as_mut_buffer_ptr(&mut self) -> *mut u8 {
+        WriteExt::as_mut_buffer_ptr(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, len: usize) {
+        WriteExt::reserve(&mut self.0, len);
+    }
+
+    #[inline]
+    fn position(&self) -> usize {
+        WriteExt::position(&self.0)
+    }
+
+    #[inline]
+    fn written(&self, start: usize, end: usize) -> &[u8] {
+        WriteExt::written(&self.0, start, end)
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        WriteExt::truncate(&mut self.0, len);
+    }
+
+    #[inline(always)]
+    fn is_too_large(&self) -> bool {
+        self.0.too_large && self.0.len > self.0.max_size
+    }
 }
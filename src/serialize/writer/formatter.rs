@@ -229,14 +229,15 @@ impl Formatter for CompactFormatter {}
 
 pub(crate) struct PrettyFormatter {
     current_indent: usize,
+    indent: usize,
     has_value: bool,
 }
 
 impl PrettyFormatter {
-    #[allow(clippy::new_without_default)]
-    pub const fn new() -> Self {
+    pub const fn new(indent: usize) -> Self {
         PrettyFormatter {
             current_indent: 0,
+            indent: indent,
             has_value: false,
         }
     }
@@ -263,7 +264,7 @@ impl Formatter for PrettyFormatter {
         W: ?Sized + WriteExt + bytes::BufMut,
     {
         self.current_indent -= 1;
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
@@ -281,7 +282,7 @@ impl Formatter for PrettyFormatter {
     where
         W: ?Sized + WriteExt + bytes::BufMut,
     {
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
@@ -321,7 +322,7 @@ impl Formatter for PrettyFormatter {
         W: ?Sized + WriteExt + bytes::BufMut,
     {
         self.current_indent -= 1;
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent;
         reserve_pretty!(writer, num_spaces);
 
         unsafe {
@@ -340,7 +341,7 @@ impl Formatter for PrettyFormatter {
     where
         W: ?Sized + WriteExt + bytes::BufMut,
     {
-        let num_spaces = self.current_indent * 2;
+        let num_spaces = self.current_indent * self.indent;
         reserve_pretty!(writer, num_spaces);
         unsafe {
             writer.put_slice(if first { b"\n" } else { b",\n" });
@@ -2,14 +2,21 @@
 // Copyright ijl (2022-2025)
 // This is an adaptation of `src/value/ser.rs` from serde-json.
 
+use crate::serialize::error::SerializeError;
 use crate::serialize::writer::WriteExt;
 use crate::serialize::writer::formatter::{CompactFormatter, Formatter, PrettyFormatter};
 use serde::ser::{self, Impossible, Serialize};
 use serde_json::error::{Error, Result};
+use smallvec::{SmallVec, smallvec};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
 pub(crate) struct Serializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
+    ensure_ascii: bool,
+    escape_html: bool,
+    nonfinite_as_string: bool,
 }
 
 impl<W> Serializer<W>
@@ -17,8 +24,19 @@ where
     W: WriteExt + bytes::BufMut,
 {
     #[inline]
-    pub fn new(writer: W) -> Self {
-        Serializer::with_formatter(writer, CompactFormatter)
+    pub fn new(
+        writer: W,
+        ensure_ascii: bool,
+        escape_html: bool,
+        nonfinite_as_string: bool,
+    ) -> Self {
+        Serializer::with_formatter(
+            writer,
+            CompactFormatter,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        )
     }
 }
 
@@ -27,8 +45,20 @@ where
     W: WriteExt + bytes::BufMut,
 {
     #[inline]
-    pub fn pretty(writer: W) -> Self {
-        Serializer::with_formatter(writer, PrettyFormatter::new())
+    pub fn pretty(
+        writer: W,
+        indent: usize,
+        ensure_ascii: bool,
+        escape_html: bool,
+        nonfinite_as_string: bool,
+    ) -> Self {
+        Serializer::with_formatter(
+            writer,
+            PrettyFormatter::new(indent),
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        )
     }
 }
 
@@ -38,8 +68,30 @@ where
     F: Formatter,
 {
     #[inline]
-    pub fn with_formatter(writer: W, formatter: F) -> Self {
-        Serializer { writer, formatter }
+    pub fn with_formatter(
+        writer: W,
+        formatter: F,
+        ensure_ascii: bool,
+        escape_html: bool,
+        nonfinite_as_string: bool,
+    ) -> Self {
+        Serializer {
+            writer,
+            formatter,
+            ensure_ascii,
+            escape_html,
+            nonfinite_as_string,
+        }
+    }
+
+    /// Abort if the output has grown past the maximum size of the writer.
+    #[inline(always)]
+    fn check_size(&self) -> Result<()> {
+        if self.writer.is_too_large() {
+            cold_path!();
+            return Err(ser::Error::custom(SerializeError::OutputTooLarge));
+        }
+        Ok(())
     }
 }
 
@@ -52,7 +104,7 @@ where
     type Error = Error;
 
     type SerializeSeq = Compound<'a, W, F>;
-    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTuple = UniqueCompound<'a, W, F>;
     type SerializeTupleStruct = Impossible<(), Error>;
     type SerializeTupleVariant = Impossible<(), Error>;
     type SerializeMap = Compound<'a, W, F>;
@@ -122,7 +174,11 @@ where
     fn serialize_f32(self, value: f32) -> Result<()> {
         if value.is_infinite() || value.is_nan() {
             cold_path!();
-            self.serialize_unit()
+            if self.nonfinite_as_string {
+                self.serialize_unit_struct(nonfinite_str(f64::from(value)))
+            } else {
+                self.serialize_unit()
+            }
         } else {
             self.formatter
                 .write_f32(&mut self.writer, value)
@@ -133,7 +189,11 @@ where
     fn serialize_f64(self, value: f64) -> Result<()> {
         if value.is_infinite() || value.is_nan() {
             cold_path!();
-            self.serialize_unit()
+            if self.nonfinite_as_string {
+                self.serialize_unit_struct(nonfinite_str(value))
+            } else {
+                self.serialize_unit()
+            }
         } else {
             self.formatter
                 .write_f64(&mut self.writer, value)
@@ -147,7 +207,15 @@ where
 
     #[inline(always)]
     fn serialize_str(self, value: &str) -> Result<()> {
-        format_escaped_str(&mut self.writer, value);
+        if self.escape_html {
+            cold_path!();
+            format_escaped_str_html(&mut self.writer, value, self.ensure_ascii);
+        } else if self.ensure_ascii {
+            cold_path!();
+            format_escaped_str_ascii(&mut self.writer, value);
+        } else {
+            format_escaped_str(&mut self.writer, value);
+        }
         Ok(())
     }
 
@@ -169,7 +237,7 @@ where
 
     #[inline(always)]
     fn serialize_unit_struct(self, name: &'static str) -> Result<()> {
-        debug_assert!(name.len() <= 36);
+        debug_assert!(name.len() <= 45);
         reserve_minimum!(self.writer);
         unsafe {
             self.writer.put_u8(b'"');
@@ -232,8 +300,18 @@ where
         })
     }
 
+    /// Write an array that omits elements whose output is identical to that
+    /// of an earlier element. This is used by `OPT_DEDUPLICATE_ARRAYS`.
+    #[cold]
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
-        unreachable!();
+        self.formatter
+            .begin_array(&mut self.writer)
+            .map_err(Error::io)?;
+        Ok(UniqueCompound {
+            ser: self,
+            state: State::First,
+            seen: HashMap::new(),
+        })
     }
 
     fn serialize_tuple_struct(
@@ -310,6 +388,7 @@ where
             .unwrap();
         self.state = State::Rest;
         value.serialize(&mut *self.ser)?;
+        self.ser.check_size()?;
         self.ser
             .formatter
             .end_array_value(&mut self.ser.writer)
@@ -371,6 +450,7 @@ where
             .begin_object_value(&mut self.ser.writer)
             .unwrap();
         value.serialize(&mut *self.ser)?;
+        self.ser.check_size()?;
         self.ser
             .formatter
             .end_object_value(&mut self.ser.writer)
@@ -385,6 +465,70 @@ where
     }
 }
 
+/// Elements are compared by the hash of their output and then by the output
+/// itself. Each written element is recorded by its range in the writer, which
+/// stays valid because only the most recent element is ever truncated. Every
+/// range with the same hash is kept so that a collision does not hide a later
+/// duplicate.
+pub(crate) struct UniqueCompound<'a, W: 'a, F: 'a> {
+    ser: &'a mut Serializer<W, F>,
+    state: State,
+    seen: HashMap<u64, SmallVec<[(usize, usize); 1]>>,
+}
+
+impl<W, F> ser::SerializeTuple for UniqueCompound<'_, W, F>
+where
+    W: WriteExt + bytes::BufMut,
+    F: Formatter,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let rollback = self.ser.writer.position();
+        self.ser
+            .formatter
+            .begin_array_value(&mut self.ser.writer, self.state == State::First)
+            .unwrap();
+        let start = self.ser.writer.position();
+        value.serialize(&mut *self.ser)?;
+        self.ser
+            .formatter
+            .end_array_value(&mut self.ser.writer)
+            .map_err(Error::io)
+            .unwrap();
+        let end = self.ser.writer.position();
+
+        let written = self.ser.writer.written(start, end);
+        match self.seen.entry(xxhash_rust::xxh3::xxh3_64(written)) {
+            Entry::Vacant(entry) => {
+                entry.insert(smallvec![(start, end)]);
+            }
+            Entry::Occupied(mut entry) => {
+                if entry.get().iter().any(|&(prev_start, prev_end)| {
+                    self.ser.writer.written(prev_start, prev_end) == written
+                }) {
+                    self.ser.writer.truncate(rollback);
+                    return Ok(());
+                }
+                cold_path!();
+                entry.get_mut().push((start, end));
+            }
+        }
+        self.ser.check_size()?;
+        self.state = State::Rest;
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        self.ser.formatter.end_array(&mut self.ser.writer).unwrap();
+        Ok(())
+    }
+}
+
 #[repr(transparent)]
 struct MapKeySerializer<'a, W: 'a, F: 'a> {
     ser: &'a mut Serializer<W, F>,
@@ -664,22 +808,99 @@ where
     }
 }
 
+#[inline(never)]
+fn format_escaped_str_ascii<W>(writer: &mut W, value: &str)
+where
+    W: ?Sized + WriteExt + bytes::BufMut,
+{
+    if value.is_ascii() {
+        // e.g., a str of the compact ASCII kind, which has nothing to escape
+        // as \uXXXX, so the SIMD implementation writes the same output
+        format_escaped_str(writer, value);
+        return;
+    }
+    unsafe {
+        reserve_str!(writer, value);
+
+        let written = crate::serialize::writer::str::format_escaped_str_ascii(
+            writer.as_mut_buffer_ptr(),
+            value.as_bytes().as_ptr(),
+            value.len(),
+        );
+
+        writer.advance_mut(written);
+    }
+}
+
+#[inline(never)]
+fn format_escaped_str_html<W>(writer: &mut W, value: &str, ensure_ascii: bool)
+where
+    W: ?Sized + WriteExt + bytes::BufMut,
+{
+    unsafe {
+        reserve_str!(writer, value);
+
+        let written = crate::serialize::writer::str::format_escaped_str_html(
+            writer.as_mut_buffer_ptr(),
+            value.as_bytes().as_ptr(),
+            value.len(),
+            ensure_ascii,
+        );
+
+        writer.advance_mut(written);
+    }
+}
+
+/// The string JavaScript uses for a `NaN` or infinite `value`, which is how
+/// `OPT_NONFINITE_AS_STRING` serializes it.
+#[cold]
+#[inline(never)]
+pub(crate) fn nonfinite_str(value: f64) -> &'static str {
+    debug_assert!(!value.is_finite());
+    if value.is_nan() {
+        "NaN"
+    } else if value.is_sign_negative() {
+        "-Infinity"
+    } else {
+        "Infinity"
+    }
+}
+
 #[inline]
-pub(crate) fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+pub(crate) fn to_writer<W, T>(
+    writer: W,
+    value: &T,
+    ensure_ascii: bool,
+    escape_html: bool,
+    nonfinite_as_string: bool,
+) -> Result<()>
 where
     W: WriteExt + bytes::BufMut,
     T: ?Sized + Serialize,
 {
-    let mut ser = Serializer::new(writer);
+    let mut ser = Serializer::new(writer, ensure_ascii, escape_html, nonfinite_as_string);
     value.serialize(&mut ser)
 }
 
 #[inline]
-pub(crate) fn to_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
+pub(crate) fn to_writer_pretty<W, T>(
+    writer: W,
+    value: &T,
+    indent: usize,
+    ensure_ascii: bool,
+    escape_html: bool,
+    nonfinite_as_string: bool,
+) -> Result<()>
 where
     W: WriteExt + bytes::BufMut,
     T: ?Sized + Serialize,
 {
-    let mut ser = Serializer::pretty(writer);
+    let mut ser = Serializer::pretty(
+        writer,
+        indent,
+        ensure_ascii,
+        escape_html,
+        nonfinite_as_string,
+    );
     value.serialize(&mut ser)
 }
@@ -7,8 +7,8 @@ mod json;
 mod num;
 mod str;
 
-pub(crate) use byteswriter::{BytesWriter, WriteExt};
-pub(crate) use json::{set_str_formatter_fn, to_writer, to_writer_pretty};
+pub(crate) use byteswriter::{BoundedBytesWriter, BytesWriter, WriteExt};
+pub(crate) use json::{nonfinite_str, set_str_formatter_fn, to_writer, to_writer_pretty};
 pub(crate) use num::{
     write_float32, write_float64, write_integer_i32, write_integer_i64, write_integer_u32,
     write_integer_u64,
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Write `unit` as `\uXXXX` in lowercase hexadecimal, as `QUOTE_TAB` does.
+#[inline(always)]
+pub(super) unsafe fn write_utf16_escape(dst: *mut u8, unit: u16) -> *mut u8 {
+    unsafe {
+        core::ptr::write(dst, b'\\');
+        core::ptr::write(dst.add(1), b'u');
+        core::ptr::write(dst.add(2), HEX[usize::from(unit >> 12)]);
+        core::ptr::write(dst.add(3), HEX[usize::from((unit >> 8) & 0xf)]);
+        core::ptr::write(dst.add(4), HEX[usize::from((unit >> 4) & 0xf)]);
+        core::ptr::write(dst.add(5), HEX[usize::from(unit & 0xf)]);
+        dst.add(6)
+    }
+}
+
+/// Format `value` as a quoted string as the other implementations do and
+/// additionally escape each non-ASCII character as `\uXXXX`, or as a UTF-16
+/// surrogate pair if outside the Basic Multilingual Plane, for
+/// `OPT_ENSURE_ASCII`. A character expands to at most 12 bytes from 4, so
+/// the reservation of the other implementations suffices.
+#[inline(never)]
+pub(crate) unsafe fn format_escaped_str_ascii(
+    odst: *mut u8,
+    value_ptr: *const u8,
+    value_len: usize,
+) -> usize {
+    unsafe {
+        let mut dst = odst;
+        let value = str_from_slice!(value_ptr, value_len);
+
+        core::ptr::write(dst, b'"');
+        dst = dst.add(1);
+
+        for each in value.chars() {
+            if each.is_ascii() {
+                #[allow(clippy::cast_possible_truncation)]
+                let byte = each as u8;
+                if *super::escape::NEED_ESCAPED.get_unchecked(usize::from(byte)) != 0 {
+                    write_escape!(byte, dst);
+                } else {
+                    core::ptr::write(dst, byte);
+                    dst = dst.add(1);
+                }
+            } else {
+                let mut units = [0u16; 2];
+                for unit in each.encode_utf16(&mut units) {
+                    dst = write_utf16_escape(dst, *unit);
+                }
+            }
+        }
+
+        core::ptr::write(dst, b'"');
+        dst = dst.add(1);
+
+        dst as usize - odst as usize
+    }
+}
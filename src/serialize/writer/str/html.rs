@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::ascii::write_utf16_escape;
+
+/// The HTML entity of `byte` if it is one of the characters escaped by
+/// `html.escape()`, which are the same characters with the same entities.
+#[inline(always)]
+fn html_entity(byte: u8) -> Option<&'static [u8]> {
+    match byte {
+        b'&' => Some(b"&amp;"),
+        b'<' => Some(b"&lt;"),
+        b'>' => Some(b"&gt;"),
+        b'"' => Some(b"&quot;"),
+        b'\'' => Some(b"&#x27;"),
+        _ => None,
+    }
+}
+
+/// Format `value` as a quoted string as the other implementations do and
+/// additionally write `&`, `<`, `>`, `"`, and `'` as HTML entities for
+/// `OPT_ESCAPE_HTML`, and, if `ensure_ascii`, escape each non-ASCII
+/// character as `format_escaped_str_ascii()` does. An entity is at most 6
+/// bytes from 1, so the reservation of the other implementations suffices.
+#[inline(never)]
+pub(crate) unsafe fn format_escaped_str_html(
+    odst: *mut u8,
+    value_ptr: *const u8,
+    value_len: usize,
+    ensure_ascii: bool,
+) -> usize {
+    unsafe {
+        let mut dst = odst;
+        let value = str_from_slice!(value_ptr, value_len);
+
+        core::ptr::write(dst, b'"');
+        dst = dst.add(1);
+
+        for each in value.chars() {
+            if each.is_ascii() {
+                #[allow(clippy::cast_possible_truncation)]
+                let byte = each as u8;
+                if let Some(entity) = html_entity(byte) {
+                    core::ptr::copy_nonoverlapping(entity.as_ptr(), dst, entity.len());
+                    dst = dst.add(entity.len());
+                } else if *super::escape::NEED_ESCAPED.get_unchecked(usize::from(byte)) != 0 {
+                    write_escape!(byte, dst);
+                } else {
+                    core::ptr::write(dst, byte);
+                    dst = dst.add(1);
+                }
+            } else if ensure_ascii {
+                let mut units = [0u16; 2];
+                for unit in each.encode_utf16(&mut units) {
+                    dst = write_utf16_escape(dst, *unit);
+                }
+            } else {
+                let len = each.len_utf8();
+                each.encode_utf8(core::slice::from_raw_parts_mut(dst, len));
+                dst = dst.add(len);
+            }
+        }
+
+        core::ptr::write(dst, b'"');
+        dst = dst.add(1);
+
+        dst as usize - odst as usize
+    }
+}
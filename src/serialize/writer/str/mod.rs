@@ -6,6 +6,9 @@ mod escape;
 #[macro_use]
 mod scalar;
 
+mod ascii;
+mod html;
+
 #[cfg(all(feature = "generic_simd", not(target_arch = "x86_64")))]
 mod generic;
 
@@ -15,6 +18,9 @@ mod sse2;
 #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
 mod avx512;
 
+pub(crate) use ascii::format_escaped_str_ascii;
+pub(crate) use html::format_escaped_str_html;
+
 #[cfg(all(not(target_arch = "x86_64"), not(feature = "generic_simd")))]
 pub(crate) use scalar::format_escaped_str_scalar;
 
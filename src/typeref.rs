@@ -6,16 +6,28 @@ use core::ptr::{NonNull, null_mut};
 use once_cell::race::OnceBox;
 use std::sync::OnceLock;
 
+use crate::decoder::orjson_decodertype_new;
+use crate::encoder::orjson_encodertype_new;
 use crate::ffi::{
-    Py_DECREF, Py_False, Py_INCREF, Py_None, Py_True, Py_XDECREF, PyBool_Type, PyBytes_Type,
-    PyDict_Type, PyErr_Clear, PyErr_NewException, PyExc_TypeError, PyFloat_Type,
-    PyImport_ImportModule, PyList_Type, PyLong_Type, PyMapping_GetItemString, PyObject,
-    PyObject_GenericGetDict, PyTuple_Type, PyTypeObject, PyUnicode_InternFromString, PyUnicode_New,
-    PyUnicode_Type, orjson_fragmenttype_new,
+    Py_DECREF, Py_False, Py_INCREF, Py_None, Py_True, Py_XDECREF, PyBool_Type, PyByteArray_Type,
+    PyBytes_Type, PyDict_Type, PyErr_Clear, PyErr_NewException, PyExc_TypeError, PyFloat_Type,
+    PyImport_ImportModule, PyList_Type, PyLong_Type, PyMapping_GetItemString, PyMemoryView_Type,
+    PyObject, PyObject_GenericGetDict, PyTuple_Type, PyTypeObject, PyUnicode_InternFromString,
+    PyUnicode_New, PyUnicode_Type, orjson_epochnanosecondstype_new, orjson_fragmenttype_new,
 };
 
 pub(crate) static mut DEFAULT: *mut PyObject = null_mut();
 pub(crate) static mut OPTION: *mut PyObject = null_mut();
+pub(crate) static mut INDENT: *mut PyObject = null_mut();
+pub(crate) static mut KEY_DELIMITER: *mut PyObject = null_mut();
+pub(crate) static mut FLOAT_SIGFIGS: *mut PyObject = null_mut();
+pub(crate) static mut MAX_OUTPUT_SIZE: *mut PyObject = null_mut();
+pub(crate) static mut DATETIME_FORMATS: *mut PyObject = null_mut();
+pub(crate) static mut KEY_TRANSFORM: *mut PyObject = null_mut();
+pub(crate) static mut TYPE_HANDLERS: *mut PyObject = null_mut();
+pub(crate) static mut VALIDATE_ONLY: *mut PyObject = null_mut();
+pub(crate) static mut MAX_SEQUENCE_LEN: *mut PyObject = null_mut();
+pub(crate) static mut FIELD_SCHEMAS: *mut PyObject = null_mut();
 
 pub(crate) static mut NONE: *mut PyObject = null_mut();
 pub(crate) static mut TRUE: *mut PyObject = null_mut();
@@ -23,6 +35,8 @@ pub(crate) static mut FALSE: *mut PyObject = null_mut();
 pub(crate) static mut EMPTY_UNICODE: *mut PyObject = null_mut();
 
 pub(crate) static mut BYTES_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut BYTEARRAY_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut MEMORYVIEW_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut STR_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut INT_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut BOOL_TYPE: *mut PyTypeObject = null_mut();
@@ -33,11 +47,19 @@ pub(crate) static mut DICT_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut DATETIME_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut DATE_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut TIME_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut TIMEDELTA_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut TUPLE_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut UUID_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut ENUM_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut FLAG_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut FIELD_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut WEAK_VALUE_DICTIONARY_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut PARTIAL_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut ORDERED_DICT_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut FRAGMENT_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut EPOCH_NANOSECONDS_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut ENCODER_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut DECODER_TYPE: *mut PyTypeObject = null_mut();
 
 pub(crate) static mut ZONEINFO_TYPE: *mut PyTypeObject = null_mut();
 
@@ -48,13 +70,50 @@ pub(crate) static mut DST_STR: *mut PyObject = null_mut();
 
 pub(crate) static mut DICT_STR: *mut PyObject = null_mut();
 pub(crate) static mut DATACLASS_FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut DATACLASS_PARAMS_STR: *mut PyObject = null_mut();
+pub(crate) static mut FROZEN_STR: *mut PyObject = null_mut();
+pub(crate) static mut KEY_STR: *mut PyObject = null_mut();
+pub(crate) static mut NAMEDTUPLE_FIELDS_STR: *mut PyObject = null_mut();
 pub(crate) static mut SLOTS_STR: *mut PyObject = null_mut();
 pub(crate) static mut FIELD_TYPE_STR: *mut PyObject = null_mut();
 pub(crate) static mut ARRAY_STRUCT_STR: *mut PyObject = null_mut();
 pub(crate) static mut DTYPE_STR: *mut PyObject = null_mut();
 pub(crate) static mut DESCR_STR: *mut PyObject = null_mut();
+pub(crate) static mut MASK_STR: *mut PyObject = null_mut();
+pub(crate) static mut NUMERATOR_STR: *mut PyObject = null_mut();
+pub(crate) static mut DENOMINATOR_STR: *mut PyObject = null_mut();
 pub(crate) static mut VALUE_STR: *mut PyObject = null_mut();
 pub(crate) static mut INT_ATTR_STR: *mut PyObject = null_mut();
+pub(crate) static mut COMPRESS_STR: *mut PyObject = null_mut();
+pub(crate) static mut FLUSH_STR: *mut PyObject = null_mut();
+pub(crate) static mut JSON_METHOD_STR: *mut PyObject = null_mut();
+pub(crate) static mut ORJSON_DEFAULT_METHOD_STR: *mut PyObject = null_mut();
+pub(crate) static mut PATH_STR: *mut PyObject = null_mut();
+pub(crate) static mut CODE_STR: *mut PyObject = null_mut();
+pub(crate) static mut BYTE_POS_STR: *mut PyObject = null_mut();
+pub(crate) static mut ANNOTATIONS_STR: *mut PyObject = null_mut();
+pub(crate) static mut PYDANTIC_FIELDS_SET_STR: *mut PyObject = null_mut();
+pub(crate) static mut PYDANTIC_FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut PYDANTIC_MODEL_FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut PYDANTIC_EXTRA_STR: *mut PyObject = null_mut();
+pub(crate) static mut PYDANTIC_ROOT_MODEL_STR: *mut PyObject = null_mut();
+pub(crate) static mut ROOT_STR: *mut PyObject = null_mut();
+pub(crate) static mut ALIAS_STR: *mut PyObject = null_mut();
+pub(crate) static mut SERIALIZATION_ALIAS_STR: *mut PyObject = null_mut();
+pub(crate) static mut READ_STR: *mut PyObject = null_mut();
+pub(crate) static mut NAMES_STR: *mut PyObject = null_mut();
+pub(crate) static mut FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut STR_ATTR_STR: *mut PyObject = null_mut();
+pub(crate) static mut KEYS_STR: *mut PyObject = null_mut();
+pub(crate) static mut DATA_STR: *mut PyObject = null_mut();
+pub(crate) static mut STRFTIME_STR: *mut PyObject = null_mut();
+pub(crate) static mut QUALNAME_STR: *mut PyObject = null_mut();
+pub(crate) static mut NAME_STR: *mut PyObject = null_mut();
+pub(crate) static mut MODULE_STR: *mut PyObject = null_mut();
+pub(crate) static mut FUNC_STR: *mut PyObject = null_mut();
+pub(crate) static mut BYTES_DUNDER_STR: *mut PyObject = null_mut();
+pub(crate) static mut INCLUDE_STR: *mut PyObject = null_mut();
+pub(crate) static mut EXCLUDE_STR: *mut PyObject = null_mut();
 
 #[allow(non_upper_case_globals)]
 pub(crate) static mut JsonEncodeError: *mut PyObject = null_mut();
@@ -83,6 +142,7 @@ unsafe fn look_up_datetime() {
         DATETIME_TYPE = (*datetime_capsule).DateTimeType;
         DATE_TYPE = (*datetime_capsule).DateType;
         TIME_TYPE = (*datetime_capsule).TimeType;
+        TIMEDELTA_TYPE = (*datetime_capsule).DeltaType;
         ZONEINFO_TYPE = (*datetime_capsule).TZInfoType;
     }
 }
@@ -93,6 +153,7 @@ unsafe fn look_up_datetime() {
         DATETIME_TYPE = look_up_type_object(c"datetime", c"datetime");
         DATE_TYPE = look_up_type_object(c"datetime", c"date");
         TIME_TYPE = look_up_type_object(c"datetime", c"time");
+        TIMEDELTA_TYPE = look_up_type_object(c"datetime", c"timedelta");
         ZONEINFO_TYPE = look_up_type_object(c"zoneinfo", c"ZoneInfo");
     }
 }
@@ -107,14 +168,18 @@ pub(crate) fn init_typerefs() {
 #[cfg_attr(feature = "optimize", optimize(size))]
 fn _init_typerefs_impl() -> bool {
     unsafe {
-        debug_assert!(crate::opt::MAX_OPT < i32::from(u16::MAX));
-
         #[cfg(not(Py_GIL_DISABLED))]
         assert!(
             crate::deserialize::KEY_MAP
                 .set(crate::deserialize::KeyMap::default())
                 .is_ok()
         );
+        #[cfg(not(Py_GIL_DISABLED))]
+        assert!(
+            crate::serialize::DATACLASS_FIELDS_MAP
+                .set(crate::serialize::DataclassFieldsMap::default())
+                .is_ok()
+        );
 
         crate::serialize::writer::set_str_formatter_fn();
         crate::ffi::set_str_create_fn();
@@ -126,6 +191,8 @@ fn _init_typerefs_impl() -> bool {
 
         STR_TYPE = &raw mut PyUnicode_Type;
         BYTES_TYPE = &raw mut PyBytes_Type;
+        BYTEARRAY_TYPE = &raw mut PyByteArray_Type;
+        MEMORYVIEW_TYPE = &raw mut PyMemoryView_Type;
         DICT_TYPE = &raw mut PyDict_Type;
         LIST_TYPE = &raw mut PyList_Type;
         TUPLE_TYPE = &raw mut PyTuple_Type;
@@ -138,9 +205,16 @@ fn _init_typerefs_impl() -> bool {
 
         UUID_TYPE = look_up_type_object(c"uuid", c"UUID");
         ENUM_TYPE = look_up_type_object(c"enum", c"EnumMeta");
+        FLAG_TYPE = look_up_type_object(c"enum", c"Flag");
         FIELD_TYPE = look_up_type_object(c"dataclasses", c"_FIELD");
+        WEAK_VALUE_DICTIONARY_TYPE = look_up_type_object(c"weakref", c"WeakValueDictionary");
+        PARTIAL_TYPE = look_up_type_object(c"functools", c"partial");
+        ORDERED_DICT_TYPE = look_up_type_object(c"collections", c"OrderedDict");
 
         FRAGMENT_TYPE = orjson_fragmenttype_new();
+        EPOCH_NANOSECONDS_TYPE = orjson_epochnanosecondstype_new();
+        ENCODER_TYPE = orjson_encodertype_new();
+        DECODER_TYPE = orjson_decodertype_new();
 
         INT_ATTR_STR = PyUnicode_InternFromString(c"int".as_ptr());
         UTCOFFSET_METHOD_STR = PyUnicode_InternFromString(c"utcoffset".as_ptr());
@@ -149,14 +223,61 @@ fn _init_typerefs_impl() -> bool {
         DST_STR = PyUnicode_InternFromString(c"dst".as_ptr());
         DICT_STR = PyUnicode_InternFromString(c"__dict__".as_ptr());
         DATACLASS_FIELDS_STR = PyUnicode_InternFromString(c"__dataclass_fields__".as_ptr());
+        DATACLASS_PARAMS_STR = PyUnicode_InternFromString(c"__dataclass_params__".as_ptr());
+        FROZEN_STR = PyUnicode_InternFromString(c"frozen".as_ptr());
+        KEY_STR = PyUnicode_InternFromString(c"key".as_ptr());
+        NAMEDTUPLE_FIELDS_STR = PyUnicode_InternFromString(c"_fields".as_ptr());
         SLOTS_STR = PyUnicode_InternFromString(c"__slots__".as_ptr());
         FIELD_TYPE_STR = PyUnicode_InternFromString(c"_field_type".as_ptr());
         ARRAY_STRUCT_STR = PyUnicode_InternFromString(c"__array_struct__".as_ptr());
         DTYPE_STR = PyUnicode_InternFromString(c"dtype".as_ptr());
         DESCR_STR = PyUnicode_InternFromString(c"descr".as_ptr());
+        MASK_STR = PyUnicode_InternFromString(c"_mask".as_ptr());
+        NUMERATOR_STR = PyUnicode_InternFromString(c"numerator".as_ptr());
+        DENOMINATOR_STR = PyUnicode_InternFromString(c"denominator".as_ptr());
         VALUE_STR = PyUnicode_InternFromString(c"value".as_ptr());
+        COMPRESS_STR = PyUnicode_InternFromString(c"compress".as_ptr());
+        FLUSH_STR = PyUnicode_InternFromString(c"flush".as_ptr());
+        JSON_METHOD_STR = PyUnicode_InternFromString(c"__json__".as_ptr());
+        ORJSON_DEFAULT_METHOD_STR = PyUnicode_InternFromString(c"__orjson_default__".as_ptr());
+        PATH_STR = PyUnicode_InternFromString(c"path".as_ptr());
+        CODE_STR = PyUnicode_InternFromString(c"code".as_ptr());
+        BYTE_POS_STR = PyUnicode_InternFromString(c"byte_pos".as_ptr());
+        ANNOTATIONS_STR = PyUnicode_InternFromString(c"__annotations__".as_ptr());
+        PYDANTIC_FIELDS_SET_STR = PyUnicode_InternFromString(c"__pydantic_fields_set__".as_ptr());
+        PYDANTIC_FIELDS_STR = PyUnicode_InternFromString(c"__pydantic_fields__".as_ptr());
+        PYDANTIC_MODEL_FIELDS_STR = PyUnicode_InternFromString(c"model_fields".as_ptr());
+        PYDANTIC_EXTRA_STR = PyUnicode_InternFromString(c"__pydantic_extra__".as_ptr());
+        PYDANTIC_ROOT_MODEL_STR = PyUnicode_InternFromString(c"__pydantic_root_model__".as_ptr());
+        ROOT_STR = PyUnicode_InternFromString(c"root".as_ptr());
+        ALIAS_STR = PyUnicode_InternFromString(c"alias".as_ptr());
+        SERIALIZATION_ALIAS_STR = PyUnicode_InternFromString(c"serialization_alias".as_ptr());
+        READ_STR = PyUnicode_InternFromString(c"read".as_ptr());
+        NAMES_STR = PyUnicode_InternFromString(c"names".as_ptr());
+        FIELDS_STR = PyUnicode_InternFromString(c"fields".as_ptr());
+        STR_ATTR_STR = PyUnicode_InternFromString(c"str".as_ptr());
+        KEYS_STR = PyUnicode_InternFromString(c"keys".as_ptr());
+        DATA_STR = PyUnicode_InternFromString(c"data".as_ptr());
+        STRFTIME_STR = PyUnicode_InternFromString(c"strftime".as_ptr());
+        QUALNAME_STR = PyUnicode_InternFromString(c"__qualname__".as_ptr());
+        NAME_STR = PyUnicode_InternFromString(c"name".as_ptr());
+        MODULE_STR = PyUnicode_InternFromString(c"__module__".as_ptr());
+        FUNC_STR = PyUnicode_InternFromString(c"func".as_ptr());
+        BYTES_DUNDER_STR = PyUnicode_InternFromString(c"__bytes__".as_ptr());
+        INCLUDE_STR = PyUnicode_InternFromString(c"include".as_ptr());
+        EXCLUDE_STR = PyUnicode_InternFromString(c"exclude".as_ptr());
         DEFAULT = PyUnicode_InternFromString(c"default".as_ptr());
         OPTION = PyUnicode_InternFromString(c"option".as_ptr());
+        INDENT = PyUnicode_InternFromString(c"indent".as_ptr());
+        KEY_DELIMITER = PyUnicode_InternFromString(c"key_delimiter".as_ptr());
+        FLOAT_SIGFIGS = PyUnicode_InternFromString(c"float_sigfigs".as_ptr());
+        MAX_OUTPUT_SIZE = PyUnicode_InternFromString(c"max_output_size".as_ptr());
+        DATETIME_FORMATS = PyUnicode_InternFromString(c"datetime_formats".as_ptr());
+        KEY_TRANSFORM = PyUnicode_InternFromString(c"key_transform".as_ptr());
+        TYPE_HANDLERS = PyUnicode_InternFromString(c"type_handlers".as_ptr());
+        VALIDATE_ONLY = PyUnicode_InternFromString(c"validate_only".as_ptr());
+        MAX_SEQUENCE_LEN = PyUnicode_InternFromString(c"max_sequence_len".as_ptr());
+        FIELD_SCHEMAS = PyUnicode_InternFromString(c"field_schemas".as_ptr());
 
         JsonEncodeError = PyExc_TypeError;
         Py_INCREF(JsonEncodeError);
@@ -176,6 +297,8 @@ fn _init_typerefs_impl() -> bool {
 
 pub(crate) struct NumpyTypes {
     pub array: *mut PyTypeObject,
+    pub recarray: *mut PyTypeObject,
+    pub masked_array: *mut PyTypeObject,
     pub float64: *mut PyTypeObject,
     pub float32: *mut PyTypeObject,
     pub float16: *mut PyTypeObject,
@@ -214,8 +337,24 @@ pub(crate) fn load_numpy_types() -> Box<Option<NonNull<NumpyTypes>>> {
             return Box::new(None);
         }
         let numpy_module_dict = PyObject_GenericGetDict(numpy, null_mut());
+        // numpy.ma is not necessarily imported by numpy
+        let masked_array = {
+            let numpy_ma = PyImport_ImportModule(c"numpy.ma".as_ptr());
+            if numpy_ma.is_null() {
+                PyErr_Clear();
+                null_mut()
+            } else {
+                let numpy_ma_dict = PyObject_GenericGetDict(numpy_ma, null_mut());
+                let ptr = look_up_numpy_type(numpy_ma_dict, c"MaskedArray");
+                Py_XDECREF(numpy_ma_dict);
+                Py_XDECREF(numpy_ma);
+                ptr
+            }
+        };
         let types = Box::new(NumpyTypes {
             array: look_up_numpy_type(numpy_module_dict, c"ndarray"),
+            recarray: look_up_numpy_type(numpy_module_dict, c"recarray"),
+            masked_array: masked_array,
             float16: look_up_numpy_type(numpy_module_dict, c"half"),
             float32: look_up_numpy_type(numpy_module_dict, c"float32"),
             float64: look_up_numpy_type(numpy_module_dict, c"float64"),
@@ -235,3 +374,51 @@ pub(crate) fn load_numpy_types() -> Box<Option<NonNull<NumpyTypes>>> {
         Box::new(Some(nonnull!(Box::<NumpyTypes>::into_raw(types))))
     }
 }
+
+pub(crate) static mut FRACTION_TYPE: OnceBox<Option<NonNull<PyTypeObject>>> = OnceBox::new();
+
+/// `fractions.Fraction`, which is imported on first use because `fractions`
+/// imports `decimal`.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn load_fraction_type() -> Box<Option<NonNull<PyTypeObject>>> {
+    unsafe {
+        let fractions = PyImport_ImportModule(c"fractions".as_ptr());
+        if fractions.is_null() {
+            PyErr_Clear();
+            return Box::new(None);
+        }
+        let fractions_module_dict = PyObject_GenericGetDict(fractions, null_mut());
+        let fraction = PyMapping_GetItemString(fractions_module_dict, c"Fraction".as_ptr());
+        Py_XDECREF(fractions_module_dict);
+        Py_XDECREF(fractions);
+        if fraction.is_null() {
+            PyErr_Clear();
+        }
+        // the module holds a reference to the type
+        Py_XDECREF(fraction);
+        Box::new(NonNull::new(fraction.cast::<PyTypeObject>()))
+    }
+}
+
+pub(crate) static mut ZLIB_COMPRESSOBJ: OnceBox<Option<NonNull<PyObject>>> = OnceBox::new();
+
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn load_zlib_compressobj() -> Box<Option<NonNull<PyObject>>> {
+    unsafe {
+        let zlib = PyImport_ImportModule(c"zlib".as_ptr());
+        if zlib.is_null() {
+            PyErr_Clear();
+            return Box::new(None);
+        }
+        let zlib_module_dict = PyObject_GenericGetDict(zlib, null_mut());
+        let compressobj = PyMapping_GetItemString(zlib_module_dict, c"compressobj".as_ptr());
+        Py_XDECREF(zlib_module_dict);
+        Py_XDECREF(zlib);
+        if compressobj.is_null() {
+            PyErr_Clear();
+        }
+        Box::new(NonNull::new(compressobj))
+    }
+}
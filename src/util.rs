@@ -53,18 +53,6 @@ macro_rules! is_subclass_by_flag {
     };
 }
 
-macro_rules! is_subclass_by_type {
-    ($ob_type:expr, $type:ident) => {
-        unsafe {
-            (*($ob_type.cast::<crate::ffi::PyTypeObject>()))
-                .ob_base
-                .ob_base
-                .ob_type
-                == $type
-        }
-    };
-}
-
 macro_rules! err {
     ($msg:expr) => {
         return Err(serde::ser::Error::custom($msg))
@@ -0,0 +1,288 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import typing
+
+import pytest
+
+import orjson
+
+
+class Point:
+    x: int
+    y: int
+
+    def __init__(self, x, y):
+        self.y = y
+        self.x = x
+
+
+class Labeled(Point):
+    label: str
+
+    def __init__(self, x, y, label):
+        super().__init__(x, y)
+        self.label = label
+
+
+class Redeclared(Labeled):
+    x: int
+    z: int
+
+    def __init__(self):
+        super().__init__(1, 2, "a")
+        self.z = 3
+
+
+class Optional:
+    a: int
+    b: int
+    c: int = 3
+
+    def __init__(self, a):
+        self.a = a
+
+
+class Private:
+    a: int
+    _b: int
+
+    def __init__(self):
+        self.a = 1
+        self._b = 2
+
+
+class Slots:
+    __slots__ = ("a", "b")
+    a: int
+    b: str
+
+    def __init__(self):
+        self.a = 1
+        self.b = "b"
+
+
+class Property:
+    a: int
+
+    @property
+    def a(self):  # type: ignore
+        raise ValueError
+
+
+class Unannotated:
+    def __init__(self):
+        self.a = 1
+
+
+class WithJson(Point):
+    def __json__(self):
+        return [self.x, self.y]
+
+
+class WithOrjsonDefault(Point):
+    def __orjson_default__(self):
+        return "point"
+
+
+class TestAnnotated:
+    def test_annotated(self):
+        """
+        attributes are in annotation order rather than assignment order
+        """
+        assert (
+            orjson.dumps(Point(1, 2), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_annotated_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Point(1, 2))
+
+    def test_annotated_inherited(self):
+        assert (
+            orjson.dumps(Labeled(1, 2, "a"), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"x":1,"y":2,"label":"a"}'
+        )
+
+    def test_annotated_redeclared(self):
+        """
+        a name redeclared by a subclass keeps the position in its base
+        """
+        assert (
+            orjson.dumps(Redeclared(), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"x":1,"y":2,"label":"a","z":3}'
+        )
+
+    def test_annotated_missing(self):
+        assert (
+            orjson.dumps(Optional(1), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"a":1,"c":3}'
+        )
+
+    def test_annotated_missing_as_null(self):
+        assert (
+            orjson.dumps(
+                Optional(1),
+                option=orjson.OPT_SERIALIZE_ANNOTATED
+                | orjson.OPT_ANNOTATED_MISSING_AS_NULL,
+            )
+            == b'{"a":1,"b":null,"c":3}'
+        )
+
+    def test_annotated_missing_as_null_only(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Optional(1), option=orjson.OPT_ANNOTATED_MISSING_AS_NULL)
+
+    def test_annotated_private(self):
+        assert (
+            orjson.dumps(Private(), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"a":1}'
+        )
+
+    def test_annotated_slots(self):
+        assert (
+            orjson.dumps(Slots(), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"a":1,"b":"b"}'
+        )
+
+    def test_annotated_nested(self):
+        assert (
+            orjson.dumps(
+                {"a": [Point(1, Point(2, 3))]}, option=orjson.OPT_SERIALIZE_ANNOTATED
+            )
+            == b'{"a":[{"x":1,"y":{"x":2,"y":3}}]}'
+        )
+
+    def test_annotated_empty(self):
+        obj = Optional(1)
+        del obj.a
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ANNOTATED) == b'{"c":3}'
+
+    def test_annotated_unannotated(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Unannotated(), option=orjson.OPT_SERIALIZE_ANNOTATED)
+
+    def test_annotated_unannotated_default(self):
+        def default(obj):
+            if isinstance(obj, Unannotated):
+                return obj.a
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                [Unannotated()], option=orjson.OPT_SERIALIZE_ANNOTATED, default=default
+            )
+            == b"[1]"
+        )
+
+    def test_annotated_property_raises(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Property(), option=orjson.OPT_SERIALIZE_ANNOTATED)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_annotated_value_error_path(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([Point(1, object())], option=orjson.OPT_SERIALIZE_ANNOTATED)
+        assert exc_info.value.path == [0, "y"]
+
+    def test_annotated_dataclass(self):
+        """
+        dataclasses serialize with their fields
+        """
+
+        @dataclasses.dataclass
+        class Data:
+            a: int
+            b: typing.ClassVar[int] = 2
+
+        assert (
+            orjson.dumps(Data(1), option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"a":1}'
+        )
+
+    def test_annotated_recursion(self):
+        obj = Point(1, 2)
+        obj.y = obj
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ANNOTATED)
+
+    def test_annotated_dict_key(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Point(1, 2): 1},
+                option=orjson.OPT_SERIALIZE_ANNOTATED | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_annotated_json_method(self):
+        """
+        __json__ with OPT_SERIALIZE_JSON_METHOD takes precedence
+        """
+        obj = WithJson(1, 2)
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_ANNOTATED
+                | orjson.OPT_SERIALIZE_JSON_METHOD,
+            )
+            == b"[1,2]"
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_annotated_orjson_default(self):
+        """
+        __orjson_default__ with OPT_SERIALIZE_ORJSON_DEFAULT takes precedence
+        """
+        assert (
+            orjson.dumps(
+                WithOrjsonDefault(1, 2),
+                option=orjson.OPT_SERIALIZE_ANNOTATED
+                | orjson.OPT_SERIALIZE_ORJSON_DEFAULT,
+            )
+            == b'"point"'
+        )
+
+    def test_annotated_type_handlers(self):
+        """
+        A handler in type_handlers for the exact type takes precedence
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_SERIALIZE_ANNOTATED,
+            type_handlers={Point: lambda obj: obj.x},
+        )
+        assert encoder.encode([Point(1, 2), Labeled(1, 2, "a")]) == (
+            b'[1,{"x":1,"y":2,"label":"a"}]'
+        )
+
+    def test_annotated_default(self):
+        """
+        default is not called for an annotated instance, as for a dataclass,
+        unless OPT_DEFAULT_FIRST is specified
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            if isinstance(obj, Point):
+                return "default"
+            raise TypeError
+
+        obj = Point(1, 2)
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_SERIALIZE_ANNOTATED)
+            == b'{"x":1,"y":2}'
+        )
+        assert calls == []
+        assert (
+            orjson.dumps(
+                obj,
+                default=default,
+                option=orjson.OPT_SERIALIZE_ANNOTATED | orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'"default"'
+        )
+        assert calls == [obj]
@@ -3,6 +3,7 @@
 
 import datetime
 import inspect
+import io
 import json
 import re
 
@@ -170,8 +171,97 @@ class TestApi:
         """
         dumps() option out of range high
         """
+        max_opt = max(
+            getattr(orjson, name) for name in dir(orjson) if name.startswith("OPT_")
+        )
         with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps(True, option=1 << 12)
+            orjson.dumps(True, option=max_opt << 1)
+
+    def test_option_range_wide(self):
+        """
+        dumps() option of more than 128 bits, with an invalid bit in its high or
+        low 64 bits, or a negative int of more than 64 bits
+        """
+        for val in (
+            1 << 128,
+            (1 << 128) | orjson.OPT_INDENT_2,
+            orjson.OPT_ENSURE_ASCII | (1 << 63),
+            orjson.OPT_ENSURE_ASCII << 63,
+            -(1 << 64),
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(True, option=val)
+
+    def test_option_wide_constants(self):
+        """
+        Options that apply once to the whole output or only to loads() are
+        2**64 or greater and combine with the other options
+        """
+        for val in (
+            orjson.OPT_CACHE_KEYS,
+            orjson.OPT_ENSURE_ASCII,
+            orjson.OPT_ESCAPE_HTML,
+            orjson.OPT_OMIT_TOP_LEVEL_NONE,
+            orjson.OPT_PARSE_NUMBER_AS_STRING,
+            orjson.OPT_PARSE_OBJECT_PAIRS,
+            orjson.OPT_STRICT_INPUT,
+        ):
+            assert val >= 1 << 64
+        assert orjson.OPT_SORT_KEYS < 1 << 63
+        assert (
+            orjson.dumps(
+                {"b": "é", "a": 1},
+                option=orjson.OPT_ENSURE_ASCII | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":1,"b":"\\u00e9"}'
+        )
+
+    def test_option_loads_only(self):
+        """
+        dumps() and Encoder reject an option that only applies to loads()
+        """
+        for val in (
+            orjson.OPT_CACHE_KEYS,
+            orjson.OPT_PARSE_NUMBER_AS_STRING,
+            orjson.OPT_PARSE_OBJECT_PAIRS,
+            orjson.OPT_STRICT_INPUT,
+            orjson.OPT_SORT_KEYS | orjson.OPT_CACHE_KEYS,
+        ):
+            with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+                orjson.dumps(True, option=val)
+            with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+                orjson.Encoder(option=val)
+
+    def test_loads_option_dumps_only(self):
+        """
+        loads(), load(), and Decoder reject an option that only applies to
+        dumps()
+        """
+        for val in (
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_ENSURE_ASCII,
+            orjson.OPT_CACHE_KEYS | orjson.OPT_NAIVE_UTC,
+        ):
+            with pytest.raises(orjson.JSONDecodeError, match="Invalid opts"):
+                orjson.loads(b"{}", option=val)
+            with pytest.raises(orjson.JSONDecodeError, match="Invalid opts"):
+                orjson.load(io.BytesIO(b"{}"), option=val)
+            with pytest.raises(orjson.JSONDecodeError, match="Invalid opts"):
+                orjson.Decoder(option=val)
+
+    def test_loads_option_valid(self):
+        """
+        loads() accepts every option that applies to it
+        """
+        option = (
+            orjson.OPT_CACHE_KEYS
+            | orjson.OPT_INT_BASE62
+            | orjson.OPT_PARSE_NUMBER_AS_STRING
+            | orjson.OPT_PARSE_OBJECT_PAIRS
+            | orjson.OPT_STRICT_INPUT
+        )
+        assert orjson.loads(b'{"a": 1}', option=option) == [("a", "1")]
 
     def test_opts_multiple(self):
         """
@@ -245,18 +335,61 @@ class TestApi:
         """
         assert (
             str(inspect.signature(orjson.dumps))
-            == "(obj, /, default=None, option=None)"
+            == "(obj, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)"
         )
         inspect.signature(orjson.dumps).bind("str")
         inspect.signature(orjson.dumps).bind("str", default=default, option=1)
         inspect.signature(orjson.dumps).bind("str", default=None, option=None)
+        inspect.signature(orjson.dumps).bind("str", indent=4)
+        inspect.signature(orjson.dumps).bind("str", key_delimiter="|")
+        inspect.signature(orjson.dumps).bind("str", float_sigfigs=3)
+        inspect.signature(orjson.dumps).bind("str", validate_only=True)
+        inspect.signature(orjson.dumps).bind("str", max_sequence_len=10)
+
+    def test_dumps_into_buffer_signature(self):
+        """
+        dumps_into_buffer() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.dumps_into_buffer))
+            == "(obj, buffer, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)"
+        )
+        buf = bytearray(8)
+        inspect.signature(orjson.dumps_into_buffer).bind("str", buf)
+        inspect.signature(orjson.dumps_into_buffer).bind(
+            "str", buf, default=default, option=1
+        )
+        inspect.signature(orjson.dumps_into_buffer).bind("str", buf, indent=4)
+        inspect.signature(orjson.dumps_into_buffer).bind("str", buf, max_output_size=4)
+        inspect.signature(orjson.dumps_into_buffer).bind(
+            "str", buf, validate_only=True
+        )
+        inspect.signature(orjson.dumps_into_buffer).bind(
+            "str", buf, max_sequence_len=10
+        )
+
+    def test_dumps_chunked_signature(self):
+        """
+        dumps_chunked() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.dumps_chunked))
+            == "(obj, chunk_size, /, default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, key_transform=None, validate_only=False, max_sequence_len=None)"
+        )
+        inspect.signature(orjson.dumps_chunked).bind("str", 8)
+        inspect.signature(orjson.dumps_chunked).bind("str", 8, default=default, option=1)
+        inspect.signature(orjson.dumps_chunked).bind("str", 8, indent=4)
+        inspect.signature(orjson.dumps_chunked).bind("str", 8, max_output_size=4)
+        inspect.signature(orjson.dumps_chunked).bind("str", 8, validate_only=True)
+        inspect.signature(orjson.dumps_chunked).bind("str", 8, max_sequence_len=10)
 
     def test_loads_signature(self):
         """
         loads() valid __text_signature__
         """
-        assert str(inspect.signature(orjson.loads)), "(obj == /)"
+        assert str(inspect.signature(orjson.loads)) == "(obj, /, option=None)"
         inspect.signature(orjson.loads).bind("[]")
+        inspect.signature(orjson.loads).bind("[]", option=orjson.OPT_CACHE_KEYS)
 
     def test_dumps_module_str(self):
         """
@@ -0,0 +1,585 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import array
+import base64
+import dataclasses
+import gzip
+import mmap
+import threading
+import uuid
+
+import pytest
+
+import orjson
+
+GZIP_PREFIX = "data:application/gzip;base64,"
+
+
+def gzip_decode(value: str) -> bytes:
+    assert value.startswith(GZIP_PREFIX)
+    return gzip.decompress(base64.b64decode(value[len(GZIP_PREFIX) :]))
+
+
+@dataclasses.dataclass
+class Blob:
+    name: str
+    data: bytes
+
+
+@dataclasses.dataclass
+class BlobView:
+    name: str
+    data: memoryview
+
+
+@dataclasses.dataclass
+class BlobSlots:
+    __slots__ = ("data", "name")
+    name: str
+    data: bytes
+
+
+class TestBytesGzip:
+    def test_bytes_gzip_roundtrip(self):
+        for obj in (b"", b"a", b"ab", b"abc", b"\x00\xff" * 17, bytes(range(256))):
+            serialized = orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+            assert gzip_decode(orjson.loads(serialized)) == obj
+
+    def test_bytes_gzip_large(self):
+        obj = b"orjson" * 100_000
+        serialized = orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+        assert len(serialized) < len(obj) // 10
+        assert gzip_decode(orjson.loads(serialized)) == obj
+
+    def test_bytes_gzip_deterministic(self):
+        obj = b"payload" * 10
+        first = orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+        second = orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+        assert first == second
+
+    def test_bytes_gzip_container(self):
+        obj = {"a": [b"first", {"b": b"second"}], "c": (b"third",)}
+        deserialized = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP),
+        )
+        assert gzip_decode(deserialized["a"][0]) == b"first"
+        assert gzip_decode(deserialized["a"][1]["b"]) == b"second"
+        assert gzip_decode(deserialized["c"][0]) == b"third"
+
+    def test_bytes_gzip_indent(self):
+        serialized = orjson.dumps(
+            [b"a"],
+            option=orjson.OPT_SERIALIZE_BYTES_GZIP | orjson.OPT_INDENT_2,
+        )
+        assert serialized.startswith(b'[\n  "data:application/gzip;base64,')
+        assert gzip_decode(orjson.loads(serialized)[0]) == b"a"
+
+    def test_bytes_gzip_default_unused(self):
+        def default(obj):
+            raise AssertionError
+
+        serialized = orjson.dumps(
+            b"a",
+            default=default,
+            option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+        )
+        assert gzip_decode(orjson.loads(serialized)) == b"a"
+
+    def test_bytes_gzip_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(b"a")
+
+    def test_bytes_gzip_bytearray(self):
+        """
+        OPT_SERIALIZE_BYTES_GZIP serializes bytearray the same as bytes
+        """
+        for obj in (b"", b"a", bytes(range(256))):
+            assert orjson.dumps(
+                bytearray(obj), option=orjson.OPT_SERIALIZE_BYTES_GZIP
+            ) == orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_bytes_gzip_bytearray_container(self):
+        obj = {"a": [bytearray(b"first")], "b": bytearray(b"second")}
+        deserialized = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP),
+        )
+        assert gzip_decode(deserialized["a"][0]) == b"first"
+        assert gzip_decode(deserialized["b"]) == b"second"
+
+    def test_bytes_gzip_bytearray_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(bytearray(b"a"))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(bytearray(b"a"), option=orjson.OPT_BYTES_AS_UUID)
+
+    def test_bytes_gzip_bytearray_subclass(self):
+        class SubByteArray(bytearray):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SubByteArray(b"a"), option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_bytes_gzip_bytearray_mutated(self):
+        """
+        a bytearray written to by another thread is compressed from a
+        snapshot of its contents
+        """
+        size = 1 << 20
+        fills = (b"a" * size, b"b" * (size // 2))
+        buf = bytearray(fills[0])
+        stop = threading.Event()
+
+        def mutate():
+            idx = 0
+            while not stop.is_set():
+                idx ^= 1
+                buf[:] = fills[idx]
+
+        thread = threading.Thread(target=mutate)
+        thread.start()
+        try:
+            for _ in range(20):
+                serialized = orjson.dumps([buf], option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+                assert gzip_decode(orjson.loads(serialized)[0]) in fills
+        finally:
+            stop.set()
+            thread.join()
+
+    def test_bytes_gzip_subclass(self):
+        class SubBytes(bytes):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SubBytes(b"a"), option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_bytes_gzip_dict_key(self):
+        deserialized = orjson.loads(
+            orjson.dumps(
+                {b"a": True},
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP | orjson.OPT_NON_STR_KEYS,
+            )
+        )
+        assert [gzip_decode(key) for key in deserialized] == [b"a"]
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({b"a": True}, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_bytes_gzip_dataclass(self):
+        deserialized = orjson.loads(
+            orjson.dumps(Blob("a", b"payload"), option=orjson.OPT_SERIALIZE_BYTES_GZIP),
+        )
+        assert deserialized["name"] == "a"
+        assert gzip_decode(deserialized["data"]) == b"payload"
+
+    def test_bytes_gzip_dataclass_slots(self):
+        deserialized = orjson.loads(
+            orjson.dumps(
+                BlobSlots("a", b"payload"),
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+            ),
+        )
+        assert deserialized["name"] == "a"
+        assert gzip_decode(deserialized["data"]) == b"payload"
+
+    def test_bytes_gzip_dataclass_memoryview(self):
+        """
+        a memoryview field serializes the same as the bytes it views
+        """
+        buf = bytearray(b"payload" * 10)
+        assert orjson.dumps(
+            BlobView("a", memoryview(buf)),
+            option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+        ) == orjson.dumps(
+            Blob("a", bytes(buf)),
+            option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+        )
+
+    def test_bytes_gzip_dataclass_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Blob("a", b"payload"))
+
+    def test_memoryview_gzip(self):
+        for obj in (b"", b"a", bytes(range(256))):
+            serialized = orjson.dumps(
+                memoryview(obj),
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+            )
+            assert serialized == orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+            )
+
+    def test_memoryview_gzip_slice(self):
+        view = memoryview(b"0123456789")[2:5]
+        serialized = orjson.dumps(view, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+        assert gzip_decode(orjson.loads(serialized)) == b"234"
+
+    def test_memoryview_gzip_container(self):
+        obj = {"a": [memoryview(b"first")], "b": memoryview(bytearray(b"second"))}
+        deserialized = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES_GZIP),
+        )
+        assert gzip_decode(deserialized["a"][0]) == b"first"
+        assert gzip_decode(deserialized["b"]) == b"second"
+
+    def test_memoryview_gzip_not_contiguous(self):
+        view = memoryview(b"0123456789")[::2]
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(view, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_memoryview_gzip_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(memoryview(b"a"))
+
+
+class TestBytesAsUuid:
+    def test_bytes_as_uuid(self):
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        assert (
+            orjson.dumps(val.bytes, option=orjson.OPT_BYTES_AS_UUID)
+            == b'"f81d4fae-7dec-11d0-a765-00a0c91e6bf6"'
+        )
+
+    def test_bytes_as_uuid_same_as_uuid(self):
+        for val in (uuid.UUID(int=0), uuid.UUID(int=2**128 - 1), uuid.uuid4()):
+            for option in (
+                0,
+                orjson.OPT_UUID_BRACED,
+                orjson.OPT_UUID_HEX,
+                orjson.OPT_UUID_URN,
+            ):
+                assert orjson.dumps(
+                    [val.bytes],
+                    option=orjson.OPT_BYTES_AS_UUID | option,
+                ) == orjson.dumps([val], option=option)
+
+    def test_bytes_as_uuid_container(self):
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        assert (
+            orjson.dumps(
+                {"a": [val.bytes], "b": Blob("x", val.bytes)},
+                option=orjson.OPT_BYTES_AS_UUID,
+            )
+            == b'{"a":["f81d4fae-7dec-11d0-a765-00a0c91e6bf6"],'
+            b'"b":{"name":"x","data":"f81d4fae-7dec-11d0-a765-00a0c91e6bf6"}}'
+        )
+
+    def test_bytes_as_uuid_other_length(self):
+        for val in (b"", b"a" * 15, b"a" * 17):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(val, option=orjson.OPT_BYTES_AS_UUID)
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([val], option=orjson.OPT_BYTES_AS_UUID)
+
+    def test_bytes_as_uuid_other_length_default(self):
+        assert (
+            orjson.dumps(
+                [b"a" * 15],
+                default=lambda obj: obj.hex(),
+                option=orjson.OPT_BYTES_AS_UUID,
+            )
+            == b'["616161616161616161616161616161"]'
+        )
+
+    def test_bytes_as_uuid_other_length_gzip(self):
+        serialized = orjson.dumps(
+            [b"a" * 17, b"b" * 16],
+            option=orjson.OPT_BYTES_AS_UUID | orjson.OPT_SERIALIZE_BYTES_GZIP,
+        )
+        deserialized = orjson.loads(serialized)
+        assert gzip_decode(deserialized[0]) == b"a" * 17
+        assert deserialized[1] == str(uuid.UUID(bytes=b"b" * 16))
+
+    def test_bytes_as_uuid_not_bytes(self):
+        val = uuid.uuid4().bytes
+        for obj in (bytearray(val), memoryview(val)):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_BYTES_AS_UUID)
+
+    def test_bytes_as_uuid_subclass(self):
+        class Bytes(bytes):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Bytes(16), option=orjson.OPT_BYTES_AS_UUID)
+
+    def test_bytes_as_uuid_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(uuid.uuid4().bytes)
+
+
+class SubBytes(bytes):
+    pass
+
+
+class TestBytesDictKey:
+    def test_bytes_dict_key_subclass_gzip(self):
+        """
+        A key that is a subclass of bytes is serialized as bytes with
+        OPT_NON_STR_KEYS and OPT_SERIALIZE_BYTES_GZIP
+        """
+        deserialized = orjson.loads(
+            orjson.dumps(
+                {SubBytes(b"a"): 1, b"b": 2},
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP | orjson.OPT_NON_STR_KEYS,
+            )
+        )
+        assert [(gzip_decode(key), val) for key, val in deserialized.items()] == [
+            (b"a", 1),
+            (b"b", 2),
+        ]
+
+    def test_bytes_dict_key_subclass_uuid(self):
+        """
+        A key that is a subclass of bytes of 16 bytes is serialized as a UUID
+        with OPT_NON_STR_KEYS and OPT_BYTES_AS_UUID
+        """
+        val = uuid.UUID("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")
+        assert (
+            orjson.dumps(
+                {SubBytes(val.bytes): 1},
+                option=orjson.OPT_BYTES_AS_UUID | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"f81d4fae-7dec-11d0-a765-00a0c91e6bf6":1}'
+        )
+        assert (
+            orjson.dumps(
+                {SubBytes(val.bytes): 1},
+                option=orjson.OPT_BYTES_AS_UUID
+                | orjson.OPT_UUID_HEX
+                | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"f81d4fae7dec11d0a76500a0c91e6bf6":1}'
+        )
+
+    def test_bytes_dict_key_subclass_same_as_bytes(self):
+        """
+        A key that is a subclass of bytes is serialized the same as bytes
+        """
+        for option in (
+            orjson.OPT_SERIALIZE_BYTES_GZIP,
+            orjson.OPT_BYTES_AS_UUID,
+            orjson.OPT_BYTES_AS_UUID | orjson.OPT_SERIALIZE_BYTES_GZIP,
+        ):
+            option |= orjson.OPT_NON_STR_KEYS
+            for val in (b"", b"a", b"a" * 16, bytes(range(256))):
+                try:
+                    expected = orjson.dumps({val: 1}, option=option)
+                except orjson.JSONEncodeError:
+                    with pytest.raises(orjson.JSONEncodeError):
+                        orjson.dumps({SubBytes(val): 1}, option=option)
+                else:
+                    assert orjson.dumps({SubBytes(val): 1}, option=option) == expected
+
+    def test_bytes_dict_key_uuid_other_length(self):
+        """
+        A key of other than 16 bytes raises with only OPT_BYTES_AS_UUID
+        """
+        for val in (b"a" * 15, SubBytes(b"a" * 17)):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(
+                    {val: 1},
+                    option=orjson.OPT_BYTES_AS_UUID | orjson.OPT_NON_STR_KEYS,
+                )
+            assert (
+                str(exc_info.value)
+                == "Dict key must a type serializable with OPT_NON_STR_KEYS"
+            )
+
+    def test_bytes_dict_key_subclass_sort_keys(self):
+        """
+        A key that is a subclass of bytes is sorted by its serialized str
+        """
+        val = uuid.UUID(int=1).bytes
+        assert (
+            orjson.dumps(
+                {SubBytes(val): 1, "a": 2},
+                option=orjson.OPT_BYTES_AS_UUID
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"00000000-0000-0000-0000-000000000001":1,"a":2}'
+        )
+
+    def test_bytes_dict_key_subclass_not_enabled(self):
+        """
+        A key that is a subclass of bytes raises without a bytes option or
+        without OPT_NON_STR_KEYS, and a value is not serialized
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({SubBytes(b"a"): 1}, option=orjson.OPT_NON_STR_KEYS)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({SubBytes(b"a"): 1}, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {"a": SubBytes(b"a")},
+                option=orjson.OPT_SERIALIZE_BYTES_GZIP | orjson.OPT_NON_STR_KEYS,
+            )
+
+
+class WithBytes:
+    def __bytes__(self):
+        return b"with bytes"
+
+
+class BytesRaises:
+    def __bytes__(self):
+        raise ValueError("invalid")
+
+
+class TestSerializeBytes:
+    def test_serialize_bytes(self):
+        """
+        OPT_SERIALIZE_BYTES serializes bytes as a base64 str
+        """
+        for obj in (b"", b"a", b"ab", b"abc", bytes(range(256))):
+            assert (
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES)
+                == b'"%s"' % base64.b64encode(obj)
+            )
+
+    def test_serialize_bytes_buffer(self):
+        """
+        OPT_SERIALIZE_BYTES serializes objects supporting the buffer protocol
+        """
+        arr = array.array("i", [1, 2, 3])
+        for obj in (bytearray(b"abc"), memoryview(b"abc"), arr, SubBytes(b"abc")):
+            assert (
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES)
+                == b'"%s"' % base64.b64encode(bytes(obj))
+            )
+
+    def test_serialize_bytes_mmap(self):
+        """
+        OPT_SERIALIZE_BYTES serializes a mmap.mmap
+        """
+        with mmap.mmap(-1, 4) as obj:
+            obj.write(b"abcd")
+            assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES) == b'"YWJjZA=="'
+
+    def test_serialize_bytes_mmap_closed(self):
+        """
+        Getting the buffer of a closed mmap.mmap raises JSONEncodeError with
+        the exception as __cause__
+        """
+        obj = mmap.mmap(-1, 4)
+        obj.close()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([obj], option=orjson.OPT_SERIALIZE_BYTES)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.path == [0]
+
+    def test_serialize_bytes_not_contiguous(self):
+        """
+        A buffer that is not C-contiguous is copied
+        """
+        view = memoryview(b"0123456789")[::2]
+        assert orjson.dumps(view, option=orjson.OPT_SERIALIZE_BYTES) == b'"MDI0Njg="'
+        view = memoryview(array.array("i", range(6)))[::-1]
+        assert (
+            orjson.dumps(view, option=orjson.OPT_SERIALIZE_BYTES)
+            == b'"%s"' % base64.b64encode(view.tobytes())
+        )
+
+    def test_serialize_bytes_dunder(self):
+        """
+        OPT_SERIALIZE_BYTES serializes an object whose type has __bytes__()
+        """
+        assert (
+            orjson.dumps([WithBytes()], option=orjson.OPT_SERIALIZE_BYTES)
+            == b'["d2l0aCBieXRlcw=="]'
+        )
+
+    def test_serialize_bytes_dunder_raises(self):
+        """
+        An exception raised by __bytes__() is the __cause__ of JSONEncodeError
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": BytesRaises()}, option=orjson.OPT_SERIALIZE_BYTES)
+        assert (
+            str(exc_info.value)
+            == "getting the bytes of an object raised an exception: BytesRaises"
+        )
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.path == ["a"]
+
+    def test_serialize_bytes_dunder_instance(self):
+        """
+        __bytes__ is looked up on the type and not the instance
+        """
+
+        class Plain:
+            pass
+
+        obj = Plain()
+        obj.__bytes__ = lambda: b"a"  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(bytes, option=orjson.OPT_SERIALIZE_BYTES)
+
+    def test_serialize_bytes_default_unused(self):
+        """
+        default is not called for an object that is serialized as bytes
+        """
+
+        def default(obj):
+            raise AssertionError
+
+        option = orjson.OPT_SERIALIZE_BYTES
+        assert orjson.dumps(bytearray(b"a"), default, option=option) == b'"YQ=="'
+
+    def test_serialize_bytes_container(self):
+        obj = {"a": [b"a", (memoryview(b"b"),)], "b": Blob("c", b"c")}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_BYTES)
+            == b'{"a":["YQ==",["Yg=="]],"b":{"name":"c","data":"Yw=="}}'
+        )
+
+    def test_serialize_bytes_gzip(self):
+        """
+        OPT_SERIALIZE_BYTES_GZIP takes precedence for bytes, bytearray, and
+        memoryview
+        """
+        serialized = orjson.loads(
+            orjson.dumps(
+                [b"a", bytearray(b"a"), memoryview(b"a"), WithBytes(), SubBytes(b"a")],
+                option=orjson.OPT_SERIALIZE_BYTES | orjson.OPT_SERIALIZE_BYTES_GZIP,
+            )
+        )
+        assert [gzip_decode(each) for each in serialized[:3]] == [b"a"] * 3
+        assert serialized[3:] == ["d2l0aCBieXRlcw==", "YQ=="]
+
+    def test_serialize_bytes_as_uuid(self):
+        """
+        OPT_BYTES_AS_UUID takes precedence for bytes of 16 bytes
+        """
+        assert (
+            orjson.dumps(
+                [bytes(16), bytes(15)],
+                option=orjson.OPT_SERIALIZE_BYTES | orjson.OPT_BYTES_AS_UUID,
+            )
+            == b'["00000000-0000-0000-0000-000000000000","AAAAAAAAAAAAAAAAAAAA"]'
+        )
+
+    def test_serialize_bytes_dict_key(self):
+        """
+        OPT_SERIALIZE_BYTES with OPT_NON_STR_KEYS serializes a key that is
+        bytes or a read-only memoryview as base64
+        """
+        assert (
+            orjson.dumps(
+                {b"a": 1, memoryview(b"b"): 2},
+                option=orjson.OPT_SERIALIZE_BYTES | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"YQ==":1,"Yg==":2}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({b"a": 1}, option=orjson.OPT_SERIALIZE_BYTES)
+
+    def test_serialize_bytes_disabled(self):
+        for obj in (bytearray(b"a"), array.array("i"), WithBytes()):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(WithBytes(), option=orjson.OPT_SERIALIZE_BYTES_GZIP)
@@ -0,0 +1,97 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+
+class TestCacheKeys:
+    def test_cache_keys_array_of_objects(self):
+        doc = orjson.dumps([{"id": idx, "name": str(idx)} for idx in range(100)])
+        deserialized = orjson.loads(doc, option=orjson.OPT_CACHE_KEYS)
+        assert deserialized == orjson.loads(doc)
+        first = list(deserialized[0])
+        for each in deserialized[1:]:
+            for key, other in zip(first, each):
+                assert key is other
+
+    def test_cache_keys_long(self):
+        key = "a" * 1024
+        deserialized = orjson.loads(
+            orjson.dumps([{key: 1}, {key: 2}]),
+            option=orjson.OPT_CACHE_KEYS,
+        )
+        assert list(deserialized[0])[0] is list(deserialized[1])[0]
+
+    def test_cache_keys_nested(self):
+        deserialized = orjson.loads(
+            b'{"a":{"a":{"a":[{"a":null}]}}}',
+            option=orjson.OPT_CACHE_KEYS,
+        )
+        assert deserialized == {"a": {"a": {"a": [{"a": None}]}}}
+        inner = deserialized["a"]["a"]["a"][0]
+        assert list(deserialized)[0] is list(inner)[0]
+
+    def test_cache_keys_distinct(self):
+        assert orjson.loads(
+            b'[{"a":1,"b":2},{"b":3,"c":4},{"\\u00e9":5}]',
+            option=orjson.OPT_CACHE_KEYS,
+        ) == [{"a": 1, "b": 2}, {"b": 3, "c": 4}, {"é": 5}]
+
+    def test_cache_keys_not_values(self):
+        """
+        OPT_CACHE_KEYS does not cache str values
+        """
+        value = "v" * 1024
+        deserialized = orjson.loads(
+            orjson.dumps([value, value]),
+            option=orjson.OPT_CACHE_KEYS,
+        )
+        assert deserialized == [value, value]
+        assert deserialized[0] is not deserialized[1]
+
+    def test_cache_keys_scalar(self):
+        assert orjson.loads(b'"a"', option=orjson.OPT_CACHE_KEYS) == "a"
+
+    def test_cache_keys_invalid_document(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(b'[{"a":1},{"a":', option=orjson.OPT_CACHE_KEYS)
+
+    def test_cache_keys_positional(self):
+        assert orjson.loads(b'{"a":1}', orjson.OPT_CACHE_KEYS) == {"a": 1}
+
+
+class TestLoadsOption:
+    def test_loads_option_none(self):
+        assert orjson.loads(b'{"a":1}', option=None) == {"a": 1}
+
+    def test_loads_option_zero(self):
+        assert orjson.loads(b'{"a":1}', option=0) == {"a": 1}
+
+    def test_loads_option_invalid_type(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(b"[]", option="1")
+
+    def test_loads_option_invalid_range(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(b"[]", option=-1)
+
+    def test_loads_option_multiple(self):
+        with pytest.raises(TypeError):
+            orjson.loads(b"[]", 0, option=0)
+
+    def test_loads_option_unexpected_keyword(self):
+        with pytest.raises(TypeError):
+            orjson.loads(b"[]", default=None)
+
+    def test_loads_missing_argument(self):
+        with pytest.raises(TypeError):
+            orjson.loads()
+
+    def test_loads_too_many_positional(self):
+        for args in ((None,), (None, None), (None, None, None)):
+            with pytest.raises(
+                TypeError, match="takes at most 2 positional arguments"
+            ):
+                orjson.loads(b"1", None, *args)  # type: ignore
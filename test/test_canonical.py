@@ -1,6 +1,10 @@
 # SPDX-License-Identifier: MPL-2.0
 # Copyright ijl (2019-2022)
 
+import struct
+
+import pytest
+
 import orjson
 
 
@@ -25,3 +29,146 @@ class TestCanonicalTests:
             orjson.dumps({"spaces": "\u2028 \u2029"})
             == b'{"spaces":"\xe2\x80\xa8 \xe2\x80\xa9"}'
         )
+
+
+class TestOptCanonical:
+    def test_canonical_rfc8785_example(self):
+        """
+        OPT_CANONICAL RFC 8785 section 3.2.2
+        """
+        obj = orjson.loads(
+            r"""{
+              "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+              "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+              "literals": [null, true, false]
+            }"""
+        )
+        expected = r"""{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"""
+        assert orjson.dumps(obj, option=orjson.OPT_CANONICAL) == expected.encode("utf-8")
+
+    def test_canonical_rfc8785_sorting(self):
+        """
+        OPT_CANONICAL RFC 8785 section 3.2.3 sorts by UTF-16 code units
+        """
+        obj = {
+            "€": "Euro Sign",
+            "\r": "Carriage Return",
+            "דּ": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\U0001f600": "Emoji: Grinning Face",
+            "\u0080": "Control",
+            "ö": "Latin Small Letter O With Diaeresis",
+        }
+        assert list(
+            orjson.loads(orjson.dumps(obj, option=orjson.OPT_CANONICAL)).keys()
+        ) == ["\r", "1", "\u0080", "ö", "€", "\U0001f600", "דּ"]
+
+    def test_canonical_rfc8785_numbers(self):
+        """
+        OPT_CANONICAL RFC 8785 appendix B
+        """
+        for val, expected in (
+            (0x0000000000000000, b"0"),
+            (0x8000000000000000, b"0"),
+            (0x0000000000000001, b"5e-324"),
+            (0x8000000000000001, b"-5e-324"),
+            (0x7FEFFFFFFFFFFFFF, b"1.7976931348623157e+308"),
+            (0xFFEFFFFFFFFFFFFF, b"-1.7976931348623157e+308"),
+            (0x4340000000000000, b"9007199254740992"),
+            (0xC340000000000000, b"-9007199254740992"),
+            (0x4430000000000000, b"295147905179352830000"),
+            (0x44B52D02C7E14AF5, b"9.999999999999997e+22"),
+            (0x44B52D02C7E14AF6, b"1e+23"),
+            (0x44B52D02C7E14AF7, b"1.0000000000000001e+23"),
+            (0x444B1AE4D6E2EF4E, b"999999999999999700000"),
+            (0x444B1AE4D6E2EF4F, b"999999999999999900000"),
+            (0x444B1AE4D6E2EF50, b"1e+21"),
+            (0x3EB0C6F7A0B5ED8C, b"9.999999999999997e-7"),
+            (0x3EB0C6F7A0B5ED8D, b"0.000001"),
+            (0x41B3DE4355555553, b"333333333.3333332"),
+            (0x41B3DE4355555554, b"333333333.33333325"),
+            (0x41B3DE4355555555, b"333333333.3333333"),
+            (0x41B3DE4355555556, b"333333333.3333334"),
+            (0x41B3DE4355555557, b"333333333.33333343"),
+            (0xBECBF647612F3696, b"-0.0000033333333333333333"),
+            (0x43143FF3C1CB0959, b"1424953923781206.2"),
+        ):
+            obj = struct.unpack(">d", val.to_bytes(8, "big"))[0]
+            assert orjson.dumps(obj, option=orjson.OPT_CANONICAL) == expected
+            assert orjson.dumps([obj], option=orjson.OPT_CANONICAL) == b"[" + expected + b"]"
+
+    def test_canonical_float_integral(self):
+        """
+        OPT_CANONICAL float with an integral value has no fraction
+        """
+        assert orjson.dumps([1.0, -2.0, 1e20], option=orjson.OPT_CANONICAL) == (
+            b"[1,-2,100000000000000000000]"
+        )
+
+    def test_canonical_float_nonfinite(self):
+        """
+        OPT_CANONICAL NaN and Infinity raise
+        """
+        for obj in (float("nan"), float("inf"), float("-inf")):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps({"a": [obj]}, option=orjson.OPT_CANONICAL)
+            assert str(exc_info.value) == "Float must be finite with OPT_CANONICAL"
+
+    def test_canonical_integer_53_bits(self):
+        """
+        OPT_CANONICAL integers must be exactly representable as a double
+        """
+        assert (
+            orjson.dumps(9007199254740991, option=orjson.OPT_CANONICAL)
+            == b"9007199254740991"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(9007199254740992, option=orjson.OPT_CANONICAL)
+
+    def test_canonical_sorts_keys(self):
+        """
+        OPT_CANONICAL sorts nested keys
+        """
+        assert (
+            orjson.dumps({"b": {"d": 1, "c": 2}, "a": []}, option=orjson.OPT_CANONICAL)
+            == b'{"a":[],"b":{"c":2,"d":1}}'
+        )
+
+    def test_canonical_equal_inputs(self):
+        """
+        OPT_CANONICAL is byte-identical for semantically equal inputs
+        """
+        assert orjson.dumps(
+            {"x": 1.0, "y": [0.5, -0.0]}, option=orjson.OPT_CANONICAL
+        ) == orjson.dumps({"y": [5e-1, 0.0], "x": 1}, option=orjson.OPT_CANONICAL)
+
+    def test_canonical_non_str_keys(self):
+        """
+        OPT_CANONICAL OPT_NON_STR_KEYS float keys
+        """
+        assert (
+            orjson.dumps(
+                {1.0: True, 1e21: False},
+                option=orjson.OPT_CANONICAL | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":true,"1e+21":false}'
+        )
+
+    def test_canonical_incompatible(self):
+        """
+        OPT_CANONICAL with options that change whitespace, escaping, or order
+        """
+        for option in (
+            orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_ENSURE_ASCII,
+            orjson.OPT_ESCAPE_HTML,
+            orjson.OPT_FLOAT_TRAILING_ZERO,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_NONFINITE_AS_STRING,
+            orjson.OPT_SERIALIZE_NUMPY,
+            orjson.OPT_SORT_KEYS_BY_HASH,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([], option=orjson.OPT_CANONICAL | option)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([], option=orjson.OPT_CANONICAL, indent=2)
@@ -3,7 +3,7 @@
 
 import abc
 import uuid
-from dataclasses import InitVar, asdict, dataclass, field
+from dataclasses import InitVar, asdict, dataclass, field, make_dataclass
 from enum import Enum
 from typing import ClassVar, Optional
 
@@ -86,6 +86,13 @@ class UnsortedDataclass:
     d: dict | None
 
 
+@dataclass(slots=True)
+class UnsortedSlotsDataclass:
+    c: int
+    b: int
+    a: int
+
+
 @dataclass
 class InitDataclass:
     a: InitVar[str]
@@ -142,6 +149,18 @@ class TestDataclass:
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(obj1)
 
+    def test_dataclass_circular_self(self):
+        """
+        dumps() dataclass referencing itself
+        """
+        obj = Dataclass1("a", 1, None)
+        obj.sub = obj
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj)
+        assert str(exc_info.value) == (
+            "Recursion limit reached at $" + "['sub']" * 255
+        )
+
     def test_dataclass_empty(self):
         """
         dumps() no attributes
@@ -203,6 +222,34 @@ class TestDataclass:
         assert "__dict__" not in dir(obj)
         assert orjson.dumps(obj) == b'{"a":"a","b":1}'
 
+    def test_dataclass_slots_repeated(self):
+        """
+        dumps() dataclass with __slots__ repeatedly, as its fields are cached
+        """
+        obj = [Slotsdataclass("a", idx, "c", "d") for idx in range(3)]
+        for _ in range(2):
+            assert (
+                orjson.dumps(obj)
+                == b'[{"a":"a","b":0},{"a":"a","b":1},{"a":"a","b":2}]'
+            )
+
+    def test_dataclass_slots_chained(self):
+        """
+        dumps() chained dataclasses with __slots__ of more types than are cached
+        """
+        types = [
+            make_dataclass(
+                f"Chained{idx}", [("idx", int), ("child", object)], slots=True
+            )
+            for idx in range(200)
+        ]
+        obj = None
+        for idx, each in enumerate(types):
+            obj = each(idx, obj)
+        expected = orjson.dumps(asdict(obj))
+        for _ in range(2):
+            assert orjson.dumps(obj) == expected
+
     def test_dataclass_default(self):
         """
         dumps() dataclass with default
@@ -296,6 +343,86 @@ class TestDataclassPassthrough:
         )
 
 
+class TestDataclassSortFields:
+    def test_dataclass_sort_fields(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS serializes fields in sorted order
+        """
+        obj = UnsortedDataclass(1, 2, 3, None)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'{"a":3,"b":2,"c":1,"d":null}'
+        )
+
+    def test_dataclass_sort_fields_slots(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS applies to a dataclass with __slots__
+        """
+        obj = UnsortedSlotsDataclass(1, 2, 3)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'{"a":3,"b":2,"c":1}'
+        )
+
+    def test_dataclass_sort_fields_under(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS does not include under attributes, InitVar,
+        or ClassVar
+        """
+        obj = InitDataclass("zxc", "vbn")
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'{"ab":"zxc vbn"}'
+        )
+        obj = Slotsdataclass("a", 1, "c", "d")
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'{"a":"a","b":1}'
+        )
+
+    def test_dataclass_sort_fields_dict(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS does not sort dict keys
+        """
+        obj = UnsortedDataclass(1, 2, 3, {"f": 2, "e": 1})
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'{"a":3,"b":2,"c":1,"d":{"f":2,"e":1}}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SORT_DATACLASS_FIELDS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":3,"b":2,"c":1,"d":{"e":1,"f":2}}'
+        )
+
+    def test_dataclass_sort_fields_nested(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS applies to a nested dataclass
+        """
+        obj = [UnsortedDataclass(1, 2, 3, None), {"a": UnsortedSlotsDataclass(4, 5, 6)}]
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+            == b'[{"a":3,"b":2,"c":1,"d":null},{"a":{"a":6,"b":5,"c":4}}]'
+        )
+
+    def test_dataclass_sort_fields_error_path(self):
+        """
+        The path of an error in a sorted field
+        """
+        obj = UnsortedDataclass(1, 2, object(), None)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS)
+        assert exc_info.value.path == ["a"]
+
+    def test_dataclass_sort_fields_empty(self):
+        """
+        OPT_SORT_DATACLASS_FIELDS with a dataclass without fields
+        """
+        for obj in (EmptyDataclass(), EmptyDataclassSlots()):
+            assert orjson.dumps(obj, option=orjson.OPT_SORT_DATACLASS_FIELDS) == b"{}"
+
 class TestAbstractDataclass:
     def test_dataclass_abc(self):
         obj = ConcreteAbc(1.0)
@@ -1,5 +1,5 @@
 # SPDX-License-Identifier: MPL-2.0
-# Copyright ijl (2019-2025)
+# Copyright ijl (2019-2026)
 
 import datetime
 
@@ -380,6 +380,75 @@ class TestDatetime:
             == b'["2018-12-01T02:03:04-05:00"]'
         )
 
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_datetime_zoneinfo_fold(self):
+        """
+        datetime.datetime fold selects the offset of an ambiguous time at a
+        fall-back transition
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        obj = [
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=0),
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=1),
+        ]
+        assert (
+            orjson.dumps(obj)
+            == b'["2023-11-05T01:30:00-04:00","2023-11-05T01:30:00-05:00"]'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NORMALIZE_UTC)
+            == b'["2023-11-05T05:30:00+00:00","2023-11-05T06:30:00+00:00"]'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_DATETIME_AS_UNIX)
+            == b"[1699162200.0,1699165800.0]"
+        )
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_datetime_zoneinfo_fold_unambiguous(self):
+        """
+        datetime.datetime fold does not change the offset of an unambiguous
+        time
+        """
+        tzinfo = zoneinfo.ZoneInfo("America/New_York")
+        for fold in (0, 1):
+            assert (
+                orjson.dumps(
+                    datetime.datetime(2023, 11, 5, 2, 30, tzinfo=tzinfo, fold=fold),
+                )
+                == b'"2023-11-05T02:30:00-05:00"'
+            )
+
+    @pytest.mark.skipif(tz is None, reason="dateutil optional")
+    def test_datetime_dateutil_fold(self):
+        """
+        datetime.datetime fold with a dateutil timezone
+        """
+        tzinfo = tz.gettz("America/New_York")
+        obj = [
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=0),
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=1),
+        ]
+        assert (
+            orjson.dumps(obj)
+            == b'["2023-11-05T01:30:00-04:00","2023-11-05T01:30:00-05:00"]'
+        )
+
+    @pytest.mark.skipif(pendulum is None, reason="pendulum not installed")
+    def test_datetime_pendulum_fold(self):
+        """
+        datetime.datetime fold with a pendulum timezone
+        """
+        tzinfo = pendulum.timezone("America/New_York")
+        obj = [
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=0),
+            datetime.datetime(2023, 11, 5, 1, 30, tzinfo=tzinfo, fold=1),
+        ]
+        assert (
+            orjson.dumps(obj)
+            == b'["2023-11-05T01:30:00-04:00","2023-11-05T01:30:00-05:00"]'
+        )
+
     @pytest.mark.skipif(pytz is None, reason="pytz optional")
     def test_datetime_pytz_negative_non_dst(self):
         """
@@ -741,122 +810,1358 @@ class TestDatetime:
             assert getattr(obj, attr) == getattr(parsed, attr)
 
 
-class TestDate:
-    def test_date(self):
+class TestDatetimeNormalizeUtc:
+    def test_datetime_normalize_utc_mixed(self):
         """
-        datetime.date
+        OPT_NORMALIZE_UTC converts aware datetime of different offsets to UTC
         """
-        assert orjson.dumps([datetime.date(2000, 1, 13)]) == b'["2000-01-13"]'
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(
+                        2018,
+                        12,
+                        1,
+                        2,
+                        3,
+                        4,
+                        9,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=5)),
+                    ),
+                    datetime.datetime(
+                        2018,
+                        12,
+                        1,
+                        2,
+                        3,
+                        4,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=-5)),
+                    ),
+                    datetime.datetime(2018, 12, 1, 2, 3, 4, tzinfo=datetime.timezone.utc),
+                ],
+                option=orjson.OPT_NORMALIZE_UTC,
+            )
+            == b'["2018-11-30T21:03:04.000009+00:00","2018-12-01T07:03:04+00:00","2018-12-01T02:03:04+00:00"]'
+        )
 
-    def test_date_min(self):
+    def test_datetime_normalize_utc_astimezone(self):
         """
-        datetime.date MINYEAR
+        OPT_NORMALIZE_UTC is equivalent to astimezone(datetime.timezone.utc)
         """
+        offsets = (
+            datetime.timedelta(hours=14),
+            datetime.timedelta(hours=5, minutes=30),
+            datetime.timedelta(minutes=1),
+            datetime.timedelta(minutes=-1),
+            datetime.timedelta(hours=-9, minutes=-30),
+            datetime.timedelta(hours=-23, minutes=-59),
+        )
+        values = (
+            datetime.datetime(1, 1, 2, 0, 0, 0),
+            datetime.datetime(1900, 3, 1, 0, 0, 0),
+            datetime.datetime(2000, 2, 29, 23, 59, 59, 999999),
+            datetime.datetime(2000, 3, 1, 0, 0, 0),
+            datetime.datetime(2023, 12, 31, 23, 30, 0),
+            datetime.datetime(2024, 1, 1, 0, 30, 0),
+            datetime.datetime(9999, 12, 30, 23, 59, 59),
+        )
+        for offset in offsets:
+            for value in values:
+                obj = value.replace(tzinfo=datetime.timezone(offset))
+                ref = obj.astimezone(datetime.timezone.utc).isoformat()
+                assert (
+                    orjson.dumps(obj, option=orjson.OPT_NORMALIZE_UTC)
+                    == f'"{ref}"'.encode()
+                )
+
+    def test_datetime_normalize_utc_z(self):
         assert (
-            orjson.dumps([datetime.date(datetime.MINYEAR, 1, 1)]) == b'["0001-01-01"]'
+            orjson.dumps(
+                datetime.datetime(
+                    1970,
+                    1,
+                    1,
+                    tzinfo=datetime.timezone(datetime.timedelta(hours=1)),
+                ),
+                option=orjson.OPT_NORMALIZE_UTC | orjson.OPT_UTC_Z,
+            )
+            == b'"1969-12-31T23:00:00Z"'
         )
 
-    def test_date_max(self):
-        """
-        datetime.date MAXYEAR
-        """
+    def test_datetime_normalize_utc_omit_microseconds(self):
         assert (
-            orjson.dumps([datetime.date(datetime.MAXYEAR, 12, 31)]) == b'["9999-12-31"]'
+            orjson.dumps(
+                datetime.datetime(
+                    2000,
+                    1,
+                    1,
+                    0,
+                    0,
+                    0,
+                    123,
+                    tzinfo=datetime.timezone(datetime.timedelta(hours=1)),
+                ),
+                option=orjson.OPT_NORMALIZE_UTC | orjson.OPT_OMIT_MICROSECONDS,
+            )
+            == b'"1999-12-31T23:00:00+00:00"'
         )
 
-    def test_date_three_digits(self):
+    def test_datetime_normalize_utc_naive(self):
         """
-        datetime.date three digit year
+        OPT_NORMALIZE_UTC does not change naive datetime
         """
         assert (
             orjson.dumps(
-                [datetime.date(312, 1, 1)],
+                datetime.datetime(2000, 1, 1, 2, 3, 4),
+                option=orjson.OPT_NORMALIZE_UTC,
             )
-            == b'["0312-01-01"]'
+            == b'"2000-01-01T02:03:04"'
         )
 
-    def test_date_two_digits(self):
+    def test_datetime_normalize_utc_naive_utc(self):
+        assert (
+            orjson.dumps(
+                datetime.datetime(2000, 1, 1, 2, 3, 4),
+                option=orjson.OPT_NORMALIZE_UTC | orjson.OPT_NAIVE_UTC,
+            )
+            == b'"2000-01-01T02:03:04+00:00"'
+        )
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_datetime_normalize_utc_zoneinfo(self):
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(
+                        2018,
+                        6,
+                        1,
+                        2,
+                        3,
+                        4,
+                        tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam"),
+                    ),
+                    datetime.datetime(
+                        2018,
+                        12,
+                        1,
+                        2,
+                        3,
+                        4,
+                        tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam"),
+                    ),
+                    datetime.datetime(
+                        2018,
+                        12,
+                        1,
+                        2,
+                        3,
+                        4,
+                        tzinfo=zoneinfo.ZoneInfo("Asia/Kolkata"),
+                    ),
+                ],
+                option=orjson.OPT_NORMALIZE_UTC,
+            )
+            == b'["2018-06-01T00:03:04+00:00","2018-12-01T01:03:04+00:00","2018-11-30T20:33:04+00:00"]'
+        )
+
+    def test_datetime_normalize_utc_dict_key(self):
+        assert (
+            orjson.dumps(
+                {
+                    datetime.datetime(
+                        2000,
+                        1,
+                        1,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=2)),
+                    ): True,
+                },
+                option=orjson.OPT_NORMALIZE_UTC | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1999-12-31T22:00:00+00:00":true}'
+        )
+
+    def test_datetime_normalize_utc_min(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                datetime.datetime(
+                    1,
+                    1,
+                    1,
+                    tzinfo=datetime.timezone(datetime.timedelta(hours=1)),
+                ),
+                option=orjson.OPT_NORMALIZE_UTC,
+            )
+        assert str(exc_info.value) == "datetime is out of range when converted to UTC"
+
+    def test_datetime_normalize_utc_max(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {
+                    datetime.datetime(
+                        9999,
+                        12,
+                        31,
+                        23,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=-1)),
+                    ): True,
+                },
+                option=orjson.OPT_NORMALIZE_UTC | orjson.OPT_NON_STR_KEYS,
+            )
+
+
+class TestDatetimeNaiveAsNull:
+    def test_datetime_naive_as_null(self):
         """
-        datetime.date two digit year
+        OPT_NAIVE_AS_NULL serializes naive datetime as null
         """
         assert (
             orjson.dumps(
-                [datetime.date(46, 1, 1)],
+                [datetime.datetime(2000, 1, 1, 2, 3, 4, 123)],
+                option=orjson.OPT_NAIVE_AS_NULL,
             )
-            == b'["0046-01-01"]'
+            == b"[null]"
         )
 
-
-class TestTime:
-    def test_time(self):
+    def test_datetime_naive_as_null_dict_value(self):
         """
-        datetime.time
+        OPT_NAIVE_AS_NULL dict value
         """
-        assert orjson.dumps([datetime.time(12, 15, 59, 111)]) == b'["12:15:59.000111"]'
-        assert orjson.dumps([datetime.time(12, 15, 59)]) == b'["12:15:59"]'
+        assert (
+            orjson.dumps(
+                {"a": datetime.datetime(2000, 1, 1)},
+                option=orjson.OPT_NAIVE_AS_NULL,
+            )
+            == b'{"a":null}'
+        )
 
-    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
-    def test_time_tz(self):
+    def test_datetime_naive_as_null_aware(self):
         """
-        datetime.time with tzinfo error
+        OPT_NAIVE_AS_NULL does not affect aware datetime
         """
-        with pytest.raises(orjson.JSONEncodeError):
+        assert (
             orjson.dumps(
                 [
-                    datetime.time(
-                        12,
-                        15,
-                        59,
-                        111,
-                        tzinfo=zoneinfo.ZoneInfo("Asia/Shanghai"),
-                    ),
+                    datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+                    datetime.datetime(2000, 1, 1),
                 ],
+                option=orjson.OPT_NAIVE_AS_NULL,
             )
+            == b'["2000-01-01T00:00:00+00:00",null]'
+        )
 
-    def test_time_microsecond_max(self):
+    def test_datetime_naive_as_null_naive_utc(self):
         """
-        datetime.time microsecond max
+        OPT_NAIVE_UTC takes precedence over OPT_NAIVE_AS_NULL
         """
-        assert orjson.dumps(datetime.time(0, 0, 0, 999999)) == b'"00:00:00.999999"'
+        assert (
+            orjson.dumps(
+                datetime.datetime(2000, 1, 1),
+                option=orjson.OPT_NAIVE_AS_NULL | orjson.OPT_NAIVE_UTC,
+            )
+            == b'"2000-01-01T00:00:00+00:00"'
+        )
 
-    def test_time_microsecond_min(self):
+    def test_datetime_naive_as_null_date_time(self):
         """
-        datetime.time microsecond min
+        OPT_NAIVE_AS_NULL does not affect date or time
         """
-        assert orjson.dumps(datetime.time(0, 0, 0, 1)) == b'"00:00:00.000001"'
-
+        assert (
+            orjson.dumps(
+                [datetime.date(2000, 1, 1), datetime.time(12, 0)],
+                option=orjson.OPT_NAIVE_AS_NULL,
+            )
+            == b'["2000-01-01","12:00:00"]'
+        )
 
-class TestDateclassPassthrough:
-    def test_passthrough_datetime(self):
-        with pytest.raises(orjson.JSONEncodeError):
+    def test_datetime_naive_as_null_key(self):
+        """
+        OPT_NAIVE_AS_NULL does not affect dict keys
+        """
+        assert (
             orjson.dumps(
-                datetime.datetime(1970, 1, 1),
-                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                {datetime.datetime(2000, 1, 1): 1},
+                option=orjson.OPT_NAIVE_AS_NULL | orjson.OPT_NON_STR_KEYS,
             )
+            == b'{"2000-01-01T00:00:00":1}'
+        )
 
-    def test_passthrough_date(self):
-        with pytest.raises(orjson.JSONEncodeError):
+    def test_datetime_naive_as_null_passthrough(self):
+        """
+        OPT_PASSTHROUGH_DATETIME takes precedence over OPT_NAIVE_AS_NULL
+        """
+        assert (
             orjson.dumps(
-                datetime.date(1970, 1, 1),
-                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                datetime.datetime(2000, 1, 1),
+                option=orjson.OPT_NAIVE_AS_NULL | orjson.OPT_PASSTHROUGH_DATETIME,
+                default=lambda obj: "default",
             )
+            == b'"default"'
+        )
 
-    def test_passthrough_time(self):
-        with pytest.raises(orjson.JSONEncodeError):
+
+UNIX = orjson.OPT_SERIALIZE_DATETIME_AS_UNIX
+UNIX_MS = orjson.OPT_SERIALIZE_DATETIME_AS_UNIX | orjson.OPT_UNIX_MILLISECONDS
+
+
+class TestDatetimeAsUnix:
+    def test_datetime_as_unix(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX serializes seconds with microseconds
+        """
+        val = datetime.datetime(
+            2023, 11, 14, 22, 13, 20, 123456, tzinfo=datetime.timezone.utc
+        )
+        assert orjson.dumps(val, option=UNIX) == b"1700000000.123456"
+        assert orjson.dumps([val], option=UNIX) == b"[1700000000.123456]"
+        assert orjson.dumps({"a": val}, option=UNIX) == b'{"a":1700000000.123456}'
+
+    def test_datetime_as_unix_whole_second(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX serializes a whole second as a float
+        """
+        val = datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc)
+        assert orjson.dumps(val, option=UNIX) == b"0.0"
+
+    def test_datetime_as_unix_milliseconds(self):
+        """
+        OPT_UNIX_MILLISECONDS serializes an integer number of milliseconds
+        """
+        val = datetime.datetime(
+            2023, 11, 14, 22, 13, 20, 123999, tzinfo=datetime.timezone.utc
+        )
+        assert orjson.dumps(val, option=UNIX_MS) == b"1700000000123"
+
+    def test_datetime_as_unix_milliseconds_not_enabled(self):
+        """
+        OPT_UNIX_MILLISECONDS has no effect without OPT_SERIALIZE_DATETIME_AS_UNIX
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps(val, option=orjson.OPT_UNIX_MILLISECONDS)
+            == b'"2000-01-01T00:00:00+00:00"'
+        )
+
+    def test_datetime_as_unix_offset(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX is the same as timestamp()
+        """
+        for val in (
+            datetime.datetime(
+                2000,
+                1,
+                1,
+                2,
+                3,
+                4,
+                5,
+                tzinfo=datetime.timezone(datetime.timedelta(hours=-5, minutes=-30)),
+            ),
+            datetime.datetime(1, 1, 1, tzinfo=datetime.timezone.utc),
+            datetime.datetime(
+                9999,
+                12,
+                31,
+                23,
+                59,
+                59,
+                tzinfo=datetime.timezone(datetime.timedelta(hours=-5)),
+            ),
+        ):
+            assert orjson.loads(orjson.dumps(val, option=UNIX)) == val.timestamp()
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_datetime_as_unix_zoneinfo(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX with a zoneinfo timezone
+        """
+        val = datetime.datetime(
+            2020, 6, 1, tzinfo=zoneinfo.ZoneInfo("America/New_York")
+        )
+        assert orjson.dumps(val, option=UNIX) == b"1590984000.0"
+        assert orjson.dumps(val, option=UNIX_MS) == b"1590984000000"
+
+    def test_datetime_as_unix_before_epoch(self):
+        """
+        A datetime before 1970 is negative and milliseconds are rounded down
+        """
+        val = datetime.datetime(
+            1969, 12, 31, 23, 59, 59, 999500, tzinfo=datetime.timezone.utc
+        )
+        assert orjson.dumps(val, option=UNIX) == b"-0.0005"
+        assert orjson.dumps(val, option=UNIX_MS) == b"-1"
+
+    def test_datetime_as_unix_naive(self):
+        """
+        A naive datetime raises unless OPT_NAIVE_UTC or OPT_NAIVE_AS_NULL
+        """
+        val = datetime.datetime(2000, 1, 1)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(val, option=UNIX)
+        assert str(exc_info.value) == (
+            "datetime without tzinfo cannot be serialized as a Unix timestamp: "
+            "use OPT_NAIVE_UTC"
+        )
+        assert orjson.dumps(val, option=UNIX | orjson.OPT_NAIVE_UTC) == b"946684800.0"
+        assert orjson.dumps(val, option=UNIX | orjson.OPT_NAIVE_AS_NULL) == b"null"
+
+    def test_datetime_as_unix_date(self):
+        """
+        A date is midnight UTC
+        """
+        assert orjson.dumps(datetime.date(2000, 1, 1), option=UNIX) == b"946684800.0"
+        assert orjson.dumps(datetime.date(1969, 12, 31), option=UNIX) == b"-86400.0"
+        assert (
+            orjson.dumps([datetime.date(2000, 1, 1)], option=UNIX_MS)
+            == b"[946684800000]"
+        )
+
+    def test_datetime_as_unix_time(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX does not affect time
+        """
+        assert orjson.dumps(datetime.time(12, 0), option=UNIX) == b'"12:00:00"'
+
+    def test_datetime_as_unix_key(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX dict keys are the timestamp
+        """
+        val = datetime.datetime(2000, 1, 1, 0, 0, 0, 500, tzinfo=datetime.timezone.utc)
+        assert (
             orjson.dumps(
-                datetime.time(12, 0, 0),
-                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                {val: 1, datetime.date(2000, 1, 2): 2},
+                option=UNIX | orjson.OPT_NON_STR_KEYS,
             )
+            == b'{"946684800.0005":1,"946771200.0":2}'
+        )
+        assert (
+            orjson.dumps({val: 1}, option=UNIX_MS | orjson.OPT_NON_STR_KEYS)
+            == b'{"946684800000":1}'
+        )
 
-    def test_passthrough_datetime_default(self):
-        def default(obj):
-            return obj.strftime("%a, %d %b %Y %H:%M:%S GMT")
+    def test_datetime_as_unix_milliseconds_key(self):
+        """
+        OPT_UNIX_MILLISECONDS dict keys are the same integer as the value
+        """
+        vals = [
+            datetime.datetime(2023, 11, 14, 22, 13, 20, 123456, timezone(hours=2)),
+            datetime.datetime(1969, 12, 31, 23, 59, 59, 999500, timezone()),
+            datetime.date(1969, 12, 31),
+        ]
+        assert (
+            orjson.dumps(
+                dict.fromkeys(vals, 1), option=UNIX_MS | orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"1699992800123":1,"-1":1,"-86400000":1}'
+        )
+        for val in vals:
+            assert orjson.dumps(
+                {val: 1}, option=UNIX_MS | orjson.OPT_NON_STR_KEYS
+            ) == b'{"%s":1}' % orjson.dumps(val, option=UNIX_MS)
 
+    def test_datetime_as_unix_milliseconds_key_naive(self):
+        """
+        OPT_UNIX_MILLISECONDS dict keys without tzinfo require OPT_NAIVE_UTC
+        """
+        val = datetime.datetime(2000, 1, 1)
         assert (
             orjson.dumps(
-                datetime.datetime(1970, 1, 1),
-                option=orjson.OPT_PASSTHROUGH_DATETIME,
-                default=default,
+                {val: 1},
+                option=UNIX_MS | orjson.OPT_NON_STR_KEYS | orjson.OPT_NAIVE_UTC,
             )
-            == b'"Thu, 01 Jan 1970 00:00:00 GMT"'
+            == b'{"946684800000":1}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({val: 1}, option=UNIX_MS | orjson.OPT_NON_STR_KEYS)
+
+    def test_datetime_as_unix_milliseconds_key_sort(self):
+        """
+        OPT_UNIX_MILLISECONDS dict keys are sorted as str with OPT_SORT_KEYS
+        """
+        vals = [
+            datetime.datetime(2001, 9, 9, 1, 46, 40, tzinfo=timezone()),
+            datetime.datetime(2000, 1, 1, tzinfo=timezone()),
+        ]
+        assert (
+            orjson.dumps(
+                dict.fromkeys(vals, 1),
+                option=UNIX_MS | orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"1000000000000":1,"946684800000":1}'
+        )
+
+    def test_datetime_as_unix_canonical(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_UNIX with OPT_CANONICAL
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps([val, val.date()], option=UNIX | orjson.OPT_CANONICAL)
+            == b"[946684800,946684800]"
+        )
+
+    def test_datetime_as_unix_passthrough(self):
+        """
+        OPT_PASSTHROUGH_DATETIME takes precedence
+        """
+        assert (
+            orjson.dumps(
+                datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+                option=UNIX | orjson.OPT_PASSTHROUGH_DATETIME,
+                default=lambda obj: "default",
+            )
+            == b'"default"'
+        )
+
+
+ISOCALENDAR = orjson.OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR
+
+
+def isocalendar(val):
+    iso = val.isocalendar()
+    return {"iso_year": iso[0], "iso_week": iso[1], "iso_weekday": iso[2]}
+
+
+class TestDatetimeAsIsocalendar:
+    def test_date_as_isocalendar(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR serializes a datetime.date
+        """
+        assert (
+            orjson.dumps(datetime.date(2026, 10, 16), option=ISOCALENDAR)
+            == b'{"iso_year":2026,"iso_week":42,"iso_weekday":5}'
+        )
+
+    def test_date_as_isocalendar_previous_year(self):
+        """
+        A date in January in week 52 or 53 of the previous ISO year
+        """
+        assert orjson.loads(
+            orjson.dumps(datetime.date(2021, 1, 1), option=ISOCALENDAR)
+        ) == {"iso_year": 2020, "iso_week": 53, "iso_weekday": 5}
+        assert orjson.loads(
+            orjson.dumps(datetime.date(2023, 1, 1), option=ISOCALENDAR)
+        ) == {"iso_year": 2022, "iso_week": 52, "iso_weekday": 7}
+
+    def test_date_as_isocalendar_next_year(self):
+        """
+        A date in December in week 1 of the next ISO year
+        """
+        assert orjson.loads(
+            orjson.dumps(datetime.date(2024, 12, 30), option=ISOCALENDAR)
+        ) == {"iso_year": 2025, "iso_week": 1, "iso_weekday": 1}
+        assert orjson.loads(
+            orjson.dumps(datetime.date(2025, 12, 31), option=ISOCALENDAR)
+        ) == {"iso_year": 2026, "iso_week": 1, "iso_weekday": 3}
+
+    def test_date_as_isocalendar_year_boundaries(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR is the same as isocalendar()
+        around the start of every year
+        """
+        for year in range(1990, 2060):
+            start = datetime.date(year, 1, 1) - datetime.timedelta(days=7)
+            for days in range(15):
+                val = start + datetime.timedelta(days=days)
+                assert orjson.loads(
+                    orjson.dumps(val, option=ISOCALENDAR)
+                ) == isocalendar(val)
+
+    def test_date_as_isocalendar_min_max(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR of MINYEAR and MAXYEAR
+        """
+        for val in (
+            datetime.date(datetime.MINYEAR, 1, 1),
+            datetime.date(datetime.MAXYEAR, 12, 31),
+        ):
+            assert orjson.loads(orjson.dumps(val, option=ISOCALENDAR)) == isocalendar(
+                val
+            )
+
+    def test_datetime_as_isocalendar(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR serializes the date of a
+        datetime.datetime, ignoring its time and tzinfo
+        """
+        for tzinfo in (None, datetime.timezone(datetime.timedelta(hours=-5))):
+            val = datetime.datetime(2024, 12, 29, 23, 59, 59, tzinfo=tzinfo)
+            assert (
+                orjson.dumps([val], option=ISOCALENDAR)
+                == b'[{"iso_year":2024,"iso_week":52,"iso_weekday":7}]'
+            )
+
+    def test_datetime_as_isocalendar_naive_as_null(self):
+        """
+        OPT_NAIVE_AS_NULL takes precedence for a naive datetime.datetime
+        """
+        val = datetime.datetime(2021, 1, 1)
+        assert (
+            orjson.dumps(val, option=ISOCALENDAR | orjson.OPT_NAIVE_AS_NULL) == b"null"
+        )
+
+    def test_datetime_as_isocalendar_unix(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR takes precedence over
+        OPT_SERIALIZE_DATETIME_AS_UNIX
+        """
+        val = datetime.datetime(2021, 1, 1, tzinfo=datetime.timezone.utc)
+        assert orjson.loads(orjson.dumps(val, option=ISOCALENDAR | UNIX)) == {
+            "iso_year": 2020,
+            "iso_week": 53,
+            "iso_weekday": 5,
+        }
+
+    def test_datetime_as_isocalendar_time(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR does not change datetime.time
+        """
+        assert (
+            orjson.dumps(datetime.time(12, 0), option=ISOCALENDAR) == b'"12:00:00"'
+        )
+
+    def test_datetime_as_isocalendar_non_str_keys(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_ISOCALENDAR does not change a key
+        """
+        assert (
+            orjson.dumps(
+                {datetime.date(2021, 1, 1): 1},
+                option=ISOCALENDAR | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2021-01-01":1}'
+        )
+
+    def test_datetime_as_isocalendar_passthrough(self):
+        """
+        OPT_PASSTHROUGH_DATETIME takes precedence
+        """
+        assert (
+            orjson.dumps(
+                datetime.date(2021, 1, 1),
+                option=ISOCALENDAR | orjson.OPT_PASSTHROUGH_DATETIME,
+                default=lambda obj: "default",
+            )
+            == b'"default"'
+        )
+
+
+EPOCH_OFFSET = orjson.OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET
+
+
+def timezone(**kwargs):
+    return datetime.timezone(datetime.timedelta(**kwargs))
+
+
+class TestDatetimeAsEpochOffset:
+    def test_datetime_as_epoch_offset(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET serializes the Unix timestamp
+        and the offset in minutes
+        """
+        val = datetime.datetime(2000, 1, 1, 5, 30, tzinfo=timezone(hours=5, minutes=30))
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET)
+            == b'{"epoch":946684800.0,"offset":330}'
+        )
+
+    def test_datetime_as_epoch_offset_round_trip(self):
+        """
+        The epoch and offset reconstruct an equal datetime with the same
+        offset
+        """
+        for val in (
+            datetime.datetime(2000, 1, 1, 5, 30, tzinfo=timezone(hours=5, minutes=30)),
+            datetime.datetime(
+                2023, 11, 14, 22, 13, 20, 123456, tzinfo=timezone(hours=-9, minutes=-30)
+            ),
+            datetime.datetime(1969, 12, 31, 23, 59, tzinfo=datetime.timezone.utc),
+        ):
+            obj = orjson.loads(orjson.dumps(val, option=EPOCH_OFFSET))
+            assert obj["epoch"] == val.timestamp()
+            tzinfo = timezone(minutes=obj["offset"])
+            res = datetime.datetime.fromtimestamp(obj["epoch"], tzinfo)
+            assert res == val
+            assert res.utcoffset() == val.utcoffset()
+            assert res.isoformat() == val.isoformat()
+
+    def test_datetime_as_epoch_offset_negative(self):
+        """
+        A negative offset
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=timezone(hours=-5, minutes=-30))
+        assert orjson.loads(orjson.dumps(val, option=EPOCH_OFFSET)) == {
+            "epoch": val.timestamp(),
+            "offset": -330,
+        }
+
+    def test_datetime_as_epoch_offset_utc(self):
+        val = datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc)
+        assert orjson.dumps(val, option=EPOCH_OFFSET) == b'{"epoch":0.0,"offset":0}'
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_datetime_as_epoch_offset_zoneinfo(self):
+        """
+        The offset of a zoneinfo timezone depends on the datetime
+        """
+        tzinfo = zoneinfo.ZoneInfo("Asia/Kolkata")
+        val = datetime.datetime(2000, 1, 1, 5, 30, tzinfo=tzinfo)
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET)
+            == b'{"epoch":946684800.0,"offset":330}'
+        )
+        tzinfo = zoneinfo.ZoneInfo("Europe/Amsterdam")
+        assert orjson.loads(
+            orjson.dumps(
+                [
+                    datetime.datetime(2024, 1, 1, tzinfo=tzinfo),
+                    datetime.datetime(2024, 7, 1, tzinfo=tzinfo),
+                ],
+                option=EPOCH_OFFSET,
+            )
+        ) == [
+            {"epoch": 1704063600.0, "offset": 60},
+            {"epoch": 1719784800.0, "offset": 120},
+        ]
+
+    def test_datetime_as_epoch_offset_milliseconds(self):
+        """
+        OPT_UNIX_MILLISECONDS serializes the epoch as milliseconds
+        """
+        val = datetime.datetime(
+            2023, 11, 14, 22, 13, 20, 123999, tzinfo=timezone(hours=5, minutes=30)
+        )
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET | orjson.OPT_UNIX_MILLISECONDS)
+            == b'{"epoch":1699980200123,"offset":330}'
+        )
+
+    def test_datetime_as_epoch_offset_seconds(self):
+        """
+        An offset of a fraction of a minute is rounded down
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=timezone(minutes=20, seconds=30))
+        assert orjson.loads(orjson.dumps(val, option=EPOCH_OFFSET))["offset"] == 20
+        val = datetime.datetime(2000, 1, 1, tzinfo=timezone(minutes=-20, seconds=-30))
+        assert orjson.loads(orjson.dumps(val, option=EPOCH_OFFSET))["offset"] == -21
+
+    def test_datetime_as_epoch_offset_naive(self):
+        """
+        A naive datetime raises unless OPT_NAIVE_UTC or OPT_NAIVE_AS_NULL
+        """
+        val = datetime.datetime(2000, 1, 1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(val, option=EPOCH_OFFSET)
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET | orjson.OPT_NAIVE_UTC)
+            == b'{"epoch":946684800.0,"offset":0}'
+        )
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET | orjson.OPT_NAIVE_AS_NULL)
+            == b"null"
+        )
+
+    def test_datetime_as_epoch_offset_precedence(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET takes precedence over
+        OPT_SERIALIZE_DATETIME_AS_UNIX, which still applies to datetime.date
+        """
+        val = [
+            datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+            datetime.date(2000, 1, 1),
+        ]
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET | UNIX)
+            == b'[{"epoch":946684800.0,"offset":0},946684800.0]'
+        )
+        assert (
+            orjson.dumps(val, option=EPOCH_OFFSET)
+            == b'[{"epoch":946684800.0,"offset":0},"2000-01-01"]'
+        )
+
+    def test_datetime_as_epoch_offset_time(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET does not apply to datetime.time
+        """
+        assert (
+            orjson.dumps(datetime.time(12, 0), option=EPOCH_OFFSET) == b'"12:00:00"'
+        )
+
+    def test_datetime_as_epoch_offset_key(self):
+        """
+        OPT_SERIALIZE_DATETIME_AS_EPOCH_OFFSET does not apply to keys
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=timezone(hours=5, minutes=30))
+        assert (
+            orjson.dumps({val: 1}, option=EPOCH_OFFSET | orjson.OPT_NON_STR_KEYS)
+            == b'{"2000-01-01T00:00:00+05:30":1}'
+        )
+
+
+NO_COLON = orjson.OPT_OFFSET_NO_COLON
+
+
+def tz(**kwargs):
+    return datetime.timezone(datetime.timedelta(**kwargs))
+
+
+class TestDatetimeOffsetNoColon:
+    def test_offset_no_colon_positive(self):
+        """
+        OPT_OFFSET_NO_COLON writes a positive offset without a colon
+        """
+        val = datetime.datetime(2000, 1, 1, 2, 3, 4, tzinfo=tz(hours=5, minutes=30))
+        assert orjson.dumps(val, option=NO_COLON) == b'"2000-01-01T02:03:04+0530"'
+
+    def test_offset_no_colon_negative(self):
+        """
+        OPT_OFFSET_NO_COLON writes a negative offset without a colon
+        """
+        val = datetime.datetime(2000, 1, 1, 2, 3, 4, tzinfo=tz(hours=-5))
+        assert orjson.dumps(val, option=NO_COLON) == b'"2000-01-01T02:03:04-0500"'
+
+    def test_offset_no_colon_rounded(self):
+        """
+        OPT_OFFSET_NO_COLON with an offset rounded to the closest minute
+        """
+        val = datetime.datetime(
+            2000, 1, 1, tzinfo=tz(hours=-3, minutes=-30, seconds=-31)
+        )
+        assert orjson.dumps(val, option=NO_COLON) == b'"2000-01-01T00:00:00-0331"'
+
+    def test_offset_no_colon_zero(self):
+        """
+        OPT_OFFSET_NO_COLON writes a zero offset as +0000
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)
+        assert orjson.dumps(val, option=NO_COLON) == b'"2000-01-01T00:00:00+0000"'
+
+    def test_offset_no_colon_utc_z(self):
+        """
+        OPT_UTC_Z takes precedence for a zero offset and does not change a
+        nonzero offset
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+                    datetime.datetime(2000, 1, 1, tzinfo=tz(hours=-5)),
+                ],
+                option=NO_COLON | orjson.OPT_UTC_Z,
+            )
+            == b'["2000-01-01T00:00:00Z","2000-01-01T00:00:00-0500"]'
+        )
+
+    def test_offset_no_colon_naive(self):
+        """
+        OPT_OFFSET_NO_COLON does not change a naive datetime.datetime except
+        with OPT_NAIVE_UTC
+        """
+        val = datetime.datetime(2000, 1, 1)
+        assert orjson.dumps(val, option=NO_COLON) == b'"2000-01-01T00:00:00"'
+        assert (
+            orjson.dumps(val, option=NO_COLON | orjson.OPT_NAIVE_UTC)
+            == b'"2000-01-01T00:00:00+0000"'
+        )
+
+    def test_offset_no_colon_time(self):
+        """
+        OPT_OFFSET_NO_COLON applies to datetime.time
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.time(12, 0, tzinfo=tz(hours=1)),
+                    datetime.time(12, 0, tzinfo=tz(hours=-9, minutes=-30)),
+                    datetime.time(12, 0, tzinfo=datetime.timezone.utc),
+                ],
+                option=NO_COLON,
+            )
+            == b'["12:00:00+0100","12:00:00-0930","12:00:00+0000"]'
+        )
+
+    def test_offset_no_colon_non_str_keys(self):
+        """
+        OPT_OFFSET_NO_COLON applies to a key with OPT_NON_STR_KEYS
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=tz(hours=2))
+        assert (
+            orjson.dumps({val: 1}, option=NO_COLON | orjson.OPT_NON_STR_KEYS)
+            == b'{"2000-01-01T00:00:00+0200":1}'
+        )
+
+    def test_offset_no_colon_epoch_nanoseconds(self):
+        """
+        OPT_OFFSET_NO_COLON applies to orjson.EpochNanoseconds
+        """
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(0), option=NO_COLON)
+            == b'"1970-01-01T00:00:00+0000"'
+        )
+
+    def test_offset_no_colon_not_enabled(self):
+        """
+        An offset is written with a colon without OPT_OFFSET_NO_COLON
+        """
+        val = datetime.datetime(2000, 1, 1, tzinfo=tz(hours=5, minutes=30))
+        assert orjson.dumps(val) == b'"2000-01-01T00:00:00+05:30"'
+
+
+class TestDate:
+    def test_date(self):
+        """
+        datetime.date
+        """
+        assert orjson.dumps([datetime.date(2000, 1, 13)]) == b'["2000-01-13"]'
+
+    def test_date_min(self):
+        """
+        datetime.date MINYEAR
+        """
+        assert (
+            orjson.dumps([datetime.date(datetime.MINYEAR, 1, 1)]) == b'["0001-01-01"]'
+        )
+
+    def test_date_max(self):
+        """
+        datetime.date MAXYEAR
+        """
+        assert (
+            orjson.dumps([datetime.date(datetime.MAXYEAR, 12, 31)]) == b'["9999-12-31"]'
+        )
+
+    def test_date_three_digits(self):
+        """
+        datetime.date three digit year
+        """
+        assert (
+            orjson.dumps(
+                [datetime.date(312, 1, 1)],
+            )
+            == b'["0312-01-01"]'
+        )
+
+    def test_date_two_digits(self):
+        """
+        datetime.date two digit year
+        """
+        assert (
+            orjson.dumps(
+                [datetime.date(46, 1, 1)],
+            )
+            == b'["0046-01-01"]'
+        )
+
+
+class TestTime:
+    def test_time(self):
+        """
+        datetime.time
+        """
+        assert orjson.dumps([datetime.time(12, 15, 59, 111)]) == b'["12:15:59.000111"]'
+        assert orjson.dumps([datetime.time(12, 15, 59)]) == b'["12:15:59"]'
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_time_tz(self):
+        """
+        datetime.time with tzinfo whose offset depends on a date error
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                [
+                    datetime.time(
+                        12,
+                        15,
+                        59,
+                        111,
+                        tzinfo=zoneinfo.ZoneInfo("Asia/Shanghai"),
+                    ),
+                ],
+            )
+
+    def test_time_tz_positive(self):
+        """
+        datetime.time with tzinfo positive offset
+        """
+        tzinfo = datetime.timezone(datetime.timedelta(hours=5))
+        assert (
+            orjson.dumps([datetime.time(12, 0, tzinfo=tzinfo)]) == b'["12:00:00+05:00"]'
+        )
+        assert (
+            orjson.dumps(datetime.time(12, 15, 59, 111, tzinfo=tzinfo))
+            == b'"12:15:59.000111+05:00"'
+        )
+
+    def test_time_tz_negative(self):
+        """
+        datetime.time with tzinfo negative offset
+        """
+        tzinfo = datetime.timezone(-datetime.timedelta(hours=3, minutes=30))
+        obj = datetime.time(23, 59, 59, 1, tzinfo=tzinfo)
+        assert orjson.dumps(obj) == b'"23:59:59.000001-03:30"'
+        assert orjson.dumps(obj) == f'"{obj.isoformat()}"'.encode("utf-8")
+
+    def test_time_tz_zero(self):
+        """
+        datetime.time with tzinfo zero offset
+        """
+        obj = datetime.time(12, 0, tzinfo=datetime.timezone.utc)
+        assert orjson.dumps(obj) == b'"12:00:00+00:00"'
+        assert orjson.dumps(obj, option=orjson.OPT_UTC_Z) == b'"12:00:00Z"'
+        obj = datetime.time(12, 0, tzinfo=datetime.timezone(datetime.timedelta(0)))
+        assert orjson.dumps(obj, option=orjson.OPT_UTC_Z) == b'"12:00:00Z"'
+
+    def test_time_tz_utc_z_nonzero(self):
+        """
+        datetime.time OPT_UTC_Z does not apply to a nonzero offset
+        """
+        obj = datetime.time(12, 0, tzinfo=datetime.timezone(datetime.timedelta(hours=1)))
+        assert orjson.dumps(obj, option=orjson.OPT_UTC_Z) == b'"12:00:00+01:00"'
+
+    def test_time_tz_omit_microseconds(self):
+        """
+        datetime.time with tzinfo OPT_OMIT_MICROSECONDS
+        """
+        obj = datetime.time(
+            12,
+            15,
+            59,
+            111,
+            tzinfo=datetime.timezone(datetime.timedelta(hours=-8)),
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_MICROSECONDS)
+            == b'"12:15:59-08:00"'
+        )
+        assert (
+            orjson.dumps(
+                datetime.time(12, 15, 59, 111, tzinfo=datetime.timezone.utc),
+                option=orjson.OPT_OMIT_MICROSECONDS | orjson.OPT_UTC_Z,
+            )
+            == b'"12:15:59Z"'
+        )
+
+    def test_time_tz_dict_key(self):
+        """
+        datetime.time with tzinfo OPT_NON_STR_KEYS
+        """
+        obj = datetime.time(12, 0, tzinfo=datetime.timezone(datetime.timedelta(hours=5)))
+        assert (
+            orjson.dumps({obj: 1}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"12:00:00+05:00":1}'
+        )
+
+    def test_time_tz_utcoffset_none(self):
+        """
+        datetime.time with tzinfo that returns None from utcoffset() error
+        """
+
+        class TzInfo(datetime.tzinfo):
+            def utcoffset(self, dt):
+                return None
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(datetime.time(12, 0, tzinfo=TzInfo()))
+
+    def test_time_tz_utcoffset_raises(self):
+        """
+        datetime.time with tzinfo that raises in utcoffset() error
+        """
+
+        class TzInfo(datetime.tzinfo):
+            def utcoffset(self, dt):
+                raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(datetime.time(12, 0, tzinfo=TzInfo()))
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_time_microsecond_max(self):
+        """
+        datetime.time microsecond max
+        """
+        assert orjson.dumps(datetime.time(0, 0, 0, 999999)) == b'"00:00:00.999999"'
+
+    def test_time_microsecond_min(self):
+        """
+        datetime.time microsecond min
+        """
+        assert orjson.dumps(datetime.time(0, 0, 0, 1)) == b'"00:00:00.000001"'
+
+
+class TestDateclassPassthrough:
+    def test_passthrough_datetime(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.datetime(1970, 1, 1),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+            )
+
+    def test_passthrough_date(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.date(1970, 1, 1),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+            )
+
+    def test_passthrough_time(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                datetime.time(12, 0, 0),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+            )
+
+    def test_passthrough_datetime_default(self):
+        def default(obj):
+            return obj.strftime("%a, %d %b %Y %H:%M:%S GMT")
+
+        assert (
+            orjson.dumps(
+                datetime.datetime(1970, 1, 1),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+                default=default,
+            )
+            == b'"Thu, 01 Jan 1970 00:00:00 GMT"'
+        )
+
+
+PAD_NANOSECONDS = orjson.OPT_PAD_NANOSECONDS
+
+
+class TestDatetimePadNanoseconds:
+    def test_pad_nanoseconds_microsecond(self):
+        """
+        OPT_PAD_NANOSECONDS writes the microsecond as the first six of nine
+        digits
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2000, 1, 1, 2, 3, 4, 123456),
+                    datetime.datetime(2000, 1, 1, 2, 3, 4, 1),
+                    datetime.datetime(2000, 1, 1, 2, 3, 4, 999999),
+                ],
+                option=PAD_NANOSECONDS,
+            )
+            == b'["2000-01-01T02:03:04.123456000",'
+            b'"2000-01-01T02:03:04.000001000",'
+            b'"2000-01-01T02:03:04.999999000"]'
+        )
+
+    def test_pad_nanoseconds_zero(self):
+        """
+        OPT_PAD_NANOSECONDS writes a zero fractional second
+        """
+        assert (
+            orjson.dumps(datetime.datetime(2000, 1, 1), option=PAD_NANOSECONDS)
+            == b'"2000-01-01T00:00:00.000000000"'
+        )
+
+    def test_pad_nanoseconds_offset(self):
+        """
+        OPT_PAD_NANOSECONDS with an offset, OPT_UTC_Z, and OPT_NAIVE_UTC
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2000, 1, 1, 0, 0, 0, 10, tzinfo=tz(hours=-5)),
+                    datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+                    datetime.datetime(2000, 1, 1),
+                ],
+                option=PAD_NANOSECONDS | orjson.OPT_UTC_Z | orjson.OPT_NAIVE_UTC,
+            )
+            == b'["2000-01-01T00:00:00.000010000-05:00",'
+            b'"2000-01-01T00:00:00.000000000Z",'
+            b'"2000-01-01T00:00:00.000000000Z"]'
+        )
+
+    def test_pad_nanoseconds_time(self):
+        """
+        OPT_PAD_NANOSECONDS applies to datetime.time
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.time(12, 0),
+                    datetime.time(12, 0, 0, 120),
+                    datetime.time(12, 0, 0, 1, tzinfo=datetime.timezone.utc),
+                ],
+                option=PAD_NANOSECONDS,
+            )
+            == b'["12:00:00.000000000","12:00:00.000120000",'
+            b'"12:00:00.000001000+00:00"]'
+        )
+
+    def test_pad_nanoseconds_omit_microseconds(self):
+        """
+        OPT_OMIT_MICROSECONDS takes precedence
+        """
+        assert (
+            orjson.dumps(
+                [datetime.datetime(2000, 1, 1, 0, 0, 0, 1), datetime.time(12, 0)],
+                option=PAD_NANOSECONDS | orjson.OPT_OMIT_MICROSECONDS,
+            )
+            == b'["2000-01-01T00:00:00","12:00:00"]'
+        )
+
+    def test_pad_nanoseconds_non_str_keys(self):
+        """
+        OPT_PAD_NANOSECONDS applies to a key with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {datetime.datetime(2000, 1, 1, 0, 0, 0, 5): 1},
+                option=PAD_NANOSECONDS | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2000-01-01T00:00:00.000005000":1}'
+        )
+
+    def test_pad_nanoseconds_epoch_nanoseconds(self):
+        """
+        OPT_PAD_NANOSECONDS writes the nanoseconds of orjson.EpochNanoseconds
+        """
+        assert (
+            orjson.dumps(
+                [orjson.EpochNanoseconds(0), orjson.EpochNanoseconds(1_000_000_123)],
+                option=PAD_NANOSECONDS,
+            )
+            == b'["1970-01-01T00:00:00.000000000+00:00",'
+            b'"1970-01-01T00:00:01.000000123+00:00"]'
+        )
+
+    def test_pad_nanoseconds_not_enabled(self):
+        """
+        Without OPT_PAD_NANOSECONDS, the fractional second is six digits or
+        omitted
+        """
+        assert (
+            orjson.dumps(
+                [datetime.datetime(2000, 1, 1, 0, 0, 0, 1), datetime.time(12, 0)]
+            )
+            == b'["2000-01-01T00:00:00.000001","12:00:00"]'
+        )
+
+
+ZONE_NAME = orjson.OPT_ZONE_NAME
+
+
+class TestDatetimeZoneName:
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_zone_name(self):
+        """
+        OPT_ZONE_NAME appends the IANA name of a zoneinfo.ZoneInfo in brackets
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(
+                        2026, 1, 1, tzinfo=zoneinfo.ZoneInfo("America/New_York")
+                    ),
+                    datetime.datetime(
+                        2026, 7, 1, 12, 30, 0, 1, tzinfo=zoneinfo.ZoneInfo("UTC")
+                    ),
+                ],
+                option=ZONE_NAME,
+            )
+            == b'["2026-01-01T00:00:00-05:00[America/New_York]",'
+            b'"2026-07-01T12:30:00.000001+00:00[UTC]"]'
+        )
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_zone_name_options(self):
+        """
+        OPT_ZONE_NAME follows the offset as written by other options
+        """
+        val = datetime.datetime(
+            2026, 7, 1, 12, 0, 0, 5, tzinfo=zoneinfo.ZoneInfo("Europe/Amsterdam")
+        )
+        assert (
+            orjson.dumps(
+                val, option=ZONE_NAME | orjson.OPT_OMIT_MICROSECONDS | NO_COLON
+            )
+            == b'"2026-07-01T12:00:00+0200[Europe/Amsterdam]"'
+        )
+        assert (
+            orjson.dumps(val, option=ZONE_NAME | PAD_NANOSECONDS)
+            == b'"2026-07-01T12:00:00.000005000+02:00[Europe/Amsterdam]"'
+        )
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_zone_name_normalize_utc(self):
+        """
+        OPT_ZONE_NAME does not append a name with OPT_NORMALIZE_UTC, which
+        writes the datetime in UTC
+        """
+        val = datetime.datetime(2026, 1, 1, tzinfo=zoneinfo.ZoneInfo("Asia/Tokyo"))
+        assert (
+            orjson.dumps(val, option=ZONE_NAME | orjson.OPT_NORMALIZE_UTC)
+            == b'"2025-12-31T15:00:00+00:00"'
+        )
+
+    def test_zone_name_without_name(self):
+        """
+        A naive datetime or a tzinfo without a name, such as a fixed offset,
+        is serialized as usual
+        """
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2026, 1, 1),
+                    datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc),
+                    datetime.datetime(
+                        2026,
+                        1,
+                        1,
+                        tzinfo=datetime.timezone(datetime.timedelta(hours=5), "X"),
+                    ),
+                ],
+                option=ZONE_NAME,
+            )
+            == b'["2026-01-01T00:00:00","2026-01-01T00:00:00+00:00",'
+            b'"2026-01-01T00:00:00+05:00"]'
+        )
+
+    def test_zone_name_key(self):
+        """
+        The name is the key attribute of the tzinfo if it is a str, and it
+        is escaped
+        """
+
+        class Keyed(datetime.tzinfo):
+            def __init__(self, key):
+                self.key = key
+
+            def utcoffset(self, dt):
+                return datetime.timedelta(hours=1)
+
+            def dst(self, dt):
+                return datetime.timedelta(0)
+
+        assert (
+            orjson.dumps(
+                [
+                    datetime.datetime(2026, 1, 1, tzinfo=Keyed('Zone/"Q"')),
+                    datetime.datetime(2026, 1, 1, tzinfo=Keyed(None)),
+                ],
+                option=ZONE_NAME,
+            )
+            == b'["2026-01-01T00:00:00+01:00[Zone/\\"Q\\"]",'
+            b'"2026-01-01T00:00:00+01:00"]'
+        )
+
+    @pytest.mark.skipif(zoneinfo is None, reason="zoneinfo not available")
+    def test_zone_name_dict_key(self):
+        """
+        OPT_ZONE_NAME applies to a dict key with OPT_NON_STR_KEYS, but not to
+        OPT_SERIALIZE_DATETIME_AS_UNIX
+        """
+        val = datetime.datetime(2026, 1, 1, tzinfo=zoneinfo.ZoneInfo("Europe/Paris"))
+        assert (
+            orjson.dumps({val: 1}, option=ZONE_NAME | orjson.OPT_NON_STR_KEYS)
+            == b'{"2026-01-01T00:00:00+01:00[Europe/Paris]":1}'
+        )
+        assert orjson.dumps(val, option=ZONE_NAME | UNIX) == b"1767222000.0"
+        assert (
+            orjson.dumps({val: 1}, option=ZONE_NAME | UNIX | orjson.OPT_NON_STR_KEYS)
+            == b'{"1767222000.0":1}'
         )
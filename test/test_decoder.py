@@ -0,0 +1,125 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import threading
+
+import pytest
+
+import orjson
+
+
+def loads_error(func, data):
+    with pytest.raises(orjson.JSONDecodeError) as exc_info:
+        func(data)
+    return exc_info.value
+
+
+class TestDecoder:
+    def test_decoder_no_arguments(self):
+        decoder = orjson.Decoder()
+        data = b'{"a":[1,2.5,null,true,"b"]}'
+        assert decoder.decode(data) == orjson.loads(data)
+
+    def test_decoder_reuse(self):
+        decoder = orjson.Decoder()
+        for i in range(10):
+            assert decoder.decode(b'{"a":%d}' % i) == {"a": i}
+
+    def test_decoder_input_types(self):
+        decoder = orjson.Decoder()
+        for data in (b"[1]", bytearray(b"[1]"), memoryview(b"[1]"), "[1]"):
+            assert decoder.decode(data) == [1]
+
+    def test_decoder_option(self):
+        decoder = orjson.Decoder(orjson.OPT_PARSE_NUMBER_AS_STRING)
+        assert decoder.decode(b"[1.5,2]") == ["1.5", "2"]
+
+    def test_decoder_option_keyword(self):
+        decoder = orjson.Decoder(option=orjson.OPT_STRICT_INPUT)
+        assert decoder.decode(b"[]") == []
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.decode(b" []")
+
+    def test_decoder_option_none(self):
+        decoder = orjson.Decoder(option=None)
+        assert decoder.decode(b" []") == []
+
+    def test_decoder_option_invalid(self):
+        for val in ("a", 1.0, 9223372036854775809, -1):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.Decoder(option=val)
+
+    def test_decoder_error(self):
+        decoder = orjson.Decoder()
+        for data in (b"", b"[", b'{"a":}', b"\xff", "[1,]", b"[] []"):
+            err = loads_error(decoder.decode, data)
+            expected = loads_error(orjson.loads, data)
+            assert str(err) == str(expected)
+            assert err.pos == expected.pos
+            assert err.code == expected.code
+
+    def test_decoder_error_type(self):
+        decoder = orjson.Decoder()
+        for data in (1, None, [b"[]"]):
+            err = loads_error(decoder.decode, data)
+            assert str(err) == str(loads_error(orjson.loads, data))
+
+    def test_decoder_error_option(self):
+        decoder = orjson.Decoder(option=orjson.OPT_STRICT_INPUT)
+        err = loads_error(decoder.decode, b"\xef\xbb\xbf[]")
+        expected = loads_error(
+            lambda data: orjson.loads(data, option=orjson.OPT_STRICT_INPUT),
+            b"\xef\xbb\xbf[]",
+        )
+        assert str(err) == str(expected)
+        assert err.code == expected.code
+
+    def test_decoder_positional_arguments(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.Decoder(None, None)  # type: ignore
+
+    def test_decoder_unexpected_keyword(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.Decoder(strict=True)  # type: ignore
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.Decoder(option=0, strict=True)  # type: ignore
+
+    def test_decoder_duplicate_argument(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.Decoder(0, option=0)  # type: ignore
+
+    def test_decoder_decode_arguments(self):
+        decoder = orjson.Decoder()
+        with pytest.raises(TypeError):
+            decoder.decode()  # type: ignore
+        with pytest.raises(TypeError):
+            decoder.decode(b"1", b"2")  # type: ignore
+        with pytest.raises(TypeError):
+            decoder.decode(obj=b"1")  # type: ignore
+
+    def test_decoder_subclass(self):
+        with pytest.raises(TypeError):
+
+            class SubDecoder(orjson.Decoder):  # type: ignore
+                pass
+
+    def test_decoder_immutable(self):
+        decoder = orjson.Decoder()
+        with pytest.raises(AttributeError):
+            decoder.option = orjson.OPT_STRICT_INPUT  # type: ignore
+
+    def test_decoder_threads(self):
+        decoder = orjson.Decoder(option=orjson.OPT_PARSE_NUMBER_AS_STRING)
+        errors = []
+
+        def decode():
+            for i in range(1000):
+                if decoder.decode(b'{"a":%d}' % i) != {"a": str(i)}:
+                    errors.append(i)
+
+        threads = [threading.Thread(target=decode) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+        assert errors == []
@@ -0,0 +1,145 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+
+import pytest
+
+import orjson
+
+
+class TestDeduplicateArrays:
+    def test_deduplicate_list(self):
+        assert (
+            orjson.dumps([1, 2, 2, 3], option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == b"[1,2,3]"
+        )
+
+    def test_deduplicate_order(self):
+        assert (
+            orjson.dumps(
+                ["b", "a", "b", "c", "a"], option=orjson.OPT_DEDUPLICATE_ARRAYS
+            )
+            == b'["b","a","c"]'
+        )
+
+    def test_deduplicate_tuple(self):
+        assert (
+            orjson.dumps((1, 1, None, None), option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == b"[1,null]"
+        )
+
+    def test_deduplicate_empty(self):
+        assert orjson.dumps([], option=orjson.OPT_DEDUPLICATE_ARRAYS) == b"[]"
+        assert orjson.dumps([[], []], option=orjson.OPT_DEDUPLICATE_ARRAYS) == b"[[]]"
+
+    def test_deduplicate_all_same(self):
+        assert orjson.dumps([0] * 1000, option=orjson.OPT_DEDUPLICATE_ARRAYS) == b"[0]"
+
+    def test_deduplicate_unique(self):
+        obj = list(range(10000))
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == orjson.dumps(obj)
+        )
+
+    def test_deduplicate_serialized_equal(self):
+        """
+        elements are compared by output rather than Python equality
+        """
+        assert (
+            orjson.dumps([1, 1.0, True, "1"], option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == b'[1,1.0,true,"1"]'
+        )
+
+    def test_deduplicate_containers(self):
+        assert (
+            orjson.dumps(
+                [{"a": 1}, {"a": 1}, {"a": 2}, [1, 2], [1, 2]],
+                option=orjson.OPT_DEDUPLICATE_ARRAYS,
+            )
+            == b'[{"a":1},{"a":2},[1,2]]'
+        )
+
+    def test_deduplicate_nested(self):
+        """
+        nested arrays are deduplicated before being compared
+        """
+        assert (
+            orjson.dumps(
+                [[1, 1], [1], {"a": [2, 2]}], option=orjson.OPT_DEDUPLICATE_ARRAYS
+            )
+            == b'[[1],{"a":[2]}]'
+        )
+
+    def test_deduplicate_dataclass(self):
+        @dataclasses.dataclass
+        class Point:
+            x: int
+            y: int
+
+        assert (
+            orjson.dumps(
+                [Point(1, 2), Point(1, 2), Point(2, 1)],
+                option=orjson.OPT_DEDUPLICATE_ARRAYS,
+            )
+            == b'[{"x":1,"y":2},{"x":2,"y":1}]'
+        )
+
+    def test_deduplicate_default(self):
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            return "x"
+
+        objs = [object(), object(), object()]
+        assert (
+            orjson.dumps(objs, option=orjson.OPT_DEDUPLICATE_ARRAYS, default=default)
+            == b'["x"]'
+        )
+        assert calls == objs
+
+    def test_deduplicate_indent(self):
+        assert (
+            orjson.dumps([1, 1, 2], option=orjson.OPT_DEDUPLICATE_ARRAYS, indent=2)
+            == b"[\n  1,\n  2\n]"
+        )
+        assert (
+            orjson.dumps(
+                [[1, 1], [1]],
+                option=orjson.OPT_DEDUPLICATE_ARRAYS | orjson.OPT_INDENT_2,
+            )
+            == b"[\n  [\n    1\n  ]\n]"
+        )
+
+    def test_deduplicate_indent_last_container(self):
+        assert (
+            orjson.dumps(
+                [{"a": 1}, {"a": 1}], option=orjson.OPT_DEDUPLICATE_ARRAYS, indent=2
+            )
+            == b'[\n  {\n    "a": 1\n  }\n]'
+        )
+
+    def test_deduplicate_large(self):
+        """
+        output is compared after the buffer has been resized
+        """
+        obj = ["a" * 4096, "b" * 4096, "a" * 4096]
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == orjson.dumps(obj[:2])
+        )
+
+    def test_deduplicate_dict_values(self):
+        assert (
+            orjson.dumps({"a": 1, "b": 1}, option=orjson.OPT_DEDUPLICATE_ARRAYS)
+            == b'{"a":1,"b":1}'
+        )
+
+    def test_deduplicate_error(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([1, 1, object()], option=orjson.OPT_DEDUPLICATE_ARRAYS)
+
+    def test_deduplicate_disabled(self):
+        assert orjson.dumps([1, 2, 2, 3]) == b"[1,2,2,3]"
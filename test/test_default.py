@@ -1,6 +1,7 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 # Copyright ijl (2019-2025), Rami Chowdhury (2020), Marc Mueller (2023), Jack Amadeo (2023)
 
+import dataclasses
 import datetime
 import sys
 import uuid
@@ -351,3 +352,287 @@ class TestType:
             raise TypeError
 
         assert orjson.dumps({1, 2}, default=default) == b"[1,2]"
+
+
+def default_datetime_as_timestamp(obj):
+    if isinstance(obj, datetime.datetime):
+        return int(obj.timestamp())
+    raise TypeError
+
+
+class TestDefaultFirst:
+    def test_default_first_datetime(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes datetime to default
+        """
+        obj = datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_datetime_as_timestamp,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b"946684800"
+        )
+
+    def test_default_first_datetime_disabled(self):
+        """
+        dumps() without OPT_DEFAULT_FIRST does not pass datetime to default
+        """
+        obj = datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)
+        assert (
+            orjson.dumps(obj, default=default_datetime_as_timestamp)
+            == b'"2000-01-01T00:00:00+00:00"'
+        )
+
+    def test_default_first_dict_value(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes dict values to default
+        """
+        obj = {"a": datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)}
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_datetime_as_timestamp,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'{"a":946684800}'
+        )
+
+    def test_default_first_list_item(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes list items to default
+        """
+        obj = [datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc)]
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_datetime_as_timestamp,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b"[946684800]"
+        )
+
+    def test_default_first_fallback(self):
+        """
+        dumps() OPT_DEFAULT_FIRST uses the built-in serializer if default
+        raises TypeError
+        """
+        obj = [
+            datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc),
+            datetime.date(2000, 1, 1),
+            uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece"),
+        ]
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_datetime_as_timestamp,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'[946684800,"2000-01-01","7202d115-7ff3-4c81-a7c1-2a1f067b1ece"]'
+        )
+
+    def test_default_first_fallback_options(self):
+        """
+        dumps() OPT_DEFAULT_FIRST fallback respects other options
+        """
+        obj = {"a": datetime.datetime(2000, 1, 1)}
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_raises,
+                option=orjson.OPT_DEFAULT_FIRST | orjson.OPT_NAIVE_UTC,
+            )
+            == b'{"a":"2000-01-01T00:00:00+00:00"}'
+        )
+
+    def test_default_first_primitives(self):
+        """
+        dumps() OPT_DEFAULT_FIRST does not pass primitives to default
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            raise TypeError
+
+        obj = {"a": [1, 1.5, "b", True, None, (2,), {"c": []}]}
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_DEFAULT_FIRST)
+            == b'{"a":[1,1.5,"b",true,null,[2],{"c":[]}]}'
+        )
+        assert calls == []
+
+    def test_default_first_containers(self):
+        """
+        dumps() OPT_DEFAULT_FIRST does not pass dict, list, tuple, or Fragment
+        to default but passes the objects they contain
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            if isinstance(obj, datetime.datetime):
+                return "dt"
+            raise TypeError
+
+        val = datetime.datetime(2000, 1, 1)
+        obj = [{"a": val}, [val], (val,), orjson.Fragment(b"[1]")]
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_DEFAULT_FIRST)
+            == b'[{"a":"dt"},["dt"],["dt"],[1]]'
+        )
+        assert calls == [val, val, val]
+
+    def test_default_first_subclass(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes subclasses of primitives to default
+        """
+
+        class SubStr(str):
+            pass
+
+        def default(obj):
+            if isinstance(obj, SubStr):
+                return obj.upper()
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                [SubStr("a")],
+                default=default,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'["A"]'
+        )
+
+    def test_default_first_nested(self):
+        """
+        dumps() OPT_DEFAULT_FIRST applies to values of built-in fallbacks
+        """
+        @dataclasses.dataclass
+        class Dataclass:
+            a: datetime.datetime
+
+        obj = Dataclass(datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc))
+        assert (
+            orjson.dumps(
+                obj,
+                default=default_datetime_as_timestamp,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'{"a":946684800}'
+        )
+
+    def test_default_first_unsupported(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes an unsupported type to default once
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            if isinstance(obj, Custom):
+                return str(obj)
+            raise TypeError
+
+        obj = Custom()
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_DEFAULT_FIRST)
+            == f'"{obj}"'.encode("utf-8")
+        )
+        assert calls == [obj]
+
+    def test_default_first_unsupported_raises(self):
+        """
+        dumps() OPT_DEFAULT_FIRST raises for an unsupported type
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                Custom(),
+                default=default_raises,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+
+    def test_default_first_error(self):
+        """
+        dumps() OPT_DEFAULT_FIRST propagates exceptions other than TypeError
+        """
+
+        def default(obj):
+            raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                [datetime.date(2000, 1, 1)],
+                default=default,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_default_first_no_default(self):
+        """
+        dumps() OPT_DEFAULT_FIRST without default uses built-in serializers
+        """
+        assert (
+            orjson.dumps(
+                {"a": datetime.date(2000, 1, 1)},
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'{"a":"2000-01-01"}'
+        )
+
+    def test_default_first_same_type(self):
+        """
+        dumps() OPT_DEFAULT_FIRST serializes an object of the same type
+        returned by default without passing it to default again
+        """
+        obj = [
+            datetime.datetime(2000, 1, 1, 0, 0, 0, 123456),
+            datetime.date(2000, 1, 1),
+        ]
+        assert (
+            orjson.dumps(
+                obj,
+                default=lambda obj: obj.replace(microsecond=0)
+                if isinstance(obj, datetime.datetime)
+                else obj,
+                option=orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'["2000-01-01T00:00:00","2000-01-01"]'
+        )
+
+    def test_default_first_same_type_dataclass(self):
+        """
+        dumps() OPT_DEFAULT_FIRST passes the fields of a dataclass of the
+        same type returned by default to default
+        """
+
+        @dataclasses.dataclass
+        class Event:
+            at: datetime.datetime
+
+        def default(obj):
+            if isinstance(obj, Event):
+                return dataclasses.replace(obj)
+            return default_datetime_as_timestamp(obj)
+
+        obj = Event(datetime.datetime(2000, 1, 1, tzinfo=datetime.timezone.utc))
+        assert (
+            orjson.dumps(obj, default=default, option=orjson.OPT_DEFAULT_FIRST)
+            == b'{"at":946684800}'
+        )
+
+    def test_default_first_non_str_keys(self):
+        """
+        dumps() OPT_DEFAULT_FIRST does not pass dict keys to default
+        """
+        obj = {datetime.date(2000, 1, 1): datetime.date(2000, 1, 1)}
+        assert (
+            orjson.dumps(
+                obj,
+                default=lambda obj: obj.year,
+                option=orjson.OPT_DEFAULT_FIRST | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"2000-01-01":2000}'
+        )
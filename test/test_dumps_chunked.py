@@ -0,0 +1,142 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+DOCUMENT = {
+    "a": [1, 2.5, None, True, "\U0001f408 é"],
+    "b": {"c": "d" * 100, "e": list(range(50))},
+}
+
+
+class TestDumpsChunked:
+    def test_dumps_chunked(self):
+        """
+        dumps_chunked() splits the output into chunks of chunk_size
+        """
+        assert orjson.dumps_chunked({"a": [1, 2]}, 4) == [b'{"a"', b":[1,", b"2]}"]
+
+    def test_dumps_chunked_reassembled(self):
+        """
+        The chunks joined are the output of dumps() for any chunk_size
+        """
+        expected = orjson.dumps(DOCUMENT)
+        for chunk_size in (1, 2, 3, 7, 64, len(expected) - 1, len(expected)):
+            chunks = orjson.dumps_chunked(DOCUMENT, chunk_size)
+            assert all(type(chunk) is bytes for chunk in chunks)
+            assert all(len(chunk) == chunk_size for chunk in chunks[:-1])
+            assert 0 < len(chunks[-1]) <= chunk_size
+            assert b"".join(chunks) == expected
+            assert orjson.loads(b"".join(chunks)) == DOCUMENT
+
+    def test_dumps_chunked_utf8(self):
+        """
+        Chunks are split at a byte offset, not at a UTF-8 character
+        """
+        chunks = orjson.dumps_chunked("\U0001f408", 2)
+        assert chunks == [b'"\xf0', b"\x9f\x90", b'\x88"']
+        with pytest.raises(UnicodeDecodeError):
+            chunks[0].decode("utf-8")
+        assert orjson.loads(b"".join(chunks)) == "\U0001f408"
+
+    def test_dumps_chunked_single(self):
+        """
+        Output no longer than chunk_size is one chunk
+        """
+        assert orjson.dumps_chunked([1, 2, 3], 7) == [b"[1,2,3]"]
+        assert orjson.dumps_chunked([1, 2, 3], 1024) == [b"[1,2,3]"]
+        assert orjson.dumps_chunked(None, 1) == [b"n", b"u", b"l", b"l"]
+
+    def test_dumps_chunked_arguments(self):
+        """
+        dumps_chunked() takes the same arguments as dumps()
+        """
+
+        class Custom:
+            pass
+
+        def default(obj):
+            return "default"
+
+        obj = {"b": Custom(), "a": [1.23456]}
+        option = orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE
+        for kwargs in (
+            {"key_delimiter": "|", "float_sigfigs": 3, "indent": 2},
+            {"key_transform": str.upper},
+        ):
+            expected = orjson.dumps(obj, default, option, **kwargs)
+            chunks = orjson.dumps_chunked(obj, 5, default, option, **kwargs)
+            assert b"".join(chunks) == expected
+            chunks = orjson.dumps_chunked(
+                obj, 5, default=default, option=option, **kwargs
+            )
+            assert b"".join(chunks) == expected
+
+    def test_dumps_chunked_max_output_size(self):
+        """
+        max_output_size limits the length of the whole output
+        """
+        assert orjson.dumps_chunked([1, 2, 3], 2, max_output_size=7) == [
+            b"[1",
+            b",2",
+            b",3",
+            b"]",
+        ]
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_chunked([1, 2, 3], 2, max_output_size=6)
+        assert str(exc_info.value) == "Output exceeds max_output_size"
+
+    def test_dumps_chunked_max_sequence_len(self):
+        """
+        max_sequence_len applies as it does to dumps()
+        """
+        assert orjson.dumps_chunked([[1, 2]], 4, max_sequence_len=2) == [
+            b"[[1,",
+            b"2]]",
+        ]
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([[1, 2, 3]], 4, max_sequence_len=2)
+
+    def test_dumps_chunked_validate_only(self):
+        """
+        validate_only=True returns None rather than chunks
+        """
+        assert orjson.dumps_chunked(DOCUMENT, 4, validate_only=True) is None
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_chunked({"a": [object()]}, 4, validate_only=True)
+        assert exc_info.value.path == ["a", 0]
+
+    def test_dumps_chunked_error(self):
+        """
+        dumps_chunked() raises the same JSONEncodeError as dumps()
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_chunked({"a": [object()]}, 4)
+        assert str(exc_info.value) == "Type is not JSON serializable: object"
+        assert exc_info.value.path == ["a", 0]
+
+    def test_dumps_chunked_invalid_chunk_size(self):
+        """
+        chunk_size that is not a positive int raises
+        """
+        for val in (0, -1, None, 1.5, "4", 2**64):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps_chunked([], val)  # type: ignore
+            assert str(exc_info.value) == "chunk_size must be a positive int"
+
+    def test_dumps_chunked_invalid_arguments(self):
+        """
+        Invalid positional and keyword arguments raise
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([])  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([], 4, None, None, None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([], 4, None, default=None)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([], 4, unknown=1)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_chunked([], 4, option=-1)
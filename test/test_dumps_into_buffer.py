@@ -0,0 +1,158 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import array
+
+import pytest
+
+import orjson
+
+
+class TestDumpsIntoBuffer:
+    def test_dumps_into_buffer_bytearray(self):
+        buf = bytearray(32)
+        assert orjson.dumps_into_buffer({"a": [1, 2]}, buf) == 11
+        assert bytes(buf[:11]) == b'{"a":[1,2]}'
+        assert buf[11:] == bytearray(21)
+
+    def test_dumps_into_buffer_memoryview(self):
+        buf = bytearray(16)
+        view = memoryview(buf)[4:12]
+        assert orjson.dumps_into_buffer([1, 2, 3], view) == 7
+        assert buf == bytearray(4) + b"[1,2,3]" + bytearray(5)
+
+    def test_dumps_into_buffer_exact(self):
+        buf = bytearray(7)
+        assert orjson.dumps_into_buffer([1, 2, 3], buf) == 7
+        assert buf == b"[1,2,3]"
+
+    def test_dumps_into_buffer_array(self):
+        buf = array.array("B", bytes(8))
+        assert orjson.dumps_into_buffer("a", buf) == 3
+        assert buf.tobytes() == b'"a"\x00\x00\x00\x00\x00'
+
+    def test_dumps_into_buffer_same_as_dumps(self):
+        obj = {"b": 1.5, "a": [None, True, "\U0001f408"]}
+        option = orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE
+        expected = orjson.dumps(obj, option=option, indent=2)
+        buf = bytearray(len(expected))
+        assert orjson.dumps_into_buffer(obj, buf, option=option, indent=2) == len(
+            expected
+        )
+        assert buf == expected
+
+    def test_dumps_into_buffer_default(self):
+        buf = bytearray(16)
+        assert orjson.dumps_into_buffer({1, 2}, buf, lambda obj: sorted(obj)) == 5
+        assert bytes(buf[:5]) == b"[1,2]"
+
+    def test_dumps_into_buffer_too_small(self):
+        buf = bytearray(b"x" * 6)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into_buffer([1, 2, 3], buf)
+        assert (
+            str(exc_info.value)
+            == "Serialized output does not fit in buffer of 6 bytes"
+        )
+        assert exc_info.value.path == []
+        assert buf == b"x" * 6
+
+    def test_dumps_into_buffer_too_small_append_newline(self):
+        buf = bytearray(b"x" * 7)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(
+                [1, 2, 3], buf, option=orjson.OPT_APPEND_NEWLINE
+            )
+        assert buf == b"x" * 7
+
+    def test_dumps_into_buffer_max_output_size(self):
+        buf = bytearray(b"x" * 16)
+        assert orjson.dumps_into_buffer([1, 2, 3], buf, max_output_size=7) == 7
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into_buffer([1, 2, 3], buf, max_output_size=6)
+        assert str(exc_info.value) == "Output exceeds max_output_size"
+        assert buf == b"[1,2,3]" + b"x" * 9
+
+    def test_dumps_into_buffer_max_output_size_larger(self):
+        buf = bytearray(b"x" * 6)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps_into_buffer([1, 2, 3], buf, max_output_size=64)
+        assert (
+            str(exc_info.value)
+            == "Serialized output does not fit in buffer of 6 bytes"
+        )
+
+    def test_dumps_into_buffer_max_sequence_len(self):
+        buf = bytearray(b"x" * 16)
+        assert orjson.dumps_into_buffer([1, 2], buf, max_sequence_len=2) == 5
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer([1, 2, 3], buf, max_sequence_len=2)
+        assert buf == b"[1,2]" + b"x" * 11
+
+    def test_dumps_into_buffer_validate_only(self):
+        buf = bytearray(b"x" * 4)
+        assert orjson.dumps_into_buffer([1, 2, 3], buf, validate_only=True) is None
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer([object()], buf, validate_only=True)
+        assert buf == b"x" * 4
+
+    def test_dumps_into_buffer_empty(self):
+        buf = bytearray()
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf)
+
+    def test_dumps_into_buffer_error_unchanged(self):
+        buf = bytearray(b"x" * 16)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer([1, object()], buf)
+        assert buf == b"x" * 16
+
+    def test_dumps_into_buffer_readonly(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, b"\x00" * 8)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, memoryview(bytearray(8)).toreadonly())
+
+    def test_dumps_into_buffer_not_buffer(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, [0] * 8)
+
+    def test_dumps_into_buffer_not_contiguous(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, memoryview(bytearray(16))[::2])
+
+    def test_dumps_into_buffer_resize_in_default(self):
+        buf = bytearray(16)
+
+        def default(obj):
+            buf.extend(b"x")
+            return None
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(object(), buf, default)
+        assert buf == bytearray(16)
+
+    def test_dumps_into_buffer_invalid_args(self):
+        buf = bytearray(8)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, None, None, None)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, option=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, None, 0, option=0)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, str, default=str)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, indent=9)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, key_delimiter=1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, buffer=buf)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, max_output_size=0)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, max_sequence_len=-1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1, buf, validate_only=1)
@@ -0,0 +1,414 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import datetime
+import gc
+import inspect
+import sys
+import weakref
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Data:
+    a: int
+
+
+class Custom:
+    pass
+
+
+def default(obj):
+    if isinstance(obj, Custom):
+        return "custom"
+    raise TypeError
+
+
+class TestEncoder:
+    def test_encoder_no_arguments(self):
+        encoder = orjson.Encoder()
+        obj = {"a": [1, 2.5, None, True, "b"]}
+        assert encoder.encode(obj) == orjson.dumps(obj)
+
+    def test_encoder_reuse(self):
+        encoder = orjson.Encoder(option=orjson.OPT_SORT_KEYS)
+        for i in range(10):
+            assert encoder.encode({"b": i, "a": i}) == b'{"a":%d,"b":%d}' % (i, i)
+
+    def test_encoder_default(self):
+        encoder = orjson.Encoder(default)
+        assert encoder.encode([Custom()]) == b'["custom"]'
+
+    def test_encoder_default_keyword(self):
+        encoder = orjson.Encoder(default=default)
+        assert encoder.encode(Custom()) == b'"custom"'
+
+    def test_encoder_default_none(self):
+        encoder = orjson.Encoder(default=None)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode(Custom())
+
+    def test_encoder_default_raises(self):
+        encoder = orjson.Encoder(default)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode(object())
+        assert isinstance(exc_info.value.__cause__, TypeError)
+
+    def test_encoder_default_refcount(self):
+        def func(obj):
+            return None
+
+        ref = sys.getrefcount(func)
+        encoders = [orjson.Encoder(func) for _ in range(10)]
+        assert sys.getrefcount(func) == ref + 10
+        del encoders
+        assert sys.getrefcount(func) == ref
+
+    def test_encoder_option(self):
+        encoder = orjson.Encoder(None, orjson.OPT_NAIVE_UTC | orjson.OPT_UTC_Z)
+        assert (
+            encoder.encode(datetime.datetime(2000, 1, 1))
+            == b'"2000-01-01T00:00:00Z"'
+        )
+
+    def test_encoder_option_keyword(self):
+        encoder = orjson.Encoder(option=orjson.OPT_PASSTHROUGH_DATACLASS)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode(Data(1))
+
+    def test_encoder_option_none(self):
+        encoder = orjson.Encoder(option=None)
+        assert encoder.encode(Data(1)) == b'{"a":1}'
+
+    def test_encoder_option_invalid(self):
+        for val in ("a", 1.0, 9223372036854775809, -1):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.Encoder(option=val)
+
+    def test_encoder_indent(self):
+        encoder = orjson.Encoder(indent=4)
+        obj = {"a": [1, 2]}
+        assert encoder.encode(obj) == orjson.dumps(obj, indent=4)
+
+    def test_encoder_encode_compact(self):
+        obj = {"a": [1, {"b": "c d"}], "e": {}}
+        for encoder in (
+            orjson.Encoder(indent=4),
+            orjson.Encoder(option=orjson.OPT_INDENT_2),
+            orjson.Encoder(option=orjson.OPT_INDENT_2, indent=8),
+        ):
+            assert encoder.encode_compact(obj) == b'{"a":[1,{"b":"c d"}],"e":{}}'
+            assert encoder.encode(obj) != orjson.dumps(obj)
+
+    def test_encoder_encode_compact_no_indent(self):
+        encoder = orjson.Encoder()
+        obj = {"a": [1, 2]}
+        assert encoder.encode_compact(obj) == encoder.encode(obj)
+
+    def test_encoder_encode_compact_option(self):
+        encoder = orjson.Encoder(
+            default=default,
+            option=orjson.OPT_INDENT_2 | orjson.OPT_SORT_KEYS,
+        )
+        obj = {"b": Custom(), "a": 1}
+        assert encoder.encode_compact(obj) == b'{"a":1,"b":"custom"}'
+        encoder = orjson.Encoder(indent=2, option=orjson.OPT_APPEND_NEWLINE)
+        assert encoder.encode_compact([1]) == b"[1]\n"
+
+    def test_encoder_encode_compact_error(self):
+        encoder = orjson.Encoder(indent=2)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode_compact(Custom())
+
+    def test_encoder_encode_compact_arguments(self):
+        encoder = orjson.Encoder(indent=2)
+        with pytest.raises(TypeError):
+            encoder.encode_compact()  # type: ignore
+        with pytest.raises(TypeError):
+            encoder.encode_compact(1, 2)  # type: ignore
+
+    def test_encoder_indent_invalid(self):
+        for val in (9, -1, "2", 2.0):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.Encoder(indent=val)
+
+    def test_encoder_indent_positional(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(None, None, 2)  # type: ignore
+
+    def test_encoder_max_output_size(self):
+        encoder = orjson.Encoder(max_output_size=7)
+        assert encoder.encode([1, 2, 3]) == b"[1,2,3]"
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode([1, 2, 3, 4])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(max_output_size=0)
+
+    def test_encoder_max_sequence_len(self):
+        encoder = orjson.Encoder(max_sequence_len=2)
+        assert encoder.encode([[1, 2]]) == b"[[1,2]]"
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode([[1, 2, 3]])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(max_sequence_len=-1)
+
+    def test_encoder_validate_only(self):
+        encoder = orjson.Encoder(validate_only=True)
+        assert encoder.encode([1, 2, 3]) is None
+        assert encoder.encode_compact([1, 2, 3]) is None
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode([object()])
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(validate_only=1)
+
+    def test_encoder_unexpected_keyword(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(sort_keys=True)  # type: ignore
+
+    def test_encoder_duplicate_argument(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(default, default=default)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(None, 0, option=0)  # type: ignore
+
+    def test_encoder_signature(self):
+        """
+        Encoder() valid __text_signature__
+        """
+        assert (
+            str(inspect.signature(orjson.Encoder))
+            == "(default=None, option=None, *, indent=None, key_delimiter=None, float_sigfigs=None, max_output_size=None, datetime_formats=None, key_transform=None, validate_only=False, max_sequence_len=None, type_handlers=None, field_schemas=None)"
+        )
+        inspect.signature(orjson.Encoder).bind(max_output_size=4)
+        inspect.signature(orjson.Encoder).bind(validate_only=True)
+        inspect.signature(orjson.Encoder).bind(max_sequence_len=10)
+
+    def test_encoder_encode_arguments(self):
+        encoder = orjson.Encoder()
+        with pytest.raises(TypeError):
+            encoder.encode()  # type: ignore
+        with pytest.raises(TypeError):
+            encoder.encode(1, 2)  # type: ignore
+        with pytest.raises(TypeError):
+            encoder.encode(obj=1)  # type: ignore
+
+    def test_encoder_subclass(self):
+        with pytest.raises(TypeError):
+
+            class SubEncoder(orjson.Encoder):  # type: ignore
+                pass
+
+    def test_encoder_immutable(self):
+        encoder = orjson.Encoder()
+        with pytest.raises(AttributeError):
+            encoder.option = orjson.OPT_SORT_KEYS  # type: ignore
+
+    def test_encoder_gc(self):
+        """
+        Encoder held by the object whose bound methods it holds is collected
+        """
+
+        class Service:
+            def __init__(self):
+                self.encoder = orjson.Encoder(
+                    default=self.default,
+                    key_transform=self.key_transform,
+                    type_handlers={set: self.handler},
+                )
+
+            def default(self, obj):
+                return str(obj)
+
+            def key_transform(self, key):
+                return key.upper()
+
+            def handler(self, obj):
+                return sorted(obj)
+
+        service = Service()
+        assert gc.is_tracked(service.encoder)
+        assert service.encoder.encode({"a": {2, 1}, "b": Custom()}).startswith(
+            b'{"A":[1,2],"B":"'
+        )
+        ref = weakref.ref(service)
+        del service
+        gc.collect()
+        assert ref() is None
+
+
+FORMATS = {
+    datetime.datetime: "%d/%m/%Y %H:%M:%S",
+    datetime.date: "%d/%m/%Y",
+    datetime.time: "%H.%M",
+}
+
+
+class DatetimeSubclass(datetime.datetime):
+    pass
+
+
+class TestEncoderDatetimeFormats:
+    def test_encoder_datetime_formats(self):
+        """
+        datetime_formats serializes each temporal type with its own format
+        """
+        encoder = orjson.Encoder(datetime_formats=FORMATS)
+        obj = {
+            "created": datetime.datetime(2026, 1, 2, 3, 4, 5, 6),
+            "day": datetime.date(2026, 1, 2),
+            "at": datetime.time(3, 4, 5),
+            "events": [
+                datetime.date(2026, 12, 31),
+                {"end": datetime.datetime(2027, 1, 1)},
+                (datetime.time(23, 59),),
+            ],
+        }
+        assert encoder.encode(obj) == (
+            b'{"created":"02/01/2026 03:04:05","day":"02/01/2026","at":"03.04",'
+            b'"events":["31/12/2026",{"end":"01/01/2027 00:00:00"},["23.59"]]}'
+        )
+
+    def test_encoder_datetime_formats_partial(self):
+        """
+        A type that is not in datetime_formats is serialized as usual
+        """
+        encoder = orjson.Encoder(datetime_formats={datetime.date: "%Y/%m/%d"})
+        assert (
+            encoder.encode(
+                [
+                    datetime.datetime(2026, 1, 2, 3, 4),
+                    datetime.date(2026, 1, 2),
+                    datetime.time(3, 4),
+                ]
+            )
+            == b'["2026-01-02T03:04:00","2026/01/02","03:04:00"]'
+        )
+
+    def test_encoder_datetime_formats_tzinfo(self):
+        """
+        datetime_formats formats an aware datetime with strftime()
+        """
+        encoder = orjson.Encoder(
+            datetime_formats={datetime.datetime: "%Y-%m-%d %H:%M %z"}
+        )
+        obj = datetime.datetime(
+            2026, 1, 2, 3, 4, tzinfo=datetime.timezone(datetime.timedelta(hours=2))
+        )
+        assert encoder.encode(obj) == b'"2026-01-02 03:04 +0200"'
+
+    def test_encoder_datetime_formats_option(self):
+        """
+        datetime_formats takes precedence over datetime options
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_SERIALIZE_DATETIME_AS_UNIX
+            | orjson.OPT_OMIT_MICROSECONDS
+            | orjson.OPT_NAIVE_UTC,
+            datetime_formats={datetime.datetime: "%Y %f"},
+        )
+        assert encoder.encode(
+            [datetime.datetime(2026, 1, 1, 0, 0, 0, 1), datetime.date(1970, 1, 2)]
+        ) == orjson.dumps(
+            ["2026 000001", datetime.date(1970, 1, 2)],
+            option=orjson.OPT_SERIALIZE_DATETIME_AS_UNIX,
+        )
+
+    def test_encoder_datetime_formats_passthrough(self):
+        """
+        OPT_PASSTHROUGH_DATETIME takes precedence over datetime_formats
+        """
+        encoder = orjson.Encoder(
+            default=lambda obj: "default",
+            option=orjson.OPT_PASSTHROUGH_DATETIME,
+            datetime_formats=FORMATS,
+        )
+        assert (
+            encoder.encode([datetime.datetime(2026, 1, 1), datetime.time()])
+            == b'["default","default"]'
+        )
+
+    def test_encoder_datetime_formats_subclass(self):
+        """
+        datetime_formats does not apply to a subclass
+        """
+        encoder = orjson.Encoder(datetime_formats=FORMATS)
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode(DatetimeSubclass(2026, 1, 1))
+
+    def test_encoder_datetime_formats_dict_key(self):
+        """
+        datetime_formats does not apply to dict keys
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_NON_STR_KEYS, datetime_formats=FORMATS
+        )
+        assert (
+            encoder.encode({datetime.date(2026, 1, 2): datetime.date(2026, 1, 2)})
+            == b'{"2026-01-02":"02/01/2026"}'
+        )
+
+    def test_encoder_datetime_formats_copy(self):
+        """
+        Modifying the dict after creating the Encoder has no effect
+        """
+        formats = {datetime.date: "%Y"}
+        encoder = orjson.Encoder(datetime_formats=formats)
+        formats[datetime.date] = "%m"
+        formats[datetime.time] = "%H"
+        assert (
+            encoder.encode([datetime.date(2026, 1, 2), datetime.time(3)])
+            == b'["2026","03:00:00"]'
+        )
+
+    def test_encoder_datetime_formats_none(self):
+        """
+        datetime_formats=None or an empty dict is the same as not specifying it
+        """
+        obj = [datetime.datetime(2026, 1, 1), datetime.date(2026, 1, 1)]
+        assert orjson.Encoder(datetime_formats=None).encode(obj) == orjson.dumps(obj)
+        assert orjson.Encoder(datetime_formats={}).encode(obj) == orjson.dumps(obj)
+
+    def test_encoder_datetime_formats_invalid(self):
+        """
+        datetime_formats must be a dict of datetime types to str
+        """
+        for val in (
+            "%Y",
+            [(datetime.date, "%Y")],
+            {datetime.timedelta: "%Y"},
+            {DatetimeSubclass: "%Y"},
+            {"date": "%Y"},
+            {datetime.date: b"%Y"},
+            {datetime.date: None},
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.Encoder(datetime_formats=val)
+            assert str(exc_info.value) == (
+                "datetime_formats must be a dict of datetime.datetime, "
+                "datetime.date, or datetime.time to str"
+            )
+
+    def test_encoder_datetime_formats_raises(self):
+        """
+        An exception raised by strftime() is the __cause__ of JSONEncodeError
+        """
+        encoder = orjson.Encoder(datetime_formats={datetime.date: "\ud800"})
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode([datetime.date(2026, 1, 1)])
+        assert exc_info.value.__cause__ is not None
+        assert exc_info.value.path == [0]
+
+    def test_encoder_datetime_formats_refcount(self):
+        """
+        datetime_formats does not leak the formatted str
+        """
+        encoder = orjson.Encoder(datetime_formats=FORMATS)
+        obj = datetime.date(2026, 1, 1)
+        ref = sys.getrefcount(obj)
+        for _ in range(10):
+            encoder.encode(obj)
+        assert sys.getrefcount(obj) == ref
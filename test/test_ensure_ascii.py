@@ -0,0 +1,156 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import json
+
+import orjson
+
+from .util import needs_data, read_fixture_str
+
+
+def stdlib_ascii(obj) -> bytes:
+    return json.dumps(obj, separators=(",", ":")).encode("ascii")
+
+
+class TestEnsureAscii:
+    def test_ensure_ascii_ascii(self):
+        """
+        dumps() OPT_ENSURE_ASCII ASCII is unchanged
+        """
+        for val in ("", "abc", 'a"b\\c', "\x00\x1f\n\t", "a" * 100):
+            assert orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII) == orjson.dumps(
+                val,
+            )
+
+    def test_ensure_ascii_ascii_escapes(self):
+        """
+        dumps() OPT_ENSURE_ASCII ASCII with quotes, backslashes, and control
+        characters at offsets of strings longer than a SIMD block
+        """
+        for length in (1, 15, 16, 17, 31, 32, 33, 63, 64, 65, 200):
+            for offset in range(0, length, 7):
+                for char in ('"', "\\", "\x00", "\x1f", "\n"):
+                    val = "a" * offset + char + "b" * (length - offset)
+                    assert orjson.dumps(
+                        val, option=orjson.OPT_ENSURE_ASCII
+                    ) == stdlib_ascii(val)
+        chars = "".join(chr(idx) for idx in range(128))
+        val = {chars: [chars * 3, chars[::-1]]}
+        assert orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII) == orjson.dumps(val)
+        assert json.loads(orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII)) == val
+
+    def test_ensure_ascii_bmp(self):
+        """
+        dumps() OPT_ENSURE_ASCII Basic Multilingual Plane
+        """
+        assert orjson.dumps("é", option=orjson.OPT_ENSURE_ASCII) == b'"\\u00e9"'
+        assert orjson.dumps("\u0080", option=orjson.OPT_ENSURE_ASCII) == b'"\\u0080"'
+        assert orjson.dumps(" ", option=orjson.OPT_ENSURE_ASCII) == b'"\\u2028"'
+        assert orjson.dumps("￿", option=orjson.OPT_ENSURE_ASCII) == b'"\\uffff"'
+
+    def test_ensure_ascii_astral(self):
+        """
+        dumps() OPT_ENSURE_ASCII astral plane as surrogate pair
+        """
+        assert (
+            orjson.dumps("😀", option=orjson.OPT_ENSURE_ASCII) == b'"\\ud83d\\ude00"'
+        )
+        assert (
+            orjson.dumps("\U00010000", option=orjson.OPT_ENSURE_ASCII)
+            == b'"\\ud800\\udc00"'
+        )
+        assert (
+            orjson.dumps("\U0010ffff", option=orjson.OPT_ENSURE_ASCII)
+            == b'"\\udbff\\udfff"'
+        )
+
+    def test_ensure_ascii_mixed(self):
+        """
+        dumps() OPT_ENSURE_ASCII mixed with ASCII and escapes
+        """
+        val = 'a"é\n😀\\日本\x00z' * 10
+        assert orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII) == stdlib_ascii(val)
+
+    def test_ensure_ascii_roundtrip(self):
+        """
+        dumps() OPT_ENSURE_ASCII roundtrips through loads()
+        """
+        val = ["é", "😀", "🏳️‍🌈", "\u0000\u007f\u0080", "Ω≈ç√∫"]
+        serialized = orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII)
+        assert serialized.isascii()
+        assert orjson.loads(serialized) == val
+        assert json.loads(serialized) == val
+
+    def test_ensure_ascii_keys(self):
+        """
+        dumps() OPT_ENSURE_ASCII dict keys
+        """
+        val = {"ключ": "значение", "😀": 1}
+        assert orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII) == stdlib_ascii(val)
+        assert (
+            orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII | orjson.OPT_SORT_KEYS)
+            == b'{"\\u043a\\u043b\\u044e\\u0447":"\\u0437\\u043d\\u0430\\u0447\\u0435\\u043d\\u0438\\u0435","\\ud83d\\ude00":1}'
+        )
+
+    def test_ensure_ascii_non_str_keys(self):
+        """
+        dumps() OPT_ENSURE_ASCII non-str keys
+        """
+        assert (
+            orjson.dumps(
+                {1: "é"},
+                option=orjson.OPT_ENSURE_ASCII | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":"\\u00e9"}'
+        )
+
+    def test_ensure_ascii_str_subclass(self):
+        """
+        dumps() OPT_ENSURE_ASCII str subclass
+        """
+
+        class Sub(str):
+            pass
+
+        assert (
+            orjson.dumps([Sub("é")], option=orjson.OPT_ENSURE_ASCII) == b'["\\u00e9"]'
+        )
+
+    def test_ensure_ascii_indent(self):
+        """
+        dumps() OPT_ENSURE_ASCII with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"é": ["😀"]},
+                option=orjson.OPT_ENSURE_ASCII | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "\\u00e9": [\n    "\\ud83d\\ude00"\n  ]\n}'
+        )
+
+    def test_ensure_ascii_fragment(self):
+        """
+        dumps() OPT_ENSURE_ASCII does not modify Fragment
+        """
+        assert (
+            orjson.dumps(orjson.Fragment('"é"'), option=orjson.OPT_ENSURE_ASCII)
+            == b'"\xc3\xa9"'
+        )
+
+    def test_ensure_ascii_encoder(self):
+        """
+        Encoder OPT_ENSURE_ASCII
+        """
+        encoder = orjson.Encoder(option=orjson.OPT_ENSURE_ASCII)
+        assert encoder.encode(["é"]) == b'["\\u00e9"]'
+
+    @needs_data
+    def test_ensure_ascii_fixture(self):
+        """
+        dumps() OPT_ENSURE_ASCII matches the standard library on fixtures
+        """
+        for filename in ("twitter.json.xz", "github.json.xz"):
+            val = json.loads(read_fixture_str(filename))
+            assert orjson.dumps(val, option=orjson.OPT_ENSURE_ASCII) == stdlib_ascii(
+                val,
+            )
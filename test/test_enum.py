@@ -1,8 +1,10 @@
 # SPDX-License-Identifier: MPL-2.0
-# Copyright ijl (2020-2025)
+# Copyright ijl (2020-2026)
 
+import base64
 import datetime
 import enum
+import gzip
 
 import pytest
 
@@ -29,6 +31,18 @@ class FlagEnum(enum.Flag):
     ONE = 1
 
 
+class Permission(enum.Flag):
+    R = 4
+    W = 2
+    X = 1
+
+
+class IntPermission(enum.IntFlag):
+    R = 4
+    W = 2
+    X = 1
+
+
 class AutoEnum(enum.auto):
     A = "a"
 
@@ -37,6 +51,15 @@ class FloatEnum(float, enum.Enum):
     ONE = 1.1
 
 
+class BytesEnum(bytes, enum.Enum):
+    A = b"a"
+
+
+class BytesValueEnum(enum.Enum):
+    A = b"a"
+    B = b""
+
+
 class Custom:
     def __init__(self, val):
         self.val = val
@@ -57,12 +80,70 @@ class UnspecifiedEnum(enum.Enum):
     F = datetime.datetime(1970, 1, 1)
 
 
+class TupleEnum(enum.Enum):
+    ORIGIN = (0, 0)
+    UNIT = (1, Custom("y"))
+
+
+class DescribeMixin:
+    def describe(self):
+        return self.name.lower()
+
+
+class BaseEnum(DescribeMixin, enum.Enum):
+    pass
+
+
+class MidEnum(BaseEnum):
+    def label(self):
+        return self.describe().upper()
+
+
+class DeepEnum(MidEnum):
+    A = "a"
+    B = 2
+
+
+class BaseIntEnum(DescribeMixin, enum.IntEnum):
+    pass
+
+
+class DeepIntEnum(BaseIntEnum):
+    ONE = 1
+
+
+class CustomEnumMeta(enum.EnumMeta):
+    pass
+
+
+class MetaEnum(enum.Enum, metaclass=CustomEnumMeta):
+    A = "a"
+    B = 2
+
+
+class MetaBaseEnum(DescribeMixin, enum.Enum, metaclass=CustomEnumMeta):
+    pass
+
+
+class MetaDeepEnum(MetaBaseEnum):
+    A = "a"
+
+
+class MetaStrEnum(str, enum.Enum, metaclass=CustomEnumMeta):
+    A = "a"
+
+
+class MetaFlag(enum.Flag, metaclass=CustomEnumMeta):
+    R = 4
+    W = 2
+
+
 class TestEnum:
     def test_cannot_subclass(self):
         """
         enum.Enum cannot be subclassed
 
-        obj->ob_type->ob_base will always be enum.EnumMeta
+        obj->ob_type->ob_base will always be enum.EnumMeta or a subclass of it
         """
         with pytest.raises(TypeError):
 
@@ -119,3 +200,467 @@ class TestEnum:
         assert (
             orjson.dumps({IntEnum.ONE: 1}, option=orjson.OPT_NON_STR_KEYS) == b'{"1":1}'
         )
+
+    def test_bytes_enum(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(BytesEnum.A)
+
+    def test_bytes_enum_gzip(self):
+        for member, value in (
+            (BytesEnum.A, b"a"),
+            (BytesValueEnum.A, b"a"),
+            (BytesValueEnum.B, b""),
+        ):
+            serialized = orjson.loads(
+                orjson.dumps(member, option=orjson.OPT_SERIALIZE_BYTES_GZIP),
+            )
+            prefix = "data:application/gzip;base64,"
+            assert serialized.startswith(prefix)
+            assert gzip.decompress(base64.b64decode(serialized[len(prefix) :])) == value
+
+    def test_bytes_enum_gzip_container(self):
+        assert orjson.dumps(
+            {"a": [BytesValueEnum.A]},
+            option=orjson.OPT_SERIALIZE_BYTES_GZIP,
+        ) == orjson.dumps({"a": [b"a"]}, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+
+    def test_bytes_enum_dict_key(self):
+        option = orjson.OPT_SERIALIZE_BYTES_GZIP | orjson.OPT_NON_STR_KEYS
+        assert orjson.dumps({BytesEnum.A: 1}, option=option) == orjson.dumps(
+            {b"a": 1}, option=option
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({BytesEnum.A: 1}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_passthrough_enum(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(UnspecifiedEnum.A, option=orjson.OPT_PASSTHROUGH_ENUM)
+
+    def test_passthrough_enum_default(self):
+        def default(obj):
+            if isinstance(obj, enum.Enum):
+                return {"name": obj.name, "value": obj.value}
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                [UnspecifiedEnum.B, {"a": UnspecifiedEnum.A}],
+                option=orjson.OPT_PASSTHROUGH_ENUM,
+                default=default,
+            )
+            == b'[{"name":"B","value":1},{"a":{"name":"A","value":"a"}}]'
+        )
+
+    def test_passthrough_enum_subclass(self):
+        """
+        enums that subclass builtin types are also passed through
+        """
+        for member in (
+            StrEnum.AAA,
+            IntEnum.ONE,
+            IntEnumEnum.ONE,
+            IntFlagEnum.ONE,
+            FlagEnum.ONE,
+            FloatEnum.ONE,
+        ):
+            assert (
+                orjson.dumps(
+                    member,
+                    option=orjson.OPT_PASSTHROUGH_ENUM,
+                    default=lambda obj: obj.name,
+                )
+                == b'"%s"' % member.name.encode("utf-8")
+            )
+
+    def test_passthrough_enum_dict_key(self):
+        assert (
+            orjson.dumps(
+                {StrEnum.AAA: IntEnum.ONE},
+                option=orjson.OPT_PASSTHROUGH_ENUM | orjson.OPT_NON_STR_KEYS,
+                default=lambda obj: obj.name,
+            )
+            == b'{"aaa":"ONE"}'
+        )
+
+    def test_enum_repr(self):
+        assert (
+            orjson.dumps(UnspecifiedEnum.B, option=orjson.OPT_ENUM_REPR)
+            == b'"<UnspecifiedEnum.B: 1>"'
+        )
+        assert (
+            orjson.dumps(UnspecifiedEnum.A, option=orjson.OPT_ENUM_REPR)
+            == b"\"<UnspecifiedEnum.A: 'a'>\""
+        )
+
+    def test_enum_repr_subclass(self):
+        """
+        enums that subclass builtin types are also serialized as repr()
+        """
+        for member in (
+            StrEnum.AAA,
+            IntEnum.ONE,
+            IntEnumEnum.ONE,
+            IntFlagEnum.ONE,
+            FlagEnum.ONE,
+            FloatEnum.ONE,
+        ):
+            assert orjson.dumps(member, option=orjson.OPT_ENUM_REPR) == orjson.dumps(
+                repr(member),
+            )
+
+    def test_enum_repr_container(self):
+        assert orjson.dumps(
+            {"a": [UnspecifiedEnum.B, (IntEnum.ONE,)]},
+            option=orjson.OPT_ENUM_REPR,
+        ) == orjson.dumps({"a": [repr(UnspecifiedEnum.B), [repr(IntEnum.ONE)]]})
+
+    def test_enum_repr_dict_key(self):
+        assert (
+            orjson.dumps(
+                {UnspecifiedEnum.B: 1},
+                option=orjson.OPT_ENUM_REPR | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"<UnspecifiedEnum.B: 1>":1}'
+        )
+
+    def test_enum_repr_custom(self):
+        class ReprEnum(enum.Enum):
+            A = 1
+
+            def __repr__(self):
+                return f"ReprEnum.{self.name}"
+
+        assert orjson.dumps(ReprEnum.A, option=orjson.OPT_ENUM_REPR) == b'"ReprEnum.A"'
+
+    def test_enum_repr_raises(self):
+        class ReprEnum(enum.Enum):
+            A = 1
+
+            def __repr__(self):
+                raise ValueError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(ReprEnum.A, option=orjson.OPT_ENUM_REPR)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_enum_repr_passthrough(self):
+        """
+        OPT_PASSTHROUGH_ENUM takes precedence
+        """
+        assert (
+            orjson.dumps(
+                UnspecifiedEnum.B,
+                option=orjson.OPT_ENUM_REPR | orjson.OPT_PASSTHROUGH_ENUM,
+                default=lambda obj: obj.name,
+            )
+            == b'"B"'
+        )
+
+
+class TestFlagAsInt:
+    def test_flag_as_int(self):
+        """
+        OPT_FLAG_AS_INT serializes a flag as its integer value
+        """
+        for flag in (Permission, IntPermission):
+            assert orjson.dumps(flag.R, option=orjson.OPT_FLAG_AS_INT) == b"4"
+
+    def test_flag_as_int_combined(self):
+        """
+        A combined flag is serialized as the OR of its members
+        """
+        for flag in (Permission, IntPermission):
+            obj = flag.R | flag.W | flag.X
+            assert orjson.dumps(obj, option=orjson.OPT_FLAG_AS_INT) == b"7"
+            assert orjson.dumps(obj) == b"7"
+            obj = flag.R | flag.X
+            assert orjson.dumps(obj, option=orjson.OPT_FLAG_AS_INT) == b"5"
+
+    def test_flag_as_int_empty(self):
+        """
+        A flag with no members set is serialized as 0
+        """
+        assert orjson.dumps(Permission(0), option=orjson.OPT_FLAG_AS_INT) == b"0"
+        assert orjson.dumps(IntPermission(0), option=orjson.OPT_FLAG_AS_INT) == b"0"
+
+    def test_flag_as_int_enum_repr(self):
+        """
+        OPT_FLAG_AS_INT takes precedence over OPT_ENUM_REPR for flags only
+        """
+        option = orjson.OPT_FLAG_AS_INT | orjson.OPT_ENUM_REPR
+        assert (
+            orjson.dumps([Permission.R | Permission.W, IntPermission.X], option=option)
+            == b"[6,1]"
+        )
+        assert (
+            orjson.dumps(UnspecifiedEnum.B, option=option)
+            == b'"<UnspecifiedEnum.B: 1>"'
+        )
+
+    def test_flag_as_int_passthrough_enum(self):
+        """
+        OPT_FLAG_AS_INT takes precedence over OPT_PASSTHROUGH_ENUM for flags only
+        """
+        option = orjson.OPT_FLAG_AS_INT | orjson.OPT_PASSTHROUGH_ENUM
+        assert (
+            orjson.dumps(
+                [Permission.R | Permission.W, IntPermission.X, UnspecifiedEnum.B],
+                option=option,
+                default=lambda obj: obj.name,
+            )
+            == b'[6,1,"B"]'
+        )
+
+    def test_flag_as_int_passthrough_subclass(self):
+        """
+        OPT_FLAG_AS_INT with OPT_PASSTHROUGH_SUBCLASS
+        """
+        option = orjson.OPT_FLAG_AS_INT | orjson.OPT_PASSTHROUGH_SUBCLASS
+        assert orjson.dumps(IntPermission.R | IntPermission.W, option=option) == b"6"
+
+    def test_flag_as_int_dict_key(self):
+        """
+        OPT_FLAG_AS_INT applies to dict keys with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {Permission.R | Permission.X: 1, IntPermission.W: 2},
+                option=orjson.OPT_FLAG_AS_INT
+                | orjson.OPT_ENUM_REPR
+                | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"5":1,"2":2}'
+        )
+
+
+ENUM_FULL = orjson.OPT_ENUM_FULL
+NON_STR_KEYS = orjson.OPT_ENUM_FULL | orjson.OPT_NON_STR_KEYS
+
+
+class TestEnumFull:
+    def test_enum_full(self):
+        """
+        OPT_ENUM_FULL serializes a member as an object of its name and value
+        """
+        assert (
+            orjson.dumps(UnspecifiedEnum.B, option=ENUM_FULL)
+            == b'{"name":"B","value":1}'
+        )
+
+    def test_enum_full_value(self):
+        """
+        The value of a member is serialized with options and default
+        """
+        assert (
+            orjson.dumps(
+                [UnspecifiedEnum.D, UnspecifiedEnum.E, UnspecifiedEnum.F],
+                option=ENUM_FULL | orjson.OPT_NAIVE_UTC,
+                default=default,
+            )
+            == b'[{"name":"D","value":{"d":{"name":"ONE","value":1}}},'
+            b'{"name":"E","value":"c"},'
+            b'{"name":"F","value":"1970-01-01T00:00:00+00:00"}]'
+        )
+
+    def test_enum_full_subclass(self):
+        """
+        enums that subclass builtin types are also serialized as an object
+        """
+        for member in (StrEnum.AAA, IntEnum.ONE, IntEnumEnum.ONE, FloatEnum.ONE):
+            assert orjson.loads(orjson.dumps(member, option=ENUM_FULL)) == {
+                "name": member.name,
+                "value": member.value,
+            }
+
+    def test_enum_full_int_enum(self):
+        """
+        OPT_ENUM_FULL serializes an enum.IntEnum member as an object
+        """
+        assert (
+            orjson.dumps([IntEnumEnum.ONE], option=ENUM_FULL)
+            == b'[{"name":"ONE","value":1}]'
+        )
+
+    def test_enum_full_tuple_value(self):
+        """
+        The tuple value of a member is serialized as an array, with default for
+        an unsupported element
+        """
+        assert (
+            orjson.dumps(TupleEnum.ORIGIN, option=ENUM_FULL)
+            == b'{"name":"ORIGIN","value":[0,0]}'
+        )
+        assert (
+            orjson.dumps(TupleEnum.UNIT, option=ENUM_FULL, default=default)
+            == b'{"name":"UNIT","value":[1,"y"]}'
+        )
+
+    def test_enum_full_value_unsupported(self):
+        """
+        A value that is not serializable without default raises with the path
+        to the value
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": [TupleEnum.UNIT]}, option=ENUM_FULL)
+        assert str(exc_info.value) == "Type is not JSON serializable: Custom"
+        assert exc_info.value.path == ["a", 0, "value", 1]
+
+    def test_enum_full_flag_composite(self):
+        """
+        A combination of flags is serialized with its name and combined value
+        """
+        member = Permission.R | Permission.W
+        assert orjson.loads(orjson.dumps(member, option=ENUM_FULL)) == {
+            "name": member.name,
+            "value": 6,
+        }
+        assert orjson.loads(
+            orjson.dumps(IntPermission.R | IntPermission.X, option=ENUM_FULL)
+        ) == {"name": (IntPermission.R | IntPermission.X).name, "value": 5}
+
+    def test_enum_full_dict_key(self):
+        """
+        A member that is a dict key with OPT_NON_STR_KEYS is its name
+        """
+        assert (
+            orjson.dumps(
+                {UnspecifiedEnum.B: UnspecifiedEnum.A, StrEnum.AAA: 2, IntEnum.ONE: 3},
+                option=NON_STR_KEYS,
+            )
+            == b'{"B":{"name":"A","value":"a"},"AAA":2,"ONE":3}'
+        )
+
+    def test_enum_full_dict_key_sort_keys(self):
+        """
+        Keys that are names are sorted with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {UnspecifiedEnum.B: 1, UnspecifiedEnum.A: 2},
+                option=NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"A":2,"B":1}'
+        )
+
+    def test_enum_full_flag(self):
+        """
+        A flag is serialized as an object unless OPT_FLAG_AS_INT is specified
+        """
+        assert orjson.dumps(Permission.R, option=ENUM_FULL) == b'{"name":"R","value":4}'
+        assert (
+            orjson.dumps(
+                {Permission.R: Permission.W},
+                option=NON_STR_KEYS | orjson.OPT_FLAG_AS_INT,
+            )
+            == b'{"4":2}'
+        )
+
+    def test_enum_full_passthrough(self):
+        """
+        OPT_PASSTHROUGH_ENUM takes precedence
+        """
+        assert (
+            orjson.dumps(
+                UnspecifiedEnum.B,
+                option=ENUM_FULL | orjson.OPT_PASSTHROUGH_ENUM,
+                default=lambda obj: obj.name,
+            )
+            == b'"B"'
+        )
+
+    def test_enum_full_enum_repr(self):
+        """
+        OPT_ENUM_FULL cannot be specified with OPT_ENUM_REPR
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(UnspecifiedEnum.B, option=ENUM_FULL | orjson.OPT_ENUM_REPR)
+
+
+class TestEnumHierarchy:
+    def test_enum_hierarchy(self):
+        """
+        A member of an enum that subclasses an enum that subclasses a mixin and
+        Enum is serialized as its value
+        """
+        assert orjson.dumps(DeepEnum.A) == b'"a"'
+        assert orjson.dumps([DeepEnum.B, {"a": DeepEnum.A}]) == b'[2,{"a":"a"}]'
+        assert orjson.dumps(DeepIntEnum.ONE) == b"1"
+        assert DeepEnum.A.label() == "A"
+
+    def test_enum_hierarchy_options(self):
+        """
+        OPT_ENUM_FULL, OPT_ENUM_REPR, OPT_PASSTHROUGH_ENUM, and dict keys apply
+        to a deep enum hierarchy
+        """
+        assert (
+            orjson.dumps(DeepEnum.B, option=orjson.OPT_ENUM_FULL)
+            == b'{"name":"B","value":2}'
+        )
+        assert (
+            orjson.dumps(DeepIntEnum.ONE, option=orjson.OPT_ENUM_REPR)
+            == b'"<DeepIntEnum.ONE: 1>"'
+        )
+        assert (
+            orjson.dumps(
+                DeepEnum.A,
+                option=orjson.OPT_PASSTHROUGH_ENUM,
+                default=lambda obj: obj.describe(),
+            )
+            == b'"a"'
+        )
+        assert (
+            orjson.dumps({DeepEnum.B: DeepEnum.A}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"2":"a"}'
+        )
+
+    def test_enum_metaclass(self):
+        """
+        A member of an enum whose metaclass subclasses EnumMeta is an enum
+        """
+        assert orjson.dumps(MetaEnum.A) == b'"a"'
+        assert orjson.dumps([MetaEnum.B, MetaDeepEnum.A]) == b'[2,"a"]'
+        assert orjson.dumps(MetaStrEnum.A) == b'"a"'
+        assert (
+            orjson.dumps({MetaEnum.B: MetaEnum.A}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"2":"a"}'
+        )
+
+    def test_enum_metaclass_options(self):
+        """
+        Enum options apply to an enum whose metaclass subclasses EnumMeta
+        """
+        assert (
+            orjson.dumps(MetaDeepEnum.A, option=orjson.OPT_ENUM_FULL)
+            == b'{"name":"A","value":"a"}'
+        )
+        assert (
+            orjson.dumps(MetaStrEnum.A, option=orjson.OPT_ENUM_REPR)
+            == b"\"<MetaStrEnum.A: 'a'>\""
+        )
+        assert (
+            orjson.dumps(
+                MetaEnum.A,
+                option=orjson.OPT_PASSTHROUGH_ENUM,
+                default=lambda obj: obj.name,
+            )
+            == b'"A"'
+        )
+        assert (
+            orjson.dumps(MetaFlag.R | MetaFlag.W, option=orjson.OPT_FLAG_AS_INT)
+            == b"6"
+        )
+
+    def test_enum_metaclass_not_enum(self):
+        """
+        An instance of a class whose metaclass is not EnumMeta is not an enum
+        """
+
+        class Meta(type):
+            pass
+
+        class NotEnum(metaclass=Meta):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(NotEnum())
+        assert orjson.dumps(NotEnum(), default=lambda obj: "default") == b'"default"'
@@ -0,0 +1,124 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import datetime
+
+import pytest
+
+import orjson
+
+# 2023-11-14T22:13:20.123456789+00:00
+NS = 1_700_000_000_123_456_789
+
+
+class TestEpochNanoseconds:
+    def test_epoch_nanoseconds(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(NS))
+            == b'"2023-11-14T22:13:20.123456789+00:00"'
+        )
+
+    def test_epoch_nanoseconds_zero(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(0)) == b'"1970-01-01T00:00:00+00:00"'
+        )
+
+    def test_epoch_nanoseconds_one(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(1))
+            == b'"1970-01-01T00:00:00.000000001+00:00"'
+        )
+
+    def test_epoch_nanoseconds_negative(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(-1))
+            == b'"1969-12-31T23:59:59.999999999+00:00"'
+        )
+
+    def test_epoch_nanoseconds_min_max(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(-(2**63)))
+            == b'"1677-09-21T00:12:43.145224192+00:00"'
+        )
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(2**63 - 1))
+            == b'"2262-04-11T23:47:16.854775807+00:00"'
+        )
+
+    def test_epoch_nanoseconds_microseconds(self):
+        """
+        whole microseconds are written the same as datetime.datetime
+        """
+        obj = datetime.datetime(2023, 11, 14, 22, 13, 20, 123000, datetime.timezone.utc)
+        assert orjson.dumps(
+            orjson.EpochNanoseconds(1_700_000_000_123_000_000),
+        ) == orjson.dumps(obj)
+
+    def test_epoch_nanoseconds_utc_z(self):
+        assert (
+            orjson.dumps(orjson.EpochNanoseconds(NS), option=orjson.OPT_UTC_Z)
+            == b'"2023-11-14T22:13:20.123456789Z"'
+        )
+
+    def test_epoch_nanoseconds_omit_microseconds(self):
+        assert (
+            orjson.dumps(
+                orjson.EpochNanoseconds(NS),
+                option=orjson.OPT_OMIT_MICROSECONDS,
+            )
+            == b'"2023-11-14T22:13:20+00:00"'
+        )
+
+    def test_epoch_nanoseconds_passthrough_datetime(self):
+        assert (
+            orjson.dumps(
+                orjson.EpochNanoseconds(NS),
+                option=orjson.OPT_PASSTHROUGH_DATETIME,
+            )
+            == b'"2023-11-14T22:13:20.123456789+00:00"'
+        )
+
+    def test_epoch_nanoseconds_container(self):
+        @dataclasses.dataclass
+        class Event:
+            ts: orjson.EpochNanoseconds
+
+        assert (
+            orjson.dumps(
+                {"a": [orjson.EpochNanoseconds(NS), (Event(orjson.EpochNanoseconds(0)),)]},
+                option=orjson.OPT_UTC_Z,
+            )
+            == b'{"a":["2023-11-14T22:13:20.123456789Z",[{"ts":"1970-01-01T00:00:00Z"}]]}'
+        )
+
+    def test_epoch_nanoseconds_dict_key(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {orjson.EpochNanoseconds(NS): True},
+                option=orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_epoch_nanoseconds_arguments(self):
+        with pytest.raises(TypeError):
+            orjson.EpochNanoseconds()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.EpochNanoseconds(1, 2)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.EpochNanoseconds(value=1)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.EpochNanoseconds("1")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.EpochNanoseconds(1.0)  # type: ignore
+
+    def test_epoch_nanoseconds_overflow(self):
+        with pytest.raises(OverflowError):
+            orjson.EpochNanoseconds(2**63)
+        with pytest.raises(OverflowError):
+            orjson.EpochNanoseconds(-(2**63) - 1)
+
+    def test_epoch_nanoseconds_subclass(self):
+        with pytest.raises(TypeError):
+
+            class SubEpochNanoseconds(orjson.EpochNanoseconds):  # type: ignore
+                pass
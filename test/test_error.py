@@ -1,6 +1,7 @@
 # SPDX-License-Identifier: (Apache-2.0 OR MIT)
 # Copyright ijl (2021-2025), Eric Jolibois (2021), o.ermakov (2023)
 
+import io
 import json
 
 import pytest
@@ -104,6 +105,130 @@ class TestJsonDecodeError:
         }
 
 
+class TestJsonDecodeErrorCode:
+    def _code(self, data, **kwargs):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(data, **kwargs)
+        return exc_info.value.code
+
+    def test_code_input_type(self):
+        """
+        code 1 for an input of an unsupported type or an invalid option
+        """
+        assert self._code(1) == 1
+        assert self._code("1", option=1 << 128) == 1
+
+    def test_code_empty(self):
+        """
+        code 3 for an empty input
+        """
+        assert self._code("") == 3
+        assert self._code(b"") == 3
+
+    def test_code_unexpected_content(self):
+        """
+        code 4 for content after the document
+        """
+        assert self._code("[1] 2") == 4
+        assert self._code("truex") == 4
+
+    def test_code_unexpected_end(self):
+        """
+        code 5 for a document that ends early
+        """
+        assert self._code("[1") == 5
+        assert self._code('"abc') == 5
+
+    def test_code_unexpected_character(self):
+        """
+        code 6 for an unexpected character
+        """
+        assert self._code('{"a" 1}') == 6
+        assert self._code("NaN") == 6
+
+    def test_code_structure(self):
+        """
+        code 7 for an invalid structure
+        """
+        assert self._code("[1,]") == 7
+        assert self._code('{"a":1,}') == 7
+
+    def test_code_number(self):
+        """
+        code 9 for an invalid number
+        """
+        assert self._code("01") == 9
+        assert self._code("1e400") == 9
+
+    def test_code_string(self):
+        """
+        code 10 for an invalid string or input that is not UTF-8
+        """
+        assert self._code('"\\ud800"') == 10
+        assert self._code('"\x01"') == 10
+        assert self._code(b'"\xff"') == 10
+        assert self._code("\ud800") == 10
+
+    def test_code_literal(self):
+        """
+        code 11 for an invalid literal
+        """
+        assert self._code("[nulx]") == 11
+
+    def test_code_recursion(self):
+        """
+        code 14 for arrays and objects that recurse too deeply
+        """
+        assert self._code("[" * 1025 + "]" * 1025) == 14
+
+    def test_code_message(self):
+        """
+        code does not change the message or position
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[1] 2")
+        assert exc_info.value.code == 4
+        assert exc_info.value.pos == 4
+        assert str(exc_info.value) == (
+            "unexpected content after document: line 1 column 5 (char 4)"
+        )
+
+    def test_byte_pos(self):
+        """
+        byte_pos is the position in bytes of the UTF-8 input
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('["éé" x]'.encode())
+        assert exc_info.value.pos == 6
+        assert exc_info.value.byte_pos == 8
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads('["éé" x]')
+        assert exc_info.value.pos == 6
+        assert exc_info.value.byte_pos == 8
+
+    def test_byte_pos_ascii(self):
+        """
+        byte_pos is pos for ASCII input or an error before parsing
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads("[1] 2")
+        assert exc_info.value.pos == exc_info.value.byte_pos == 4
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(1)
+        assert exc_info.value.pos == exc_info.value.byte_pos == 0
+
+    def test_code_load(self):
+        """
+        load() sets code
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.load(io.BytesIO(b"[1"))
+        assert exc_info.value.code == 5
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.load(object())
+        assert exc_info.value.code == 1
+
+
 class Custom:
     pass
 
@@ -0,0 +1,176 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+from typing import NamedTuple
+
+import pytest
+
+import orjson
+
+try:
+    import numpy
+except ImportError:
+    numpy = None  # type: ignore
+
+
+@dataclasses.dataclass
+class Data:
+    a: object
+
+
+class Pair(NamedTuple):
+    a: object
+    b: object
+
+
+def error_path(obj, **kwargs):
+    with pytest.raises(orjson.JSONEncodeError) as exc_info:
+        orjson.dumps(obj, **kwargs)
+    return exc_info.value.path
+
+
+class TestErrorPath:
+    def test_error_path_root(self):
+        assert error_path(object()) == []
+
+    def test_error_path_list(self):
+        assert error_path([1, 2, object()]) == [2]
+
+    def test_error_path_tuple(self):
+        assert error_path((1, (2, object()))) == [1, 1]
+
+    def test_error_path_dict(self):
+        assert error_path({"a": 1, "b": object()}) == ["b"]
+
+    def test_error_path_nested(self):
+        assert error_path({"a": [1, {"b": object()}]}) == ["a", 1, "b"]
+
+    def test_error_path_dataclass(self):
+        assert error_path([Data({"b": [object()]})]) == [0, "a", "b", 0]
+
+    def test_error_path_namedtuple(self):
+        assert error_path(
+            {"a": Pair(1, object())},
+            option=orjson.OPT_NAMEDTUPLE_AS_OBJECT,
+        ) == ["a", "b"]
+
+    def test_error_path_iterator(self):
+        assert error_path(
+            {"a": iter([1, object()])},
+            option=orjson.OPT_SERIALIZE_ITERATORS,
+        ) == ["a", 1]
+
+    def test_error_path_sort_keys(self):
+        assert error_path(
+            {"b": object(), "a": 1},
+            option=orjson.OPT_SORT_KEYS,
+        ) == ["b"]
+
+    def test_error_path_non_str_keys(self):
+        assert error_path(
+            {1: [object()]},
+            option=orjson.OPT_NON_STR_KEYS,
+        ) == ["1", 0]
+
+    def test_error_path_default(self):
+        def default(obj):
+            raise ValueError
+
+        exc_path = error_path({"a": [object()]}, default=default)
+        assert exc_path == ["a", 0]
+
+    def test_error_path_default_nested(self):
+        def default(obj):
+            return {"b": [obj]}
+
+        assert error_path([object()], default=default)[:3] == [0, "b", 0]
+
+    def test_error_path_str(self):
+        assert error_path({"a": ["\ud800"]}) == ["a", 0]
+
+    def test_error_path_fresh(self):
+        assert error_path({"a": [object()]}) == ["a", 0]
+        assert error_path([[object()]]) == [0, 0]
+        assert error_path(object()) == []
+
+    def test_error_path_encoder(self):
+        encoder = orjson.Encoder()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode({"a": [object()]})
+        assert exc_info.value.path == ["a", 0]
+
+    def test_error_path_recursion_limit(self):
+        """
+        The message of the recursion limit error includes the path to where
+        it was reached as an RFC 9535 normalized path
+        """
+        obj: list = [{"a": None}]
+        obj[0]["a"] = obj
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"b": obj})
+        assert str(exc_info.value) == (
+            "Recursion limit reached at $['b']" + "[0]['a']" * 126 + "[0]"
+        )
+        assert exc_info.value.path == ["b"] + [0, "a"] * 126 + [0]
+
+    def test_error_path_recursion_limit_key(self):
+        """
+        A key in the path of the recursion limit error escapes ' and \\
+        """
+        obj: dict = {}
+        obj["it's\\"] = [obj]
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.Encoder().encode(obj)
+        assert str(exc_info.value) == (
+            "Recursion limit reached at $" + "['it\\'s\\\\'][0]" * 127
+        )
+
+    def test_error_path_recursion_limit_root(self):
+        """
+        Other errors do not include the path in the message
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": [object()]})
+        assert str(exc_info.value) == "Type is not JSON serializable: object"
+
+    def test_error_path_argument(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(1, option="a")  # type: ignore
+        assert str(exc_info.value) == "Invalid opts"
+        assert exc_info.value.path == []
+
+    def test_error_path_fixed_message(self):
+        """
+        Every JSONEncodeError has a path, including one raised for the
+        arguments rather than while serializing
+        """
+        for func in (
+            lambda: orjson.dumps(),  # type: ignore
+            lambda: orjson.dumps(1, indent=9),
+            lambda: orjson.dumps(1, zxc=1),  # type: ignore
+            lambda: orjson.Encoder(max_sequence_len=0),
+            lambda: orjson.dumps_into_buffer(1, b"xx"),
+            lambda: orjson.dumps_chunked(1, 0),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                func()
+            assert exc_info.value.path == []
+
+    def test_error_path_fixed_message_after_error(self):
+        """
+        The path of an earlier error is not attached to a later error raised
+        for the arguments
+        """
+        assert error_path({"a": [object()]}) == ["a", 0]
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(1, option="a")  # type: ignore
+        assert exc_info.value.path == []
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_error_path_numpy(self):
+        arr = numpy.array([[1, 2], [3, object()]], dtype=object)
+        assert error_path(
+            {"a": arr},
+            option=orjson.OPT_SERIALIZE_NUMPY,
+        ) == ["a", 3]
@@ -0,0 +1,112 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import html
+import json
+
+import orjson
+
+
+def stdlib_html(obj) -> bytes:
+    return json.dumps(html.escape(obj), ensure_ascii=False).encode("utf-8")
+
+
+class TestEscapeHtml:
+    def test_escape_html_all(self):
+        """
+        dumps() OPT_ESCAPE_HTML escapes &, <, >, ", and ' as HTML entities
+        """
+        assert (
+            orjson.dumps("<a href=\"x\">'&'</a>", option=orjson.OPT_ESCAPE_HTML)
+            == b'"&lt;a href=&quot;x&quot;&gt;&#x27;&amp;&#x27;&lt;/a&gt;"'
+        )
+
+    def test_escape_html_each(self):
+        """
+        dumps() OPT_ESCAPE_HTML of each character is the same as html.escape()
+        """
+        for val in ("&", "<", ">", '"', "'", "<>&\"'"):
+            assert orjson.dumps(val, option=orjson.OPT_ESCAPE_HTML) == stdlib_html(
+                val
+            )
+
+    def test_escape_html_unchanged(self):
+        """
+        dumps() OPT_ESCAPE_HTML does not change other characters
+        """
+        for val in ("", "abc", "a\\b", "\x00\x1f\n\t", "/", "a" * 100):
+            assert orjson.dumps(val, option=orjson.OPT_ESCAPE_HTML) == orjson.dumps(
+                val
+            )
+
+    def test_escape_html_non_ascii(self):
+        """
+        dumps() OPT_ESCAPE_HTML writes non-ASCII characters unescaped
+        """
+        val = "<é>日本😀& " * 10
+        assert orjson.dumps(val, option=orjson.OPT_ESCAPE_HTML) == stdlib_html(val)
+
+    def test_escape_html_ensure_ascii(self):
+        """
+        dumps() OPT_ESCAPE_HTML with OPT_ENSURE_ASCII
+        """
+        assert (
+            orjson.dumps(
+                "<é>😀", option=orjson.OPT_ESCAPE_HTML | orjson.OPT_ENSURE_ASCII
+            )
+            == b'"&lt;\\u00e9&gt;\\ud83d\\ude00"'
+        )
+
+    def test_escape_html_keys(self):
+        """
+        dumps() OPT_ESCAPE_HTML applies to dict keys and nested strings
+        """
+        assert (
+            orjson.dumps({"<a>": ["&", {"'": '"'}]}, option=orjson.OPT_ESCAPE_HTML)
+            == b'{"&lt;a&gt;":["&amp;",{"&#x27;":"&quot;"}]}'
+        )
+
+    def test_escape_html_indent(self):
+        """
+        dumps() OPT_ESCAPE_HTML with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a": "<b>"}, option=orjson.OPT_ESCAPE_HTML | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "a": "&lt;b&gt;"\n}'
+        )
+
+    def test_escape_html_str_subclass(self):
+        """
+        dumps() OPT_ESCAPE_HTML applies to a str subclass
+        """
+
+        class Sub(str):
+            pass
+
+        assert orjson.dumps(Sub("<"), option=orjson.OPT_ESCAPE_HTML) == b'"&lt;"'
+
+    def test_escape_html_fragment(self):
+        """
+        dumps() OPT_ESCAPE_HTML does not change orjson.Fragment
+        """
+        assert (
+            orjson.dumps(orjson.Fragment(b'"<&>"'), option=orjson.OPT_ESCAPE_HTML)
+            == b'"<&>"'
+        )
+
+    def test_escape_html_loads(self):
+        """
+        loads() of OPT_ESCAPE_HTML output is the escaped str
+        """
+        val = "<a>&'\""
+        assert orjson.loads(
+            orjson.dumps(val, option=orjson.OPT_ESCAPE_HTML)
+        ) == html.escape(val)
+
+    def test_escape_html_not_enabled(self):
+        """
+        dumps() does not escape HTML characters without OPT_ESCAPE_HTML
+        """
+        assert orjson.dumps("<a>&'\"") == b'"<a>&\'\\""'
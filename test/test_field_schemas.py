@@ -0,0 +1,205 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+from typing import Optional
+
+import pytest
+
+import orjson
+
+MESSAGE = (
+    'field_schemas must be a dict of type to a dict of "include" or "exclude" '
+    "to a set of str"
+)
+
+
+@dataclasses.dataclass
+class User:
+    id: int
+    name: str
+    password: str
+
+
+@dataclasses.dataclass
+class Group:
+    id: int
+    name: str
+    members: list
+
+
+@dataclasses.dataclass
+class Admin(User):
+    pass
+
+
+@dataclasses.dataclass
+class Private:
+    a: int
+    _b: int
+    c: Optional[int] = None
+
+
+@dataclasses.dataclass(slots=True)
+class Slotted:
+    a: int
+    b: int
+    c: int
+
+
+class TestFieldSchemas:
+    def test_field_schemas_include_per_type(self):
+        """
+        field_schemas applies a different include set to each type
+        """
+        encoder = orjson.Encoder(
+            field_schemas={
+                User: {"include": {"id", "name"}},
+                Group: {"include": {"name", "members"}},
+            }
+        )
+        assert (
+            encoder.encode(Group(1, "admin", [User(2, "a", "secret")]))
+            == b'{"name":"admin","members":[{"id":2,"name":"a"}]}'
+        )
+
+    def test_field_schemas_exclude(self):
+        """
+        field_schemas exclude omits the given fields
+        """
+        encoder = orjson.Encoder(field_schemas={User: {"exclude": {"password"}}})
+        assert encoder.encode(User(1, "a", "secret")) == b'{"id":1,"name":"a"}'
+
+    def test_field_schemas_include_exclude(self):
+        """
+        A field in both include and exclude is omitted
+        """
+        encoder = orjson.Encoder(
+            field_schemas={
+                User: {"include": {"id", "name"}, "exclude": frozenset(("name",))}
+            }
+        )
+        assert encoder.encode(User(1, "a", "secret")) == b'{"id":1}'
+
+    def test_field_schemas_unknown_field(self):
+        """
+        A name in include that is not a field has no effect
+        """
+        encoder = orjson.Encoder(field_schemas={User: {"include": {"id", "email"}}})
+        assert encoder.encode(User(1, "a", "secret")) == b'{"id":1}'
+
+    def test_field_schemas_not_registered(self):
+        """
+        A type that is not in field_schemas is serialized as usual
+        """
+        encoder = orjson.Encoder(field_schemas={Group: {"include": {"id"}}})
+        assert (
+            encoder.encode(User(1, "a", "secret"))
+            == b'{"id":1,"name":"a","password":"secret"}'
+        )
+
+    def test_field_schemas_exact_type(self):
+        """
+        field_schemas does not apply to a subclass
+        """
+        encoder = orjson.Encoder(field_schemas={User: {"include": {"id"}}})
+        assert (
+            encoder.encode(Admin(1, "a", "secret"))
+            == b'{"id":1,"name":"a","password":"secret"}'
+        )
+
+    def test_field_schemas_private(self):
+        """
+        A field beginning with _ is omitted even if in include, and
+        OPT_OMIT_NONE applies to included fields
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_OMIT_NONE,
+            field_schemas={Private: {"include": {"a", "_b", "c"}}},
+        )
+        assert encoder.encode(Private(1, 2)) == b'{"a":1}'
+
+    def test_field_schemas_slots(self):
+        """
+        field_schemas applies to a dataclass with __slots__
+        """
+        encoder = orjson.Encoder(
+            field_schemas={Slotted: {"include": {"a", "c"}, "exclude": {"a"}}}
+        )
+        assert encoder.encode(Slotted(1, 2, 3)) == b'{"c":3}'
+
+    def test_field_schemas_sort(self):
+        """
+        field_schemas with OPT_SORT_DATACLASS_FIELDS
+        """
+        encoder = orjson.Encoder(
+            option=orjson.OPT_SORT_DATACLASS_FIELDS,
+            field_schemas={
+                User: {"exclude": {"id"}},
+                Slotted: {"exclude": {"b"}},
+            },
+        )
+        assert (
+            encoder.encode([User(1, "a", "secret"), Slotted(3, 2, 1)])
+            == b'[{"name":"a","password":"secret"},{"a":3,"c":1}]'
+        )
+
+    def test_field_schemas_passthrough(self):
+        """
+        field_schemas does not apply with OPT_PASSTHROUGH_DATACLASS
+        """
+        encoder = orjson.Encoder(
+            default=lambda obj: "default",
+            option=orjson.OPT_PASSTHROUGH_DATACLASS,
+            field_schemas={User: {"include": {"id"}}},
+        )
+        assert encoder.encode(User(1, "a", "secret")) == b'"default"'
+
+    def test_field_schemas_empty(self):
+        """
+        field_schemas of None or an empty dict has no effect
+        """
+        for val in (None, {}):
+            encoder = orjson.Encoder(field_schemas=val)
+            assert (
+                encoder.encode(User(1, "a", "secret"))
+                == b'{"id":1,"name":"a","password":"secret"}'
+            )
+
+    def test_field_schemas_empty_include(self):
+        """
+        An empty include set omits every field
+        """
+        encoder = orjson.Encoder(field_schemas={User: {"include": set()}})
+        assert encoder.encode(User(1, "a", "secret")) == b"{}"
+
+    def test_field_schemas_invalid(self):
+        """
+        field_schemas that is not a dict of type to a dict of include or
+        exclude to a set of str raises
+        """
+        for val in (
+            [],
+            {"User": {"include": {"id"}}},
+            {User: {"id"}},
+            {User: {}},
+            {User: {"include": ["id"]}},
+            {User: {"include": {"id"}, "only": {"id"}}},
+            {User: {"exclude": {1}}},
+            {User: {"include": {"id"}}, Group: {"exclude": "id"}},
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.Encoder(field_schemas=val)
+            assert str(exc_info.value) == MESSAGE
+
+    def test_field_schemas_copied(self):
+        """
+        field_schemas is copied when the Encoder is created
+        """
+        include = {"id"}
+        schemas = {User: {"include": include}}
+        encoder = orjson.Encoder(field_schemas=schemas)
+        include.add("name")
+        schemas[User]["exclude"] = {"id"}
+        schemas.clear()
+        assert encoder.encode(User(1, "a", "secret")) == b'{"id":1}'
@@ -0,0 +1,69 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import re
+import struct
+
+import orjson
+
+from .util import needs_data, read_fixture_str
+
+
+def read_vectors():
+    """
+    Each line of data/float/shortest.txt is the IEEE 754 binary64 bits of a
+    float in hexadecimal and its expected output with no options
+    """
+    vectors = []
+    for line in read_fixture_str("shortest.txt", "float").splitlines():
+        bits, expected = line.split(" ")
+        vectors.append(
+            (struct.unpack(">d", bytes.fromhex(bits))[0], expected.encode("ascii"))
+        )
+    return vectors
+
+
+def shortest_digits(output):
+    match = re.fullmatch(r"-?([0-9.]+)(?:e[+-]?[0-9]+)?", output)
+    assert match is not None
+    return match.group(1).replace(".", "").strip("0")
+
+
+@needs_data
+class TestFloatFormat:
+    def test_float_format_vectors(self):
+        """
+        dumps() of each float is the same as the test vector
+        """
+        for value, expected in read_vectors():
+            assert orjson.dumps(value) == expected
+
+    def test_float_format_vectors_array(self):
+        """
+        dumps() of the floats in an array and object is the same as the test
+        vectors
+        """
+        vectors = read_vectors()
+        values = [value for value, _ in vectors]
+        expected = b"[" + b",".join(each for _, each in vectors) + b"]"
+        assert orjson.dumps(values) == expected
+        assert orjson.dumps({"a": values}) == b'{"a":' + expected + b"}"
+        assert orjson.Encoder().encode(values) == expected
+
+    def test_float_format_vectors_roundtrip(self):
+        """
+        Each test vector deserializes to the same bits
+        """
+        for value, expected in read_vectors():
+            assert struct.pack(">d", orjson.loads(expected)) == struct.pack(
+                ">d", value
+            )
+
+    def test_float_format_vectors_shortest(self):
+        """
+        Each test vector has the same shortest round-tripping digits as repr()
+        """
+        for value, expected in read_vectors():
+            assert shortest_digits(expected.decode("ascii")) == shortest_digits(
+                repr(abs(value))
+            )
@@ -0,0 +1,175 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import math
+
+import pytest
+
+import orjson
+
+
+class TestFloatSigfigs:
+    def test_float_sigfigs_small(self):
+        """
+        float_sigfigs rounds a float less than 1
+        """
+        assert orjson.dumps(0.00012345, float_sigfigs=3) == b"0.000123"
+
+    def test_float_sigfigs_large(self):
+        """
+        float_sigfigs rounds the integer part of a float
+        """
+        assert orjson.dumps(12345.678, float_sigfigs=3) == b"12300.0"
+
+    def test_float_sigfigs_negative(self):
+        """
+        float_sigfigs rounds a negative float
+        """
+        assert orjson.dumps(-12345.678, float_sigfigs=3) == b"-12300.0"
+
+    def test_float_sigfigs_carry(self):
+        """
+        float_sigfigs rounding that carries into another digit
+        """
+        assert orjson.dumps(9.9996, float_sigfigs=4) == b"10.0"
+
+    def test_float_sigfigs_half_even(self):
+        """
+        float_sigfigs rounds on the exact value of the float
+        """
+        assert (
+            orjson.dumps([0.125, 0.375, 2.675], float_sigfigs=2)
+            == b"[0.12,0.38,2.7]"
+        )
+        assert orjson.dumps(2.675, float_sigfigs=3) == b"2.67"
+
+    def test_float_sigfigs_same_as_format(self):
+        """
+        float_sigfigs is the same as formatting with Python's e format
+        """
+        values = [
+            math.pi,
+            -math.e,
+            1 / 3,
+            5e-324,
+            1.2e308,
+            123456789012345678.0,
+            0.1 + 0.2,
+        ]
+        for sigfigs in range(1, 18):
+            assert orjson.loads(orjson.dumps(values, float_sigfigs=sigfigs)) == [
+                float(f"{value:.{sigfigs - 1}e}") for value in values
+            ]
+
+    def test_float_sigfigs_max(self):
+        """
+        float_sigfigs=17 does not change any float
+        """
+        values = [0.1, 1 / 3, math.pi, 5e-324, 1.7976931348623157e308]
+        assert orjson.dumps(values, float_sigfigs=17) == orjson.dumps(values)
+
+    def test_float_sigfigs_zero(self):
+        """
+        float_sigfigs does not change zero
+        """
+        assert orjson.dumps([0.0, -0.0], float_sigfigs=1) == b"[0.0,-0.0]"
+
+    def test_float_sigfigs_nonfinite(self):
+        """
+        float_sigfigs does not change nan or infinity
+        """
+        assert (
+            orjson.dumps([math.nan, math.inf, -math.inf], float_sigfigs=3)
+            == b"[null,null,null]"
+        )
+
+    def test_float_sigfigs_overflow(self):
+        """
+        float_sigfigs does not round a float to beyond the maximum float
+        """
+        assert (
+            orjson.dumps(1.7976931348623157e308, float_sigfigs=1)
+            == b"1.7976931348623157e+308"
+        )
+        assert (
+            orjson.dumps(-1.7976931348623157e308, float_sigfigs=2)
+            == b"-1.7976931348623157e+308"
+        )
+        assert orjson.dumps(1.6976931348623157e308, float_sigfigs=2) == b"1.7e+308"
+
+    def test_float_sigfigs_nested(self):
+        """
+        float_sigfigs applies to floats in containers
+        """
+        assert (
+            orjson.dumps({"a": [1.23456, {"b": 6.54321}], "c": 1}, float_sigfigs=2)
+            == b'{"a":[1.2,{"b":6.5}],"c":1}'
+        )
+
+    def test_float_sigfigs_not_int(self):
+        """
+        float_sigfigs does not change int
+        """
+        assert orjson.dumps(12345, float_sigfigs=1) == b"12345"
+
+    def test_float_sigfigs_not_key(self):
+        """
+        float_sigfigs does not apply to dict keys
+        """
+        assert (
+            orjson.dumps(
+                {1.23456: 1.23456},
+                option=orjson.OPT_NON_STR_KEYS,
+                float_sigfigs=2,
+            )
+            == b'{"1.23456":1.2}'
+        )
+
+    def test_float_sigfigs_canonical(self):
+        """
+        float_sigfigs with OPT_CANONICAL
+        """
+        assert (
+            orjson.dumps(
+                [12345.678, 0.00000012345],
+                option=orjson.OPT_CANONICAL,
+                float_sigfigs=3,
+            )
+            == b"[12300,1.23e-7]"
+        )
+
+    def test_float_sigfigs_none(self):
+        """
+        float_sigfigs=None is the same as not specifying it
+        """
+        assert orjson.dumps(math.pi, float_sigfigs=None) == orjson.dumps(math.pi)
+
+    def test_float_sigfigs_invalid(self):
+        """
+        float_sigfigs must be an int from 1 to 17
+        """
+        for val in (0, 18, -1, 2**64, 3.0, "3", True):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(1.0, float_sigfigs=val)
+            assert (
+                str(exc_info.value) == "float_sigfigs must be an int from 1 to 17"
+            )
+
+    def test_float_sigfigs_encoder(self):
+        """
+        Encoder() float_sigfigs
+        """
+        encoder = orjson.Encoder(float_sigfigs=3)
+        assert encoder.encode([0.00012345, 12345.678]) == b"[0.000123,12300.0]"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(float_sigfigs=0)
+
+    def test_float_sigfigs_dumps_into_buffer(self):
+        """
+        dumps_into_buffer() float_sigfigs
+        """
+        buf = bytearray(8)
+        assert orjson.dumps_into_buffer(0.00012345, buf, float_sigfigs=3) == 8
+        assert buf == b"0.000123"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_into_buffer(1.0, buf, float_sigfigs=18)
@@ -0,0 +1,152 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import math
+import random
+
+import orjson
+
+
+class TestFloatTrailingZero:
+    def test_float_trailing_zero_whole(self):
+        """
+        OPT_FLOAT_TRAILING_ZERO serializes a whole float with .0
+        """
+        assert (
+            orjson.dumps(
+                [1.0, -1.0, 100.0, 1e15], option=orjson.OPT_FLOAT_TRAILING_ZERO
+            )
+            == b"[1.0,-1.0,100.0,1000000000000000.0]"
+        )
+
+    def test_float_trailing_zero_large(self):
+        """
+        A float less than 1e21 is serialized in fixed notation
+        """
+        assert orjson.dumps(1e16) == b"1e+16"
+        assert (
+            orjson.dumps(1e16, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"10000000000000000.0"
+        )
+        assert (
+            orjson.dumps(1e20, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"100000000000000000000.0"
+        )
+        assert (
+            orjson.dumps(-2.5e17, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"-250000000000000000.0"
+        )
+        assert (
+            orjson.dumps(123456789012345678.0, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"123456789012345680.0"
+        )
+        assert (
+            orjson.dumps(9.999999999999999e20, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"999999999999999900000.0"
+        )
+
+    def test_float_trailing_zero_small(self):
+        """
+        A float of at least 1e-6 is serialized in fixed notation
+        """
+        assert orjson.dumps(1e-5, option=orjson.OPT_FLOAT_TRAILING_ZERO) == b"0.00001"
+        assert orjson.dumps(1e-6, option=orjson.OPT_FLOAT_TRAILING_ZERO) == b"0.000001"
+        assert (
+            orjson.dumps(-1.5e-6, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"-0.0000015"
+        )
+        assert (
+            orjson.dumps(1.2345678901234567e-6, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"0.0000012345678901234567"
+        )
+
+    def test_float_trailing_zero_exponent(self):
+        """
+        A float outside the fixed range is serialized as usual
+        """
+        for value in (1e21, -1e21, 1e22, 1.7976931348623157e308, 1e-7, 5e-324):
+            assert (
+                orjson.dumps(value, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+                == orjson.dumps(value)
+            )
+        assert orjson.dumps(1e21, option=orjson.OPT_FLOAT_TRAILING_ZERO) == b"1e+21"
+        assert orjson.dumps(1e-7, option=orjson.OPT_FLOAT_TRAILING_ZERO) == b"1e-7"
+
+    def test_float_trailing_zero_no_exponent(self):
+        """
+        No float in the fixed range is serialized with an exponent
+        """
+        rng = random.Random(0)
+        for exponent in range(-6, 21):
+            for _ in range(100):
+                value = rng.uniform(1, 10) * 10.0**exponent
+                if not 1e-6 <= value < 1e21:
+                    continue
+                output = orjson.dumps(value, option=orjson.OPT_FLOAT_TRAILING_ZERO)
+                assert b"e" not in output
+                assert b"." in output
+                assert orjson.loads(output) == value
+
+    def test_float_trailing_zero_roundtrip(self):
+        """
+        The output is the same float as its input
+        """
+        values = [0.1, 1 / 3, math.pi, 2**53 + 0.0, 2.0**64, 1e20 / 3]
+        assert (
+            orjson.loads(orjson.dumps(values, option=orjson.OPT_FLOAT_TRAILING_ZERO))
+            == values
+        )
+
+    def test_float_trailing_zero_zero(self):
+        """
+        Zero and negative zero
+        """
+        assert (
+            orjson.dumps([0.0, -0.0], option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"[0.0,-0.0]"
+        )
+
+    def test_float_trailing_zero_nonfinite(self):
+        """
+        NaN and Infinity are serialized as null
+        """
+        assert (
+            orjson.dumps(
+                [math.nan, math.inf, -math.inf], option=orjson.OPT_FLOAT_TRAILING_ZERO
+            )
+            == b"[null,null,null]"
+        )
+
+    def test_float_trailing_zero_int(self):
+        """
+        OPT_FLOAT_TRAILING_ZERO does not change int
+        """
+        assert (
+            orjson.dumps([1, 10**16], option=orjson.OPT_FLOAT_TRAILING_ZERO)
+            == b"[1,10000000000000000]"
+        )
+
+    def test_float_trailing_zero_dict_key(self):
+        """
+        OPT_FLOAT_TRAILING_ZERO applies to float keys with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {1e16: 1e16},
+                option=orjson.OPT_FLOAT_TRAILING_ZERO | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"10000000000000000.0":10000000000000000.0}'
+        )
+
+    def test_float_trailing_zero_sigfigs(self):
+        """
+        float_sigfigs rounds before formatting
+        """
+        assert (
+            orjson.dumps(
+                12345678901234567890.0,
+                option=orjson.OPT_FLOAT_TRAILING_ZERO,
+                float_sigfigs=2,
+            )
+            == b"12000000000000000000.0"
+        )
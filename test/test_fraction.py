@@ -0,0 +1,150 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import fractions
+
+import pytest
+
+import orjson
+
+OPTION = orjson.OPT_SERIALIZE_FRACTIONS
+AS_FLOAT = orjson.OPT_SERIALIZE_FRACTIONS | orjson.OPT_FRACTION_AS_FLOAT
+
+
+class SubFraction(fractions.Fraction):
+    pass
+
+
+class TestFraction:
+    def test_fraction(self):
+        """
+        OPT_SERIALIZE_FRACTIONS serializes a Fraction as [numerator, denominator]
+        """
+        assert orjson.dumps(fractions.Fraction(1, 3), option=OPTION) == b"[1,3]"
+
+    def test_fraction_normalized(self):
+        """
+        The numerator and denominator are in lowest terms and the sign is on
+        the numerator
+        """
+        assert orjson.dumps(fractions.Fraction(6, -4), option=OPTION) == b"[-3,2]"
+        assert orjson.dumps(fractions.Fraction(0), option=OPTION) == b"[0,1]"
+        assert orjson.dumps(fractions.Fraction(5), option=OPTION) == b"[5,1]"
+
+    def test_fraction_exact(self):
+        """
+        The array form is exact where a float is not
+        """
+        val = fractions.Fraction(1, 10) + fractions.Fraction(2, 10)
+        numerator, denominator = orjson.loads(orjson.dumps(val, option=OPTION))
+        assert fractions.Fraction(numerator, denominator) == val
+        assert fractions.Fraction(float(val)) != val
+
+    def test_fraction_not_enabled(self):
+        """
+        A Fraction is not serialized without OPT_SERIALIZE_FRACTIONS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(fractions.Fraction(1, 3))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(fractions.Fraction(1, 3), option=orjson.OPT_FRACTION_AS_FLOAT)
+
+    def test_fraction_as_float(self):
+        """
+        OPT_FRACTION_AS_FLOAT serializes a Fraction as float()
+        """
+        assert orjson.dumps(fractions.Fraction(1, 4), option=AS_FLOAT) == b"0.25"
+        assert (
+            orjson.dumps(fractions.Fraction(1, 3), option=AS_FLOAT)
+            == b"0.3333333333333333"
+        )
+        assert orjson.dumps(fractions.Fraction(-3), option=AS_FLOAT) == b"-3.0"
+
+    def test_fraction_64_bit(self):
+        """
+        A numerator or denominator in the 64-bit range
+        """
+        for val in (
+            fractions.Fraction(2**64 - 1, 2),
+            fractions.Fraction(-(2**63), 3),
+            fractions.Fraction(1, 2**64 - 1),
+        ):
+            assert orjson.dumps(val, option=OPTION) == (
+                f"[{val.numerator},{val.denominator}]".encode()
+            )
+
+    def test_fraction_exceeds_64_bit(self):
+        """
+        A numerator or denominator exceeding 64 bits raises as an int does,
+        and OPT_FRACTION_AS_FLOAT is correctly rounded
+        """
+        for val in (
+            fractions.Fraction(2**64 + 1, 3),
+            fractions.Fraction(-(2**100), 7),
+            fractions.Fraction(1, 2**64 + 1),
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(val, option=OPTION)
+            assert str(exc_info.value) == "Integer exceeds 64-bit range"
+            assert orjson.loads(orjson.dumps(val, option=AS_FLOAT)) == float(val)
+
+    def test_fraction_exceeds_64_bit_path(self):
+        """
+        The path of an error locates the numerator or denominator
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": fractions.Fraction(1, 2**64 + 1)}, option=OPTION)
+        assert exc_info.value.path == ["a", 1]
+
+    def test_fraction_as_float_overflow(self):
+        """
+        A Fraction too large for a float raises with the OverflowError as the
+        cause
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([fractions.Fraction(10**400, 3)], option=AS_FLOAT)
+        assert (
+            str(exc_info.value) == "converting a fraction raised an exception: Fraction"
+        )
+        assert isinstance(exc_info.value.__cause__, OverflowError)
+        assert exc_info.value.path == [0]
+
+    def test_fraction_strict_integer(self):
+        """
+        OPT_STRICT_INTEGER applies to the numerator and denominator
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                fractions.Fraction(2**60, 3), option=OPTION | orjson.OPT_STRICT_INTEGER
+            )
+
+    def test_fraction_nested(self):
+        """
+        A Fraction in a list and dict, with OPT_INDENT_2
+        """
+        val = {"a": [fractions.Fraction(1, 2)], "b": fractions.Fraction(3, 4)}
+        assert orjson.dumps(val, option=OPTION) == b'{"a":[[1,2]],"b":[3,4]}'
+        assert orjson.dumps(val, option=AS_FLOAT) == b'{"a":[0.5],"b":0.75}'
+        assert (
+            orjson.dumps(
+                [fractions.Fraction(1, 2)], option=OPTION | orjson.OPT_INDENT_2
+            )
+            == b"[\n  [\n    1,\n    2\n  ]\n]"
+        )
+
+    def test_fraction_subclass(self):
+        """
+        OPT_SERIALIZE_FRACTIONS does not apply to a subclass
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SubFraction(1, 2), option=OPTION)
+        assert orjson.dumps(SubFraction(1, 2), option=OPTION, default=str) == b'"1/2"'
+
+    def test_fraction_dict_key(self):
+        """
+        A Fraction is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {fractions.Fraction(1, 2): 1}, option=OPTION | orjson.OPT_NON_STR_KEYS
+            )
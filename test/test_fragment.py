@@ -41,6 +41,13 @@ class TestFragment:
     def test_fragment_fragment_str_empty(self):
         assert orjson.dumps(orjson.Fragment("")) == b""
 
+    def test_fragment_fragment_bytes_empty(self):
+        assert orjson.dumps(orjson.Fragment(b"")) == b""
+
+    def test_fragment_fragment_empty_list(self):
+        assert orjson.dumps([orjson.Fragment(""), 1]) == b"[,1]"
+        assert orjson.dumps([orjson.Fragment(b""), 1]) == b"[,1]"
+
     def test_fragment_fragment_str_str(self):
         assert orjson.dumps(orjson.Fragment('"str"')) == b'"str"'
 
@@ -81,6 +88,78 @@ class TestFragment:
             orjson.dumps(orjson.Fragment(b"{}", contents=b"{}"))  # type: ignore
 
 
+class Cached:
+    def __init__(self, key):
+        self.key = key
+
+
+def nested_list(depth, inner):
+    for _ in range(depth):
+        inner = [inner]
+    return inner
+
+
+class TestFragmentDefault:
+    def test_fragment_default(self):
+        """
+        A Fragment returned by default is written unchanged
+        """
+        cache = {"a": b'{"b": [1, 2]}'}
+
+        def default(obj):
+            if isinstance(obj, Cached):
+                return orjson.Fragment(cache[obj.key])
+            raise TypeError
+
+        assert (
+            orjson.dumps({"key": Cached("a")}, default=default)
+            == b'{"key":{"b": [1, 2]}}'
+        )
+        assert (
+            orjson.dumps([Cached("a")], default=lambda obj: cache[obj.key].decode())
+            == b'["{\\"b\\": [1, 2]}"]'
+        )
+
+    def test_fragment_default_options(self):
+        """
+        A Fragment returned by default or a type handler is not reformatted
+        """
+        val = [Cached("a"), 1]
+        assert (
+            orjson.dumps(
+                val,
+                default=lambda obj: orjson.Fragment('{"a":1}'),
+                option=orjson.OPT_INDENT_2 | orjson.OPT_DEFAULT_FIRST,
+            )
+            == b'[\n  {"a":1},\n  1\n]'
+        )
+        encoder = orjson.Encoder(
+            type_handlers={Cached: lambda obj: orjson.Fragment(b"[ 1 ]")}
+        )
+        assert encoder.encode(val) == b"[[ 1 ],1]"
+
+    def test_fragment_default_leaf(self):
+        """
+        A Fragment returned by default does not count toward the recursion
+        limit however deeply nested its contents are
+        """
+        contents = b"[[[]]]"
+        assert (
+            orjson.dumps(
+                nested_list(254, Cached("a")),
+                default=lambda obj: orjson.Fragment(contents),
+            )
+            == b"[" * 254 + contents + b"]" * 254
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nested_list(254, orjson.loads(contents)))
+        contents = b"[" * 1024 + b"]" * 1024
+        assert (
+            orjson.dumps(Cached("a"), default=lambda obj: orjson.Fragment(contents))
+            == contents
+        )
+
+
 @pytest.mark.skipif(pd is None, reason="pandas is not installed")
 class TestFragmentPandas:
     def test_fragment_pandas(self):
@@ -113,3 +113,72 @@ def test_concurrent_mutation_does_not_crash() -> None:
     )
     res = _run_python(code)
     assert res.returncode == 0, res.stderr
+
+
+@pytest.mark.skipif(not _IS_FREETHREADING, reason="requires free-threading build")
+def test_concurrent_bytearray_mutation_snapshot() -> None:
+    """A `bytearray` mutated while serializing is compressed from a stable snapshot."""
+
+    code: str = textwrap.dedent(
+        """
+        import base64
+        import gzip
+        import threading
+        import traceback
+
+        import orjson
+
+        size: int = 1 << 16
+        fills: tuple[bytes, ...] = (b"a" * size, b"b" * size, b"c" * (size // 2))
+        shared: bytearray = bytearray(fills[0])
+
+        errors: list[str] = []
+        stop = threading.Event()
+        start = threading.Barrier(3)
+
+        def mutate() -> None:
+            \"\"\"Overwrite and resize the shared bytearray.\"\"\"
+            try:
+                start.wait(timeout=5.0)
+                i: int = 0
+                while stop.is_set() is False:
+                    shared[:] = fills[i % len(fills)]
+                    i += 1
+            except Exception:
+                errors.append(traceback.format_exc())
+
+        def dump() -> None:
+            \"\"\"Serialize the bytearray and check it is one of the fills.\"\"\"
+            try:
+                start.wait(timeout=5.0)
+                for _ in range(500):
+                    value = orjson.loads(
+                        orjson.dumps(shared, option=orjson.OPT_SERIALIZE_BYTES_GZIP)
+                    )
+                    prefix = "data:application/gzip;base64,"
+                    data = gzip.decompress(base64.b64decode(value[len(prefix) :]))
+                    if data not in fills:
+                        raise AssertionError("torn bytearray snapshot")
+                stop.set()
+            except Exception:
+                errors.append(traceback.format_exc())
+                stop.set()
+
+        t1 = threading.Thread(target=mutate, daemon=True)
+        t2 = threading.Thread(target=dump, daemon=True)
+        t1.start()
+        t2.start()
+        start.wait(timeout=5.0)
+        t2.join(timeout=30.0)
+        if t2.is_alive() is True:
+            stop.set()
+            raise AssertionError("dump thread did not terminate")
+        stop.set()
+        t1.join(timeout=0.25)
+
+        if len(errors) > 0:
+            raise AssertionError("\\n".join(errors))
+        """
+    )
+    res = _run_python(code)
+    assert res.returncode == 0, res.stderr
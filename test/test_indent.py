@@ -4,6 +4,8 @@
 import datetime
 import json
 
+import pytest
+
 import orjson
 
 from .util import needs_data, read_fixture_obj
@@ -124,3 +126,67 @@ class TestIndentedOutput:
             indent=2,
             ensure_ascii=False,
         ).encode("utf-8")
+
+
+class TestIndentWidth:
+    def test_indent_equivalent(self):
+        """
+        indent=n is equivalent to indent=n in the standard library
+        """
+        obj = {"a": "b", "c": {"d": True}, "e": [1, 2], "f": [], "g": {}}
+        for indent in range(9):
+            assert orjson.dumps(obj, indent=indent) == json.dumps(
+                obj,
+                indent=indent,
+            ).encode("utf-8")
+
+    def test_indent_2_option(self):
+        obj = {"a": [1, {"b": None}]}
+        assert orjson.dumps(obj, indent=2) == orjson.dumps(
+            obj,
+            option=orjson.OPT_INDENT_2,
+        )
+
+    def test_indent_overrides_option(self):
+        obj = {"a": [1]}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_INDENT_2, indent=4)
+            == b'{\n    "a": [\n        1\n    ]\n}'
+        )
+
+    def test_indent_none(self):
+        obj = {"a": [1]}
+        assert orjson.dumps(obj, indent=None) == b'{"a":[1]}'
+        assert orjson.dumps(
+            obj,
+            option=orjson.OPT_INDENT_2,
+            indent=None,
+        ) == orjson.dumps(obj, option=orjson.OPT_INDENT_2)
+
+    def test_indent_options(self):
+        obj = {"b": 1, "a": 2}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE,
+                indent=4,
+            )
+            == b'{\n    "a": 2,\n    "b": 1\n}\n'
+        )
+
+    def test_indent_max_depth(self):
+        fixture = b"".join(
+            (b"".join(b"[" for _ in range(254)), b"".join(b"]" for _ in range(254))),
+        )
+        obj = orjson.loads(fixture)
+        assert orjson.loads(orjson.dumps(obj, indent=8)) == obj
+
+    def test_indent_out_of_range(self):
+        for indent in (-1, 9, 2**64):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([], indent=indent)
+
+    def test_indent_invalid_type(self):
+        for indent in ("2", 2.0, True, [2]):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([], indent=indent)  # type: ignore
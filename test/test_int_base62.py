@@ -0,0 +1,183 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+MAX_53 = 9007199254740991
+MAX_I64 = 9223372036854775807
+MIN_I64 = -9223372036854775808
+MAX_U64 = 18446744073709551615
+
+
+class TestIntBase62:
+    def test_int_base62_within_53_bits(self):
+        """
+        dumps() OPT_INT_BASE62 int within 53 bits is a number
+        """
+        for val in (0, 1, -1, MAX_53, -MAX_53):
+            assert orjson.dumps(val, option=orjson.OPT_INT_BASE62) == str(
+                val,
+            ).encode("ascii")
+
+    def test_int_base62_above_53_bits(self):
+        """
+        dumps() OPT_INT_BASE62 int above 53 bits
+        """
+        assert (
+            orjson.dumps(MAX_53 + 1, option=orjson.OPT_INT_BASE62) == b'"~fFgnDxSe8"'
+        )
+        assert (
+            orjson.dumps(-MAX_53 - 1, option=orjson.OPT_INT_BASE62)
+            == b'"~-fFgnDxSe8"'
+        )
+
+    def test_int_base62_limits(self):
+        """
+        dumps() OPT_INT_BASE62 64-bit limits
+        """
+        assert orjson.dumps(MAX_I64, option=orjson.OPT_INT_BASE62) == b'"~AzL8n0Y58m7"'
+        assert (
+            orjson.dumps(MIN_I64, option=orjson.OPT_INT_BASE62) == b'"~-AzL8n0Y58m8"'
+        )
+        assert orjson.dumps(MAX_U64, option=orjson.OPT_INT_BASE62) == b'"~LygHa16AHYF"'
+
+    def test_int_base62_out_of_range(self):
+        """
+        dumps() OPT_INT_BASE62 int outside 64 bits raises
+        """
+        for val in (MAX_U64 + 1, MIN_I64 - 1, 2**100):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(val, option=orjson.OPT_INT_BASE62)
+            assert str(exc_info.value) == "Integer exceeds 64-bit range"
+
+    def test_int_base62_strict_integer(self):
+        """
+        dumps() OPT_INT_BASE62 takes precedence over OPT_STRICT_INTEGER
+        """
+        assert (
+            orjson.dumps(
+                MAX_53 + 1,
+                option=orjson.OPT_INT_BASE62 | orjson.OPT_STRICT_INTEGER,
+            )
+            == b'"~fFgnDxSe8"'
+        )
+
+    def test_int_base62_nested(self):
+        """
+        dumps() OPT_INT_BASE62 in containers
+        """
+        assert (
+            orjson.dumps(
+                {"id": MAX_I64, "ids": [1, MAX_U64]},
+                option=orjson.OPT_INT_BASE62,
+            )
+            == b'{"id":"~AzL8n0Y58m7","ids":[1,"~LygHa16AHYF"]}'
+        )
+
+    def test_int_base62_non_str_keys(self):
+        """
+        dumps() OPT_INT_BASE62 does not affect int keys
+        """
+        assert (
+            orjson.dumps(
+                {MAX_I64: 1},
+                option=orjson.OPT_INT_BASE62 | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"9223372036854775807":1}'
+        )
+
+    def test_int_base62_roundtrip(self):
+        """
+        loads() OPT_INT_BASE62 roundtrips dumps()
+        """
+        for val in (
+            MAX_53 + 1,
+            -MAX_53 - 1,
+            2**60,
+            -(2**60),
+            MAX_I64,
+            MIN_I64,
+            MAX_I64 + 1,
+            MAX_U64,
+        ):
+            serialized = orjson.dumps([val], option=orjson.OPT_INT_BASE62)
+            assert orjson.loads(serialized, option=orjson.OPT_INT_BASE62) == [val]
+
+    def test_int_base62_loads_root_and_object(self):
+        """
+        loads() OPT_INT_BASE62 root and object values
+        """
+        assert orjson.loads(b'"~AzL8n0Y58m7"', option=orjson.OPT_INT_BASE62) == MAX_I64
+        assert orjson.loads(
+            b'{"id":"~-AzL8n0Y58m8"}',
+            option=orjson.OPT_INT_BASE62,
+        ) == {"id": MIN_I64}
+
+    def test_int_base62_loads_default(self):
+        """
+        loads() without OPT_INT_BASE62 is unchanged
+        """
+        assert orjson.loads(b'"~AzL8n0Y58m7"') == "~AzL8n0Y58m7"
+
+    def test_int_base62_loads_key(self):
+        """
+        loads() OPT_INT_BASE62 does not affect keys
+        """
+        assert orjson.loads(
+            b'{"~AzL8n0Y58m7":"~AzL8n0Y58m7"}',
+            option=orjson.OPT_INT_BASE62,
+        ) == {"~AzL8n0Y58m7": MAX_I64}
+
+    def test_int_base62_loads_not_encoding(self):
+        """
+        loads() OPT_INT_BASE62 str that is not an encoding is unchanged
+        """
+        for val in (
+            "",
+            "-",
+            "~",
+            "~-",
+            "hello",
+            "~hello",
+            "AzL8n0Y58m7",
+            "-AzL8n0Y58m8",
+            "-~AzL8n0Y58m8",
+            "~~AzL8n0Y58m7",
+            "~12345678",
+            "~0AzL8n0Y58m7",
+            "~-0fFgnDxSe8",
+            "~AzL8n0Y58m7a",
+            "~-AzL8n0Y58m9",
+            "~fFgnDxSe7",
+            "12345678",
+            "abcdefghi",
+            "0AzL8n0Y58m7",
+            "0000000000",
+            "-0fFgnDxSe8",
+            "fFgnDxSe8!",
+            "fFgnD-xSe8",
+            "AzL8n0Y58m7a",
+            "ZZZZZZZZZZZ",
+            "-AzL8n0Y58m9",
+            "fFgnDxSe7",
+            "-fFgnDxSe7",
+            "fFgnDxSe8 ",
+            "fFgnDxSé8",
+        ):
+            assert orjson.loads(
+                orjson.dumps([val]),
+                option=orjson.OPT_INT_BASE62,
+            ) == [val]
+
+    def test_int_base62_loads_identifier(self):
+        """
+        loads() OPT_INT_BASE62 str of base-62 digits without the prefix is
+        unchanged
+        """
+        for val in ("abcdefghij", "ProductID1", "userName12", "-Identifier"):
+            assert orjson.loads(
+                orjson.dumps({"id": val}),
+                option=orjson.OPT_INT_BASE62,
+            ) == {"id": val}
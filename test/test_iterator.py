@@ -0,0 +1,256 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import itertools
+
+import pytest
+
+import orjson
+
+
+class Countdown:
+    def __init__(self, start):
+        self.current = start
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if self.current <= 0:
+            raise StopIteration
+        self.current -= 1
+        return self.current + 1
+
+
+class TestSerializeIterators:
+    def test_generator(self):
+        assert (
+            orjson.dumps(
+                (x * 2 for x in range(4)), option=orjson.OPT_SERIALIZE_ITERATORS
+            )
+            == b"[0,2,4,6]"
+        )
+
+    def test_generator_function(self):
+        def gen():
+            yield 1
+            yield "a"
+            yield None
+            yield {"b": [True]}
+
+        assert (
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'[1,"a",null,{"b":[true]}]'
+        )
+
+    def test_iterator_builtin(self):
+        assert (
+            orjson.dumps(iter([1, 2]), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[1,2]"
+        )
+        assert (
+            orjson.dumps(map(str, range(3)), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'["0","1","2"]'
+        )
+        assert (
+            orjson.dumps(zip("ab", range(2)), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'[["a",0],["b",1]]'
+        )
+        assert (
+            orjson.dumps(iter({"a": 1, "b": 2}), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'["a","b"]'
+        )
+        assert (
+            orjson.dumps(reversed([1, 2]), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[2,1]"
+        )
+        assert (
+            orjson.dumps(
+                itertools.islice(itertools.count(), 3),
+                option=orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b"[0,1,2]"
+        )
+
+    def test_iterator_class(self):
+        assert (
+            orjson.dumps(Countdown(3), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[3,2,1]"
+        )
+
+    def test_iterator_empty(self):
+        assert orjson.dumps(iter([]), option=orjson.OPT_SERIALIZE_ITERATORS) == b"[]"
+        assert (
+            orjson.dumps((x for x in ()), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[]"
+        )
+
+    def test_iterator_empty_indent(self):
+        assert (
+            orjson.dumps(iter([]), option=orjson.OPT_SERIALIZE_ITERATORS, indent=2)
+            == b"[]"
+        )
+
+    def test_iterator_indent(self):
+        assert (
+            orjson.dumps(
+                iter([1, [2]]), option=orjson.OPT_SERIALIZE_ITERATORS, indent=2
+            )
+            == b"[\n  1,\n  [\n    2\n  ]\n]"
+        )
+
+    def test_iterator_consumed(self):
+        gen = (x for x in range(3))
+        assert orjson.dumps(gen, option=orjson.OPT_SERIALIZE_ITERATORS) == b"[0,1,2]"
+        assert orjson.dumps(gen, option=orjson.OPT_SERIALIZE_ITERATORS) == b"[]"
+
+    def test_iterator_nested(self):
+        assert (
+            orjson.dumps(
+                {"a": (iter(range(i)) for i in range(3)), "b": [iter("xy")]},
+                option=orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b'{"a":[[],[0],[0,1]],"b":[["x","y"]]}'
+        )
+
+    def test_iterator_dataclass(self):
+        @dataclasses.dataclass
+        class Response:
+            items: object
+
+        assert (
+            orjson.dumps(Response(iter([1, 2])), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'{"items":[1,2]}'
+        )
+
+    def test_iterator_not_iterable(self):
+        """
+        containers and other iterables are not affected
+        """
+        assert (
+            orjson.dumps([1, (2,), {"a": "b"}], option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'[1,[2],{"a":"b"}]'
+        )
+        for obj in (range(2), {1, 2}, frozenset()):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_str_bytes_dict(self):
+        """
+        str, bytes, and dict, which are iterable, are not serialized as an
+        array of their characters, bytes, or keys
+        """
+        assert orjson.dumps("abc", option=orjson.OPT_SERIALIZE_ITERATORS) == b'"abc"'
+        assert (
+            orjson.dumps({"a": "bc"}, option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b'{"a":"bc"}'
+        )
+        for obj in (b"abc", bytearray(b"abc"), memoryview(b"abc")):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS)
+        for obj in ({}.keys(), {}.values(), {}.items()):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_subclass_with_next(self):
+        """
+        A subclass of str, bytes, dict, list, or tuple that defines __next__()
+        is not consumed as an iterator with OPT_PASSTHROUGH_SUBCLASS
+        """
+
+        def subclass(base):
+            return type(f"Sub{base.__name__}", (base,), {"__next__": lambda self: 1})
+
+        option = orjson.OPT_SERIALIZE_ITERATORS | orjson.OPT_PASSTHROUGH_SUBCLASS
+        for base, args in (
+            (str, ("abc",)),
+            (bytes, (b"abc",)),
+            (dict, ({"a": 1},)),
+            (list, ([1],)),
+            (tuple, ((1,),)),
+        ):
+            obj = subclass(base)(*args)
+            assert (
+                orjson.dumps(obj, default=lambda _: "default", option=option)
+                == b'"default"'
+            )
+
+    def test_iterator_default(self):
+        def default(obj):
+            if isinstance(obj, set):
+                return sorted(obj)
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                iter([{2, 1}]), option=orjson.OPT_SERIALIZE_ITERATORS, default=default
+            )
+            == b"[[1,2]]"
+        )
+
+    def test_iterator_default_returns_iterator(self):
+        def default(obj):
+            if isinstance(obj, set):
+                return iter(sorted(obj))
+            raise TypeError
+
+        assert (
+            orjson.dumps({2, 1}, option=orjson.OPT_SERIALIZE_ITERATORS, default=default)
+            == b"[1,2]"
+        )
+
+    def test_iterator_exception(self):
+        def gen():
+            yield 1
+            raise ValueError("mid-iteration")
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert str(exc_info.value.__cause__) == "mid-iteration"
+
+    def test_iterator_exception_element(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(iter([1, object()]), option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_exception_releases(self):
+        """
+        the generator is closed after an error in a later element
+        """
+        closed = []
+
+        def gen():
+            try:
+                yield 1
+                yield object()
+                yield 2
+            finally:
+                closed.append(True)
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(gen(), option=orjson.OPT_SERIALIZE_ITERATORS)
+        assert closed == [True]
+
+    def test_iterator_recursion(self):
+        def nest(depth):
+            if depth:
+                yield nest(depth - 1)
+
+        assert (
+            orjson.dumps(nest(3), option=orjson.OPT_SERIALIZE_ITERATORS)
+            == b"[[[[]]]]"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(nest(1024), option=orjson.OPT_SERIALIZE_ITERATORS)
+
+    def test_iterator_dict_key(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {iter([]): 1},
+                option=orjson.OPT_SERIALIZE_ITERATORS | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_iterator_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(x for x in range(2))
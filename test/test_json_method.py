@@ -0,0 +1,134 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+
+import pytest
+
+import orjson
+
+
+class Custom:
+    def __init__(self, value):
+        self.value = value
+
+    def __json__(self):
+        return {"value": self.value}
+
+
+class Recursive:
+    def __json__(self):
+        return self
+
+
+class Raises:
+    def __json__(self):
+        raise ValueError("invalid")
+
+
+class Unsupported:
+    def __json__(self):
+        return {1, 2}
+
+
+class TestJsonMethod:
+    def test_json_method(self):
+        assert (
+            orjson.dumps(Custom(1), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+            == b'{"value":1}'
+        )
+
+    def test_json_method_nested(self):
+        assert (
+            orjson.dumps(
+                {"a": [Custom(Custom("b"))]},
+                option=orjson.OPT_SERIALIZE_JSON_METHOD,
+            )
+            == b'{"a":[{"value":{"value":"b"}}]}'
+        )
+
+    def test_json_method_tuple(self):
+        assert (
+            orjson.dumps((Custom(None),), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+            == b'[{"value":null}]'
+        )
+
+    def test_json_method_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Custom(1))
+
+    def test_json_method_disabled_default(self):
+        def default(obj):
+            if isinstance(obj, Custom):
+                return "default"
+            raise TypeError
+
+        assert orjson.dumps(Custom(1), default=default) == b'"default"'
+
+    def test_json_method_before_default(self):
+        def default(obj):
+            raise AssertionError
+
+        assert (
+            orjson.dumps(
+                Custom(1),
+                default=default,
+                option=orjson.OPT_SERIALIZE_JSON_METHOD,
+            )
+            == b'{"value":1}'
+        )
+
+    def test_json_method_returns_default_type(self):
+        def default(obj):
+            if isinstance(obj, set):
+                return sorted(obj)
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                Unsupported(),
+                default=default,
+                option=orjson.OPT_SERIALIZE_JSON_METHOD,
+            )
+            == b"[1,2]"
+        )
+
+    def test_json_method_returns_unsupported(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Unsupported(), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+
+    def test_json_method_recursive(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Recursive(), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+        assert str(exc_info.value) == "default serializer exceeds recursion limit"
+
+    def test_json_method_raises(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Raises(), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_json_method_instance_attribute(self):
+        """
+        __json__ is looked up on the type
+        """
+        obj = type("Plain", (), {})()
+        obj.__json__ = lambda: "instance"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_JSON_METHOD)
+
+    def test_json_method_supported_type(self):
+        """
+        __json__ is not used for types orjson serializes natively
+        """
+
+        @dataclasses.dataclass
+        class Data:
+            a: int
+
+            def __json__(self):
+                return "json"
+
+        assert (
+            orjson.dumps(Data(1), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+            == b'{"a":1}'
+        )
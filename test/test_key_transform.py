@@ -0,0 +1,269 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+import dataclasses
+import types
+
+import pytest
+
+import orjson
+
+
+def camel(key):
+    first, *rest = key.split("_")
+    return first + "".join(word.capitalize() for word in rest)
+
+
+class TestKeyTransform:
+    def test_key_transform(self):
+        """
+        key_transform is called with each dict key
+        """
+        assert (
+            orjson.dumps({"user_id": 1, "user_name": "a"}, key_transform=camel)
+            == b'{"userId":1,"userName":"a"}'
+        )
+
+    def test_key_transform_nested(self):
+        """
+        key_transform applies to dicts in a dict, list, and tuple
+        """
+        obj = {"a_b": [{"c_d": ({"e_f": 1},)}], "g_h": {"i_j": {}}}
+        assert (
+            orjson.dumps(obj, key_transform=camel)
+            == b'{"aB":[{"cD":[{"eF":1}]}],"gH":{"iJ":{}}}'
+        )
+
+    def test_key_transform_cache(self):
+        """
+        key_transform is called once per distinct key in a call to dumps()
+        """
+        calls = []
+
+        def transform(key):
+            calls.append(key)
+            return key.upper()
+
+        obj = [{"a": 1, "b": 2}, {"a": 3}, {"b": {"a": 4}}]
+        assert (
+            orjson.dumps(obj, key_transform=transform)
+            == b'[{"A":1,"B":2},{"A":3},{"B":{"A":4}}]'
+        )
+        assert sorted(calls) == ["a", "b"]
+        orjson.dumps(obj, key_transform=transform)
+        assert sorted(calls) == ["a", "a", "b", "b"]
+
+    def test_key_transform_dict_subclass(self):
+        """
+        key_transform applies to a dict subclass
+        """
+        obj = collections.OrderedDict(a_b=1)
+        assert orjson.dumps(obj, key_transform=camel) == b'{"aB":1}'
+
+    def test_key_transform_mapping(self):
+        """
+        key_transform applies to a mapping with OPT_SERIALIZE_MAPPING
+        """
+        assert (
+            orjson.dumps(
+                types.MappingProxyType({"a_b": 1}),
+                option=orjson.OPT_SERIALIZE_MAPPING,
+                key_transform=camel,
+            )
+            == b'{"aB":1}'
+        )
+
+    def test_key_transform_dataclass(self):
+        """
+        key_transform does not apply to the fields of a dataclass
+        """
+
+        @dataclasses.dataclass
+        class Value:
+            a_b: dict
+
+        assert (
+            orjson.dumps(Value({"c_d": 1}), key_transform=camel)
+            == b'{"a_b":{"cD":1}}'
+        )
+
+    def test_key_transform_non_str_keys(self):
+        """
+        A key that is not a str is transformed after its conversion with
+        OPT_NON_STR_KEYS
+        """
+        calls = []
+
+        def transform(key):
+            calls.append(key)
+            return f"k{key}"
+
+        assert (
+            orjson.dumps(
+                {1: 1, None: 2, "a": 3},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_transform=transform,
+            )
+            == b'{"k1":1,"knull":2,"ka":3}'
+        )
+        assert calls == ["1", "null", "a"]
+
+    def test_key_transform_non_str_keys_not_enabled(self):
+        """
+        A key that is not a str raises without OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({1: 1}, key_transform=camel)
+        assert str(exc_info.value) == "Dict key must be str"
+
+    def test_key_transform_sort_keys(self):
+        """
+        OPT_SORT_KEYS sorts the transformed keys
+        """
+        assert (
+            orjson.dumps(
+                {"a": 1, "b": 2, "c": 3},
+                option=orjson.OPT_SORT_KEYS,
+                key_transform={"a": "z", "b": "y", "c": "x"}.get,
+            )
+            == b'{"x":3,"y":2,"z":1}'
+        )
+
+    def test_key_transform_unique_keys(self):
+        """
+        OPT_UNIQUE_KEYS raises if transformed keys collide
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a_b": 1, "aB": 2},
+                option=orjson.OPT_UNIQUE_KEYS,
+                key_transform=camel,
+            )
+        assert str(exc_info.value) == "Dict key is duplicated after conversion to str"
+
+    def test_key_transform_raises(self):
+        """
+        An exception from key_transform is raised unchanged
+        """
+
+        def transform(key):
+            raise ValueError(key)
+
+        with pytest.raises(ValueError) as exc_info:
+            orjson.dumps({"a": 1}, key_transform=transform)
+        assert type(exc_info.value) is ValueError
+        assert exc_info.value.args == ("a",)
+        assert exc_info.value.__cause__ is None
+        assert not hasattr(exc_info.value, "path")
+
+    def test_key_transform_raises_nested(self):
+        """
+        An exception from key_transform in a nested dict is raised unchanged
+        """
+
+        def transform(key):
+            if key == "b":
+                raise KeyError(key)
+            return key
+
+        with pytest.raises(KeyError) as exc_info:
+            orjson.dumps({"a": [{"b": 1}]}, key_transform=transform)
+        assert exc_info.value.args == ("b",)
+        with pytest.raises(KeyError):
+            orjson.dumps(
+                {"a": [{"b": 1}]},
+                key_transform=transform,
+                validate_only=True,
+            )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": {1}}, key_transform=transform)
+        assert exc_info.value.path == ["a"]
+
+    def test_key_transform_raises_aborts(self):
+        """
+        An exception from key_transform aborts serialization
+        """
+        calls = []
+
+        def transform(key):
+            calls.append(key)
+            if key == "b":
+                raise ValueError(key)
+            return key
+
+        with pytest.raises(ValueError):
+            orjson.dumps({"a": 1, "b": 2, "c": 3}, key_transform=transform)
+        assert calls == ["a", "b"]
+
+    def test_key_transform_not_str(self):
+        """
+        key_transform must return str
+        """
+        for val in (1, None, b"a"):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps({"a": 1}, key_transform=lambda _, val=val: val)
+            assert str(exc_info.value) == "key_transform must return str"
+
+    def test_key_transform_error_path(self):
+        """
+        The path of an error includes the transformed key
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a_b": [{"c_d": {1}}]}, key_transform=camel)
+        assert exc_info.value.path == ["aB", 0, "cD"]
+
+    def test_key_transform_empty(self):
+        """
+        key_transform is not called for an empty dict
+        """
+
+        def transform(key):
+            raise ValueError(key)
+
+        assert orjson.dumps({}, key_transform=transform) == b"{}"
+
+    def test_key_transform_mutates(self):
+        """
+        key_transform changing the dict does not change what is serialized
+        """
+        obj = {"a": 1, "b": 2}
+
+        def transform(key):
+            obj.clear()
+            return key
+
+        assert orjson.dumps(obj, key_transform=transform) == b'{"a":1,"b":2}'
+
+    def test_key_transform_none(self):
+        """
+        key_transform=None is the same as not specifying it
+        """
+        assert orjson.dumps({"a_b": 1}, key_transform=None) == b'{"a_b":1}'
+
+    def test_key_transform_invalid(self):
+        """
+        key_transform must be callable
+        """
+        for val in (1, "a", {}):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps({}, key_transform=val)
+            assert str(exc_info.value) == "key_transform must be callable"
+
+    def test_key_transform_encoder(self):
+        """
+        orjson.Encoder accepts key_transform
+        """
+        encoder = orjson.Encoder(key_transform=camel)
+        assert encoder.encode({"a_b": 1}) == b'{"aB":1}'
+        assert encoder.encode({"a_b": 2}) == b'{"aB":2}'
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(key_transform=1)
+
+    def test_key_transform_dumps_into_buffer(self):
+        """
+        dumps_into_buffer() accepts key_transform
+        """
+        buf = bytearray(16)
+        assert orjson.dumps_into_buffer({"a_b": 1}, buf, key_transform=camel) == 8
+        assert bytes(buf[:8]) == b'{"aB":1}'
@@ -0,0 +1,179 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+
+import pytest
+
+import orjson
+
+
+class SubList(list):
+    pass
+
+
+class TestLengthTagged:
+    def test_length_tagged_list(self):
+        """
+        OPT_LENGTH_TAGGED serializes a list with its length
+        """
+        assert (
+            orjson.dumps([1, "a", None], option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":3,"items":[1,"a",null]}'
+        )
+
+    def test_length_tagged_dict(self):
+        """
+        OPT_LENGTH_TAGGED serializes a dict with its length
+        """
+        assert (
+            orjson.dumps({"a": 1, "b": 2}, option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":2,"items":{"a":1,"b":2}}'
+        )
+
+    def test_length_tagged_tuple(self):
+        """
+        OPT_LENGTH_TAGGED serializes a tuple with its length
+        """
+        assert (
+            orjson.dumps((1, 2), option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":2,"items":[1,2]}'
+        )
+
+    def test_length_tagged_empty(self):
+        """
+        An empty list, tuple, and dict have a length of 0
+        """
+        assert (
+            orjson.dumps([], option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":0,"items":[]}'
+        )
+        assert (
+            orjson.dumps((), option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":0,"items":[]}'
+        )
+        assert (
+            orjson.dumps({}, option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":0,"items":{}}'
+        )
+
+    def test_length_tagged_nested(self):
+        """
+        Nested containers are serialized with their length
+        """
+        assert orjson.dumps(
+            {"a": [[1], {"b": ()}]}, option=orjson.OPT_LENGTH_TAGGED
+        ) == (
+            b'{"len":1,"items":{"a":{"len":2,"items":['
+            b'{"len":1,"items":[1]},'
+            b'{"len":1,"items":{"b":{"len":0,"items":[]}}}]}}}'
+        )
+
+    def test_length_tagged_sort_keys(self):
+        """
+        OPT_SORT_KEYS sorts the items of a dict but not len and items
+        """
+        assert (
+            orjson.dumps(
+                {"b": 1, "a": 2}, option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_SORT_KEYS
+            )
+            == b'{"len":2,"items":{"a":2,"b":1}}'
+        )
+
+    def test_length_tagged_indent(self):
+        """
+        OPT_LENGTH_TAGGED with OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps([1], option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_INDENT_2)
+            == b'{\n  "len": 1,\n  "items": [\n    1\n  ]\n}'
+        )
+
+    def test_length_tagged_subclass(self):
+        """
+        A subclass of list or dict is serialized with its length unless
+        OPT_PASSTHROUGH_SUBCLASS
+        """
+        obj = [SubList([1]), collections.OrderedDict(a=1)]
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_LENGTH_TAGGED)
+            == b'{"len":2,"items":[{"len":1,"items":[1]},{"len":1,"items":{"a":1}}]}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_PASSTHROUGH_SUBCLASS,
+                default=lambda _: "x",
+            )
+            == b'{"len":2,"items":["x","x"]}'
+        )
+
+    def test_length_tagged_default(self):
+        """
+        A container returned by default is serialized with its length
+        """
+        assert (
+            orjson.dumps(
+                object(), option=orjson.OPT_LENGTH_TAGGED, default=lambda _: [1, 2]
+            )
+            == b'{"len":2,"items":[1,2]}'
+        )
+
+    def test_length_tagged_error_path(self):
+        """
+        The path of an error does not include len or items
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": [1, {2}]}, option=orjson.OPT_LENGTH_TAGGED)
+        assert exc_info.value.path == ["a", 1]
+
+    def test_length_tagged_dict_key(self):
+        """
+        A tuple dict key is joined with key_delimiter
+        """
+        assert (
+            orjson.dumps(
+                {(1, "a"): 1},
+                option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_NON_STR_KEYS,
+                key_delimiter="_",
+            )
+            == b'{"len":1,"items":{"1_a":1}}'
+        )
+
+    def test_length_tagged_not_enabled(self):
+        """
+        Containers are not serialized with their length without
+        OPT_LENGTH_TAGGED
+        """
+        assert orjson.dumps({"a": [1, ()]}) == b'{"a":[1,[]]}'
+
+    def test_length_tagged_deduplicate_arrays(self):
+        """
+        OPT_LENGTH_TAGGED is incompatible with OPT_DEDUPLICATE_ARRAYS
+        """
+        with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+            orjson.dumps(
+                [1, 1, 2],
+                option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_DEDUPLICATE_ARRAYS,
+            )
+        with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+            orjson.Encoder(
+                option=orjson.OPT_LENGTH_TAGGED | orjson.OPT_DEDUPLICATE_ARRAYS
+            )
+
+    def test_length_tagged_omit_none(self):
+        """
+        The length of a dict does not count members omitted by OPT_OMIT_NONE
+        """
+        option = orjson.OPT_LENGTH_TAGGED | orjson.OPT_OMIT_NONE
+        assert (
+            orjson.dumps({"a": None, "b": 1}, option=option)
+            == b'{"len":1,"items":{"b":1}}'
+        )
+        assert (
+            orjson.dumps({"a": None}, option=option) == b'{"len":0,"items":{}}'
+        )
+        assert orjson.dumps([None, {"a": [None]}], option=option) == (
+            b'{"len":2,"items":[null,'
+            b'{"len":1,"items":{"a":{"len":1,"items":[null]}}}]}'
+        )
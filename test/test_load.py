@@ -0,0 +1,162 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import array
+import io
+import mmap
+import tempfile
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+
+class ChunkedReader:
+    """
+    file-like object that returns at most a few bytes per read()
+    """
+
+    def __init__(self, data, chunk=3):
+        self.data = data
+        self.pos = 0
+        self.chunk = chunk
+
+    def read(self, size=-1):
+        end = self.pos + min(size, self.chunk)
+        ret = self.data[self.pos : end]
+        self.pos = end
+        return ret
+
+
+class TestLoad:
+    def test_load_bytesio(self):
+        assert orjson.load(io.BytesIO(b'{"a": [1, 2.5, null, true]}')) == {
+            "a": [1, 2.5, None, True],
+        }
+
+    def test_load_stringio(self):
+        assert orjson.load(io.StringIO('["é", "\U0001f408"]')) == [
+            "é",
+            "\U0001f408",
+        ]
+
+    def test_load_file(self):
+        with tempfile.TemporaryFile() as fp:
+            fp.write(b'{"a": "b"}')
+            fp.seek(0)
+            assert orjson.load(fp) == {"a": "b"}
+
+    def test_load_text_file(self):
+        with tempfile.TemporaryFile("w+", encoding="utf-8") as fp:
+            fp.write('{"a": "é"}')
+            fp.seek(0)
+            assert orjson.load(fp) == {"a": "é"}
+
+    def test_load_chunked(self):
+        """
+        multibyte characters split across chunks are reassembled
+        """
+        doc = '{"key": ["\U0001f408", 1.5]}'.encode("utf-8")
+        assert orjson.load(ChunkedReader(doc)) == orjson.loads(doc)
+
+    def test_load_chunked_str(self):
+        doc = '{"key": ["\U0001f408", 1.5]}'
+        assert orjson.load(ChunkedReader(doc)) == orjson.loads(doc)
+
+    def test_load_large(self):
+        obj = [{"id": i, "name": str(i) * 10} for i in range(10000)]
+        doc = orjson.dumps(obj)
+        assert len(doc) > 64 * 1024 * 2
+        assert orjson.load(io.BytesIO(doc)) == obj
+
+    def test_load_input_types(self):
+        doc = b'{"a": 1}'
+        for val in (doc, bytearray(doc), memoryview(doc), doc.decode("utf-8")):
+            assert orjson.load(val) == {"a": 1}
+
+    def test_load_buffer_protocol(self):
+        assert orjson.load(array.array("b", b"[1, 2]")) == [1, 2]
+
+    def test_load_mmap(self):
+        with tempfile.TemporaryFile() as fp:
+            fp.write(b'{"a": [1, 2]}')
+            fp.flush()
+            with mmap.mmap(fp.fileno(), 0, access=mmap.ACCESS_READ) as buf:
+                assert orjson.load(buf) == {"a": [1, 2]}
+
+    def test_load_option(self):
+        assert orjson.load(
+            io.BytesIO(b"[1.50]"),
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == ["1.50"]
+        assert orjson.load(
+            io.BytesIO(b"[1.50]"),
+            orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == ["1.50"]
+
+    def test_load_empty(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(io.BytesIO(b""))
+
+    def test_load_invalid_position(self):
+        """
+        error positions are relative to the whole document
+        """
+        doc = b'{"a": [1, 2, 3], "b": tru}'
+        with pytest.raises(orjson.JSONDecodeError) as load_exc:
+            orjson.load(ChunkedReader(doc))
+        with pytest.raises(orjson.JSONDecodeError) as loads_exc:
+            orjson.loads(doc)
+        assert load_exc.value.pos == loads_exc.value.pos
+        assert load_exc.value.lineno == loads_exc.value.lineno
+        assert load_exc.value.colno == loads_exc.value.colno
+        assert load_exc.value.pos > 3
+
+    def test_load_invalid_utf8(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(io.BytesIO(b'["\xff"]'))
+
+    def test_load_surrogate(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(io.StringIO('["\ud800"]'))
+
+    def test_load_read_raises(self):
+        class Reader:
+            def read(self, size=-1):
+                raise OSError("read")
+
+        with pytest.raises(OSError):
+            orjson.load(Reader())
+
+    def test_load_read_invalid_type(self):
+        class Reader:
+            def read(self, size=-1):
+                return [1]
+
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(Reader())
+
+    def test_load_invalid_type(self):
+        for val in (None, 1, [b"[]"], object()):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.load(val)
+
+    def test_load_arguments(self):
+        with pytest.raises(TypeError):
+            orjson.load()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.load(io.BytesIO(b"[]"), fp=1)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.load(io.BytesIO(b"[]"), 0, option=0)  # type: ignore
+        for args in ((None, None), (None, None, None)):
+            with pytest.raises(
+                TypeError, match="takes at most 2 positional arguments"
+            ):
+                orjson.load(io.BytesIO(b"1"), *args)  # type: ignore
+
+    @needs_data
+    def test_load_fixture(self):
+        doc = read_fixture_bytes("twitter.json.xz")
+        assert orjson.load(ChunkedReader(doc, 4096)) == orjson.loads(doc)
@@ -0,0 +1,374 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+import gc
+import types
+import weakref
+
+import pytest
+
+import orjson
+
+
+class Mapping:
+    def __init__(self, data):
+        self.data = data
+
+    def __getitem__(self, key):
+        return self.data[key]
+
+    def __len__(self):
+        return len(self.data)
+
+    def keys(self):
+        return self.data.keys()
+
+
+class RaisingMapping(Mapping):
+    def __getitem__(self, key):
+        raise KeyError(key)
+
+
+class Value:
+    def __init__(self, value):
+        self.value = value
+
+
+def value_default(obj):
+    if isinstance(obj, Value):
+        return obj.value
+    raise TypeError
+
+
+class TestMapping:
+    def test_mapping_proxy(self):
+        """
+        OPT_SERIALIZE_MAPPING serializes types.MappingProxyType
+        """
+        assert (
+            orjson.dumps(
+                types.MappingProxyType({"a": 1, "b": [2]}),
+                option=orjson.OPT_SERIALIZE_MAPPING,
+            )
+            == b'{"a":1,"b":[2]}'
+        )
+
+    def test_mapping_proxy_class_dict(self):
+        """
+        OPT_SERIALIZE_MAPPING serializes the __dict__ of a class
+        """
+
+        class Custom:
+            a = 1
+            b = "b"
+
+        obj = orjson.loads(
+            orjson.dumps(
+                Custom.__dict__, option=orjson.OPT_SERIALIZE_MAPPING, default=str
+            )
+        )
+        assert obj["a"] == 1
+        assert obj["b"] == "b"
+        assert list(obj) == list(Custom.__dict__)
+
+    def test_mapping_proxy_not_enabled(self):
+        """
+        types.MappingProxyType is not serialized without OPT_SERIALIZE_MAPPING
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(types.MappingProxyType({}))
+
+    def test_mapping_proxy_mapping(self):
+        """
+        types.MappingProxyType of a mapping that is not a dict
+        """
+        obj = types.MappingProxyType(collections.OrderedDict(a=1))
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING) == b'{"a":1}'
+        obj = types.MappingProxyType(types.MappingProxyType({"a": 1}))
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING) == b'{"a":1}'
+
+    def test_mapping(self):
+        """
+        OPT_SERIALIZE_MAPPING serializes an object with keys() and __getitem__
+        """
+        assert (
+            orjson.dumps(
+                Mapping({"b": 1, "a": None}), option=orjson.OPT_SERIALIZE_MAPPING
+            )
+            == b'{"b":1,"a":null}'
+        )
+
+    def test_mapping_empty(self):
+        """
+        An empty mapping
+        """
+        assert orjson.dumps(Mapping({}), option=orjson.OPT_SERIALIZE_MAPPING) == b"{}"
+        assert (
+            orjson.dumps(
+                types.MappingProxyType({}), option=orjson.OPT_SERIALIZE_MAPPING
+            )
+            == b"{}"
+        )
+
+    def test_mapping_nested(self):
+        """
+        A mapping in a mapping, list, and dict
+        """
+        obj = Mapping({"a": types.MappingProxyType({"b": Mapping({"c": 1})})})
+        assert (
+            orjson.dumps([obj, {"d": obj}], option=orjson.OPT_SERIALIZE_MAPPING)
+            == b'[{"a":{"b":{"c":1}}},{"d":{"a":{"b":{"c":1}}}}]'
+        )
+
+    def test_mapping_sort_keys(self):
+        """
+        OPT_SORT_KEYS sorts the keys of a mapping
+        """
+        assert (
+            orjson.dumps(
+                Mapping({"b": 1, "a": 2}),
+                option=orjson.OPT_SERIALIZE_MAPPING | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"b":1}'
+        )
+        assert (
+            orjson.dumps(
+                types.MappingProxyType({"b": 1, "a": 2}),
+                option=orjson.OPT_SERIALIZE_MAPPING | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"b":1}'
+        )
+
+    def test_mapping_non_str_keys(self):
+        """
+        OPT_NON_STR_KEYS applies to the keys of a mapping
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Mapping({1: 1}), option=orjson.OPT_SERIALIZE_MAPPING)
+        assert (
+            orjson.dumps(
+                Mapping({1: 1}),
+                option=orjson.OPT_SERIALIZE_MAPPING | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":1}'
+        )
+
+    def test_mapping_getitem_raises(self):
+        """
+        An exception from __getitem__ is the __cause__ of JSONEncodeError
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(RaisingMapping({"a": 1}), option=orjson.OPT_SERIALIZE_MAPPING)
+        assert isinstance(exc_info.value.__cause__, KeyError)
+
+    def test_mapping_default(self):
+        """
+        A value orjson does not serialize is passed to default
+        """
+        assert (
+            orjson.dumps(
+                Mapping({"a": object()}),
+                option=orjson.OPT_SERIALIZE_MAPPING,
+                default=lambda _: "x",
+            )
+            == b'{"a":"x"}'
+        )
+
+    def test_mapping_error_path(self):
+        """
+        An error in a value includes its key in the path
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([Mapping({"a": {1}})], option=orjson.OPT_SERIALIZE_MAPPING)
+        assert exc_info.value.path == [0, "a"]
+
+    def test_mapping_recursion(self):
+        """
+        A mapping that contains itself raises JSONEncodeError
+        """
+        data = {}
+        obj = Mapping(data)
+        data["a"] = obj
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING)
+
+    def test_mapping_dict_key(self):
+        """
+        A mapping is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {types.MappingProxyType({}): 1},
+                option=orjson.OPT_SERIALIZE_MAPPING | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_mapping_not_mapping(self):
+        """
+        OPT_SERIALIZE_MAPPING does not change serializing other types
+        """
+
+        class GetItem:
+            def __getitem__(self, key):
+                return key
+
+        assert (
+            orjson.dumps(
+                [(1,), "a", collections.OrderedDict(a=1)],
+                option=orjson.OPT_SERIALIZE_MAPPING,
+            )
+            == b'[[1],"a",{"a":1}]'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(GetItem(), option=orjson.OPT_SERIALIZE_MAPPING)
+
+    def test_mapping_passthrough_subclass(self):
+        """
+        OPT_SERIALIZE_MAPPING does not serialize a dict subclass with
+        OPT_PASSTHROUGH_SUBCLASS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                collections.OrderedDict(a=1),
+                option=orjson.OPT_SERIALIZE_MAPPING | orjson.OPT_PASSTHROUGH_SUBCLASS,
+            )
+
+
+class TestWeakValueDictionary:
+    def test_weak_value_dictionary(self):
+        """
+        OPT_SERIALIZE_MAPPING serializes a weakref.WeakValueDictionary
+        """
+        a = Value(1)
+        b = Value(2)
+        obj = weakref.WeakValueDictionary({"a": a, "b": b})
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_MAPPING, default=value_default
+            )
+            == b'{"a":1,"b":2}'
+        )
+
+    def test_weak_value_dictionary_collected(self):
+        """
+        An entry whose value was garbage collected is skipped
+        """
+        a = Value(1)
+        b = Value(2)
+        c = Value(3)
+        obj = weakref.WeakValueDictionary({"a": a, "b": b, "c": c})
+        del b
+        gc.collect()
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_MAPPING, default=value_default
+            )
+            == b'{"a":1,"c":3}'
+        )
+        del a, c
+        gc.collect()
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING) == b"{}"
+
+    def test_weak_value_dictionary_dead_ref(self):
+        """
+        An entry whose reference is dead but not yet removed is skipped
+        """
+        a = Value(1)
+        b = Value(2)
+        obj = weakref.WeakValueDictionary({"a": a, "b": b})
+        # removal is deferred while the dictionary is being iterated
+        items = iter(obj.items())
+        next(items)
+        del b
+        gc.collect()
+        assert len(obj.data) == 2
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_MAPPING, default=value_default
+            )
+            == b'{"a":1}'
+        )
+        del items
+
+    def test_weak_value_dictionary_collected_while_serializing(self):
+        """
+        A value whose last reference is dropped while serializing is
+        serialized
+        """
+        values = [Value(1), Value(2)]
+        obj = weakref.WeakValueDictionary({"a": values[0], "b": values[1]})
+
+        def default(value):
+            values.clear()
+            gc.collect()
+            return value.value
+
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING, default=default)
+            == b'{"a":1,"b":2}'
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_MAPPING) == b"{}"
+
+    def test_weak_value_dictionary_options(self):
+        """
+        OPT_SORT_KEYS and OPT_NON_STR_KEYS apply to a WeakValueDictionary
+        """
+        a = Value(1)
+        b = Value(2)
+        obj = weakref.WeakValueDictionary({2: b, 1: a})
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_MAPPING, default=value_default
+            )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_MAPPING
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_SORT_KEYS,
+                default=value_default,
+            )
+            == b'{"1":1,"2":2}'
+        )
+
+    def test_weak_value_dictionary_nested(self):
+        """
+        A WeakValueDictionary in a list and dict
+        """
+        a = Value(1)
+        obj = weakref.WeakValueDictionary({"a": a})
+        assert (
+            orjson.dumps(
+                [obj, {"b": obj}],
+                option=orjson.OPT_SERIALIZE_MAPPING,
+                default=value_default,
+            )
+            == b'[{"a":1},{"b":{"a":1}}]'
+        )
+
+    def test_weak_value_dictionary_subclass(self):
+        """
+        A subclass of WeakValueDictionary is serialized as a mapping
+        """
+
+        class Subclass(weakref.WeakValueDictionary):
+            pass
+
+        a = Value(1)
+        b = Value(2)
+        obj = Subclass({"a": a, "b": b})
+        del b
+        gc.collect()
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_MAPPING, default=value_default
+            )
+            == b'{"a":1}'
+        )
+
+    def test_weak_value_dictionary_not_enabled(self):
+        """
+        A WeakValueDictionary is not serialized without OPT_SERIALIZE_MAPPING
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(weakref.WeakValueDictionary())
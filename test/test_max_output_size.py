@@ -0,0 +1,109 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+
+class TestMaxOutputSize:
+    def test_max_output_size(self):
+        """
+        max_output_size allows output of at most that many bytes
+        """
+        assert orjson.dumps([1, 2], max_output_size=5) == b"[1,2]"
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([1, 2], max_output_size=4)
+        assert str(exc_info.value) == "Output exceeds max_output_size"
+
+    def test_max_output_size_str(self):
+        """
+        max_output_size applies to a top-level str
+        """
+        assert orjson.dumps("a" * 4096, max_output_size=4098) == (
+            b'"' + b"a" * 4096 + b'"'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps("a" * 4096, max_output_size=4097)
+
+    def test_max_output_size_append_newline(self):
+        """
+        max_output_size includes the newline of OPT_APPEND_NEWLINE
+        """
+        assert (
+            orjson.dumps([], option=orjson.OPT_APPEND_NEWLINE, max_output_size=3)
+            == b"[]\n"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([], option=orjson.OPT_APPEND_NEWLINE, max_output_size=2)
+
+    def test_max_output_size_indent(self):
+        """
+        max_output_size applies to pretty-printed output
+        """
+        obj = {"a": [1, 2]}
+        output = orjson.dumps(obj, option=orjson.OPT_INDENT_2)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_INDENT_2, max_output_size=len(output))
+            == output
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj, option=orjson.OPT_INDENT_2, max_output_size=len(output) - 1
+            )
+
+    def test_max_output_size_aborts_early(self):
+        """
+        Serializing stops soon after the output exceeds max_output_size
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            return "a" * 1024
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                [object() for _ in range(1000)],
+                default=default,
+                max_output_size=4096,
+            )
+        assert str(exc_info.value) == "Output exceeds max_output_size"
+        assert len(calls) < 10
+
+    def test_max_output_size_nested(self):
+        """
+        max_output_size aborts a nested container
+        """
+        obj = {"a": [{"b": "c" * 1024} for _ in range(100)]}
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, max_output_size=2048)
+        assert orjson.dumps(obj, max_output_size=len(orjson.dumps(obj))) == (
+            orjson.dumps(obj)
+        )
+
+    def test_max_output_size_deduplicate_arrays(self):
+        """
+        max_output_size with OPT_DEDUPLICATE_ARRAYS
+        """
+        obj = ["a" * 1024] * 100
+        assert orjson.dumps(
+            obj, option=orjson.OPT_DEDUPLICATE_ARRAYS, max_output_size=1030
+        ) == (b'["' + b"a" * 1024 + b'"]')
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(["a" * 1024, "b" * 1024], max_output_size=2048)
+
+    def test_max_output_size_none(self):
+        """
+        max_output_size=None is the same as not specifying it
+        """
+        assert orjson.dumps([1], max_output_size=None) == b"[1]"
+
+    def test_max_output_size_invalid(self):
+        """
+        max_output_size must be a positive int
+        """
+        for val in (0, -1, 2**64, 1.0, "1", True):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(1, max_output_size=val)
+            assert str(exc_info.value) == "max_output_size must be a positive int"
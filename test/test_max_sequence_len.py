@@ -0,0 +1,146 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import numpy
+
+
+class SubList(list):
+    pass
+
+
+class TestMaxSequenceLen:
+    def test_max_sequence_len_within(self):
+        """
+        dumps() max_sequence_len allows an array of at most that many elements
+        """
+        assert orjson.dumps([1, 2], max_sequence_len=2) == b"[1,2]"
+        assert orjson.dumps((1, 2), max_sequence_len=2) == b"[1,2]"
+        assert orjson.dumps([], max_sequence_len=1) == b"[]"
+
+    def test_max_sequence_len_exceeded(self):
+        """
+        dumps() max_sequence_len raises on a list or tuple with more elements
+        """
+        for obj in ([1, 2, 3], (1, 2, 3), SubList([1, 2, 3])):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, max_sequence_len=2)
+            assert str(exc_info.value) == "Array exceeds max_sequence_len of 2"
+            assert exc_info.value.path == []
+
+    def test_max_sequence_len_nested(self):
+        """
+        dumps() max_sequence_len applies to each array and raises with its path
+        """
+        obj = {"a": [[1], {"b": (1, 2, 3)}]}
+        assert orjson.dumps(obj, max_sequence_len=3)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, max_sequence_len=2)
+        assert exc_info.value.path == ["a", 1, "b"]
+
+    def test_max_sequence_len_before_elements(self):
+        """
+        dumps() max_sequence_len raises before serializing any element
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            return None
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([object()] * 3, default=default, max_sequence_len=2)
+        assert calls == []
+
+    def test_max_sequence_len_deduplicate_arrays(self):
+        """
+        dumps() max_sequence_len counts elements before OPT_DEDUPLICATE_ARRAYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                [1, 1, 1], option=orjson.OPT_DEDUPLICATE_ARRAYS, max_sequence_len=2
+            )
+
+    def test_max_sequence_len_default_result(self):
+        """
+        dumps() max_sequence_len applies to a list returned by default
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": {1, 2, 3}}, default=list, max_sequence_len=2)
+        assert exc_info.value.path == ["a"]
+
+    def test_max_sequence_len_range(self):
+        """
+        dumps() max_sequence_len applies to the length of a range serialized
+        with OPT_RANGE_AS_ARRAY, which is checked before iterating it
+        """
+        opt = orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY
+        assert orjson.dumps(range(2), option=opt, max_sequence_len=2) == b"[0,1]"
+        for obj in (range(3), range(2**64), range(-(2**70), 2**70)):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps({"a": obj}, option=opt, max_sequence_len=2)
+            assert str(exc_info.value) == "Array exceeds max_sequence_len of 2"
+            assert exc_info.value.path == ["a"]
+        assert orjson.dumps(
+            range(3), option=orjson.OPT_SERIALIZE_RANGE, max_sequence_len=2
+        )
+
+    def test_max_sequence_len_iterator(self):
+        """
+        dumps() max_sequence_len counts the items of an iterator and raises
+        without consuming more than one item past it
+        """
+        opt = orjson.OPT_SERIALIZE_ITERATORS
+        assert orjson.dumps(iter([1, 2]), option=opt, max_sequence_len=2) == b"[1,2]"
+        consumed = []
+
+        def gen():
+            for idx in range(10):
+                consumed.append(idx)
+                yield idx
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": gen()}, option=opt, max_sequence_len=2)
+        assert str(exc_info.value) == "Array exceeds max_sequence_len of 2"
+        assert exc_info.value.path == ["a"]
+        assert consumed == [0, 1, 2]
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_max_sequence_len_numpy(self):
+        """
+        dumps() max_sequence_len applies to each dimension of a numpy.ndarray
+        """
+        opt = orjson.OPT_SERIALIZE_NUMPY
+        arr = numpy.zeros((2, 3), numpy.int32)
+        assert orjson.dumps(arr, option=opt, max_sequence_len=3)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": arr}, option=opt, max_sequence_len=2)
+        assert str(exc_info.value) == "Array exceeds max_sequence_len of 2"
+        assert exc_info.value.path == ["a"]
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(arr.T.copy(), option=opt, max_sequence_len=2)
+
+    def test_max_sequence_len_validate_only(self):
+        """
+        dumps() max_sequence_len applies with validate_only
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps([1, 2, 3], max_sequence_len=2, validate_only=True)
+
+    def test_max_sequence_len_none(self):
+        """
+        dumps() max_sequence_len=None is the same as not specifying it
+        """
+        assert orjson.dumps([1, 2, 3], max_sequence_len=None) == b"[1,2,3]"
+
+    def test_max_sequence_len_invalid(self):
+        """
+        dumps() max_sequence_len that is not a positive int raises
+        """
+        for val in (0, -1, 1.5, "1", 2**64):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps([], max_sequence_len=val)  # type: ignore
+            assert str(exc_info.value) == "max_sequence_len must be a positive int"
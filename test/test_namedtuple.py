@@ -0,0 +1,166 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+import typing
+
+import pytest
+
+import orjson
+
+Point = collections.namedtuple("Point", ["x", "y"])
+
+Empty = collections.namedtuple("Empty", [])
+
+
+class Line(typing.NamedTuple):
+    start: Point
+    end: Point
+    label: typing.Optional[str] = None
+
+
+class SubPoint(Point):
+    pass
+
+
+class TestNamedTuple:
+    def test_namedtuple(self):
+        assert (
+            orjson.dumps(Point(1, 2), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_namedtuple_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Point(1, 2))
+
+    def test_namedtuple_typing(self):
+        assert (
+            orjson.dumps(
+                Line(Point(0, 0), Point(1, 1)),
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'{"start":{"x":0,"y":0},"end":{"x":1,"y":1},"label":null}'
+        )
+
+    def test_namedtuple_empty(self):
+        assert (
+            orjson.dumps(Empty(), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT) == b"{}"
+        )
+
+    def test_namedtuple_subclass(self):
+        assert (
+            orjson.dumps(SubPoint(1, 2), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_namedtuple_tuple(self):
+        """
+        OPT_NAMEDTUPLE_AS_OBJECT serializes tuple as an array
+        """
+        assert (
+            orjson.dumps(
+                (1, Point(1, 2), ()),
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'[1,{"x":1,"y":2},[]]'
+        )
+
+    def test_namedtuple_tuple_subclass(self):
+        """
+        OPT_NAMEDTUPLE_AS_OBJECT does not serialize tuple subclasses without _fields
+        """
+
+        class SubTuple(tuple):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SubTuple((1,)), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_containers(self):
+        assert (
+            orjson.dumps(
+                {"a": [Point([Point(1, 2)], {"b": Point(None, "c")})]},
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'{"a":[{"x":[{"x":1,"y":2}],"y":{"b":{"x":null,"y":"c"}}}]}'
+        )
+
+    def test_namedtuple_nested_deep(self):
+        obj = Point(0, None)
+        for idx in range(1, 200):
+            obj = Point(idx, obj)
+        deserialized = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_NAMEDTUPLE_AS_OBJECT),
+        )
+        for idx in reversed(range(200)):
+            assert deserialized["x"] == idx
+            deserialized = deserialized["y"]
+        assert deserialized is None
+
+    def test_namedtuple_recursion_limit(self):
+        obj = Point(0, None)
+        for idx in range(1, 300):
+            obj = Point(idx, obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_default(self):
+        class Custom:
+            pass
+
+        def default(obj):
+            if isinstance(obj, Custom):
+                return "custom"
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                Point(Custom(), 1),
+                default=default,
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT,
+            )
+            == b'{"x":"custom","y":1}'
+        )
+
+    def test_namedtuple_sort_keys(self):
+        """
+        OPT_SORT_KEYS does not reorder namedtuple fields
+        """
+        assert (
+            orjson.dumps(
+                Line(Point(0, 0), Point(1, 1), "a"),
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"start":{"x":0,"y":0},"end":{"x":1,"y":1},"label":"a"}'
+        )
+
+    def test_namedtuple_indent(self):
+        assert (
+            orjson.dumps(
+                Point(1, [2]),
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "x": 1,\n  "y": [\n    2\n  ]\n}'
+        )
+
+    def test_namedtuple_fields_mismatch(self):
+        class Invalid(tuple):
+            _fields = ("a",)
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Invalid((1, 2)), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_fields_invalid_type(self):
+        class Invalid(tuple):
+            _fields = ["a"]
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Invalid((1,)), option=orjson.OPT_NAMEDTUPLE_AS_OBJECT)
+
+    def test_namedtuple_dict_key(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Point(1, 2): True},
+                option=orjson.OPT_NAMEDTUPLE_AS_OBJECT | orjson.OPT_NON_STR_KEYS,
+            )
@@ -3,6 +3,7 @@
 
 import dataclasses
 import datetime
+import json
 import uuid
 
 import pytest
@@ -21,6 +22,12 @@ class SubStr(str):
     pass
 
 
+@dataclasses.dataclass(frozen=True)
+class Point:
+    x: object
+    y: object
+
+
 class TestNonStrKeyTests:
     def test_dict_keys_duplicate(self):
         """
@@ -153,6 +160,66 @@ class TestNonStrKeyTests:
             == b'{"true":true,"false":false}'
         )
 
+    def test_dict_keys_none(self):
+        assert (
+            orjson.dumps({None: None}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"null":null}'
+        )
+
+    def test_dict_keys_bool_none_stdlib(self):
+        """
+        True, False, and None keys are the same as json.dumps()
+        """
+        for obj in ({True: 1}, {False: 1}, {None: 1}, {True: 1, False: 2, None: 3}):
+            expected = json.dumps(obj, separators=(",", ":")).encode("utf-8")
+            assert orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == expected
+
+    def test_dict_keys_bool_equal_int(self):
+        """
+        True and 1, and False and 0, are the same dict key, so the first key
+        inserted is kept with the last value
+        """
+        for obj, expected in (
+            ({True: "a", 1: "b"}, b'{"true":"b"}'),
+            ({1: "a", True: "b"}, b'{"1":"b"}'),
+            ({False: "a", 0: "b", 0.0: "c"}, b'{"false":"c"}'),
+            ({1.0: "a", True: "b"}, b'{"1.0":"b"}'),
+        ):
+            assert len(obj) == 1
+            assert orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == expected
+            assert (
+                orjson.dumps(
+                    obj, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS
+                )
+                == expected
+            )
+
+    def test_dict_keys_bool_none_sort(self):
+        """
+        True, False, and None keys are sorted as "true", "false", and "null"
+        """
+        assert (
+            orjson.dumps(
+                {True: 1, None: 2, False: 3, "m": 4},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"false":3,"m":4,"null":2,"true":1}'
+        )
+
+    def test_dict_keys_bool_not_int(self):
+        """
+        A bool key is not affected by options for int keys
+        """
+        assert (
+            orjson.dumps(
+                {True: 1, False: 2},
+                option=orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_STRICT_INTEGER
+                | orjson.OPT_INT_BASE62,
+            )
+            == b'{"true":1,"false":2}'
+        )
+
     def test_dict_keys_datetime(self):
         assert (
             orjson.dumps(
@@ -216,6 +283,32 @@ class TestNonStrKeyTests:
             == b'{"12:15:59.000111":true}'
         )
 
+    def test_dict_keys_time_tz(self):
+        """
+        A time key with a timezone is the same as a time value
+        """
+        val = datetime.time(
+            12,
+            15,
+            59,
+            111,
+            tzinfo=datetime.timezone(datetime.timedelta(hours=5, minutes=30)),
+        )
+        assert (
+            orjson.dumps({val: val}, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"12:15:59.000111+05:30":"12:15:59.000111+05:30"}'
+        )
+
+    def test_dict_keys_time_opt(self):
+        """
+        A time key uses the same options as a time value
+        """
+        val = datetime.time(0, 0, 0, 1, tzinfo=datetime.timezone.utc)
+        for option in (0, orjson.OPT_UTC_Z, orjson.OPT_OMIT_MICROSECONDS):
+            assert orjson.dumps(
+                {val: True}, option=orjson.OPT_NON_STR_KEYS | option
+            ) == orjson.dumps({orjson.loads(orjson.dumps(val, option=option)): True})
+
     def test_dict_non_str_and_sort_keys(self):
         assert (
             orjson.dumps(
@@ -276,6 +369,65 @@ class TestNonStrKeyTests:
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
 
+    def test_dict_keys_dataclass_frozen(self):
+        """
+        A frozen dataclass key is serialized as its JSON object in a str
+        """
+        obj = {Point(1, 2): "a", Point(3, "\u00e9"): "b"}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)
+            == b'{"{\\"x\\":1,\\"y\\":2}":"a","{\\"x\\":3,\\"y\\":\\"\xc3\xa9\\"}":"b"}'
+        )
+        assert orjson.loads(orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS)) == {
+            json.dumps(
+                dataclasses.asdict(key), separators=(",", ":"), ensure_ascii=False
+            ): val
+            for key, val in obj.items()
+        }
+
+    def test_dict_keys_dataclass_frozen_nested(self):
+        """
+        A frozen dataclass key is compact and its fields respect options
+        """
+        obj = {"a": [{Point(Point(1, None), (datetime.date(2026, 1, 1),)): 1}]}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_OMIT_NONE | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "a": [\n    {\n'
+            b'      "{\\"x\\":{\\"x\\":1},\\"y\\":[\\"2026-01-01\\"]}": 1\n'
+            b"    }\n  ]\n}"
+        )
+
+    def test_dict_keys_dataclass_frozen_sort(self):
+        """
+        Frozen dataclass keys are sorted by their serialized str
+        """
+        obj = {Point(2, 0): "b", "{": "c", Point(1, 0): "a"}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS)
+            == b'{"{":"c","{\\"x\\":1,\\"y\\":0}":"a","{\\"x\\":2,\\"y\\":0}":"b"}'
+        )
+
+    def test_dict_keys_dataclass_frozen_invalid(self):
+        """
+        A frozen dataclass key with a field that is not serializable raises
+        without passing the field to default, and it requires OPT_NON_STR_KEYS
+        """
+        obj = [{Point(1, object()): 1}]
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                obj, default=lambda val: "default", option=orjson.OPT_NON_STR_KEYS
+            )
+        assert str(exc_info.value) == (
+            "Dict key dataclass is not serializable: "
+            "Type is not JSON serializable: object"
+        )
+        assert exc_info.value.path == [0]
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({Point(1, 2): 1})
+
     def test_dict_keys_list(self):
         with pytest.raises(TypeError):
             _ = {[]: True}
@@ -303,3 +455,261 @@ class TestNonStrKeyTests:
         val = Obj()
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps({val: True}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_dict_keys_unique_duplicate(self):
+        """
+        OPT_UNIQUE_KEYS raises on keys that collide after conversion to str
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"1": True, 1: False},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+        assert str(exc_info.value) == "Dict key is duplicated after conversion to str"
+
+    def test_dict_keys_unique_duplicate_sorted(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {"b": 1, "1": True, 1: False, "a": 2},
+                option=orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_UNIQUE_KEYS
+                | orjson.OPT_SORT_KEYS,
+            )
+
+    def test_dict_keys_unique_duplicate_types(self):
+        """
+        OPT_UNIQUE_KEYS detects collisions between non-str types
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {None: 1, "null": 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {datetime.date(1970, 1, 1): 1, "1970-01-01": 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {True: 1, "true": 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+
+    def test_dict_keys_unique_nested(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {"a": [{"1": True, 1: False}]},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+
+    def test_dict_keys_unique_valid(self):
+        obj = {"1": True, 2: False, None: 3, datetime.date(1970, 1, 1): 4}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+            )
+            == b'{"1":true,"2":false,"null":3,"1970-01-01":4}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_UNIQUE_KEYS
+                | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"1":true,"1970-01-01":4,"2":false,"null":3}'
+        )
+
+    def test_dict_keys_unique_str_keys(self):
+        """
+        OPT_UNIQUE_KEYS has no effect on dict with str keys
+        """
+        assert (
+            orjson.dumps({"a": 1, "b": 2}, option=orjson.OPT_UNIQUE_KEYS)
+            == b'{"a":1,"b":2}'
+        )
+
+    def test_dict_keys_tuple_joined(self):
+        """
+        OPT_NON_STR_KEYS joins tuple keys with key_delimiter
+        """
+        assert (
+            orjson.dumps(
+                {("a", 1): True, ("b", 2): False},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter="|",
+            )
+            == b'{"a|1":true,"b|2":false}'
+        )
+
+    def test_dict_keys_tuple_scalars(self):
+        """
+        OPT_NON_STR_KEYS joins tuple keys of each type serialized as a key
+        """
+        key = (
+            "a",
+            SubStr("b"),
+            1,
+            1.5,
+            True,
+            None,
+            datetime.date(1970, 1, 1),
+            uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece"),
+        )
+        assert (
+            orjson.dumps(
+                {key: True},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter=",",
+            )
+            == b'{"a,b,1,1.5,true,null,1970-01-01,7202d115-7ff3-4c81-a7c1-2a1f067b1ece":true}'
+        )
+
+    def test_dict_keys_tuple_options(self):
+        """
+        OPT_NON_STR_KEYS tuple key elements respect other options
+        """
+        assert (
+            orjson.dumps(
+                {(datetime.datetime(1970, 1, 1), 1): True},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_NAIVE_UTC,
+                key_delimiter="/",
+            )
+            == b'{"1970-01-01T00:00:00+00:00/1":true}'
+        )
+
+    def test_dict_keys_tuple_delimiter(self):
+        """
+        OPT_NON_STR_KEYS tuple key delimiter may be empty or non-ASCII
+        """
+        obj = {("a", "b"): 1}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS, key_delimiter="")
+            == b'{"ab":1}'
+        )
+        assert orjson.dumps(
+            obj, option=orjson.OPT_NON_STR_KEYS, key_delimiter="→"
+        ) == '{"a→b":1}'.encode("utf-8")
+
+    def test_dict_keys_tuple_empty(self):
+        """
+        OPT_NON_STR_KEYS serializes an empty tuple key as an empty str
+        """
+        assert (
+            orjson.dumps({(): 1}, option=orjson.OPT_NON_STR_KEYS, key_delimiter="|")
+            == b'{"":1}'
+        )
+
+    def test_dict_keys_tuple_single(self):
+        """
+        OPT_NON_STR_KEYS serializes a tuple key of one element without delimiter
+        """
+        assert (
+            orjson.dumps({(1,): 1}, option=orjson.OPT_NON_STR_KEYS, key_delimiter="|")
+            == b'{"1":1}'
+        )
+
+    def test_dict_keys_tuple_sort(self):
+        """
+        OPT_NON_STR_KEYS sorts tuple keys after joining
+        """
+        assert (
+            orjson.dumps(
+                {("b", 1): 1, ("a", 2): 2, "a|1": 3},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_SORT_KEYS,
+                key_delimiter="|",
+            )
+            == b'{"a|1":3,"a|2":2,"b|1":1}'
+        )
+
+    def test_dict_keys_tuple_unique(self):
+        """
+        OPT_UNIQUE_KEYS raises on tuple keys that collide after joining
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {("a", "b|c"): 1, ("a|b", "c"): 2},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UNIQUE_KEYS,
+                key_delimiter="|",
+            )
+
+    def test_dict_keys_tuple_no_delimiter(self):
+        """
+        OPT_NON_STR_KEYS raises on tuple keys without key_delimiter
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({("a", 1): True}, option=orjson.OPT_NON_STR_KEYS)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {("a", 1): True},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter=None,
+            )
+
+    def test_dict_keys_tuple_no_option(self):
+        """
+        key_delimiter without OPT_NON_STR_KEYS does not allow tuple keys
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({("a", 1): True}, key_delimiter="|")
+
+    def test_dict_keys_tuple_nested(self):
+        """
+        OPT_NON_STR_KEYS raises on tuple keys containing a tuple
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {("a", (1, 2)): True},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter="|",
+            )
+        assert "tuple" in str(exc_info.value)
+
+    def test_dict_keys_tuple_invalid_element(self):
+        """
+        OPT_NON_STR_KEYS raises on tuple keys containing a non-scalar
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {("a", frozenset()): True},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter="|",
+            )
+
+    def test_dict_keys_tuple_subclass(self):
+        """
+        OPT_NON_STR_KEYS does not join tuple subclass keys
+        """
+
+        class SubTuple(tuple):
+            pass
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {SubTuple(("a", 1)): True},
+                option=orjson.OPT_NON_STR_KEYS,
+                key_delimiter="|",
+            )
+
+    def test_dict_keys_tuple_delimiter_invalid(self):
+        """
+        key_delimiter that is not a str raises
+        """
+        for val in (1, b"|", ["|"]):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps({}, key_delimiter=val)  # type: ignore
+
+    def test_dict_keys_tuple_encoder(self):
+        """
+        Encoder() accepts key_delimiter
+        """
+        obj = {("a", 1): True}
+        encoder = orjson.Encoder(
+            option=orjson.OPT_NON_STR_KEYS,
+            key_delimiter="|",
+        )
+        assert encoder.encode(obj) == b'{"a|1":true}'
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(key_delimiter=1)  # type: ignore
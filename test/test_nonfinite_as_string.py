@@ -0,0 +1,152 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import json
+
+import pytest
+
+import orjson
+
+from .util import numpy
+
+
+class TestNonfiniteAsString:
+    def test_nonfinite_as_string(self):
+        """
+        OPT_NONFINITE_AS_STRING serializes NaN and infinity as strings
+        """
+        assert (
+            orjson.dumps(
+                [float("nan"), float("inf"), float("-inf")],
+                option=orjson.OPT_NONFINITE_AS_STRING,
+            )
+            == b'["NaN","Infinity","-Infinity"]'
+        )
+
+    def test_nonfinite_as_string_negative_nan(self):
+        """
+        The sign of NaN is not serialized
+        """
+        assert (
+            orjson.dumps(-float("nan"), option=orjson.OPT_NONFINITE_AS_STRING)
+            == b'"NaN"'
+        )
+
+    def test_nonfinite_as_string_default(self):
+        """
+        NaN and infinity are null by default
+        """
+        assert orjson.dumps([float("nan"), float("inf"), float("-inf")]) == (
+            b"[null,null,null]"
+        )
+
+    def test_nonfinite_as_string_finite(self):
+        """
+        OPT_NONFINITE_AS_STRING does not change finite floats
+        """
+        assert (
+            orjson.dumps([0.0, -0.0, 1.5, 1e300], option=orjson.OPT_NONFINITE_AS_STRING)
+            == orjson.dumps([0.0, -0.0, 1.5, 1e300])
+        )
+
+    def test_nonfinite_as_string_nested(self):
+        """
+        OPT_NONFINITE_AS_STRING applies to nested values and indented output
+        """
+        assert (
+            orjson.dumps(
+                {"a": [float("inf")]},
+                option=orjson.OPT_NONFINITE_AS_STRING | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "a": [\n    "Infinity"\n  ]\n}'
+        )
+
+    def test_nonfinite_as_string_loads(self):
+        """
+        The strings do not round-trip to float
+        """
+        assert orjson.loads(
+            orjson.dumps([float("inf")], option=orjson.OPT_NONFINITE_AS_STRING)
+        ) == ["Infinity"]
+
+    def test_nonfinite_as_string_json(self):
+        """
+        The strings are the names json.dumps() writes without quotes
+        """
+        obj = [float("nan"), float("inf"), float("-inf")]
+        assert orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_NONFINITE_AS_STRING)
+        ) == [
+            each.strip('"') for each in json.dumps(obj)[1:-1].split(", ")
+        ]
+
+    def test_nonfinite_as_string_float_trailing_zero(self):
+        """
+        OPT_NONFINITE_AS_STRING may be combined with OPT_FLOAT_TRAILING_ZERO
+        """
+        assert (
+            orjson.dumps(
+                [1.0, float("nan")],
+                option=orjson.OPT_NONFINITE_AS_STRING | orjson.OPT_FLOAT_TRAILING_ZERO,
+            )
+            == b'[1.0,"NaN"]'
+        )
+
+    def test_nonfinite_as_string_passthrough(self):
+        """
+        OPT_PASSTHROUGH_NON_FINITE takes precedence
+        """
+        assert (
+            orjson.dumps(
+                [float("nan")],
+                option=orjson.OPT_NONFINITE_AS_STRING
+                | orjson.OPT_PASSTHROUGH_NON_FINITE,
+                default=lambda obj: "nan",
+            )
+            == b'["nan"]'
+        )
+
+    def test_nonfinite_as_string_non_str_keys(self):
+        """
+        A NaN or infinite dict key is serialized as its string
+        """
+        assert (
+            orjson.dumps(
+                {float("nan"): 1, float("-inf"): 2},
+                option=orjson.OPT_NONFINITE_AS_STRING | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"NaN":1,"-Infinity":2}'
+        )
+        assert orjson.dumps({float("nan"): 1}, option=orjson.OPT_NON_STR_KEYS) == (
+            b'{"null":1}'
+        )
+
+
+@pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+class TestNonfiniteAsStringNumpy:
+    def test_nonfinite_as_string_numpy_array(self):
+        """
+        OPT_NONFINITE_AS_STRING applies to numpy float arrays
+        """
+        for dtype in (numpy.float64, numpy.float32, numpy.float16):
+            arr = numpy.array([1.0, numpy.nan, numpy.inf, -numpy.inf], dtype)
+            assert (
+                orjson.dumps(
+                    arr,
+                    option=orjson.OPT_NONFINITE_AS_STRING | orjson.OPT_SERIALIZE_NUMPY,
+                )
+                == b'[1.0,"NaN","Infinity","-Infinity"]'
+            )
+
+    def test_nonfinite_as_string_numpy_scalar(self):
+        """
+        OPT_NONFINITE_AS_STRING applies to numpy float scalars
+        """
+        for dtype in (numpy.float64, numpy.float32, numpy.float16):
+            assert (
+                orjson.dumps(
+                    [dtype("nan"), dtype("-inf")],
+                    option=orjson.OPT_NONFINITE_AS_STRING | orjson.OPT_SERIALIZE_NUMPY,
+                )
+                == b'["NaN","-Infinity"]'
+            )
@@ -0,0 +1,119 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import json
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_str
+
+
+class TestParseNumberAsString:
+    def test_number_as_string_int(self):
+        assert orjson.loads(
+            "[0, -0, 1, -1, 18446744073709551616]",
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == [
+            "0",
+            "-0",
+            "1",
+            "-1",
+            "18446744073709551616",
+        ]
+
+    def test_number_as_string_float(self):
+        assert orjson.loads(
+            "[1.50, 1e5, 1E+05, -2.0e-007, 0.000]",
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == [
+            "1.50",
+            "1e5",
+            "1E+05",
+            "-2.0e-007",
+            "0.000",
+        ]
+
+    def test_number_as_string_root(self):
+        assert orjson.loads("1.50", option=orjson.OPT_PARSE_NUMBER_AS_STRING) == "1.50"
+        assert (
+            orjson.loads(b" \n-10 ", option=orjson.OPT_PARSE_NUMBER_AS_STRING)
+            == "-10"
+        )
+
+    def test_number_as_string_object(self):
+        assert orjson.loads(
+            '{"a": 1.0, "-1": {"2": [3.0]}, "b": -4}',
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == {
+            "a": "1.0",
+            "-1": {"2": ["3.0"]},
+            "b": "-4",
+        }
+
+    def test_number_as_string_strings(self):
+        """
+        digits in strings, including after escaped quotes, are not numbers
+        """
+        assert orjson.loads(
+            r'["1", "\"2", "\\", 3, "\\\"45", 6]',
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == [
+            "1",
+            '"2',
+            "\\",
+            "3",
+            '\\"45',
+            "6",
+        ]
+
+    def test_number_as_string_other_types(self):
+        assert orjson.loads(
+            '[true, false, null, "a", [], {}, 1]',
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING,
+        ) == [
+            True,
+            False,
+            None,
+            "a",
+            [],
+            {},
+            "1",
+        ]
+
+    def test_number_as_string_cache_keys(self):
+        assert orjson.loads(
+            '[{"1": 1}, {"1": 2}]',
+            option=orjson.OPT_PARSE_NUMBER_AS_STRING | orjson.OPT_CACHE_KEYS,
+        ) == [{"1": "1"}, {"1": "2"}]
+
+    def test_number_as_string_invalid(self):
+        for doc in ("[01]", "[1.]", "[-]", "[1e]", "[1e400]"):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(doc, option=orjson.OPT_PARSE_NUMBER_AS_STRING)
+
+    def test_number_as_string_fragment(self):
+        doc = '{"a":[1.50,2E+05,-0]}'
+        obj = orjson.loads(doc, option=orjson.OPT_PARSE_NUMBER_AS_STRING)
+        assert (
+            orjson.dumps(
+                {"a": [orjson.Fragment(each) for each in obj["a"]]},
+            ).decode("utf-8")
+            == doc
+        )
+
+    def test_number_as_string_disabled(self):
+        assert orjson.loads("[1.50]") == [1.5]
+
+    @needs_data
+    def test_number_as_string_fixtures(self):
+        for filename in ("twitter.json.xz", "canada.json.xz", "citm_catalog.json.xz"):
+            doc = read_fixture_str(filename)
+            assert orjson.loads(
+                doc, option=orjson.OPT_PARSE_NUMBER_AS_STRING
+            ) == json.loads(
+                doc,
+                parse_int=str,
+                parse_float=str,
+            )
@@ -0,0 +1,85 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+ACCEPTED = (
+    ("0", 0),
+    ("-0", 0),
+    ("1e05", 100000.0),
+    ("0e1", 0.0),
+    ("0.0e-0", 0.0),
+    ("1.5e+3", 1500.0),
+    ("-1.5E-3", -0.0015),
+    ("[-0.5]", [-0.5]),
+)
+
+# (document, pos, msg)
+REJECTED = (
+    ("01", 0, "number with leading zero is not allowed"),
+    ("00", 0, "number with leading zero is not allowed"),
+    ("-01", 1, "number with leading zero is not allowed"),
+    ("-00", 1, "number with leading zero is not allowed"),
+    ("01.5", 0, "number with leading zero is not allowed"),
+    ("[01]", 1, "number with leading zero is not allowed"),
+    (".5", 0, "unexpected character"),
+    ("+1", 0, "unexpected character"),
+    ("NaN", 0, "unexpected character"),
+    ("Infinity", 0, "unexpected character"),
+    ("-", 1, "unexpected end of data"),
+    ("1.", 2, "unexpected end of data"),
+    ("1e", 2, "unexpected end of data"),
+    ("1E+", 3, "unexpected end of data"),
+    ("-.5", 1, "no digit after minus sign"),
+    ("-Infinity", 1, "no digit after minus sign"),
+    ('{"a":-}', 6, "no digit after minus sign"),
+    ("1.e5", 2, "no digit after decimal point"),
+    ("[1.]", 3, "no digit after decimal point"),
+    ("1e+-1", 3, "no digit after exponent sign"),
+    ("0x1", 1, "unexpected content after document"),
+    ("0.5.1", 3, "unexpected content after document"),
+)
+
+
+class TestNumberGrammar:
+    def test_number_accepted(self):
+        """
+        loads() accepts numbers of the RFC 8259 grammar
+        """
+        for doc, expected in ACCEPTED:
+            assert orjson.loads(doc) == expected
+            assert orjson.loads(doc.encode("utf-8")) == expected
+
+    def test_number_rejected(self):
+        """
+        loads() rejects numbers outside the RFC 8259 grammar at the position of
+        the first invalid character
+        """
+        for doc, pos, msg in REJECTED:
+            for data in (doc, doc.encode("utf-8")):
+                with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                    orjson.loads(data)
+                assert exc_info.value.msg == msg, doc
+                assert exc_info.value.pos == pos, doc
+
+    def test_number_rejected_option(self):
+        """
+        loads() rejects the same numbers with OPT_STRICT_INPUT and
+        OPT_PARSE_NUMBER_AS_STRING
+        """
+        for option in (orjson.OPT_STRICT_INPUT, orjson.OPT_PARSE_NUMBER_AS_STRING):
+            decoder = orjson.Decoder(option=option)
+            for doc, pos, msg in REJECTED:
+                with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                    decoder.decode(doc)
+                assert exc_info.value.msg == msg, doc
+                assert exc_info.value.pos == pos, doc
+
+    def test_number_accepted_as_string(self):
+        """
+        loads() OPT_PARSE_NUMBER_AS_STRING keeps the original representation
+        """
+        for doc in ("-0", "1e05", "0.0e-0", "-1.5E-3"):
+            assert orjson.loads(doc, option=orjson.OPT_PARSE_NUMBER_AS_STRING) == doc
@@ -2,6 +2,8 @@
 # Copyright ijl (2020-2026), Ben Sully (2021), Nazar Kostetskyi (2022), Aviram Hassan (2020-2021), Marco Ribeiro (2020), Eric Jolibois (2021)
 # mypy: ignore-errors
 
+import base64
+import decimal
 import sys
 
 import pytest
@@ -166,6 +168,67 @@ class TestNumpy:
             == b"[null,null,null,-0.0,0.0,3.140625]"
         )
 
+    def test_numpy_array_f16_shortest(self):
+        assert (
+            orjson.dumps(
+                numpy.array(
+                    [0.1, numpy.pi, -2.2, 65504.0, 0.000000059604645, -0.0, numpy.inf],
+                    numpy.float16,
+                ),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NUMPY_SHORTEST_FLOAT,
+            )
+            == b"[0.1,3.14,-2.2,65500.0,6e-8,-0.0,null]"
+        )
+
+    def test_numpy_array_f16_shortest_repr(self):
+        obj = numpy.arange(0, 65536, dtype=numpy.uint16).view(numpy.float16)
+        obj = obj[numpy.isfinite(obj)]
+        serialized = orjson.dumps(
+            obj,
+            option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NUMPY_SHORTEST_FLOAT,
+        )
+        deserialized = orjson.loads(serialized)
+        assert numpy.array_equal(obj, numpy.array(deserialized, numpy.float16))
+        for each, val in zip(obj.tolist(), deserialized):
+            assert float(str(numpy.float16(each))) == val
+
+    def test_numpy_array_f32_shortest(self):
+        """
+        OPT_NUMPY_SHORTEST_FLOAT does not change float32 or float64
+        """
+        for dtype in (numpy.float32, numpy.float64):
+            obj = numpy.array([0.1, numpy.pi, 3.4028235e38], dtype)
+            assert orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NUMPY_SHORTEST_FLOAT,
+            ) == orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_float64_matches_float(self):
+        ref = [
+            0.0,
+            -0.0,
+            0.1,
+            1.0,
+            100.0,
+            123.123,
+            1.5e-7,
+            1e16,
+            1e22,
+            -1.7976931348623157e308,
+            5e-324,
+            2.2250738585072014e-308,
+            9007199254740993.0,
+        ]
+        assert orjson.dumps(
+            numpy.array(ref, numpy.float64),
+            option=orjson.OPT_SERIALIZE_NUMPY,
+        ) == orjson.dumps(ref)
+        for each in ref:
+            assert orjson.dumps(
+                numpy.float64(each),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            ) == orjson.dumps(each)
+
     def test_numpy_array_f32_edge(self):
         assert (
             orjson.dumps(
@@ -473,6 +536,47 @@ class TestNumpy:
             orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
         assert "unsupported datatype in numpy array" in str(cm)
 
+    def test_numpy_array_object(self):
+        array = numpy.array([1, "a", None, [True, 1.5], {"b": 2}], dtype=object)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[1,"a",null,[true,1.5],{"b":2}]'
+        )
+
+    def test_numpy_array_object_d2(self):
+        array = numpy.array([[1, "a"], [None, 2.5]], dtype=object)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[[1,"a"],[null,2.5]]'
+        )
+
+    def test_numpy_array_object_decimal(self):
+        array = numpy.array(
+            [decimal.Decimal("0.1"), decimal.Decimal("1.50")],
+            dtype=object,
+        )
+
+        def default(obj):
+            if isinstance(obj, decimal.Decimal):
+                return str(obj)
+            raise TypeError
+
+        assert (
+            orjson.dumps(array, default=default, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'["0.1","1.50"]'
+        )
+
+    def test_numpy_array_object_decimal_no_default(self):
+        array = numpy.array([decimal.Decimal("0.1")], dtype=object)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_array_object_recursion(self):
+        array = numpy.empty(1, dtype=object)
+        array[0] = array
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
     def test_numpy_array_d1(self):
         array = numpy.array([1])
         assert (
@@ -536,8 +640,7 @@ class TestNumpy:
     def test_numpy_array_dimension_zero(self):
         array = numpy.array(0)
         assert array.ndim == 0
-        with pytest.raises(orjson.JSONEncodeError):
-            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"0"
 
         array = numpy.empty((0, 4, 2))
         assert (
@@ -572,6 +675,82 @@ class TestNumpy:
             == array.tolist()
         )
 
+    def test_numpy_array_0d_int(self):
+        """
+        A 0-dimensional int array is serialized as its item
+        """
+        for dtype in (numpy.int8, numpy.int32, numpy.int64, numpy.uint64):
+            array = numpy.array(5, dtype=dtype)
+            assert array.ndim == 0
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"5"
+
+    def test_numpy_array_0d_float(self):
+        """
+        A 0-dimensional float array is serialized as its item
+        """
+        for dtype in (numpy.float16, numpy.float32, numpy.float64):
+            array = numpy.array(1.5, dtype=dtype)
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"1.5"
+
+    def test_numpy_array_0d_bool(self):
+        """
+        A 0-dimensional bool array is serialized as its item
+        """
+        assert (
+            orjson.dumps(numpy.array(True), option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"true"
+        )
+        assert (
+            orjson.dumps(numpy.array(False), option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"false"
+        )
+
+    def test_numpy_array_0d_datetime64(self):
+        """
+        A 0-dimensional datetime64 array is serialized as its item
+        """
+        array = numpy.array(numpy.datetime64("2021-01-01T00:00:00"))
+        assert array.ndim == 0
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'"2021-01-01T00:00:00"'
+        )
+
+    def test_numpy_array_0d_object(self):
+        """
+        A 0-dimensional object array is serialized as its item
+        """
+        array = numpy.array({"a": [1, 2]}, dtype=object)
+        assert array.ndim == 0
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b'{"a":[1,2]}'
+        )
+
+    def test_numpy_array_0d_dict_value(self):
+        """
+        A 0-dimensional array as a dict value and list item
+        """
+        assert (
+            orjson.dumps(
+                {"a": numpy.array(1), "b": [numpy.array(2.5), numpy.array(True)]},
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b'{"a":1,"b":[2.5,true]}'
+        )
+
+    def test_numpy_array_0d_unsupported(self):
+        """
+        A 0-dimensional array of an unsupported type is passed to default
+        """
+        array = numpy.array("a")
+        assert array.ndim == 0
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY, default=str)
+            == b'"a"'
+        )
+
     def test_numpy_array_dimension_max(self):
         array = numpy.random.rand(
             1,
@@ -701,6 +880,19 @@ class TestNumpy:
             == b"1.0"
         )
 
+    def test_numpy_scalar_float16_shortest(self):
+        assert (
+            orjson.dumps(
+                numpy.float16(0.1),
+                option=orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NUMPY_SHORTEST_FLOAT,
+            )
+            == b"0.1"
+        )
+        assert (
+            orjson.dumps(numpy.float16(0.1), option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"0.099975586"
+        )
+
     def test_numpy_scalar_float32(self):
         assert (
             orjson.dumps(numpy.float32(1.0), option=orjson.OPT_SERIALIZE_NUMPY)
@@ -1131,6 +1323,388 @@ class TestNumpyEquivalence:
         )
 
 
+@pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+class TestNumpyStructured:
+    def test_numpy_structured(self):
+        array = numpy.array(
+            [(1, 1.5, True), (-2, 0.25, False)],
+            dtype=[
+                ("id", numpy.int32),
+                ("value", numpy.float64),
+                ("flag", numpy.bool_),
+            ],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"id":1,"value":1.5,"flag":true},{"id":-2,"value":0.25,"flag":false}]'
+        )
+
+    def test_numpy_structured_all_types(self):
+        dtype = [
+            ("i1", numpy.int8),
+            ("i2", numpy.int16),
+            ("i4", numpy.int32),
+            ("i8", numpy.int64),
+            ("u1", numpy.uint8),
+            ("u2", numpy.uint16),
+            ("u4", numpy.uint32),
+            ("u8", numpy.uint64),
+            ("f2", numpy.float16),
+            ("f4", numpy.float32),
+            ("f8", numpy.float64),
+        ]
+        array = numpy.array(
+            [
+                (
+                    -128,
+                    -32768,
+                    -2147483648,
+                    -9223372036854775808,
+                    255,
+                    65535,
+                    4294967295,
+                    18446744073709551615,
+                    0.5,
+                    0.5,
+                    0.5,
+                ),
+            ],
+            dtype=dtype,
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"i1":-128,"i2":-32768,"i4":-2147483648,"i8":-9223372036854775808,'
+            b'"u1":255,"u2":65535,"u4":4294967295,"u8":18446744073709551615,'
+            b'"f2":0.5,"f4":0.5,"f8":0.5}]'
+        )
+
+    def test_numpy_structured_packed(self):
+        """
+        fields are read at unaligned offsets
+        """
+        dtype = numpy.dtype(
+            [("a", numpy.int8), ("b", numpy.int64), ("c", numpy.float32)],
+        )
+        assert dtype.itemsize == 13
+        array = numpy.array([(1, 2**40, 1.5), (-1, -(2**40), -1.5)], dtype=dtype)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":1099511627776,"c":1.5},{"a":-1,"b":-1099511627776,"c":-1.5}]'
+        )
+
+    def test_numpy_structured_aligned(self):
+        dtype = numpy.dtype([("a", numpy.int8), ("b", numpy.int64)], align=True)
+        assert dtype.itemsize == 16
+        array = numpy.array([(1, 2), (3, 4)], dtype=dtype)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":2},{"a":3,"b":4}]'
+        )
+
+    def test_numpy_structured_offsets(self):
+        """
+        fields are in the order of names rather than of offsets
+        """
+        dtype = numpy.dtype(
+            {
+                "names": ["b", "a"],
+                "formats": [numpy.int32, numpy.int32],
+                "offsets": [4, 0],
+            },
+        )
+        array = numpy.zeros(1, dtype=dtype)
+        array["a"] = 1
+        array["b"] = 2
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b'[{"b":2,"a":1}]'
+        )
+
+    def test_numpy_structured_nested(self):
+        dtype = numpy.dtype(
+            [
+                ("id", numpy.int64),
+                ("point", [("x", numpy.float64), ("y", numpy.float64)]),
+            ],
+        )
+        array = numpy.array([(1, (0.5, 1.5)), (2, (2.5, 3.5))], dtype=dtype)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"id":1,"point":{"x":0.5,"y":1.5}},{"id":2,"point":{"x":2.5,"y":3.5}}]'
+        )
+
+    def test_numpy_structured_d2(self):
+        array = numpy.array(
+            [[(1,), (2,)], [(3,), (4,)]],
+            dtype=[("a", numpy.int32)],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[[{"a":1},{"a":2}],[{"a":3},{"a":4}]]'
+        )
+
+    def test_numpy_structured_empty(self):
+        array = numpy.array([], dtype=[("a", numpy.int32)])
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[]"
+
+    def test_numpy_structured_no_fields(self):
+        array = numpy.zeros(2, dtype=[])
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[{},{}]"
+
+    def test_numpy_structured_recarray(self):
+        array = numpy.rec.array(
+            [(1, 1.5), (2, 2.5)],
+            dtype=[("a", numpy.int64), ("b", numpy.float64)],
+        )
+        assert isinstance(array, numpy.recarray)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":1.5},{"a":2,"b":2.5}]'
+        )
+
+    def test_numpy_structured_datetime64(self):
+        array = numpy.array(
+            [(1, numpy.datetime64("2021-01-01T00:00:00"))],
+            dtype=[("a", numpy.int32), ("b", "datetime64[s]")],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":"2021-01-01T00:00:00"}]'
+        )
+
+    def test_numpy_structured_object(self):
+        array = numpy.array(
+            [(1, {"a": [1, 2]}), (2, None)],
+            dtype=[("id", numpy.int32), ("data", object)],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"id":1,"data":{"a":[1,2]}},{"id":2,"data":null}]'
+        )
+
+    def test_numpy_structured_object_default(self):
+        array = numpy.array(
+            [(decimal.Decimal("1.5"),)],
+            dtype=[("a", object)],
+        )
+        assert (
+            orjson.dumps(
+                array,
+                option=orjson.OPT_SERIALIZE_NUMPY,
+                default=lambda obj: str(obj),
+            )
+            == b'[{"a":"1.5"}]'
+        )
+
+    def test_numpy_structured_object_error_path(self):
+        array = numpy.array(
+            [(1, None), (2, decimal.Decimal("1.5"))],
+            dtype=[("id", numpy.int32), ("data", object)],
+        )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+        assert exc_info.value.path == [1, "data"]
+
+    def test_numpy_structured_non_native_endian(self):
+        """
+        non-native byte order of a field raises
+        """
+        wrong_endianness = ">" if sys.byteorder == "little" else "<"
+        array = numpy.array(
+            [(1, 2)],
+            dtype=[("a", f"{wrong_endianness}i4"), ("b", numpy.int32)],
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_structured_non_native_endian_nested(self):
+        wrong_endianness = ">" if sys.byteorder == "little" else "<"
+        array = numpy.array(
+            [((1,),)],
+            dtype=[("a", [("b", f"{wrong_endianness}f8")])],
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_structured_native_endian_explicit(self):
+        """
+        an explicit native byte order is supported
+        """
+        native_endianness = "<" if sys.byteorder == "little" else ">"
+        array = numpy.array(
+            [(1, 2.5)],
+            dtype=[("a", f"{native_endianness}i4"), ("b", f"{native_endianness}f8")],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":2.5}]'
+        )
+
+    def test_numpy_structured_unsupported_field(self):
+        for dtype in (
+            [("a", "U4")],
+            [("a", "S4")],
+            [("a", numpy.int32, (2,))],
+            [("a", "timedelta64[s]")],
+        ):
+            array = numpy.zeros(1, dtype=dtype)
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_structured_unsupported_field_default(self):
+        array = numpy.zeros(1, dtype=[("a", "U4")])
+        assert (
+            orjson.dumps(
+                array,
+                option=orjson.OPT_SERIALIZE_NUMPY,
+                default=numpy_default,
+            )
+            == b'[[""]]'
+        )
+
+    def test_numpy_void_without_fields(self):
+        array = numpy.zeros(2, dtype="V4")
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_structured_titles(self):
+        """
+        titles are not serialized as additional fields
+        """
+        dtype = numpy.dtype([(("title", "a"), numpy.int32), ("b", numpy.int32)])
+        array = numpy.array([(1, 2)], dtype=dtype)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1,"b":2}]'
+        )
+
+
+@pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+class TestNumpyMasked:
+    def test_numpy_masked(self):
+        """
+        A masked element of numpy.ma.MaskedArray is null
+        """
+        array = numpy.ma.array([1, 2, 3], mask=[False, True, False], dtype=numpy.int64)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[1,null,3]"
+
+    def test_numpy_masked_types(self):
+        for dtype, data, expected in (
+            (numpy.float64, [1.5, 0, -2], b"[1.5,null,-2.0]"),
+            (numpy.float32, [1.5, 0, -2], b"[1.5,null,-2.0]"),
+            (numpy.int8, [1, 0, -2], b"[1,null,-2]"),
+            (numpy.uint16, [1, 0, 2], b"[1,null,2]"),
+            (numpy.bool_, [True, True, False], b"[true,null,false]"),
+        ):
+            array = numpy.ma.array(data, mask=[False, True, False], dtype=dtype)
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == expected
+
+    def test_numpy_masked_nomask(self):
+        """
+        A masked array without a mask is its data
+        """
+        array = numpy.ma.array([1.5, 2.5])
+        assert array.mask is numpy.ma.nomask
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[1.5,2.5]"
+        array = numpy.ma.array([1.5, 2.5], mask=[False, False])
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[1.5,2.5]"
+
+    def test_numpy_masked_all(self):
+        """
+        A fully masked array is null for every element
+        """
+        array = numpy.ma.masked_all((2, 3), dtype=numpy.int32)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[null,null,null],[null,null,null]]"
+        )
+        array = numpy.ma.array([1, 2], mask=True)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[null,null]"
+
+    def test_numpy_masked_d2(self):
+        array = numpy.ma.masked_greater(
+            numpy.array([[1, 5, 2], [6, 3, 7]], dtype=numpy.int64), 4
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[1,null,2],[null,3,null]]"
+        )
+
+    def test_numpy_masked_d3(self):
+        data = numpy.arange(8, dtype=numpy.int32).reshape(2, 2, 2)
+        array = numpy.ma.masked_where(data % 3 == 0, data)
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[[null,1],[2,null]],[[4,5],[null,7]]]"
+        )
+        assert (
+            orjson.loads(orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY))
+            == array.tolist()
+        )
+
+    def test_numpy_masked_row(self):
+        """
+        A row of a multi-dimensional array may be fully masked or unmasked
+        """
+        array = numpy.ma.array(
+            [[1, 2], [3, 4], [5, 6]],
+            mask=[[True, True], [False, False], [False, True]],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b"[[null,null],[3,4],[5,null]]"
+        )
+
+    def test_numpy_masked_empty(self):
+        array = numpy.ma.array([], mask=[], dtype=numpy.float64)
+        assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == b"[]"
+
+    def test_numpy_masked_dimension_zero(self):
+        for mask, expected in ((True, b"null"), (False, b"5")):
+            array = numpy.ma.array(5, mask=mask)
+            assert orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY) == expected
+
+    def test_numpy_masked_datetime64(self):
+        array = numpy.ma.array(
+            numpy.array(
+                ["2021-01-01T00:00:00", "2021-01-02T00:00:00"], "datetime64[s]"
+            ),
+            mask=[True, False],
+        )
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[null,"2021-01-02T00:00:00"]'
+        )
+
+    def test_numpy_masked_object(self):
+        array = numpy.ma.array([{"a": 1}, "b", 3], mask=[False, False, True])
+        assert (
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'[{"a":1},"b",null]'
+        )
+
+    def test_numpy_masked_nested(self):
+        array = numpy.ma.array([1, 2], mask=[True, False])
+        assert (
+            orjson.dumps({"a": [array]}, option=orjson.OPT_SERIALIZE_NUMPY)
+            == b'{"a":[[null,2]]}'
+        )
+
+    def test_numpy_masked_structured(self):
+        """
+        A structured masked array has a structured mask and is unsupported
+        """
+        array = numpy.ma.array(
+            [(1, 1.5), (2, 2.5)],
+            mask=[(False, True), (False, False)],
+            dtype=[("a", numpy.int32), ("b", numpy.float64)],
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+    def test_numpy_masked_not_enabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(numpy.ma.array([1, 2], mask=[True, False]))
+
 @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
 class NumpyEndianness:
     def test_numpy_array_dimension_zero(self):
@@ -1138,3 +1712,126 @@ class NumpyEndianness:
         array = numpy.array([0, 1, 0.4, 5.7], dtype=f"{wrong_endianness}f8")
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(array, option=orjson.OPT_SERIALIZE_NUMPY)
+
+
+def numpy_from_base64(obj):
+    return numpy.frombuffer(
+        base64.b64decode(obj["data"]), dtype=numpy.dtype(obj["dtype"])
+    ).reshape(obj["shape"])
+
+
+NUMPY_BASE64 = orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_NUMPY_BASE64
+
+
+@pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+class TestNumpyBase64:
+    def test_numpy_base64(self):
+        """
+        OPT_NUMPY_BASE64 serializes the dtype, shape, and base64 of the data
+        """
+        array = numpy.array([[1, 2, 3], [4, 5, 6]], numpy.dtype("<i4"))
+        assert orjson.loads(orjson.dumps(array, option=NUMPY_BASE64)) == {
+            "dtype": "<i4",
+            "shape": [2, 3],
+            "data": base64.b64encode(array.tobytes()).decode("ascii"),
+        }
+
+    def test_numpy_base64_big_endian(self):
+        """
+        OPT_NUMPY_BASE64 includes the byte order of a big-endian dtype
+        """
+        for dtype in (">i2", ">u4", ">i8", ">f4", ">f8"):
+            array = numpy.array([[0, 1], [2, 127]], numpy.dtype(dtype))
+            obj = orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+            assert obj["dtype"] == dtype
+            assert obj["data"] == base64.b64encode(array.tobytes()).decode("ascii")
+            roundtrip = numpy_from_base64(obj)
+            assert roundtrip.dtype == array.dtype
+            assert roundtrip.tobytes() == array.tobytes()
+            assert (roundtrip == array).all()
+
+    def test_numpy_base64_little_endian(self):
+        """
+        OPT_NUMPY_BASE64 round-trips a little-endian dtype on any system
+        """
+        array = numpy.array([1.5, -2.25, numpy.inf], numpy.dtype("<f8"))
+        roundtrip = numpy_from_base64(
+            orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+        )
+        assert roundtrip.dtype == array.dtype
+        assert roundtrip.tobytes() == array.tobytes()
+
+    def test_numpy_base64_not_byte_ordered(self):
+        """
+        A dtype without a byte order is serialized with |
+        """
+        array = numpy.array([True, False], numpy.bool_)
+        obj = orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+        assert obj["dtype"] == "|b1"
+        assert (numpy_from_base64(obj) == array).all()
+
+    def test_numpy_base64_datetime64(self):
+        """
+        OPT_NUMPY_BASE64 includes the unit of numpy.datetime64
+        """
+        array = numpy.array(["2021-01-01T00:00:00", "NaT"], numpy.dtype(">M8[s]"))
+        obj = orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+        assert obj["dtype"] == ">M8[s]"
+        assert numpy_from_base64(obj).tobytes() == array.tobytes()
+
+    def test_numpy_base64_zero_dimension(self):
+        """
+        A 0-dimensional array has an empty shape
+        """
+        array = numpy.array(5, numpy.dtype(">i4"))
+        obj = orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+        assert obj["shape"] == []
+        assert numpy_from_base64(obj) == array
+
+    def test_numpy_base64_empty(self):
+        """
+        An empty array has empty data
+        """
+        array = numpy.zeros((0, 3), numpy.dtype(">f4"))
+        obj = orjson.loads(orjson.dumps(array, option=NUMPY_BASE64))
+        assert obj == {"dtype": ">f4", "shape": [0, 3], "data": ""}
+        assert numpy_from_base64(obj).shape == (0, 3)
+
+    def test_numpy_base64_nested(self):
+        """
+        OPT_NUMPY_BASE64 applies to arrays in containers but not to scalars
+        """
+        array = numpy.array([1], numpy.dtype(">i4"))
+        assert orjson.loads(
+            orjson.dumps({"a": [array, numpy.int32(2)]}, option=NUMPY_BASE64)
+        ) == {"a": [{"dtype": ">i4", "shape": [1], "data": "AAAAAQ=="}, 2]}
+
+    def test_numpy_base64_not_contiguous(self):
+        """
+        OPT_NUMPY_BASE64 requires a C-contiguous array
+        """
+        array = numpy.arange(6, dtype=numpy.int32).reshape(2, 3).T
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(array, option=NUMPY_BASE64)
+        assert orjson.dumps(
+            array, option=NUMPY_BASE64, default=lambda obj: obj.tolist()
+        ) == orjson.dumps(array.tolist())
+
+    def test_numpy_base64_unsupported(self):
+        """
+        OPT_NUMPY_BASE64 does not support object, structured, or masked arrays
+        """
+        for array in (
+            numpy.array([1, "a"], dtype=object),
+            numpy.array([(1, 1.5)], dtype=[("a", numpy.int32), ("b", numpy.float64)]),
+            numpy.ma.array([1, 2], mask=[True, False]),
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(array, option=NUMPY_BASE64)
+
+    def test_numpy_base64_not_enabled(self):
+        """
+        OPT_NUMPY_BASE64 has no effect without OPT_SERIALIZE_NUMPY
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(numpy.array([1]), option=orjson.OPT_NUMPY_BASE64)
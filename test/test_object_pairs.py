@@ -0,0 +1,148 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import json
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_str
+
+
+class TestParseObjectPairs:
+    def test_object_pairs(self):
+        """
+        OPT_PARSE_OBJECT_PAIRS deserializes an object as a list of (key, value)
+        tuples
+        """
+        assert orjson.loads(
+            '{"b": 1, "a": [true, null], "c": "d"}',
+            option=orjson.OPT_PARSE_OBJECT_PAIRS,
+        ) == [
+            ("b", 1),
+            ("a", [True, None]),
+            ("c", "d"),
+        ]
+        val = orjson.loads('{"a": 1, "b": 2.5}', option=orjson.OPT_PARSE_OBJECT_PAIRS)
+        assert all(type(pair) is tuple for pair in val)
+
+    def test_object_pairs_duplicate_keys(self):
+        """
+        Duplicate keys are kept in document order
+        """
+        assert orjson.loads(
+            '{"a": 1, "b": 2, "a": 3}', option=orjson.OPT_PARSE_OBJECT_PAIRS
+        ) == [
+            ("a", 1),
+            ("b", 2),
+            ("a", 3),
+        ]
+        assert orjson.loads('{"a": 1, "b": 2, "a": 3}') == {"a": 3, "b": 2}
+
+    def test_object_pairs_nested(self):
+        """
+        Nested objects, including in arrays, are lists of pairs
+        """
+        assert orjson.loads(
+            '[{"a": {"b": {}}}, {}, [{"c": [{"d": 1}]}]]',
+            option=orjson.OPT_PARSE_OBJECT_PAIRS,
+        ) == [
+            [("a", [("b", [])])],
+            [],
+            [[("c", [[("d", 1)]])]],
+        ]
+
+    def test_object_pairs_empty(self):
+        """
+        An empty object is an empty list
+        """
+        assert orjson.loads("{}", option=orjson.OPT_PARSE_OBJECT_PAIRS) == []
+        assert (
+            orjson.loads('{"a": {}}', option=orjson.OPT_PARSE_OBJECT_PAIRS)
+            == [("a", [])]
+        )
+
+    def test_object_pairs_not_object(self):
+        """
+        Documents without an object are unaffected
+        """
+        for doc in ("1", '"a"', "null", "[]", '[1, "a", [2.5]]'):
+            assert (
+                orjson.loads(doc, option=orjson.OPT_PARSE_OBJECT_PAIRS)
+                == orjson.loads(doc)
+            )
+
+    def test_object_pairs_json(self):
+        """
+        OPT_PARSE_OBJECT_PAIRS is the same as json.loads() with object_pairs_hook
+        of list
+        """
+        doc = '{"a": [{"b": 1, "b": 2}, {}], "c": {"d": {"e": null}}, "f": -1.5}'
+        assert (
+            orjson.loads(doc, option=orjson.OPT_PARSE_OBJECT_PAIRS)
+            == json.loads(doc, object_pairs_hook=list)
+        )
+
+    def test_object_pairs_options(self):
+        """
+        OPT_PARSE_OBJECT_PAIRS with OPT_CACHE_KEYS, OPT_PARSE_NUMBER_AS_STRING,
+        and OPT_INT_BASE62
+        """
+        val = orjson.loads(
+            '[{"key": 1}, {"key": 2}]',
+            option=orjson.OPT_PARSE_OBJECT_PAIRS
+            | orjson.OPT_CACHE_KEYS
+            | orjson.OPT_PARSE_NUMBER_AS_STRING,
+        )
+        assert val == [[("key", "1")], [("key", "2")]]
+        assert val[0][0][0] is val[1][0][0]
+        assert (
+            orjson.loads(
+                orjson.dumps({"a": 2**63}, option=orjson.OPT_INT_BASE62),
+                option=orjson.OPT_PARSE_OBJECT_PAIRS | orjson.OPT_INT_BASE62,
+            )
+            == [("a", 2**63)]
+        )
+
+    def test_object_pairs_decoder(self):
+        """
+        orjson.Decoder accepts OPT_PARSE_OBJECT_PAIRS
+        """
+        decoder = orjson.Decoder(option=orjson.OPT_PARSE_OBJECT_PAIRS)
+        assert decoder.decode(b'{"a": {"b": 1}}') == [("a", [("b", 1)])]
+        assert orjson.Decoder().decode(b'{"a": {"b": 1}}') == {"a": {"b": 1}}
+
+    def test_object_pairs_dumps(self):
+        """
+        dumps() rejects OPT_PARSE_OBJECT_PAIRS and serializes pairs as arrays
+        """
+        pairs = orjson.loads('{"a": {"b": 1}}', option=orjson.OPT_PARSE_OBJECT_PAIRS)
+        assert orjson.dumps(pairs) == b'[["a",[["b",1]]]]'
+        with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+            orjson.dumps(pairs, option=orjson.OPT_PARSE_OBJECT_PAIRS)
+        assert orjson.OPT_PARSE_OBJECT_PAIRS > 2**64 - 1
+
+    def test_object_pairs_invalid(self):
+        """
+        Invalid documents raise JSONDecodeError
+        """
+        for doc in ('{"a": 1', '{"a" 1}', '{"a": 1,}', '{"a": {"b": }}'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(doc, option=orjson.OPT_PARSE_OBJECT_PAIRS)
+
+
+@needs_data
+class TestParseObjectPairsFixture:
+    @pytest.mark.parametrize(
+        "filename", ("twitter.json.xz", "github.json.xz", "citm_catalog.json.xz")
+    )
+    def test_object_pairs_fixture(self, filename):
+        """
+        Fixtures are the same as json.loads() with object_pairs_hook of list
+        """
+        doc = read_fixture_str(filename)
+        assert (
+            orjson.loads(doc, option=orjson.OPT_PARSE_OBJECT_PAIRS)
+            == json.loads(doc, object_pairs_hook=list)
+        )
@@ -0,0 +1,272 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+from collections import OrderedDict
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Optional:
+    a: int | None
+    b: str | None = None
+
+
+@dataclasses.dataclass(slots=True)
+class OptionalSlots:
+    a: int | None
+    b: str | None = None
+
+
+class TestOmitNone:
+    def test_omit_none_dict(self):
+        """
+        OPT_OMIT_NONE omits a dict member whose value is None
+        """
+        assert (
+            orjson.dumps({"a": 1, "b": None, "c": "c"}, option=orjson.OPT_OMIT_NONE)
+            == b'{"a":1,"c":"c"}'
+        )
+
+    def test_omit_none_dict_empty(self):
+        """
+        A dict that is empty after omission is serialized as {}
+        """
+        assert orjson.dumps({"a": None}, option=orjson.OPT_OMIT_NONE) == b"{}"
+        assert (
+            orjson.dumps({"a": {"b": None}}, option=orjson.OPT_OMIT_NONE)
+            == b'{"a":{}}'
+        )
+        assert orjson.dumps([{"a": None}], option=orjson.OPT_OMIT_NONE) == b"[{}]"
+        assert (
+            orjson.dumps({"a": None}, option=orjson.OPT_OMIT_NONE | orjson.OPT_INDENT_2)
+            == b"{}"
+        )
+
+    def test_omit_none_nested(self):
+        """
+        OPT_OMIT_NONE applies to a nested dict
+        """
+        assert (
+            orjson.dumps(
+                {"a": {"b": None, "c": {"d": None, "e": 1}}},
+                option=orjson.OPT_OMIT_NONE,
+            )
+            == b'{"a":{"c":{"e":1}}}'
+        )
+
+    def test_omit_none_list(self):
+        """
+        OPT_OMIT_NONE does not omit None in a list or tuple
+        """
+        assert (
+            orjson.dumps([None, {"a": None}, (None,)], option=orjson.OPT_OMIT_NONE)
+            == b"[null,{},[null]]"
+        )
+
+    def test_omit_none_top_level(self):
+        """
+        OPT_OMIT_NONE does not omit None that is not a member
+        """
+        assert orjson.dumps(None, option=orjson.OPT_OMIT_NONE) == b"null"
+
+    def test_omit_none_falsy(self):
+        """
+        OPT_OMIT_NONE omits only None and not other falsy values
+        """
+        assert (
+            orjson.dumps(
+                {"a": 0, "b": False, "c": "", "d": [], "e": {}, "f": 0.0},
+                option=orjson.OPT_OMIT_NONE,
+            )
+            == b'{"a":0,"b":false,"c":"","d":[],"e":{},"f":0.0}'
+        )
+
+    def test_omit_none_sort_keys(self):
+        """
+        OPT_OMIT_NONE with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"c": None, "b": 1, "a": None},
+                option=orjson.OPT_OMIT_NONE | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"b":1}'
+        )
+
+    def test_omit_none_non_str_keys(self):
+        """
+        OPT_OMIT_NONE with OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {1: None, 2: 2}, option=orjson.OPT_OMIT_NONE | orjson.OPT_NON_STR_KEYS
+            )
+            == b'{"2":2}'
+        )
+
+    def test_omit_none_unique_keys(self):
+        """
+        An omitted member is not a duplicate key with OPT_UNIQUE_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {1: None, "1": 1},
+                option=orjson.OPT_OMIT_NONE
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_UNIQUE_KEYS,
+            )
+            == b'{"1":1}'
+        )
+
+    def test_omit_none_key_transform(self):
+        """
+        OPT_OMIT_NONE with key_transform does not transform an omitted key
+        """
+        keys = []
+
+        def key_transform(key):
+            keys.append(key)
+            return key.upper()
+
+        assert (
+            orjson.dumps(
+                {"a": 1, "b": None},
+                option=orjson.OPT_OMIT_NONE,
+                key_transform=key_transform,
+            )
+            == b'{"A":1}'
+        )
+        assert keys == ["a"]
+
+    def test_omit_none_dict_subclass(self):
+        """
+        OPT_OMIT_NONE applies to a dict subclass
+        """
+        assert (
+            orjson.dumps(
+                OrderedDict([("a", None), ("b", 1)]), option=orjson.OPT_OMIT_NONE
+            )
+            == b'{"b":1}'
+        )
+
+    def test_omit_none_dataclass(self):
+        """
+        OPT_OMIT_NONE omits a dataclass field whose value is None
+        """
+        assert orjson.dumps(Optional(1), option=orjson.OPT_OMIT_NONE) == b'{"a":1}'
+        assert (
+            orjson.dumps(Optional(None, "b"), option=orjson.OPT_OMIT_NONE)
+            == b'{"b":"b"}'
+        )
+        assert orjson.dumps(Optional(None), option=orjson.OPT_OMIT_NONE) == b"{}"
+
+    def test_omit_none_dataclass_slots(self):
+        """
+        OPT_OMIT_NONE omits a field of a dataclass with __slots__
+        """
+        assert orjson.dumps(OptionalSlots(1), option=orjson.OPT_OMIT_NONE) == b'{"a":1}'
+        assert orjson.dumps(OptionalSlots(None), option=orjson.OPT_OMIT_NONE) == b"{}"
+
+    def test_omit_none_dataclass_sort_fields(self):
+        """
+        OPT_OMIT_NONE with OPT_SORT_DATACLASS_FIELDS
+        """
+        obj = [Optional(None, "b"), OptionalSlots(1)]
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_OMIT_NONE | orjson.OPT_SORT_DATACLASS_FIELDS
+            )
+            == b'[{"b":"b"},{"a":1}]'
+        )
+
+    def test_omit_none_dataclass_nested(self):
+        """
+        OPT_OMIT_NONE applies to a dict in a dataclass and a dataclass in a dict
+        """
+        assert (
+            orjson.dumps(
+                {"a": Optional({"b": None}), "c": None}, option=orjson.OPT_OMIT_NONE
+            )
+            == b'{"a":{"a":{}}}'
+        )
+
+    def test_omit_none_key_not_str(self):
+        """
+        The key of an omitted member is not serialized
+        """
+        assert orjson.dumps({1: None}, option=orjson.OPT_OMIT_NONE) == b"{}"
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({1: 1}, option=orjson.OPT_OMIT_NONE)
+
+    def test_omit_none_not_enabled(self):
+        """
+        A member whose value is None is serialized without OPT_OMIT_NONE
+        """
+        assert orjson.dumps({"a": None}) == b'{"a":null}'
+        assert orjson.dumps(Optional(None)) == b'{"a":null,"b":null}'
+
+
+class TestOmitTopLevelNone:
+    def test_omit_top_level_none(self):
+        """
+        OPT_OMIT_TOP_LEVEL_NONE serializes None as empty output
+        """
+        assert orjson.dumps(None, option=orjson.OPT_OMIT_TOP_LEVEL_NONE) == b""
+        assert orjson.dumps(None) == b"null"
+
+    def test_omit_top_level_none_nested(self):
+        """
+        OPT_OMIT_TOP_LEVEL_NONE does not apply to None in a container
+        """
+        option = orjson.OPT_OMIT_TOP_LEVEL_NONE
+        assert orjson.dumps([None], option=option) == b"[null]"
+        assert orjson.dumps({"a": None}, option=option) == b'{"a":null}'
+        assert orjson.dumps(Optional(None), option=option) == b'{"a":null,"b":null}'
+        assert orjson.dumps([], option=option) == b"[]"
+        assert orjson.dumps(0, option=option) == b"0"
+
+    def test_omit_top_level_none_default(self):
+        """
+        None returned by default for the top-level object is serialized
+        """
+        option = orjson.OPT_OMIT_TOP_LEVEL_NONE
+        assert orjson.dumps(object(), default=lambda obj: None, option=option) == (
+            b"null"
+        )
+
+    def test_omit_top_level_none_options(self):
+        """
+        The output is empty with OPT_APPEND_NEWLINE and indent, and
+        OPT_OMIT_NONE still applies to nested members
+        """
+        option = orjson.OPT_OMIT_TOP_LEVEL_NONE
+        assert orjson.dumps(None, option=option | orjson.OPT_APPEND_NEWLINE) == b""
+        assert orjson.dumps([None], option=option | orjson.OPT_APPEND_NEWLINE) == (
+            b"[null]\n"
+        )
+        assert orjson.dumps(None, option=option | orjson.OPT_INDENT_2) == b""
+        assert orjson.dumps(None, option=option, indent=4) == b""
+        assert orjson.dumps(None, option=option | orjson.OPT_OMIT_NONE) == b""
+        assert (
+            orjson.dumps({"a": None, "b": [None]}, option=option | orjson.OPT_OMIT_NONE)
+            == b'{"b":[null]}'
+        )
+
+    def test_omit_top_level_none_encoder(self):
+        """
+        OPT_OMIT_TOP_LEVEL_NONE applies to orjson.Encoder, dumps_into_buffer(), and
+        dumps_chunked()
+        """
+        option = orjson.OPT_OMIT_TOP_LEVEL_NONE
+        encoder = orjson.Encoder(option=option)
+        assert encoder.encode(None) == b""
+        assert encoder.encode([None]) == b"[null]"
+        buffer = bytearray(b"xyz")
+        assert orjson.dumps_into_buffer(None, buffer, option=option) == 0
+        assert buffer == b"xyz"
+        assert orjson.dumps_chunked(None, 2, option=option) == []
+        assert orjson.dumps_chunked(orjson.Fragment(b""), 2) == []
@@ -0,0 +1,195 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+
+import pytest
+
+import orjson
+
+OPTION = orjson.OPT_SERIALIZE_ORJSON_DEFAULT
+
+
+class Custom:
+    def __init__(self, value):
+        self.value = value
+
+    def __orjson_default__(self):
+        return {"value": self.value}
+
+
+class Both:
+    def __orjson_default__(self):
+        return "__orjson_default__"
+
+    def __json__(self):
+        return "__json__"
+
+
+class Recursive:
+    def __orjson_default__(self):
+        return self
+
+
+class Nested:
+    def __init__(self, depth):
+        self.depth = depth
+
+    def __orjson_default__(self):
+        if self.depth == 0:
+            return 0
+        return [Nested(self.depth - 1)]
+
+
+class Chain:
+    def __init__(self, depth):
+        self.depth = depth
+
+    def __orjson_default__(self):
+        if self.depth == 0:
+            return 0
+        return Chain(self.depth - 1)
+
+
+class Raises:
+    def __orjson_default__(self):
+        raise ValueError("invalid")
+
+
+class Unsupported:
+    def __orjson_default__(self):
+        return {1, 2}
+
+
+class Plain:
+    pass
+
+
+class TestOrjsonDefault:
+    def test_orjson_default(self):
+        assert orjson.dumps(Custom(1), option=OPTION) == b'{"value":1}'
+
+    def test_orjson_default_nested(self):
+        assert (
+            orjson.dumps({"a": [Custom(Custom("b"))]}, option=OPTION)
+            == b'{"a":[{"value":{"value":"b"}}]}'
+        )
+
+    def test_orjson_default_disabled(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Custom(1))
+
+    def test_orjson_default_before_default(self):
+        def default(obj):
+            raise AssertionError
+
+        assert orjson.dumps(Custom(1), default=default, option=OPTION) == b'{"value":1}'
+
+    def test_orjson_default_fallback_default(self):
+        """
+        default is called for a type without __orjson_default__
+        """
+
+        def default(obj):
+            if isinstance(obj, Plain):
+                return "default"
+            raise TypeError
+
+        assert (
+            orjson.dumps([Custom(1), Plain()], default=default, option=OPTION)
+            == b'[{"value":1},"default"]'
+        )
+
+    def test_orjson_default_returns_default_type(self):
+        """
+        default is called for an unsupported object returned by
+        __orjson_default__
+        """
+
+        def default(obj):
+            if isinstance(obj, set):
+                return sorted(obj)
+            raise TypeError
+
+        assert orjson.dumps(Unsupported(), default=default, option=OPTION) == b"[1,2]"
+
+    def test_orjson_default_returns_unsupported(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Unsupported(), option=OPTION)
+
+    def test_orjson_default_before_json_method(self):
+        """
+        __orjson_default__ takes precedence over __json__
+        """
+        assert (
+            orjson.dumps(Both(), option=OPTION | orjson.OPT_SERIALIZE_JSON_METHOD)
+            == b'"__orjson_default__"'
+        )
+        assert (
+            orjson.dumps(Both(), option=orjson.OPT_SERIALIZE_JSON_METHOD)
+            == b'"__json__"'
+        )
+
+    def test_orjson_default_type_handlers(self):
+        """
+        type_handlers of orjson.Encoder takes precedence
+        """
+        encoder = orjson.Encoder(
+            option=OPTION, type_handlers={Custom: lambda obj: "handler"}
+        )
+        assert encoder.encode(Custom(1)) == b'"handler"'
+
+    def test_orjson_default_recursive(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Recursive(), option=OPTION)
+        assert str(exc_info.value) == "default serializer exceeds recursion limit"
+
+    def test_orjson_default_chain_limit(self):
+        """
+        __orjson_default__ shares the limit of nested calls with default
+        """
+        assert orjson.dumps(Chain(200), option=OPTION) == b"0"
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Chain(300), option=OPTION)
+        assert str(exc_info.value) == "default serializer exceeds recursion limit"
+
+    def test_orjson_default_nested_limit(self):
+        """
+        A structure returned by __orjson_default__ is subject to the recursion
+        limit
+        """
+        expected = b"[" * 200 + b"0" + b"]" * 200
+        assert orjson.dumps(Nested(200), option=OPTION) == expected
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(Nested(300), option=OPTION)
+        assert str(exc_info.value) == "Recursion limit reached at $" + "[0]" * 254
+
+    def test_orjson_default_raises(self):
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": Raises()}, option=OPTION)
+        assert str(exc_info.value) == "__orjson_default__() raised an exception: Raises"
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.path == ["a"]
+
+    def test_orjson_default_instance_attribute(self):
+        """
+        __orjson_default__ is looked up on the type
+        """
+        obj = Plain()
+        obj.__orjson_default__ = lambda: "instance"  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=OPTION)
+
+    def test_orjson_default_supported_type(self):
+        """
+        __orjson_default__ is not used for types orjson serializes natively
+        """
+
+        @dataclasses.dataclass
+        class Data:
+            a: int
+
+            def __orjson_default__(self):
+                return "orjson_default"
+
+        assert orjson.dumps(Data(1), option=OPTION) == b'{"a":1}'
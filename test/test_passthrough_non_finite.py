@@ -0,0 +1,131 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import math
+
+import pytest
+
+import orjson
+
+NAN = float("nan")
+INF = float("inf")
+
+
+def non_finite_default(obj):
+    if isinstance(obj, float):
+        return str(obj)
+    raise TypeError
+
+
+class TestPassthroughNonFinite:
+    def test_passthrough_non_finite_nan(self):
+        """
+        OPT_PASSTHROUGH_NON_FINITE passes NaN to default
+        """
+        assert (
+            orjson.dumps(
+                NAN,
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE,
+                default=non_finite_default,
+            )
+            == b'"nan"'
+        )
+
+    def test_passthrough_non_finite_infinity(self):
+        """
+        OPT_PASSTHROUGH_NON_FINITE passes infinity to default
+        """
+        assert (
+            orjson.dumps(
+                [INF, -INF],
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE,
+                default=non_finite_default,
+            )
+            == b'["inf","-inf"]'
+        )
+
+    def test_passthrough_non_finite_finite(self):
+        """
+        A finite float is not passed to default
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            raise TypeError
+
+        assert (
+            orjson.dumps(
+                [0.0, -1.5, 1e308, math.ulp(0.0)],
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE,
+                default=default,
+            )
+            == b"[0.0,-1.5,1e+308,5e-324]"
+        )
+        assert calls == []
+
+    def test_passthrough_non_finite_nested(self):
+        """
+        A float that is not finite in a dict and list
+        """
+        assert (
+            orjson.dumps(
+                {"a": [1.0, NAN], "b": NAN},
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE,
+                default=non_finite_default,
+            )
+            == b'{"a":[1.0,"nan"],"b":"nan"}'
+        )
+
+    def test_passthrough_non_finite_no_default(self):
+        """
+        A float that is not finite raises without default
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([NAN], option=orjson.OPT_PASSTHROUGH_NON_FINITE)
+        assert str(exc_info.value) == "Type is not JSON serializable: float"
+        assert exc_info.value.path == [0]
+
+    def test_passthrough_non_finite_default_raises(self):
+        """
+        default raising TypeError raises JSONEncodeError
+        """
+
+        def default(obj):
+            raise TypeError
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(INF, option=orjson.OPT_PASSTHROUGH_NON_FINITE, default=default)
+
+    def test_passthrough_non_finite_canonical(self):
+        """
+        OPT_PASSTHROUGH_NON_FINITE passes NaN to default with OPT_CANONICAL
+        """
+        assert (
+            orjson.dumps(
+                [NAN],
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE | orjson.OPT_CANONICAL,
+                default=non_finite_default,
+            )
+            == b'["nan"]'
+        )
+
+    def test_passthrough_non_finite_dict_key(self):
+        """
+        A dict key that is not finite is not passed to default
+        """
+        assert (
+            orjson.dumps(
+                {NAN: 1},
+                option=orjson.OPT_PASSTHROUGH_NON_FINITE | orjson.OPT_NON_STR_KEYS,
+                default=non_finite_default,
+            )
+            == b'{"null":1}'
+        )
+
+    def test_passthrough_non_finite_not_enabled(self):
+        """
+        A float that is not finite is serialized as null without
+        OPT_PASSTHROUGH_NON_FINITE
+        """
+        assert orjson.dumps([NAN, INF], default=non_finite_default) == b"[null,null]"
@@ -0,0 +1,277 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+
+import pytest
+
+import orjson
+
+try:
+    import pydantic
+except ImportError:
+    pydantic = None  # type: ignore
+
+if pydantic is not None:
+
+    class Member(pydantic.BaseModel):
+        id: int
+        name: str
+        active: bool = True
+
+    class Aliased(pydantic.BaseModel):
+        display_name: str = pydantic.Field(alias="displayName")
+        created_at: int = pydantic.Field(serialization_alias="createdAt")
+        both: int = pydantic.Field(alias="a", serialization_alias="b")
+
+    class Team(pydantic.BaseModel):
+        name: str
+        members: list[Member]
+        lead: Member | None = None
+
+    class Admin(Member):
+        level: int = 1
+
+    class Extra(pydantic.BaseModel):
+        model_config = pydantic.ConfigDict(extra="allow")
+
+        id: int
+
+    class Root(pydantic.RootModel[list[int]]):
+        pass
+
+    @pydantic.dataclasses.dataclass
+    class PydanticDataclass:
+        id: int
+
+
+@pytest.mark.skipif(pydantic is None, reason="pydantic not installed")
+class TestPydantic:
+    def test_pydantic(self):
+        """
+        OPT_SERIALIZE_PYDANTIC serializes a model as an object of its fields
+        """
+        assert (
+            orjson.dumps(Member(id=1, name="a"), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"id":1,"name":"a","active":true}'
+        )
+
+    def test_pydantic_model_dump(self):
+        """
+        OPT_SERIALIZE_PYDANTIC is the same as model_dump(by_alias=True)
+        """
+        obj = Team(
+            name="t",
+            members=[Member(id=1, name="a"), Member(id=2, name="b", active=False)],
+        )
+        assert (
+            orjson.loads(orjson.dumps(obj, option=orjson.OPT_SERIALIZE_PYDANTIC))
+            == obj.model_dump(by_alias=True)
+        )
+
+    def test_pydantic_not_enabled(self):
+        """
+        A model is not serialized without OPT_SERIALIZE_PYDANTIC
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Member(id=1, name="a"))
+
+    def test_pydantic_alias(self):
+        """
+        A field is keyed by its serialization_alias, else its alias
+        """
+        obj = Aliased(displayName="a", created_at=1, a=2)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"displayName":"a","createdAt":1,"b":2}'
+        )
+        assert (
+            orjson.loads(orjson.dumps(obj, option=orjson.OPT_SERIALIZE_PYDANTIC))
+            == obj.model_dump(by_alias=True)
+        )
+
+    def test_pydantic_alias_generator(self):
+        """
+        A field is keyed by an alias from alias_generator
+        """
+
+        class Generated(pydantic.BaseModel):
+            model_config = pydantic.ConfigDict(
+                alias_generator=lambda name: name.upper()
+            )
+
+            id: int
+
+        assert (
+            orjson.dumps(Generated(ID=1), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"ID":1}'
+        )
+
+    def test_pydantic_nested(self):
+        """
+        A model in a model, list, and dict
+        """
+        member = Member(id=1, name="a")
+        obj = Team(name="t", members=[member], lead=member)
+        assert (
+            orjson.dumps(
+                {"team": obj, "members": [member]}, option=orjson.OPT_SERIALIZE_PYDANTIC
+            )
+            == b'{"team":{"name":"t","members":[{"id":1,"name":"a","active":true}],'
+            b'"lead":{"id":1,"name":"a","active":true}},'
+            b'"members":[{"id":1,"name":"a","active":true}]}'
+        )
+
+    def test_pydantic_subclass(self):
+        """
+        Fields of a base model are first
+        """
+        assert (
+            orjson.dumps(
+                Admin(level=2, name="a", id=1), option=orjson.OPT_SERIALIZE_PYDANTIC
+            )
+            == b'{"id":1,"name":"a","active":true,"level":2}'
+        )
+
+    def test_pydantic_extra(self):
+        """
+        Extra fields follow declared fields
+        """
+        assert (
+            orjson.dumps(Extra(id=1, other="a"), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"id":1,"other":"a"}'
+        )
+
+    def test_pydantic_exclude_unset(self):
+        """
+        OPT_PYDANTIC_EXCLUDE_UNSET omits fields that were not set
+        """
+        obj = Member(id=1, name="a")
+        option = orjson.OPT_SERIALIZE_PYDANTIC | orjson.OPT_PYDANTIC_EXCLUDE_UNSET
+        assert orjson.dumps(obj, option=option) == b'{"id":1,"name":"a"}'
+        assert orjson.loads(orjson.dumps(obj, option=option)) == obj.model_dump(
+            exclude_unset=True
+        )
+        obj.active = False
+        assert (
+            orjson.dumps(obj, option=option) == b'{"id":1,"name":"a","active":false}'
+        )
+
+    def test_pydantic_exclude_unset_not_enabled(self):
+        """
+        OPT_PYDANTIC_EXCLUDE_UNSET has no effect without OPT_SERIALIZE_PYDANTIC
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                Member(id=1, name="a"),
+                option=orjson.OPT_PYDANTIC_EXCLUDE_UNSET,
+            )
+
+    def test_pydantic_root_model(self):
+        """
+        A RootModel is serialized as its root
+        """
+        assert (
+            orjson.dumps(Root([1, 2]), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b"[1,2]"
+        )
+        assert (
+            orjson.dumps({"a": Root([])}, option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"a":[]}'
+        )
+
+    def test_pydantic_dataclass(self):
+        """
+        A pydantic dataclass is serialized as a dataclass
+        """
+        assert orjson.dumps(PydanticDataclass(id=1)) == b'{"id":1}'
+        assert (
+            orjson.dumps(PydanticDataclass(id=1), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"id":1}'
+        )
+
+    def test_pydantic_default(self):
+        """
+        A field of a type orjson does not serialize is passed to default
+        """
+
+        class Value(pydantic.BaseModel):
+            model_config = pydantic.ConfigDict(arbitrary_types_allowed=True)
+
+            value: object
+
+        class Custom:
+            pass
+
+        assert (
+            orjson.dumps(
+                Value(value=Custom()),
+                option=orjson.OPT_SERIALIZE_PYDANTIC,
+                default=lambda _: "custom",
+            )
+            == b'{"value":"custom"}'
+        )
+
+    def test_pydantic_error_path(self):
+        """
+        An error in a field includes its key in the path
+        """
+
+        class Value(pydantic.BaseModel):
+            value: object
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                [Value(value={"a": {1}})], option=orjson.OPT_SERIALIZE_PYDANTIC
+            )
+        assert exc_info.value.path == [0, "value", "a"]
+
+    def test_pydantic_dict_key(self):
+        """
+        A model is not a valid dict key
+        """
+
+        class Key(pydantic.BaseModel):
+            model_config = pydantic.ConfigDict(frozen=True)
+
+            id: int
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Key(id=1): 1},
+                option=orjson.OPT_SERIALIZE_PYDANTIC | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_pydantic_annotated(self):
+        """
+        OPT_SERIALIZE_PYDANTIC takes precedence over OPT_SERIALIZE_ANNOTATED
+        """
+        obj = Aliased(displayName="a", created_at=1, a=2)
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_PYDANTIC | orjson.OPT_SERIALIZE_ANNOTATED,
+            )
+            == b'{"displayName":"a","createdAt":1,"b":2}'
+        )
+
+
+class TestPydanticPlain:
+    def test_pydantic_plain_class(self):
+        """
+        OPT_SERIALIZE_PYDANTIC does not change serializing other classes
+        """
+
+        @dataclasses.dataclass
+        class Point:
+            x: int
+
+        class Other:
+            pass
+
+        assert (
+            orjson.dumps(Point(1), option=orjson.OPT_SERIALIZE_PYDANTIC)
+            == b'{"x":1}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Other(), option=orjson.OPT_SERIALIZE_PYDANTIC)
@@ -0,0 +1,208 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+
+class TestRange:
+    def test_range(self):
+        """
+        OPT_SERIALIZE_RANGE serializes a range as start, stop, and step
+        """
+        assert (
+            orjson.dumps(range(5), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":0,"stop":5,"step":1}'
+        )
+        assert (
+            orjson.dumps(range(2, 10, 3), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":2,"stop":10,"step":3}'
+        )
+
+    def test_range_not_enabled(self):
+        """
+        A range is not serialized without OPT_SERIALIZE_RANGE
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(1))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(1), option=orjson.OPT_RANGE_AS_ARRAY)
+
+    def test_range_negative_step(self):
+        """
+        A range with a negative step
+        """
+        assert (
+            orjson.dumps(range(10, -5, -3), option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":10,"stop":-5,"step":-3}'
+        )
+        assert (
+            orjson.dumps(
+                range(10, -5, -3),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY,
+            )
+            == b"[10,7,4,1,-2]"
+        )
+
+    def test_range_empty(self):
+        """
+        An empty range is serialized as its arguments or an empty array
+        """
+        for obj in (range(0), range(5, 5), range(5, 0), range(0, 5, -1)):
+            assert orjson.loads(
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_RANGE)
+            ) == {
+                "start": obj.start,
+                "stop": obj.stop,
+                "step": obj.step,
+            }
+            assert (
+                orjson.dumps(
+                    obj, option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY
+                )
+                == b"[]"
+            )
+
+    def test_range_large(self):
+        """
+        The object form does not depend on the length of the range
+        """
+        obj = range(-(2**63), 2**63 - 1, 7)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_RANGE)
+            == b'{"start":-9223372036854775808,"stop":9223372036854775807,"step":7}'
+        )
+
+    def test_range_big_int(self):
+        """
+        A range with an int that does not fit in 64 bits raises
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(range(2**64), option=orjson.OPT_SERIALIZE_RANGE)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                range(2**64, 2**64 + 1),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY,
+            )
+
+    def test_range_as_array(self):
+        """
+        OPT_RANGE_AS_ARRAY serializes a range as an array of its values
+        """
+        assert (
+            orjson.dumps(
+                range(5), option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY
+            )
+            == b"[0,1,2,3,4]"
+        )
+        obj = range(-1000, 1000, 7)
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY
+            )
+            == orjson.dumps(list(obj))
+        )
+
+    def test_range_strict_integer(self):
+        """
+        OPT_STRICT_INTEGER applies to the values of a range
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                range(2**53 + 1),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_STRICT_INTEGER,
+            )
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                range(2**53 - 1, 2**53 + 2),
+                option=orjson.OPT_SERIALIZE_RANGE
+                | orjson.OPT_RANGE_AS_ARRAY
+                | orjson.OPT_STRICT_INTEGER,
+            )
+        assert exc_info.value.path == [1]
+
+    def test_range_nested(self):
+        """
+        A range in a list and dict
+        """
+        assert (
+            orjson.dumps(
+                [range(1, 2), {"a": range(3)}], option=orjson.OPT_SERIALIZE_RANGE
+            )
+            == b'[{"start":1,"stop":2,"step":1},{"a":{"start":0,"stop":3,"step":1}}]'
+        )
+        assert (
+            orjson.dumps(
+                [range(1, 2), {"a": range(3)}],
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_RANGE_AS_ARRAY,
+            )
+            == b'[[1],{"a":[0,1,2]}]'
+        )
+
+    def test_range_indent(self):
+        """
+        OPT_INDENT_2 applies to a range
+        """
+        assert (
+            orjson.dumps(
+                {"a": range(2)}, option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "a": {\n    "start": 0,\n    "stop": 2,\n    "step": 1\n  }\n}'
+        )
+        assert (
+            orjson.dumps(
+                range(2),
+                option=orjson.OPT_SERIALIZE_RANGE
+                | orjson.OPT_RANGE_AS_ARRAY
+                | orjson.OPT_INDENT_2,
+            )
+            == b"[\n  0,\n  1\n]"
+        )
+
+    def test_range_dict_key(self):
+        """
+        A range is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {range(1): 1},
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_range_default(self):
+        """
+        OPT_SERIALIZE_RANGE is checked before default
+        """
+        assert (
+            orjson.dumps(range(1), option=orjson.OPT_SERIALIZE_RANGE, default=list)
+            == b'{"start":0,"stop":1,"step":1}'
+        )
+        assert orjson.dumps(range(2), default=list) == b"[0,1]"
+
+    def test_range_default_first(self):
+        """
+        OPT_DEFAULT_FIRST calls default before OPT_SERIALIZE_RANGE
+        """
+        assert (
+            orjson.dumps(
+                range(2),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_DEFAULT_FIRST,
+                default=list,
+            )
+            == b"[0,1]"
+        )
+
+    def test_range_iterator(self):
+        """
+        OPT_SERIALIZE_RANGE does not apply to an iterator of a range
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(iter(range(2)), option=orjson.OPT_SERIALIZE_RANGE)
+        assert (
+            orjson.dumps(
+                iter(range(2)),
+                option=orjson.OPT_SERIALIZE_RANGE | orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b"[0,1]"
+        )
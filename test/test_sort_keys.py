@@ -1,5 +1,12 @@
 # SPDX-License-Identifier: MPL-2.0
-# Copyright ijl (2020-2025)
+# Copyright ijl (2020-2026)
+
+import os
+import random
+import subprocess
+import sys
+
+import pytest
 
 import orjson
 
@@ -50,3 +57,244 @@ class TestDictSortKeys:
         serialized = orjson.dumps(obj, option=orjson.OPT_SORT_KEYS)
         val = orjson.loads(serialized)
         assert list(val.keys()) == sorted(list(val.keys()))
+
+
+class TestDictSortKeysByHash:
+    def test_sort_keys_by_hash(self):
+        """
+        OPT_SORT_KEYS_BY_HASH order is fixed
+        """
+        obj = {"b": 1, "c": 2, "a": 3}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_HASH)
+            == b'{"b":1,"c":2,"a":3}'
+        )
+        obj = {key: idx for idx, key in enumerate(("a", "b", "c", "d", "e", "key"))}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_HASH)
+            == b'{"d":3,"b":1,"c":2,"key":5,"e":4,"a":0}'
+        )
+
+    def test_sort_keys_by_hash_insertion_order(self):
+        """
+        OPT_SORT_KEYS_BY_HASH does not depend on insertion order
+        """
+        keys = [f"key{idx}" for idx in range(100)]
+        expected = orjson.dumps(
+            dict.fromkeys(keys, 0), option=orjson.OPT_SORT_KEYS_BY_HASH
+        )
+        rng = random.Random(0)
+        for _ in range(10):
+            rng.shuffle(keys)
+            serialized = orjson.dumps(
+                dict.fromkeys(keys, 0), option=orjson.OPT_SORT_KEYS_BY_HASH
+            )
+            assert serialized == expected
+
+    def test_sort_keys_by_hash_not_sorted(self):
+        """
+        OPT_SORT_KEYS_BY_HASH is not lexical order
+        """
+        keys = [f"key{idx}" for idx in range(100)]
+        val = orjson.loads(
+            orjson.dumps(dict.fromkeys(keys, 0), option=orjson.OPT_SORT_KEYS_BY_HASH)
+        )
+        assert sorted(val) == sorted(keys)
+        assert list(val) != sorted(keys)
+
+    def test_sort_keys_by_hash_across_runs(self):
+        """
+        OPT_SORT_KEYS_BY_HASH does not depend on PYTHONHASHSEED
+        """
+        code = (
+            "import orjson, sys; "
+            "obj = {f'key{idx}': [idx, {'a': 1, 'b': 2, 'c': 3}] "
+            "for idx in range(50)}; "
+            "option = orjson.OPT_SORT_KEYS_BY_HASH; "
+            "sys.stdout.buffer.write(orjson.dumps(obj, option=option))"
+        )
+        outputs = set()
+        for seed in ("0", "1", "12345"):
+            proc = subprocess.run(
+                [sys.executable, "-c", code],
+                check=True,
+                capture_output=True,
+                env={**os.environ, "PYTHONHASHSEED": seed},
+            )
+            outputs.add(proc.stdout)
+        assert len(outputs) == 1
+        obj = {f"key{idx}": [idx, {"a": 1, "b": 2, "c": 3}] for idx in range(50)}
+        assert outputs == {orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_HASH)}
+
+    def test_sort_keys_by_hash_nested(self):
+        """
+        OPT_SORT_KEYS_BY_HASH applies to nested dict
+        """
+        assert (
+            orjson.dumps(
+                {"x": {"b": 1, "c": 2, "a": 3}}, option=orjson.OPT_SORT_KEYS_BY_HASH
+            )
+            == b'{"x":{"b":1,"c":2,"a":3}}'
+        )
+        assert (
+            orjson.dumps(
+                [{"a": 3, "c": 2, "b": 1}], option=orjson.OPT_SORT_KEYS_BY_HASH
+            )
+            == b'[{"b":1,"c":2,"a":3}]'
+        )
+
+    def test_sort_keys_by_hash_with_sort_keys(self):
+        """
+        OPT_SORT_KEYS_BY_HASH takes precedence over OPT_SORT_KEYS
+        """
+        obj = {"a": 3, "c": 2, "b": 1}
+        assert orjson.dumps(
+            obj, option=orjson.OPT_SORT_KEYS_BY_HASH | orjson.OPT_SORT_KEYS
+        ) == orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_HASH)
+
+    def test_sort_keys_by_hash_non_str_keys(self):
+        """
+        OPT_SORT_KEYS_BY_HASH with OPT_NON_STR_KEYS orders by the key as str
+        """
+        assert orjson.dumps(
+            {"a": 3, "c": 2, "b": 1},
+            option=orjson.OPT_SORT_KEYS_BY_HASH | orjson.OPT_NON_STR_KEYS,
+        ) == orjson.dumps(
+            {"a": 3, "b": 1, "c": 2},
+            option=orjson.OPT_SORT_KEYS_BY_HASH,
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {1: 1, "1": 2},
+                option=orjson.OPT_SORT_KEYS_BY_HASH
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_UNIQUE_KEYS,
+            )
+
+    def test_sort_keys_by_hash_canonical(self):
+        """
+        OPT_SORT_KEYS_BY_HASH cannot be combined with OPT_CANONICAL
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({}, option=orjson.OPT_SORT_KEYS_BY_HASH | orjson.OPT_CANONICAL)
+
+
+class TestDictSortKeysByValue:
+    def test_sort_keys_by_value(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE orders a dict by its values ascending
+        """
+        scores = {"alice": 30, "bob": 10, "carol": 50, "dave": 20}
+        assert (
+            orjson.dumps(scores, option=orjson.OPT_SORT_KEYS_BY_VALUE)
+            == b'{"bob":10,"dave":20,"alice":30,"carol":50}'
+        )
+
+    def test_sort_keys_by_value_stable(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE keeps the insertion order of equal values
+        """
+        scores = {"e": 2, "d": 1, "c": 2.0, "b": 1, "a": 2}
+        assert (
+            orjson.dumps(scores, option=orjson.OPT_SORT_KEYS_BY_VALUE)
+            == b'{"d":1,"b":1,"e":2,"c":2.0,"a":2}'
+        )
+        keys = [f"key{idx}" for idx in range(100)]
+        obj = {key: idx % 7 for idx, key in enumerate(keys)}
+        val = orjson.loads(orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_VALUE))
+        assert list(val.items()) == sorted(obj.items(), key=lambda item: item[1])
+
+    def test_sort_keys_by_value_types(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE compares values with <
+        """
+        assert (
+            orjson.dumps(
+                {"a": "b", "b": "a", "c": "ab"}, option=orjson.OPT_SORT_KEYS_BY_VALUE
+            )
+            == b'{"b":"a","c":"ab","a":"b"}'
+        )
+        assert (
+            orjson.dumps(
+                {"a": [2], "b": [1, 2], "c": [1]},
+                option=orjson.OPT_SORT_KEYS_BY_VALUE,
+            )
+            == b'{"c":[1],"b":[1,2],"a":[2]}'
+        )
+
+    def test_sort_keys_by_value_nested(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE applies to nested dict
+        """
+        assert (
+            orjson.dumps(
+                [{"x": {"b": 2, "a": 3, "c": 1}}], option=orjson.OPT_SORT_KEYS_BY_VALUE
+            )
+            == b'[{"x":{"c":1,"b":2,"a":3}}]'
+        )
+
+    def test_sort_keys_by_value_incomparable(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE raises if values cannot be compared
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": 1, "b": "1"}, option=orjson.OPT_SORT_KEYS_BY_VALUE)
+        assert isinstance(exc_info.value.__cause__, TypeError)
+        assert str(exc_info.value).startswith(
+            "comparing dict values raised an exception"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({"a": {}, "b": {}}, option=orjson.OPT_SORT_KEYS_BY_VALUE)
+
+    def test_sort_keys_by_value_partial_order(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE with values that are not totally ordered
+        """
+        obj = {str(idx): val for idx, val in enumerate((3, float("nan"), 1, 2) * 10)}
+        val = orjson.loads(orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_VALUE))
+        assert sorted(val) == sorted(obj)
+        obj = {str(idx): {idx % 3, idx % 5} for idx in range(30)}
+        val = orjson.loads(
+            orjson.dumps(obj, option=orjson.OPT_SORT_KEYS_BY_VALUE, default=sorted)
+        )
+        assert sorted(val) == sorted(obj)
+
+    def test_sort_keys_by_value_non_str_keys(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE with OPT_NON_STR_KEYS and OPT_UNIQUE_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {1: 2, "a": 1, None: 3},
+                option=orjson.OPT_SORT_KEYS_BY_VALUE | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"a":1,"1":2,"null":3}'
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {1: 1, "a": 2, "1": 3},
+                option=orjson.OPT_SORT_KEYS_BY_VALUE
+                | orjson.OPT_NON_STR_KEYS
+                | orjson.OPT_UNIQUE_KEYS,
+            )
+
+    def test_sort_keys_by_value_with_sort_keys(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE takes precedence over OPT_SORT_KEYS
+        """
+        obj = {"a": 3, "c": 2, "b": 1}
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_SORT_KEYS_BY_VALUE | orjson.OPT_SORT_KEYS
+            )
+            == b'{"b":1,"c":2,"a":3}'
+        )
+
+    def test_sort_keys_by_value_incompatible(self):
+        """
+        OPT_SORT_KEYS_BY_VALUE cannot be combined with OPT_SORT_KEYS_BY_HASH or
+        OPT_CANONICAL
+        """
+        for option in (orjson.OPT_SORT_KEYS_BY_HASH, orjson.OPT_CANONICAL):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps({}, option=orjson.OPT_SORT_KEYS_BY_VALUE | option)
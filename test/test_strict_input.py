@@ -0,0 +1,74 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import io
+
+import pytest
+
+import orjson
+
+
+class TestStrictInput:
+    def test_strict_input(self):
+        """
+        OPT_STRICT_INPUT deserializes a document without leading whitespace
+        """
+        for val, expected in ((b"[1]", [1]), (b'"a"', "a"), (b"{} \n", {})):
+            assert orjson.loads(val, option=orjson.OPT_STRICT_INPUT) == expected
+
+    def test_strict_input_bom(self):
+        """
+        OPT_STRICT_INPUT raises on a UTF-8 byte order mark
+        """
+        for val in (b"\xef\xbb\xbf[1]", "\ufeff[1]", b"\xef\xbb\xbf"):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(val, option=orjson.OPT_STRICT_INPUT)
+            assert str(exc_info.value).startswith(
+                "Input begins with a UTF-8 byte order mark: 0xef:"
+            )
+            assert exc_info.value.pos == 0
+            assert exc_info.value.code == 6
+
+    def test_strict_input_whitespace(self):
+        """
+        OPT_STRICT_INPUT raises on leading whitespace and includes the byte
+        """
+        for val, byte in (
+            (b" []", "0x20"),
+            (b"\t[]", "0x09"),
+            (b"\n[]", "0x0a"),
+            (b"\r\n[]", "0x0d"),
+            (" {}", "0x20"),
+        ):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads(val, option=orjson.OPT_STRICT_INPUT)
+            assert str(exc_info.value).startswith(
+                f"Input begins with whitespace: {byte}:"
+            )
+            assert exc_info.value.code == 6
+
+    def test_strict_input_buffer(self):
+        """
+        OPT_STRICT_INPUT applies to bytearray and memoryview
+        """
+        for val in (bytearray(b" []"), memoryview(b" []")):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(val, option=orjson.OPT_STRICT_INPUT)
+
+    def test_strict_input_load(self):
+        """
+        OPT_STRICT_INPUT applies to load()
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.load(io.BytesIO(b" []"), option=orjson.OPT_STRICT_INPUT)
+        assert orjson.load(io.BytesIO(b"[]"), option=orjson.OPT_STRICT_INPUT) == []
+
+    def test_strict_input_not_enabled(self):
+        """
+        Leading whitespace is skipped and a byte order mark is invalid without
+        OPT_STRICT_INPUT
+        """
+        assert orjson.loads(b" \t\r\n[1]") == [1]
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads(b"\xef\xbb\xbf[1]")
+        assert str(exc_info.value).startswith("unexpected character")
@@ -95,6 +95,89 @@ class TestSubclass:
             orjson.dumps(obj)
 
 
+class SubOrderedDict(collections.OrderedDict):
+    def __getitem__(self, key):
+        raise AssertionError("__getitem__ called")
+
+
+class TestSubclassCollections:
+    def test_ordereddict(self):
+        obj = collections.OrderedDict([("b", 1), ("a", 2)])
+        assert orjson.dumps(obj) == b'{"b":1,"a":2}'
+
+    def test_ordereddict_move_to_end(self):
+        obj = collections.OrderedDict([("a", 1), ("b", 2), ("c", 3)])
+        obj.move_to_end("a")
+        assert orjson.dumps(obj) == b'{"b":2,"c":3,"a":1}'
+        obj.move_to_end("c", last=False)
+        assert orjson.dumps(obj) == b'{"c":3,"b":2,"a":1}'
+        assert orjson.dumps(obj) == json.dumps(obj, separators=(",", ":")).encode()
+
+    def test_ordereddict_nested(self):
+        inner = collections.OrderedDict([("y", 1), ("x", 2)])
+        inner.move_to_end("y")
+        obj = {"a": [inner], "b": collections.OrderedDict([("c", inner)])}
+        assert orjson.dumps(obj) == b'{"a":[{"x":2,"y":1}],"b":{"c":{"x":2,"y":1}}}'
+
+    def test_ordereddict_sort_keys(self):
+        obj = collections.OrderedDict([("b", 1), ("c", 2), ("a", 3)])
+        obj.move_to_end("b")
+        assert (
+            orjson.dumps([obj], option=orjson.OPT_SORT_KEYS)
+            == b'[{"a":3,"b":1,"c":2}]'
+        )
+
+    def test_ordereddict_non_str_keys(self):
+        obj = collections.OrderedDict([(1, "a"), (2, "b")])
+        obj.move_to_end(1)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS) == b'{"2":"b","1":"a"}'
+        )
+
+    def test_ordereddict_subclass(self):
+        obj = SubOrderedDict([("a", 1), ("b", 2)])
+        obj.move_to_end("a")
+        assert orjson.dumps(obj) == b'{"b":2,"a":1}'
+
+    def test_ordereddict_empty(self):
+        assert orjson.dumps(collections.OrderedDict()) == b"{}"
+
+    def test_ordereddict_circular(self):
+        obj = collections.OrderedDict()
+        obj["obj"] = obj
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj)
+
+    def test_defaultdict(self):
+        obj = collections.defaultdict(list)
+        obj["b"].append(1)
+        obj["a"].append(2)
+        assert orjson.dumps(obj) == b'{"b":[1],"a":[2]}'
+
+    def test_defaultdict_factory_not_called(self):
+        calls = []
+
+        def factory():
+            calls.append(None)
+            return 0
+
+        obj = collections.defaultdict(factory, {"a": 1})
+        assert orjson.dumps(obj) == b'{"a":1}'
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == b'{"a":1}'
+        assert calls == []
+        assert dict(obj) == {"a": 1}
+
+    def test_defaultdict_nested(self):
+        obj = collections.defaultdict(lambda: collections.defaultdict(int))
+        obj["b"]["y"] += 1
+        obj["a"]["x"] += 2
+        assert orjson.dumps([obj]) == b'[{"b":{"y":1},"a":{"x":2}}]'
+
+    def test_defaultdict_sort_keys(self):
+        obj = collections.defaultdict(int, {"b": 1, "c": 2, "a": 3})
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == b'{"a":3,"b":1,"c":2}'
+
+
 class TestSubclassPassthrough:
     def test_subclass_str(self):
         with pytest.raises(orjson.JSONEncodeError):
@@ -111,3 +194,15 @@ class TestSubclassPassthrough:
     def test_subclass_list(self):
         with pytest.raises(orjson.JSONEncodeError):
             orjson.dumps(SubList(["a", "b"]), option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+
+    def test_subclass_ordereddict(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                collections.OrderedDict(a=1), option=orjson.OPT_PASSTHROUGH_SUBCLASS
+            )
+
+    def test_subclass_defaultdict(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                collections.defaultdict(int), option=orjson.OPT_PASSTHROUGH_SUBCLASS
+            )
@@ -0,0 +1,219 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import datetime
+
+import pytest
+
+import orjson
+
+
+class SubTimedelta(datetime.timedelta):
+    pass
+
+
+class TestTimedelta:
+    def test_timedelta(self):
+        """
+        OPT_SERIALIZE_TIMEDELTA serializes a timedelta as a number of seconds
+        """
+        assert (
+            orjson.dumps(
+                datetime.timedelta(days=1, hours=2, minutes=3, seconds=4),
+                option=orjson.OPT_SERIALIZE_TIMEDELTA,
+            )
+            == b"93784.0"
+        )
+        assert (
+            orjson.dumps(
+                datetime.timedelta(seconds=1.5), option=orjson.OPT_SERIALIZE_TIMEDELTA
+            )
+            == b"1.5"
+        )
+
+    def test_timedelta_not_enabled(self):
+        """
+        A timedelta is not serialized without OPT_SERIALIZE_TIMEDELTA
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(datetime.timedelta(1))
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(datetime.timedelta(1), option=orjson.OPT_TIMEDELTA_ISO8601)
+
+    def test_timedelta_zero(self):
+        """
+        A zero timedelta
+        """
+        assert (
+            orjson.dumps(datetime.timedelta(0), option=orjson.OPT_SERIALIZE_TIMEDELTA)
+            == b"0.0"
+        )
+        assert (
+            orjson.dumps(
+                datetime.timedelta(0),
+                option=orjson.OPT_SERIALIZE_TIMEDELTA | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'"PT0S"'
+        )
+
+    def test_timedelta_sub_second(self):
+        """
+        A timedelta of less than a second has up to six fractional digits
+        without trailing zeros
+        """
+        for val, expected, expected_iso in (
+            (datetime.timedelta(microseconds=1), b"0.000001", b'"PT0.000001S"'),
+            (datetime.timedelta(microseconds=10), b"0.00001", b'"PT0.00001S"'),
+            (datetime.timedelta(milliseconds=500), b"0.5", b'"PT0.5S"'),
+            (datetime.timedelta(microseconds=999999), b"0.999999", b'"PT0.999999S"'),
+            (datetime.timedelta(microseconds=123450), b"0.12345", b'"PT0.12345S"'),
+        ):
+            assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_TIMEDELTA) == expected
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_SERIALIZE_TIMEDELTA
+                    | orjson.OPT_TIMEDELTA_ISO8601,
+                )
+                == expected_iso
+            )
+
+    def test_timedelta_negative(self):
+        """
+        A negative timedelta is normalized by Python to negative days and
+        positive seconds, and is serialized as the negative duration
+        """
+        for val, expected, expected_iso in (
+            (datetime.timedelta(seconds=-1), b"-1.0", b'"-PT1S"'),
+            (datetime.timedelta(microseconds=-1), b"-0.000001", b'"-PT0.000001S"'),
+            (datetime.timedelta(days=-1), b"-86400.0", b'"-P1D"'),
+            (datetime.timedelta(days=-1, hours=1), b"-82800.0", b'"-PT23H"'),
+            (
+                -datetime.timedelta(days=1, hours=2, seconds=4.5),
+                b"-93604.5",
+                b'"-P1DT2H4.5S"',
+            ),
+        ):
+            assert orjson.dumps(val, option=orjson.OPT_SERIALIZE_TIMEDELTA) == expected
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_SERIALIZE_TIMEDELTA
+                    | orjson.OPT_TIMEDELTA_ISO8601,
+                )
+                == expected_iso
+            )
+
+    def test_timedelta_total_seconds(self):
+        """
+        loads() of the number of seconds is total_seconds()
+        """
+        for val in (
+            datetime.timedelta(0),
+            datetime.timedelta(microseconds=1),
+            datetime.timedelta(microseconds=-1),
+            datetime.timedelta(days=3, seconds=0.1),
+            datetime.timedelta(days=-3, seconds=0.1),
+            datetime.timedelta.max,
+            datetime.timedelta.min,
+        ):
+            assert (
+                orjson.loads(orjson.dumps(val, option=orjson.OPT_SERIALIZE_TIMEDELTA))
+                == val.total_seconds()
+            )
+
+    def test_timedelta_iso8601(self):
+        """
+        OPT_TIMEDELTA_ISO8601 omits components that are zero
+        """
+        for val, expected in (
+            (datetime.timedelta(days=2), b'"P2D"'),
+            (datetime.timedelta(hours=1), b'"PT1H"'),
+            (datetime.timedelta(minutes=1), b'"PT1M"'),
+            (datetime.timedelta(seconds=1), b'"PT1S"'),
+            (datetime.timedelta(days=1, seconds=1), b'"P1DT1S"'),
+            (datetime.timedelta(hours=1, microseconds=10), b'"PT1H0.00001S"'),
+            (
+                datetime.timedelta(days=1, hours=2, minutes=3, seconds=4.5),
+                b'"P1DT2H3M4.5S"',
+            ),
+        ):
+            assert (
+                orjson.dumps(
+                    val,
+                    option=orjson.OPT_SERIALIZE_TIMEDELTA
+                    | orjson.OPT_TIMEDELTA_ISO8601,
+                )
+                == expected
+            )
+
+    def test_timedelta_min_max(self):
+        """
+        timedelta.min and timedelta.max
+        """
+        assert (
+            orjson.dumps(datetime.timedelta.max, option=orjson.OPT_SERIALIZE_TIMEDELTA)
+            == b"86399999999999.999999"
+        )
+        assert (
+            orjson.dumps(datetime.timedelta.min, option=orjson.OPT_SERIALIZE_TIMEDELTA)
+            == b"-86399999913600.0"
+        )
+        assert (
+            orjson.dumps(
+                datetime.timedelta.max,
+                option=orjson.OPT_SERIALIZE_TIMEDELTA | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'"P999999999DT23H59M59.999999S"'
+        )
+        assert (
+            orjson.dumps(
+                datetime.timedelta.min,
+                option=orjson.OPT_SERIALIZE_TIMEDELTA | orjson.OPT_TIMEDELTA_ISO8601,
+            )
+            == b'"-P999999999D"'
+        )
+
+    def test_timedelta_nested(self):
+        """
+        A timedelta in a list and dict, with OPT_INDENT_2
+        """
+        val = {"a": [datetime.timedelta(seconds=1)]}
+        assert (
+            orjson.dumps(val, option=orjson.OPT_SERIALIZE_TIMEDELTA)
+            == b'{"a":[1.0]}'
+        )
+        assert (
+            orjson.dumps(
+                val,
+                option=orjson.OPT_SERIALIZE_TIMEDELTA
+                | orjson.OPT_TIMEDELTA_ISO8601
+                | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "a": [\n    "PT1S"\n  ]\n}'
+        )
+
+    def test_timedelta_subclass(self):
+        """
+        OPT_SERIALIZE_TIMEDELTA does not apply to a subclass
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SubTimedelta(1), option=orjson.OPT_SERIALIZE_TIMEDELTA)
+        assert (
+            orjson.dumps(
+                SubTimedelta(1),
+                option=orjson.OPT_SERIALIZE_TIMEDELTA,
+                default=lambda obj: "default",
+            )
+            == b'"default"'
+        )
+
+    def test_timedelta_dict_key(self):
+        """
+        A timedelta is not a valid dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {datetime.timedelta(1): 1},
+                option=orjson.OPT_SERIALIZE_TIMEDELTA | orjson.OPT_NON_STR_KEYS,
+            )
@@ -0,0 +1,262 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import enum
+import functools
+import os.path
+import types
+
+import pytest
+
+import orjson
+
+
+FUNCTION = f"{__name__}.function"
+
+
+def describe(obj):
+    return {"__callable__": orjson.qualname(obj)}
+
+
+def function(val):
+    return val
+
+
+class Custom:
+    def method(self):
+        pass
+
+
+class SubDict(dict):
+    pass
+
+
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+
+class Shape(enum.Enum):
+    ORIGIN = Point(0, 0)
+    UNIT = (Point(1, 0), Point(0, 1))
+
+
+def point(obj):
+    return [obj.x, obj.y]
+
+
+CALLABLE_HANDLERS = {
+    types.FunctionType: describe,
+    types.BuiltinFunctionType: describe,
+    functools.partial: describe,
+}
+
+
+class TestTypeHandlers:
+    def test_type_handlers_callables(self):
+        """
+        type_handlers serializes a function, builtin function, and partial
+        """
+        encoder = orjson.Encoder(type_handlers=CALLABLE_HANDLERS)
+        assert orjson.loads(
+            encoder.encode([function, len, functools.partial(function, 1)])
+        ) == [
+            {"__callable__": FUNCTION},
+            {"__callable__": "builtins.len"},
+            {"__callable__": FUNCTION},
+        ]
+
+    def test_type_handlers_nested(self):
+        """
+        type_handlers applies in a dict and to the value returned by a handler
+        """
+        encoder = orjson.Encoder(
+            type_handlers={
+                functools.partial: lambda obj: [obj.func, obj.args],
+                types.FunctionType: describe,
+            }
+        )
+        assert orjson.loads(encoder.encode({"a": functools.partial(function, 1)})) == {
+            "a": [{"__callable__": FUNCTION}, [1]]
+        }
+
+    def test_type_handlers_exact_type(self):
+        """
+        type_handlers does not apply to a subclass
+        """
+        encoder = orjson.Encoder(type_handlers={dict: lambda obj: "dict"})
+        assert encoder.encode(SubDict(a=1)) == b'{"a":1}'
+
+    def test_type_handlers_native_type(self):
+        """
+        type_handlers does not apply to a type that is serialized natively
+        """
+        encoder = orjson.Encoder(
+            type_handlers={int: lambda obj: "int", str: lambda obj: "str"}
+        )
+        assert encoder.encode([1, "a"]) == b'[1,"a"]'
+
+    def test_type_handlers_precedence(self):
+        """
+        type_handlers takes precedence over __json__ and default
+        """
+
+        class WithJson:
+            def __json__(self):
+                return "__json__"
+
+        encoder = orjson.Encoder(
+            default=lambda obj: "default",
+            type_handlers={WithJson: lambda obj: "handler"},
+        )
+        assert encoder.encode([WithJson(), Custom()]) == b'["handler","default"]'
+
+    def test_type_handlers_not_registered(self):
+        """
+        A type that is not in type_handlers raises without default
+        """
+        encoder = orjson.Encoder(type_handlers=CALLABLE_HANDLERS)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode([Custom().method])
+        assert str(exc_info.value) == "Type is not JSON serializable: method"
+        assert exc_info.value.path == [0]
+
+    def test_type_handlers_raises(self):
+        """
+        An exception raised by a handler is the __cause__ of JSONEncodeError
+        """
+
+        def handler(obj):
+            raise ValueError("invalid")
+
+        encoder = orjson.Encoder(type_handlers={types.FunctionType: handler})
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode({"a": function})
+        assert (
+            str(exc_info.value)
+            == "type_handlers callable raised an exception: function"
+        )
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.path == ["a"]
+
+    def test_type_handlers_recursion(self):
+        """
+        A handler returning an instance of its own type raises
+        """
+        encoder = orjson.Encoder(type_handlers={Custom: lambda obj: obj})
+        with pytest.raises(orjson.JSONEncodeError):
+            encoder.encode(Custom())
+
+    def test_type_handlers_empty(self):
+        """
+        type_handlers of None or an empty dict has no effect
+        """
+        for val in (None, {}):
+            encoder = orjson.Encoder(type_handlers=val)
+            with pytest.raises(orjson.JSONEncodeError):
+                encoder.encode(function)
+
+    def test_type_handlers_invalid(self):
+        """
+        type_handlers that is not a dict of type to callable raises
+        """
+        for val in ([], {"function": describe}, {types.FunctionType: "describe"}):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.Encoder(type_handlers=val)
+            assert (
+                str(exc_info.value)
+                == "type_handlers must be a dict of type to callable"
+            )
+
+    def test_type_handlers_copied(self):
+        """
+        type_handlers is copied when the Encoder is created
+        """
+        handlers = {types.FunctionType: describe}
+        encoder = orjson.Encoder(type_handlers=handlers)
+        handlers.clear()
+        assert orjson.loads(encoder.encode(function)) == {"__callable__": FUNCTION}
+
+    def test_type_handlers_enum_value(self):
+        """
+        type_handlers applies to the value of an enum member
+        """
+        encoder = orjson.Encoder(type_handlers={Point: point})
+        assert encoder.encode(Shape.ORIGIN) == b"[0,0]"
+        assert encoder.encode({"a": Shape.UNIT}) == b'{"a":[[1,0],[0,1]]}'
+
+    def test_type_handlers_enum_value_not_registered(self):
+        """
+        The value of an enum member is passed to default if its type is not in
+        type_handlers
+        """
+        encoder = orjson.Encoder(
+            default=lambda obj: "default", type_handlers={SubDict: point}
+        )
+        assert encoder.encode(Shape.ORIGIN) == b'"default"'
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(type_handlers={SubDict: point}).encode(Shape.ORIGIN)
+
+    def test_type_handlers_enum_value_raises(self):
+        """
+        An exception raised by a handler of the value of an enum member
+        """
+
+        def handler(obj):
+            raise ValueError("invalid")
+
+        encoder = orjson.Encoder(type_handlers={Point: handler})
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            encoder.encode([Shape.ORIGIN])
+        assert (
+            str(exc_info.value) == "type_handlers callable raised an exception: Point"
+        )
+        assert isinstance(exc_info.value.__cause__, ValueError)
+        assert exc_info.value.path == [0]
+
+    def test_type_handlers_enum_type(self):
+        """
+        type_handlers does not apply to an enum, which is serialized natively,
+        unless OPT_PASSTHROUGH_ENUM is set
+        """
+        handlers = {Shape: lambda obj: obj.name, Point: point}
+        assert orjson.Encoder(type_handlers=handlers).encode(Shape.ORIGIN) == b"[0,0]"
+        assert (
+            orjson.Encoder(
+                option=orjson.OPT_PASSTHROUGH_ENUM, type_handlers=handlers
+            ).encode(Shape.ORIGIN)
+            == b'"ORIGIN"'
+        )
+
+
+class TestQualname:
+    def test_qualname(self):
+        """
+        qualname() returns the module and qualified name
+        """
+        assert orjson.qualname(function) == FUNCTION
+        assert orjson.qualname(Custom) == f"{__name__}.Custom"
+        assert orjson.qualname(Custom.method) == f"{__name__}.Custom.method"
+        assert orjson.qualname(len) == "builtins.len"
+        assert orjson.qualname(os.path.join) == f"{os.path.__name__}.join"
+
+    def test_qualname_partial(self):
+        """
+        qualname() unwraps functools.partial
+        """
+        obj = functools.partial(functools.partial(function, 1))
+        assert orjson.qualname(obj) == FUNCTION
+
+    def test_qualname_method_descriptor(self):
+        """
+        qualname() returns only the qualified name if __module__ is not a str
+        """
+        assert orjson.qualname(list.append) == "list.append"
+
+    def test_qualname_invalid(self):
+        """
+        qualname() raises AttributeError without __qualname__
+        """
+        with pytest.raises(AttributeError):
+            orjson.qualname(1)
@@ -100,3 +100,96 @@ class TestUUID:
         )
         for val in uuids:
             assert orjson.dumps(val) == f'"{val}"'.encode("utf-8")
+
+    def test_uuid_hex(self):
+        val = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert (
+            orjson.dumps(val, option=orjson.OPT_UUID_HEX)
+            == b'"7202d1157ff34c81a7c12a1f067b1ece"'
+        )
+        assert orjson.dumps(val, option=orjson.OPT_UUID_HEX) == (
+            f'"{val.hex}"'.encode("utf-8")
+        )
+
+    def test_uuid_urn(self):
+        val = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert (
+            orjson.dumps(val, option=orjson.OPT_UUID_URN)
+            == b'"urn:uuid:7202d115-7ff3-4c81-a7c1-2a1f067b1ece"'
+        )
+        assert orjson.dumps(val, option=orjson.OPT_UUID_URN) == (
+            f'"{val.urn}"'.encode("utf-8")
+        )
+
+    def test_uuid_braced(self):
+        val = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert (
+            orjson.dumps(val, option=orjson.OPT_UUID_BRACED)
+            == b'"{7202d115-7ff3-4c81-a7c1-2a1f067b1ece}"'
+        )
+        assert orjson.dumps(val, option=orjson.OPT_UUID_BRACED) == (
+            f'"{{{val}}}"'.encode("utf-8")
+        )
+
+    def test_uuid_format_leading_zeroes(self):
+        val = uuid.UUID(int=1)
+        assert orjson.dumps(val, option=orjson.OPT_UUID_HEX) == (
+            b'"00000000000000000000000000000001"'
+        )
+        assert orjson.dumps(val, option=orjson.OPT_UUID_URN) == (
+            b'"urn:uuid:00000000-0000-0000-0000-000000000001"'
+        )
+        assert orjson.dumps(val, option=orjson.OPT_UUID_BRACED) == (
+            b'"{00000000-0000-0000-0000-000000000001}"'
+        )
+
+    def test_uuid_format_container(self):
+        val = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert (
+            orjson.dumps({"a": [val, (val,)]}, option=orjson.OPT_UUID_HEX)
+            == b'{"a":["7202d1157ff34c81a7c12a1f067b1ece",["7202d1157ff34c81a7c12a1f067b1ece"]]}'
+        )
+        assert (
+            orjson.dumps({"a": [val, (val,)]}, option=orjson.OPT_UUID_BRACED)
+            == b'{"a":["{7202d115-7ff3-4c81-a7c1-2a1f067b1ece}",["{7202d115-7ff3-4c81-a7c1-2a1f067b1ece}"]]}'
+        )
+
+    def test_uuid_format_dict_key(self):
+        val = uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece")
+        assert (
+            orjson.dumps(
+                {val: True},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UUID_HEX,
+            )
+            == b'{"7202d1157ff34c81a7c12a1f067b1ece":true}'
+        )
+        assert (
+            orjson.dumps(
+                {val: True},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UUID_URN,
+            )
+            == b'{"urn:uuid:7202d115-7ff3-4c81-a7c1-2a1f067b1ece":true}'
+        )
+        assert (
+            orjson.dumps(
+                {val: True},
+                option=orjson.OPT_NON_STR_KEYS | orjson.OPT_UUID_BRACED,
+            )
+            == b'{"{7202d115-7ff3-4c81-a7c1-2a1f067b1ece}":true}'
+        )
+
+    def test_uuid_format_exclusive(self):
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                uuid.uuid4(),
+                option=orjson.OPT_UUID_HEX | orjson.OPT_UUID_URN,
+            )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.Encoder(option=orjson.OPT_UUID_HEX | orjson.OPT_UUID_URN)
+        for opt in (
+            orjson.OPT_UUID_BRACED | orjson.OPT_UUID_HEX,
+            orjson.OPT_UUID_BRACED | orjson.OPT_UUID_URN,
+            orjson.OPT_UUID_BRACED | orjson.OPT_UUID_HEX | orjson.OPT_UUID_URN,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(uuid.uuid4(), option=opt)
@@ -0,0 +1,149 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import datetime
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Member:
+    name: str
+    joined: object
+
+
+class Unsupported:
+    pass
+
+
+class TestValidateOnly:
+    def test_validate_only_valid(self):
+        """
+        dumps() validate_only=True returns None for a serializable object
+        """
+        obj = {
+            "a": [1, 2.5, None, True, "b"],
+            "c": datetime.date(2026, 1, 1),
+            "d": Member("x", datetime.datetime(2026, 1, 1)),
+        }
+        assert orjson.dumps(obj, validate_only=True) is None
+
+    def test_validate_only_false(self):
+        """
+        dumps() validate_only=False is the same as not specifying it
+        """
+        assert orjson.dumps([1], validate_only=False) == b"[1]"
+
+    def test_validate_only_path(self):
+        """
+        dumps() validate_only=True raises with the path to the invalid value
+        """
+        obj = {"a": [1, {"b": [Unsupported()]}]}
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, validate_only=True)
+        assert str(exc_info.value) == "Type is not JSON serializable: Unsupported"
+        assert exc_info.value.path == ["a", 1, "b", 0]
+
+    def test_validate_only_same_error(self):
+        """
+        dumps() validate_only=True raises the same error as dumps()
+        """
+        for obj in (
+            {"a": Unsupported()},
+            [{"a": 1}, {"b": [1, 2**64]}],
+            {"a": {1: 2}},
+            [Member("x", {"y": "\ud800"})],
+        ):
+            with pytest.raises(orjson.JSONEncodeError) as expected:
+                orjson.dumps(obj)
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps(obj, validate_only=True)
+            assert str(exc_info.value) == str(expected.value)
+            assert exc_info.value.path == expected.value.path
+
+    def test_validate_only_first_error(self):
+        """
+        dumps() validate_only=True stops at the first invalid value
+        """
+        calls = []
+
+        def default(obj):
+            calls.append(obj)
+            raise TypeError
+
+        items = [Unsupported(), Unsupported()]
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([0, items], default=default, validate_only=True)
+        assert exc_info.value.path == [1, 0]
+        assert calls == [items[0]]
+
+    def test_validate_only_default(self):
+        """
+        dumps() validate_only=True calls default and validates its result
+        """
+
+        def default(obj):
+            if isinstance(obj, Unsupported):
+                return {"c": [Unsupported]}
+            raise TypeError
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps({"a": Unsupported()}, default=default, validate_only=True)
+        assert exc_info.value.path == ["a", "c", 0]
+
+    def test_validate_only_option(self):
+        """
+        dumps() validate_only=True applies option
+        """
+        obj = {1: datetime.time(1, tzinfo=datetime.timezone.utc)}
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, validate_only=True)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_NON_STR_KEYS, validate_only=True)
+            is None
+        )
+
+    def test_validate_only_circular(self):
+        """
+        dumps() validate_only=True raises on a circular reference
+        """
+        obj: dict = {"a": []}
+        obj["a"].append(obj)
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(obj, validate_only=True)
+        assert str(exc_info.value) == "Recursion limit reached at $" + "['a'][0]" * 127
+
+    def test_validate_only_max_output_size(self):
+        """
+        dumps() validate_only=True does not produce output, so it does not
+        apply max_output_size
+        """
+        assert orjson.dumps([1] * 100, max_output_size=5, validate_only=True) is None
+
+    def test_validate_only_key_transform(self):
+        """
+        dumps() validate_only=True calls key_transform
+        """
+
+        def key_transform(key):
+            if key == "b":
+                return 1
+            return key
+
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(
+                {"a": {"b": 1}}, key_transform=key_transform, validate_only=True
+            )
+        assert exc_info.value.path == ["a"]
+
+    def test_validate_only_invalid(self):
+        """
+        dumps() validate_only that is not a bool raises
+        """
+        for val in (None, 1, 0, "true"):
+            with pytest.raises(orjson.JSONEncodeError) as exc_info:
+                orjson.dumps([], validate_only=val)  # type: ignore
+            assert str(exc_info.value) == "validate_only must be a bool"
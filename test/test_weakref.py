@@ -0,0 +1,154 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import gc
+import weakref
+
+import pytest
+
+import orjson
+
+
+class Value:
+    def __init__(self, value):
+        self.value = value
+
+    def __call__(self):
+        return self.value
+
+
+class SubDict(dict):
+    pass
+
+
+@dataclasses.dataclass
+class Data:
+    a: int
+
+
+class TestWeakrefProxy:
+    def test_weakref_proxy(self):
+        """
+        OPT_SERIALIZE_WEAKREF serializes the referent of a weakref.proxy
+        """
+        obj = SubDict(a=[1, 2])
+        assert (
+            orjson.dumps(weakref.proxy(obj), option=orjson.OPT_SERIALIZE_WEAKREF)
+            == b'{"a":[1,2]}'
+        )
+
+    def test_weakref_proxy_types(self):
+        """
+        A proxy is serialized as its referent would be
+        """
+        data = Data(1)
+        obj = {1}
+        assert (
+            orjson.dumps(weakref.proxy(data), option=orjson.OPT_SERIALIZE_WEAKREF)
+            == b'{"a":1}'
+        )
+        assert (
+            orjson.dumps(
+                weakref.proxy(obj), option=orjson.OPT_SERIALIZE_WEAKREF, default=sorted
+            )
+            == b"[1]"
+        )
+
+    def test_weakref_proxy_callable(self):
+        """
+        A proxy of a callable object is serialized
+        """
+        obj = Value(1)
+        proxy = weakref.proxy(obj)
+        assert type(proxy) is weakref.CallableProxyType
+        assert (
+            orjson.dumps(
+                proxy,
+                option=orjson.OPT_SERIALIZE_WEAKREF,
+                default=lambda val: val.value,
+            )
+            == b"1"
+        )
+
+    def test_weakref_proxy_nested(self):
+        """
+        A proxy in a list and dict
+        """
+        obj = SubDict(a=1)
+        proxy = weakref.proxy(obj)
+        assert (
+            orjson.dumps([proxy, {"b": proxy}], option=orjson.OPT_SERIALIZE_WEAKREF)
+            == b'[{"a":1},{"b":{"a":1}}]'
+        )
+
+    def test_weakref_proxy_options(self):
+        """
+        Options apply to the referent
+        """
+        obj = SubDict(b=1, a=2)
+        assert (
+            orjson.dumps(
+                weakref.proxy(obj),
+                option=orjson.OPT_SERIALIZE_WEAKREF | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"a":2,"b":1}'
+        )
+
+    def test_weakref_proxy_dead(self):
+        """
+        A proxy whose referent was garbage collected raises JSONEncodeError
+        """
+        obj = Value(1)
+        proxy = weakref.proxy(obj)
+        del obj
+        gc.collect()
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([proxy], option=orjson.OPT_SERIALIZE_WEAKREF)
+        assert str(exc_info.value) == "weakref.proxy referent no longer exists"
+        assert exc_info.value.path == [0]
+
+    def test_weakref_proxy_default(self):
+        """
+        A referent that is not serialized is passed to default
+        """
+        obj = Value(1)
+        assert (
+            orjson.dumps(
+                weakref.proxy(obj),
+                option=orjson.OPT_SERIALIZE_WEAKREF,
+                default=lambda val: val.value,
+            )
+            == b"1"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(weakref.proxy(obj), option=orjson.OPT_SERIALIZE_WEAKREF)
+
+    def test_weakref_proxy_iterators(self):
+        """
+        A proxy is serialized as its referent with OPT_SERIALIZE_ITERATORS
+        """
+        obj = SubDict(a=1)
+        assert (
+            orjson.dumps(
+                weakref.proxy(obj),
+                option=orjson.OPT_SERIALIZE_WEAKREF | orjson.OPT_SERIALIZE_ITERATORS,
+            )
+            == b'{"a":1}'
+        )
+
+    def test_weakref_ref(self):
+        """
+        A weakref.ref is not serialized
+        """
+        obj = Value(1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(weakref.ref(obj), option=orjson.OPT_SERIALIZE_WEAKREF)
+
+    def test_weakref_proxy_not_enabled(self):
+        """
+        A proxy is not serialized without OPT_SERIALIZE_WEAKREF
+        """
+        obj = SubDict(a=1)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(weakref.proxy(obj))